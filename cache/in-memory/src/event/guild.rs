@@ -56,6 +56,13 @@ impl InMemoryCache {
             self.cache_stage_instances(guild.id, guild.stage_instances);
         }
 
+        if self.wants(ResourceType::SCHEDULED_EVENT) {
+            self.0
+                .guild_scheduled_events
+                .insert(guild.id, HashSet::new());
+            self.cache_scheduled_events(guild.id, guild.guild_scheduled_events);
+        }
+
         let guild = CachedGuild {
             id: guild.id,
             afk_channel_id: guild.afk_channel_id,
@@ -142,6 +149,14 @@ impl UpdateCache for GuildDelete {
             remove_ids(&cache.0.guild_roles, &cache.0.roles, id);
         }
 
+        if cache.wants(ResourceType::SCHEDULED_EVENT) {
+            remove_ids(
+                &cache.0.guild_scheduled_events,
+                &cache.0.scheduled_events,
+                id,
+            );
+        }
+
         if cache.wants(ResourceType::VOICE_STATE) {
             // Clear out a guilds voice states when a guild leaves
             cache.0.voice_state_guilds.remove(&id);
@@ -276,6 +291,7 @@ mod tests {
             emojis: Vec::new(),
             explicit_content_filter: ExplicitContentFilter::AllMembers,
             features: vec![],
+            guild_scheduled_events: Vec::new(),
             icon: None,
             joined_at: Some("".to_owned()),
             large: false,
@@ -353,6 +369,7 @@ mod tests {
             emojis: Vec::new(),
             explicit_content_filter: ExplicitContentFilter::None,
             features: Vec::new(),
+            guild_scheduled_events: Vec::new(),
             icon: None,
             id: GuildId(1),
             joined_at: None,
@@ -188,8 +188,10 @@ mod tests {
                     }),
                 },
                 guild_id: Some(GuildId(3)),
+                guild_locale: None,
                 id: InteractionId(4),
                 kind: InteractionType::ApplicationCommand,
+                locale: None,
                 member: Some(PartialMember {
                     deaf: false,
                     joined_at: Some("joined at".into()),
@@ -8,6 +8,7 @@ pub mod message;
 pub mod presence;
 pub mod reaction;
 pub mod role;
+pub mod scheduled_event;
 pub mod stage_instance;
 pub mod thread;
 pub mod voice_state;
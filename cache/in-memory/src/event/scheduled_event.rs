@@ -0,0 +1,215 @@
+use crate::{config::ResourceType, InMemoryCache, UpdateCache};
+use twilight_model::{
+    gateway::payload::{
+        GuildScheduledEventCreate, GuildScheduledEventDelete, GuildScheduledEventUpdate,
+        GuildScheduledEventUserAdd, GuildScheduledEventUserRemove,
+    },
+    id::{GuildId, ScheduledEventId},
+    scheduled_event::GuildScheduledEvent,
+};
+
+impl InMemoryCache {
+    pub(crate) fn cache_scheduled_events(
+        &self,
+        guild_id: GuildId,
+        scheduled_events: impl IntoIterator<Item = GuildScheduledEvent>,
+    ) {
+        for scheduled_event in scheduled_events {
+            self.cache_scheduled_event(guild_id, scheduled_event);
+        }
+    }
+
+    fn cache_scheduled_event(&self, guild_id: GuildId, scheduled_event: GuildScheduledEvent) {
+        self.0
+            .guild_scheduled_events
+            .entry(guild_id)
+            .or_default()
+            .insert(scheduled_event.id);
+
+        crate::upsert_guild_item(
+            &self.0.scheduled_events,
+            guild_id,
+            scheduled_event.id,
+            scheduled_event,
+        );
+    }
+
+    fn delete_scheduled_event(&self, scheduled_event_id: ScheduledEventId) {
+        if let Some((_, data)) = self.0.scheduled_events.remove(&scheduled_event_id) {
+            let guild_id = data.guild_id;
+
+            if let Some(mut scheduled_events) = self.0.guild_scheduled_events.get_mut(&guild_id) {
+                scheduled_events.remove(&scheduled_event_id);
+            }
+        }
+    }
+
+    /// Adjust the number of users subscribed to a scheduled event, if the
+    /// count is known.
+    fn update_scheduled_event_user_count(
+        &self,
+        scheduled_event_id: ScheduledEventId,
+        update: impl FnOnce(u64) -> u64,
+    ) {
+        if let Some(mut item) = self.0.scheduled_events.get_mut(&scheduled_event_id) {
+            if let Some(user_count) = item.data.user_count.as_mut() {
+                *user_count = update(*user_count);
+            }
+        }
+    }
+}
+
+impl UpdateCache for GuildScheduledEventCreate {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::SCHEDULED_EVENT) {
+            return;
+        }
+
+        cache.cache_scheduled_event(self.guild_id, self.0.clone());
+    }
+}
+
+impl UpdateCache for GuildScheduledEventDelete {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::SCHEDULED_EVENT) {
+            return;
+        }
+
+        cache.delete_scheduled_event(self.id);
+    }
+}
+
+impl UpdateCache for GuildScheduledEventUpdate {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::SCHEDULED_EVENT) {
+            return;
+        }
+
+        cache.cache_scheduled_event(self.guild_id, self.0.clone());
+    }
+}
+
+impl UpdateCache for GuildScheduledEventUserAdd {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::SCHEDULED_EVENT) {
+            return;
+        }
+
+        cache.update_scheduled_event_user_count(self.guild_scheduled_event_id, |count| {
+            count.saturating_add(1)
+        });
+    }
+}
+
+impl UpdateCache for GuildScheduledEventUserRemove {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::SCHEDULED_EVENT) {
+            return;
+        }
+
+        cache.update_scheduled_event_user_count(self.guild_scheduled_event_id, |count| {
+            count.saturating_sub(1)
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use twilight_model::{
+        id::{ChannelId, UserId},
+        scheduled_event::{EntityType, PrivacyLevel, Status},
+    };
+
+    fn scheduled_event() -> GuildScheduledEvent {
+        GuildScheduledEvent {
+            channel_id: Some(ChannelId(1)),
+            creator: None,
+            creator_id: None,
+            description: None,
+            entity_id: None,
+            entity_metadata: None,
+            entity_type: EntityType::Voice,
+            guild_id: GuildId(2),
+            id: ScheduledEventId(3),
+            image: None,
+            name: "name".into(),
+            privacy_level: PrivacyLevel::GuildOnly,
+            recurrence_rule: None,
+            scheduled_end_time: None,
+            scheduled_start_time: "2021-01-01T00:00:00+00:00".into(),
+            status: Status::Scheduled,
+            user_count: Some(1),
+        }
+    }
+
+    #[test]
+    fn test_scheduled_events() {
+        let cache = InMemoryCache::new();
+        let scheduled_event = scheduled_event();
+
+        cache.update(&GuildScheduledEventCreate(scheduled_event.clone()));
+
+        {
+            let cached_events = cache
+                .guild_scheduled_events(scheduled_event.guild_id)
+                .unwrap();
+            assert_eq!(1, cached_events.len());
+        }
+
+        let new_scheduled_event = GuildScheduledEvent {
+            name: "a new name".into(),
+            ..scheduled_event.clone()
+        };
+
+        cache.update(&GuildScheduledEventUpdate(new_scheduled_event.clone()));
+
+        {
+            let cached_event = cache.scheduled_event(scheduled_event.id).unwrap();
+            assert_eq!(new_scheduled_event.name, cached_event.name);
+        }
+
+        cache.update(&GuildScheduledEventDelete(new_scheduled_event));
+
+        {
+            let cached_events = cache
+                .guild_scheduled_events(scheduled_event.guild_id)
+                .unwrap();
+            assert!(cached_events.is_empty());
+            assert!(cache.scheduled_event(scheduled_event.id).is_none());
+        }
+    }
+
+    #[test]
+    fn test_scheduled_event_users() {
+        let cache = InMemoryCache::new();
+        let scheduled_event = scheduled_event();
+        cache.update(&GuildScheduledEventCreate(scheduled_event.clone()));
+
+        cache.update(&GuildScheduledEventUserAdd {
+            guild_id: scheduled_event.guild_id,
+            guild_scheduled_event_id: scheduled_event.id,
+            user_id: UserId(4),
+        });
+        assert_eq!(
+            Some(2),
+            cache
+                .scheduled_event(scheduled_event.id)
+                .unwrap()
+                .user_count
+        );
+
+        cache.update(&GuildScheduledEventUserRemove {
+            guild_id: scheduled_event.guild_id,
+            guild_scheduled_event_id: scheduled_event.id,
+            user_id: UserId(4),
+        });
+        assert_eq!(
+            Some(1),
+            cache
+                .scheduled_event(scheduled_event.id)
+                .unwrap()
+                .user_count
+        );
+    }
+}
@@ -106,7 +106,11 @@ use twilight_model::{
     channel::{Group, GuildChannel, PrivateChannel, StageInstance},
     gateway::event::Event,
     guild::{GuildIntegration, Role},
-    id::{ChannelId, EmojiId, GuildId, IntegrationId, MessageId, RoleId, StageId, UserId},
+    id::{
+        ChannelId, EmojiId, GuildId, IntegrationId, MessageId, RoleId, ScheduledEventId, StageId,
+        UserId,
+    },
+    scheduled_event::GuildScheduledEvent,
     user::{CurrentUser, User},
     voice::VoiceState,
 };
@@ -162,12 +166,14 @@ struct InMemoryCacheRef {
     guild_members: DashMap<GuildId, HashSet<UserId>>,
     guild_presences: DashMap<GuildId, HashSet<UserId>>,
     guild_roles: DashMap<GuildId, HashSet<RoleId>>,
+    guild_scheduled_events: DashMap<GuildId, HashSet<ScheduledEventId>>,
     guild_stage_instances: DashMap<GuildId, HashSet<StageId>>,
     integrations: DashMap<(GuildId, IntegrationId), GuildItem<GuildIntegration>>,
     members: DashMap<(GuildId, UserId), CachedMember>,
     messages: DashMap<ChannelId, VecDeque<CachedMessage>>,
     presences: DashMap<(GuildId, UserId), CachedPresence>,
     roles: DashMap<RoleId, GuildItem<Role>>,
+    scheduled_events: DashMap<ScheduledEventId, GuildItem<GuildScheduledEvent>>,
     stage_instances: DashMap<StageId, GuildItem<StageInstance>>,
     unavailable_guilds: DashSet<GuildId>,
     users: DashMap<UserId, User>,
@@ -264,12 +270,14 @@ impl InMemoryCache {
         self.0.guild_members.clear();
         self.0.guild_presences.clear();
         self.0.guild_roles.clear();
+        self.0.guild_scheduled_events.clear();
         self.0.guild_stage_instances.clear();
         self.0.integrations.clear();
         self.0.members.clear();
         self.0.messages.clear();
         self.0.presences.clear();
         self.0.roles.clear();
+        self.0.scheduled_events.clear();
         self.0.unavailable_guilds.clear();
         self.0.users.clear();
         self.0.voice_state_channels.clear();
@@ -456,6 +464,20 @@ impl InMemoryCache {
         self.0.guild_roles.get(&guild_id).map(|r| r.clone())
     }
 
+    /// Gets the set of scheduled events in a guild.
+    ///
+    /// This is a O(m) operation, where m is the amount of scheduled events in
+    /// the guild. This requires the [`GUILD_SCHEDULED_EVENTS`] intent. The
+    /// [`ResourceType::SCHEDULED_EVENT`] resource type must be enabled.
+    ///
+    /// [`GUILD_SCHEDULED_EVENTS`]: twilight_model::gateway::Intents::GUILD_SCHEDULED_EVENTS
+    pub fn guild_scheduled_events(&self, guild_id: GuildId) -> Option<HashSet<ScheduledEventId>> {
+        self.0
+            .guild_scheduled_events
+            .get(&guild_id)
+            .map(|r| r.value().clone())
+    }
+
     /// Gets the set of stage instances in a guild.
     ///
     /// This is a O(m) operation, where m is the amount of stage instances in
@@ -538,6 +560,23 @@ impl InMemoryCache {
         self.0.roles.get(&role_id).map(|r| r.data.clone())
     }
 
+    /// Gets a scheduled event by ID.
+    ///
+    /// This is an O(1) operation. This requires the [`GUILD_SCHEDULED_EVENTS`]
+    /// intent. The [`ResourceType::SCHEDULED_EVENT`] resource type must be
+    /// enabled.
+    ///
+    /// [`GUILD_SCHEDULED_EVENTS`]: twilight_model::gateway::Intents::GUILD_SCHEDULED_EVENTS
+    pub fn scheduled_event(
+        &self,
+        scheduled_event_id: ScheduledEventId,
+    ) -> Option<GuildScheduledEvent> {
+        self.0
+            .scheduled_events
+            .get(&scheduled_event_id)
+            .map(|r| r.data.clone())
+    }
+
     /// Gets a stage instance by ID.
     ///
     /// This is an O(1) operation. This requires the [`GUILDS`] intent.
@@ -663,6 +702,11 @@ impl UpdateCache for Event {
             GuildDelete(v) => c.update(v.deref()),
             GuildEmojisUpdate(v) => c.update(v),
             GuildIntegrationsUpdate(_) => {}
+            GuildScheduledEventCreate(v) => c.update(v.deref()),
+            GuildScheduledEventDelete(v) => c.update(v.deref()),
+            GuildScheduledEventUpdate(v) => c.update(v.deref()),
+            GuildScheduledEventUserAdd(v) => c.update(v),
+            GuildScheduledEventUserRemove(v) => c.update(v),
             GuildUpdate(v) => c.update(v.deref()),
             IntegrationCreate(v) => c.update(v.deref()),
             IntegrationDelete(v) => c.update(v.deref()),
@@ -5,7 +5,7 @@ use super::{
     scheme::ShardScheme,
 };
 use crate::{
-    shard::{LargeThresholdError, ResumeSession, ShardBuilder},
+    shard::{EventQueuePolicy, LargeThresholdError, ResumeSession, ShardBuilder},
     EventTypeFlags,
 };
 use std::{collections::HashMap, sync::Arc};
@@ -95,6 +95,20 @@ impl ClusterBuilder {
         Cluster::new_with_config(self.0).await
     }
 
+    /// Bound the number of events queued for the event stream of each shard,
+    /// and set what to do with events when a shard's queue is full.
+    ///
+    /// Monitor the queues via [`Cluster::event_queues`]. Refer to
+    /// [`ShardBuilder::event_queue`] for more information.
+    ///
+    /// [`ShardBuilder::event_queue`]: crate::shard::ShardBuilder::event_queue
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn event_queue(mut self, capacity: usize, policy: EventQueuePolicy) -> Self {
+        self.1 = self.1.event_queue(capacity, policy);
+
+        self
+    }
+
     /// Set the event types to process.
     ///
     /// This is an optimization technique; all events not included in the
@@ -1,7 +1,10 @@
 use super::{builder::ClusterBuilder, config::Config, event::Events, scheme::ShardScheme};
 use crate::{
     cluster::event::ShardEventsWithId,
-    shard::{raw_message::Message, Information, ResumeSession, Shard},
+    shard::{
+        raw_message::Message, EventQueueStats, GuildMembers, Information, RequestMembersError,
+        RequestMembersErrorType, ResumeSession, Shard,
+    },
     Intents,
 };
 use futures_util::{future, stream::SelectAll};
@@ -11,8 +14,10 @@ use std::{
     fmt::{Display, Formatter, Result as FmtResult},
     iter::FromIterator,
     sync::Arc,
+    time::Duration,
 };
 use twilight_http::Client as HttpClient;
+use twilight_model::gateway::payload::RequestGuildMembers;
 
 /// Sending a command to a shard failed.
 #[derive(Debug)]
@@ -98,6 +103,98 @@ pub enum ClusterCommandErrorType {
     },
 }
 
+/// Requesting the members of a guild via a shard failed.
+#[derive(Debug)]
+pub struct ClusterRequestMembersError {
+    kind: ClusterRequestMembersErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl ClusterRequestMembersError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ClusterRequestMembersErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        ClusterRequestMembersErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
+
+    fn from_request_members(error: RequestMembersError) -> Self {
+        let (kind, source) = error.into_parts();
+
+        let new_kind = match kind {
+            RequestMembersErrorType::Interrupted => ClusterRequestMembersErrorType::Interrupted,
+            RequestMembersErrorType::Sending => ClusterRequestMembersErrorType::Sending,
+            RequestMembersErrorType::TimedOut => ClusterRequestMembersErrorType::TimedOut,
+        };
+
+        Self {
+            kind: new_kind,
+            source,
+        }
+    }
+}
+
+impl Display for ClusterRequestMembersError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            ClusterRequestMembersErrorType::Interrupted => {
+                f.write_str("the shard was shut down before all members were received")
+            }
+            ClusterRequestMembersErrorType::Sending => f.write_str("sending the request failed"),
+            ClusterRequestMembersErrorType::ShardNonexistent { id } => {
+                f.write_str("shard ")?;
+                Display::fmt(id, f)?;
+
+                f.write_str(" does not exist")
+            }
+            ClusterRequestMembersErrorType::TimedOut => {
+                f.write_str("not all members were received before the timeout")
+            }
+        }
+    }
+}
+
+impl Error for ClusterRequestMembersError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`ClusterRequestMembersError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ClusterRequestMembersErrorType {
+    /// Shard was shut down before all chunks of members were received.
+    Interrupted,
+    /// The shard exists, but sending the request failed.
+    Sending,
+    /// Provided shard ID does not exist.
+    ShardNonexistent {
+        /// Provided shard ID.
+        id: u64,
+    },
+    /// Not all chunks of members were received before the timeout.
+    TimedOut,
+}
+
 /// Sending a raw websocket message via a shard failed.
 #[derive(Debug)]
 pub struct ClusterSendError {
@@ -515,6 +612,18 @@ impl Cluster {
             .collect()
     }
 
+    /// Return the state of the event queue of each shard, such as its length
+    /// and the number of dropped events.
+    ///
+    /// Refer to [`ClusterBuilder::event_queue`] for bounding the queues.
+    pub fn event_queues(&self) -> HashMap<u64, EventQueueStats> {
+        self.0
+            .shards
+            .iter()
+            .map(|(id, shard)| (*id, shard.event_queue()))
+            .collect()
+    }
+
     /// Send a command to the specified shard.
     ///
     /// # Errors
@@ -559,6 +668,41 @@ impl Cluster {
             .map_err(ClusterCommandError::from_send)
     }
 
+    /// Request members of a guild via the specified shard and wait until all
+    /// of them are received.
+    ///
+    /// Refer to [`Shard::request_members`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ClusterRequestMembersErrorType::Interrupted`] error type if
+    /// the shard was shut down before all members were received.
+    ///
+    /// Returns a [`ClusterRequestMembersErrorType::Sending`] error type if the
+    /// shard exists, but sending the request failed.
+    ///
+    /// Returns a [`ClusterRequestMembersErrorType::ShardNonexistent`] error
+    /// type if the provided shard ID does not exist in the cluster.
+    ///
+    /// Returns a [`ClusterRequestMembersErrorType::TimedOut`] error type if
+    /// not all members were received before the timeout.
+    pub async fn request_members(
+        &self,
+        id: u64,
+        request: RequestGuildMembers,
+        timeout: Duration,
+    ) -> Result<GuildMembers, ClusterRequestMembersError> {
+        let shard = self.shard(id).ok_or(ClusterRequestMembersError {
+            kind: ClusterRequestMembersErrorType::ShardNonexistent { id },
+            source: None,
+        })?;
+
+        shard
+            .request_members(request, timeout)
+            .await
+            .map_err(ClusterRequestMembersError::from_request_members)
+    }
+
     /// Send a raw websocket message.
     ///
     /// # Examples
@@ -611,8 +755,9 @@ impl Cluster {
 #[cfg(test)]
 mod tests {
     use super::{
-        Cluster, ClusterCommandError, ClusterCommandErrorType, ClusterSendError,
-        ClusterSendErrorType, ClusterStartError, ClusterStartErrorType,
+        Cluster, ClusterCommandError, ClusterCommandErrorType, ClusterRequestMembersError,
+        ClusterRequestMembersErrorType, ClusterSendError, ClusterSendErrorType, ClusterStartError,
+        ClusterStartErrorType,
     };
     use static_assertions::{assert_fields, assert_impl_all};
     use std::{error::Error, fmt::Debug};
@@ -620,6 +765,9 @@ mod tests {
     assert_impl_all!(ClusterCommandErrorType: Debug, Send, Sync);
     assert_fields!(ClusterCommandErrorType::ShardNonexistent: id);
     assert_impl_all!(ClusterCommandError: Error, Send, Sync);
+    assert_impl_all!(ClusterRequestMembersErrorType: Debug, Send, Sync);
+    assert_fields!(ClusterRequestMembersErrorType::ShardNonexistent: id);
+    assert_impl_all!(ClusterRequestMembersError: Error, Send, Sync);
     assert_impl_all!(ClusterSendErrorType: Debug, Send, Sync);
     assert_fields!(ClusterSendErrorType::ShardNonexistent: id);
     assert_impl_all!(ClusterSendError: Error, Send, Sync);
@@ -5,7 +5,7 @@ use twilight_model::gateway::event::EventType;
 bitflags! {
     /// Bitflags representing all of the possible types of events.
     #[non_exhaustive]
-    pub struct EventTypeFlags: u64 {
+    pub struct EventTypeFlags: u128 {
         /// User has been banned from a guild.
         const BAN_ADD = 1;
         /// User has been unbanned from a guild.
@@ -41,6 +41,16 @@ bitflags! {
         const GUILD_EMOJIS_UPDATE = 1 << 12;
         /// A guild's integrations have been updated.
         const GUILD_INTEGRATIONS_UPDATE = 1 << 13;
+        /// A scheduled event has been created in a guild.
+        const GUILD_SCHEDULED_EVENT_CREATE = 1 << 64;
+        /// A scheduled event has been deleted from a guild.
+        const GUILD_SCHEDULED_EVENT_DELETE = 1 << 65;
+        /// A scheduled event in a guild has been updated.
+        const GUILD_SCHEDULED_EVENT_UPDATE = 1 << 66;
+        /// A user has subscribed to a scheduled event in a guild.
+        const GUILD_SCHEDULED_EVENT_USER_ADD = 1 << 67;
+        /// A user has unsubscribed from a scheduled event in a guild.
+        const GUILD_SCHEDULED_EVENT_USER_REMOVE = 1 << 68;
         /// A guild has been updated.
         const GUILD_UPDATE = 1 << 14;
         /// A guild integration was created.
@@ -257,6 +267,17 @@ impl EventTypeFlags {
     pub const GUILD_PRESENCES: EventTypeFlags =
         EventTypeFlags::from_bits_truncate(EventTypeFlags::PRESENCE_UPDATE.bits());
 
+    /// All [`EventTypeFlags`] in [`Intents::GUILD_SCHEDULED_EVENTS`].
+    ///
+    /// [`Intents::GUILD_SCHEDULED_EVENTS`]: crate::Intents::GUILD_SCHEDULED_EVENTS
+    pub const GUILD_SCHEDULED_EVENTS: EventTypeFlags = EventTypeFlags::from_bits_truncate(
+        EventTypeFlags::GUILD_SCHEDULED_EVENT_CREATE.bits()
+            | EventTypeFlags::GUILD_SCHEDULED_EVENT_DELETE.bits()
+            | EventTypeFlags::GUILD_SCHEDULED_EVENT_UPDATE.bits()
+            | EventTypeFlags::GUILD_SCHEDULED_EVENT_USER_ADD.bits()
+            | EventTypeFlags::GUILD_SCHEDULED_EVENT_USER_REMOVE.bits(),
+    );
+
     /// All [`EventTypeFlags`] in [`Intents::GUILD_VOICE_STATES`].
     ///
     /// [`Intents::GUILD_VOICE_STATES`]: crate::Intents::GUILD_VOICE_STATES
@@ -289,6 +310,13 @@ impl From<EventType> for EventTypeFlags {
             EventType::GuildDelete => EventTypeFlags::GUILD_DELETE,
             EventType::GuildEmojisUpdate => EventTypeFlags::GUILD_EMOJIS_UPDATE,
             EventType::GuildIntegrationsUpdate => EventTypeFlags::GUILD_INTEGRATIONS_UPDATE,
+            EventType::GuildScheduledEventCreate => EventTypeFlags::GUILD_SCHEDULED_EVENT_CREATE,
+            EventType::GuildScheduledEventDelete => EventTypeFlags::GUILD_SCHEDULED_EVENT_DELETE,
+            EventType::GuildScheduledEventUpdate => EventTypeFlags::GUILD_SCHEDULED_EVENT_UPDATE,
+            EventType::GuildScheduledEventUserAdd => EventTypeFlags::GUILD_SCHEDULED_EVENT_USER_ADD,
+            EventType::GuildScheduledEventUserRemove => {
+                EventTypeFlags::GUILD_SCHEDULED_EVENT_USER_REMOVE
+            }
             EventType::GuildUpdate => EventTypeFlags::GUILD_UPDATE,
             EventType::IntegrationCreate => EventTypeFlags::INTEGRATION_CREATE,
             EventType::IntegrationDelete => EventTypeFlags::INTEGRATION_DELETE,
@@ -44,6 +44,12 @@
 //! twilight-gateway = { default-features = false, features = ["rustls", "simd-json"], version = "0.2" }
 //! ```
 //!
+//! #### `etf`
+//!
+//! The `etf` feature makes shards connect with the [Erlang External Term Format]
+//! encoding instead of JSON, which produces smaller payloads. It is not enabled
+//! by default, and when enabled the `simd-json` feature has no effect.
+//!
 //! ### TLS
 //!
 //! `twilight-gateway` has features to enable [`async-tungstenite`] and
@@ -84,6 +90,16 @@
 //! Enabling **only** `zlib-simd` will make the library use [`zlib-ng`] which is a modern
 //! fork of zlib that is faster and more effective, but it needs `cmake` to compile.
 //!
+//! ### zstd
+//!
+//! The `zstd` feature makes shards request zstd-stream transport compression,
+//! decompressed with the [`zstd`] crate, which takes precedence over zlib. It is
+//! not enabled by default.
+//!
+//! Enabling `zstd` compiles the zlib inflater out entirely, even if
+//! `compression` is also enabled, so shards can't fall back to zlib-stream
+//! compression at runtime.
+//!
 //! ### Tracing
 //!
 //! The `tracing` feature enables logging via the [`tracing`] crate.
@@ -99,6 +115,7 @@
 //!
 //! This is disabled by default.
 //!
+//! [Erlang External Term Format]: https://erlang.org/doc/apps/erts/erl_ext_dist.html
 //! [`async-tungstenite`]: https://crates.io/crates/async-tungstenite
 //! [`native-tls`]: https://crates.io/crates/native-tls
 //! [`rustls`]: https://crates.io/crates/rustls
@@ -107,6 +124,7 @@
 //! [`tracing`]: https://crates.io/crates/tracing
 //! [`twilight-http`]: https://twilight-rs.github.io/twilight/twilight_http/index.html
 //! [`zlib-ng`]: https://github.com/zlib-ng/zlib-ng
+//! [`zstd`]: https://crates.io/crates/zstd
 //! [discord badge]: https://img.shields.io/discord/745809834183753828?color=%237289DA&label=discord%20server&logo=discord&style=for-the-badge
 //! [discord link]: https://discord.gg/7jj8n7D
 //! [docs:discord:sharding]: https://discord.com/developers/docs/topics/gateway#sharding
@@ -1,4 +1,4 @@
-use super::{config::Config, Events, Shard};
+use super::{config::Config, EventQueuePolicy, Events, Shard};
 use crate::EventTypeFlags;
 use std::{
     error::Error,
@@ -177,6 +177,8 @@ impl ShardBuilder {
         }
 
         Self(Config {
+            event_queue_capacity: None,
+            event_queue_policy: EventQueuePolicy::default(),
             event_types: EventTypeFlags::default(),
             gateway_url: None,
             http_client: HttpClient::new(token.clone()),
@@ -197,6 +199,33 @@ impl ShardBuilder {
         Shard::new_with_config(self.0)
     }
 
+    /// Bound the number of events queued for the event stream, and set what to
+    /// do with events when the queue is full.
+    ///
+    /// Monitor the queue via [`Shard::event_queue`]. A capacity of 0 is
+    /// treated as a capacity of 1.
+    ///
+    /// Defaults to an unbounded queue.
+    ///
+    /// # Examples
+    ///
+    /// Queue up to 1000 events, dropping the oldest events when the consumer
+    /// of the event stream falls behind:
+    ///
+    /// ```no_run
+    /// use twilight_gateway::{shard::EventQueuePolicy, Intents, Shard};
+    ///
+    /// let (shard, events) = Shard::builder("token", Intents::GUILDS)
+    ///     .event_queue(1000, EventQueuePolicy::DropOldest)
+    ///     .build();
+    /// ```
+    pub const fn event_queue(mut self, capacity: usize, policy: EventQueuePolicy) -> Self {
+        self.0.event_queue_capacity = Some(capacity);
+        self.0.event_queue_policy = policy;
+
+        self
+    }
+
     /// Set the event types to process.
     ///
     /// This is an optimization technique; all events not included in the
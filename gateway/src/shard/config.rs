@@ -1,3 +1,4 @@
+use super::EventQueuePolicy;
 use crate::EventTypeFlags;
 use std::sync::Arc;
 use twilight_gateway_queue::Queue;
@@ -15,6 +16,8 @@ use twilight_model::gateway::{
 /// [`Shard::builder`]: super::Shard::builder
 #[derive(Clone, Debug)]
 pub struct Config {
+    pub(super) event_queue_capacity: Option<usize>,
+    pub(super) event_queue_policy: EventQueuePolicy,
     pub(crate) event_types: EventTypeFlags,
     pub(crate) gateway_url: Option<Box<str>>,
     pub(crate) http_client: Client,
@@ -30,6 +33,16 @@ pub struct Config {
 }
 
 impl Config {
+    /// Maximum number of events queued for the event stream, if bounded.
+    pub const fn event_queue_capacity(&self) -> Option<usize> {
+        self.event_queue_capacity
+    }
+
+    /// What to do with events when the event queue is full.
+    pub const fn event_queue_policy(&self) -> EventQueuePolicy {
+        self.event_queue_policy
+    }
+
     /// Copy of the event type flags.
     pub const fn event_types(&self) -> EventTypeFlags {
         self.event_types
@@ -1,11 +1,14 @@
-use super::json;
+use super::{
+    event_queue::{self, EventQueuePolicy, EventReceiver, EventSender, Shared},
+    payload,
+};
 use crate::{Event, EventTypeFlags};
 use std::{
     convert::TryFrom,
     error::Error,
     fmt::{Debug, Display, Formatter, Result as FmtResult},
+    sync::Arc,
 };
-use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
 use twilight_model::gateway::event::shard::Payload;
 
 #[derive(Debug)]
@@ -65,15 +68,20 @@ pub enum EmitJsonErrorType {
 #[derive(Clone, Debug)]
 pub struct Emitter {
     event_types: EventTypeFlags,
-    tx: UnboundedSender<Event>,
+    tx: EventSender,
 }
 
 impl Emitter {
-    /// Create a new emitter for events and bytes.
-    pub fn new(event_types: EventTypeFlags) -> (Self, UnboundedReceiver<Event>) {
-        let (tx, rx) = mpsc::unbounded_channel();
-
-        (Self { event_types, tx }, rx)
+    /// Create a new emitter for events and bytes, queueing them in a queue
+    /// with an optional capacity.
+    pub fn new(
+        event_types: EventTypeFlags,
+        capacity: Option<usize>,
+        policy: EventQueuePolicy,
+    ) -> (Self, EventReceiver, Arc<Shared>) {
+        let (tx, rx, shared) = event_queue::channel(capacity, policy);
+
+        (Self { event_types, tx }, rx, shared)
     }
 
     /// Whether the configured event types include an individual event type.
@@ -91,25 +99,26 @@ impl Emitter {
     ///
     /// [`EventTypeFlags::SHARD_PAYLOAD`]: crate::EventTypeFlags::SHARD_PAYLOAD
     #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace"))]
-    pub fn bytes(&self, bytes: &[u8]) {
+    pub async fn bytes(&self, bytes: &[u8]) {
         if self.wants(EventTypeFlags::SHARD_PAYLOAD) {
             self.send(Event::ShardPayload(Payload {
                 bytes: bytes.to_vec(),
             }))
+            .await;
         }
     }
 
     /// Send an event to the listener if it has subscribed to its event type.
     #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace"))]
-    pub fn event(&self, event: Event) {
+    pub async fn event(&self, event: Event) {
         let event_type = EventTypeFlags::from(event.kind());
 
         if self.wants(event_type) {
-            self.send(event);
+            self.send(event).await;
         }
     }
 
-    /// Emit a JSON payload that hasn't been deserialized yet, but only if the
+    /// Emit a payload that hasn't been deserialized yet, but only if the
     /// listener wants the event type.
     ///
     /// # Errors
@@ -118,14 +127,14 @@ impl Emitter {
     /// event type is unknown.
     ///
     /// Returns a [`EmitJsonErrorType::Parsing`] error type if the combination
-    /// of the provided opcode, sequence, event type, and JSON could not be
+    /// of the provided opcode, sequence, event type, and payload could not be
     /// parsed into an event.
-    pub fn json(
+    pub async fn payload(
         &self,
         op: u8,
         seq: Option<u64>,
         event_type: Option<&str>,
-        json: &mut [u8],
+        bytes: &mut [u8],
     ) -> Result<(), EmitJsonError> {
         let flag = EventTypeFlags::try_from((op, event_type)).map_err(|(op, event_type)| {
             EmitJsonError {
@@ -139,33 +148,34 @@ impl Emitter {
 
         if self.wants(flag) {
             let gateway_event =
-                json::parse_gateway_event(op, seq, event_type, json).map_err(|source| {
+                payload::parse_gateway_event(op, seq, event_type, bytes).map_err(|source| {
                     EmitJsonError {
                         kind: EmitJsonErrorType::Parsing,
                         source: Some(Box::new(source)),
                     }
                 })?;
-            self.event(Event::from(gateway_event));
+            self.event(Event::from(gateway_event)).await;
         }
 
         Ok(())
     }
 
-    fn send(&self, event: Event) {
-        let _res = self.tx.send(event);
+    async fn send(&self, event: Event) {
+        self.tx.send(event).await;
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Emitter;
+    use super::{Emitter, EventQueuePolicy};
     use crate::{Event, EventTypeFlags};
     use tokio::time::{self, Duration};
 
     #[tokio::test]
     async fn test_bytes_send() {
-        let (emitter, mut rx) = Emitter::new(EventTypeFlags::SHARD_PAYLOAD);
-        emitter.bytes(&[1]);
+        let (emitter, mut rx, _) =
+            Emitter::new(EventTypeFlags::SHARD_PAYLOAD, None, EventQueuePolicy::Block);
+        emitter.bytes(&[1]).await;
 
         assert!(rx.recv().await.is_some());
         assert!(time::timeout(Duration::from_millis(10), rx.recv())
@@ -175,8 +185,9 @@ mod tests {
 
     #[tokio::test]
     async fn test_event_sends_to_rx() {
-        let (emitter, mut rx) = Emitter::new(EventTypeFlags::default());
-        emitter.event(Event::GatewayReconnect);
+        let (emitter, mut rx, _) =
+            Emitter::new(EventTypeFlags::default(), None, EventQueuePolicy::Block);
+        emitter.event(Event::GatewayReconnect).await;
 
         assert!(rx.recv().await.is_some());
 
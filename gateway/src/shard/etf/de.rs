@@ -0,0 +1,479 @@
+//! Deserializer of terms in the External Term Format.
+
+use super::{tag, EtfError, EtfErrorType};
+use serde::de::{
+    value::{SeqDeserializer, StrDeserializer},
+    Deserialize, DeserializeSeed, Deserializer as DeserializerTrait, EnumAccess, Error as DeError,
+    IntoDeserializer, MapAccess, SeqAccess, VariantAccess, Visitor,
+};
+use std::{convert::TryFrom, str};
+
+/// Integer decoded from one of the integer tags.
+#[derive(Clone, Copy)]
+enum Integer {
+    Negative(i64),
+    Positive(u64),
+}
+
+/// Deserializer reading a term from a slice of bytes.
+#[derive(Debug)]
+pub struct Deserializer<'de> {
+    input: &'de [u8],
+}
+
+impl<'de> Deserializer<'de> {
+    /// Create a deserializer for a term prefixed by the version number of
+    /// the format.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EtfErrorType::VersionInvalid`] error type if the input
+    /// doesn't start with the supported version number.
+    pub fn from_slice(input: &'de [u8]) -> Result<Self, EtfError> {
+        let mut deserializer = Self { input };
+
+        match deserializer.read_u8()? {
+            tag::VERSION => Ok(deserializer),
+            version => Err(EtfError::new(EtfErrorType::VersionInvalid { version })),
+        }
+    }
+
+    /// Ensure that the entire input has been consumed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EtfErrorType::TrailingBytes`] error type if there are
+    /// bytes left after the term.
+    pub const fn end(&self) -> Result<(), EtfError> {
+        if self.input.is_empty() {
+            Ok(())
+        } else {
+            Err(EtfError::new(EtfErrorType::TrailingBytes))
+        }
+    }
+
+    /// Whether the next term is the `nil` atom, consuming it if so.
+    fn is_nil(&mut self) -> Result<bool, EtfError> {
+        let tag = self.peek_tag()?;
+
+        if !matches!(
+            tag,
+            tag::ATOM | tag::ATOM_UTF8 | tag::SMALL_ATOM | tag::SMALL_ATOM_UTF8
+        ) {
+            return Ok(false);
+        }
+
+        let input = self.input;
+        self.read_u8()?;
+
+        if self.read_atom(tag)? == "nil" {
+            return Ok(true);
+        }
+
+        self.input = input;
+
+        Ok(false)
+    }
+
+    fn peek_tag(&self) -> Result<u8, EtfError> {
+        self.input
+            .first()
+            .copied()
+            .ok_or_else(|| EtfError::new(EtfErrorType::Eof))
+    }
+
+    fn read_bytes(&mut self, len: usize) -> Result<&'de [u8], EtfError> {
+        if self.input.len() < len {
+            return Err(EtfError::new(EtfErrorType::Eof));
+        }
+
+        let (bytes, rest) = self.input.split_at(len);
+        self.input = rest;
+
+        Ok(bytes)
+    }
+
+    fn read_u8(&mut self) -> Result<u8, EtfError> {
+        self.read_bytes(1).map(|bytes| bytes[0])
+    }
+
+    fn read_u16(&mut self) -> Result<u16, EtfError> {
+        let bytes = self.read_bytes(2)?;
+
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn read_u32(&mut self) -> Result<u32, EtfError> {
+        let bytes = self.read_bytes(4)?;
+
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    /// Read a length, which is bounded by the remaining input since every
+    /// element takes at least one byte.
+    fn read_len(&mut self, len: u32) -> Result<usize, EtfError> {
+        let len = usize::try_from(len).map_err(|_| EtfError::new(EtfErrorType::Eof))?;
+
+        if len > self.input.len() {
+            return Err(EtfError::new(EtfErrorType::Eof));
+        }
+
+        Ok(len)
+    }
+
+    /// Read the name of an atom whose tag has already been read.
+    fn read_atom(&mut self, tag: u8) -> Result<&'de str, EtfError> {
+        let len = match tag {
+            tag::ATOM | tag::ATOM_UTF8 => usize::from(self.read_u16()?),
+            _ => usize::from(self.read_u8()?),
+        };
+
+        str::from_utf8(self.read_bytes(len)?).map_err(|source| EtfError {
+            kind: EtfErrorType::AtomInvalid,
+            source: Some(Box::new(source)),
+        })
+    }
+
+    /// Read a big integer whose tag has already been read.
+    fn read_big(&mut self, tag: u8) -> Result<Integer, EtfError> {
+        let len = if tag == tag::SMALL_BIG {
+            usize::from(self.read_u8()?)
+        } else {
+            let len = self.read_u32()?;
+
+            self.read_len(len)?
+        };
+
+        let sign = self.read_u8()?;
+        let digits = self.read_bytes(len)?;
+        let mut value = 0_u64;
+
+        // Digits are stored in little endian order, so the most significant
+        // digits are at the end and may only be zeroes.
+        for (index, digit) in digits.iter().enumerate() {
+            if index >= 8 {
+                if *digit != 0 {
+                    return Err(EtfError::new(EtfErrorType::IntegerTooLarge));
+                }
+
+                continue;
+            }
+
+            value |= u64::from(*digit) << (index * 8);
+        }
+
+        if sign == 0 {
+            return Ok(Integer::Positive(value));
+        }
+
+        // The absolute value of `i64::MIN` is one more than `i64::MAX`.
+        if value > i64::MAX as u64 + 1 {
+            return Err(EtfError::new(EtfErrorType::IntegerTooLarge));
+        }
+
+        #[allow(clippy::cast_possible_wrap)]
+        Ok(Integer::Negative((value as i64).wrapping_neg()))
+    }
+
+    fn read_float(&mut self) -> Result<f64, EtfError> {
+        let bytes = self.read_bytes(31)?;
+        let end = bytes.iter().position(|byte| *byte == 0).unwrap_or(31);
+
+        str::from_utf8(&bytes[..end])
+            .ok()
+            .and_then(|float| float.trim().parse().ok())
+            .ok_or_else(|| EtfError::new(EtfErrorType::FloatInvalid))
+    }
+
+    /// Ensure that a list is terminated by an empty list, as lists with other
+    /// tails have no equivalent in serde's data model.
+    fn read_list_tail(&mut self) -> Result<(), EtfError> {
+        match self.read_u8()? {
+            tag::NIL => Ok(()),
+            _ => Err(EtfError::new(EtfErrorType::ListImproper)),
+        }
+    }
+
+    fn visit_integer<V: Visitor<'de>>(integer: Integer, visitor: V) -> Result<V::Value, EtfError> {
+        match integer {
+            Integer::Negative(value) => visitor.visit_i64(value),
+            Integer::Positive(value) => visitor.visit_u64(value),
+        }
+    }
+
+    fn visit_seq<V: Visitor<'de>>(&mut self, len: usize, visitor: V) -> Result<V::Value, EtfError> {
+        let mut access = Elements {
+            deserializer: self,
+            remaining: len,
+        };
+        let value = visitor.visit_seq(&mut access)?;
+
+        if access.remaining == 0 {
+            Ok(value)
+        } else {
+            Err(EtfError::invalid_length(len, &"fewer elements in sequence"))
+        }
+    }
+}
+
+impl<'de> DeserializerTrait<'de> for &mut Deserializer<'de> {
+    type Error = EtfError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.read_u8()? {
+            tag::SMALL_INTEGER => visitor.visit_u8(self.read_u8()?),
+            tag::INTEGER => {
+                #[allow(clippy::cast_possible_wrap)]
+                let value = self.read_u32()? as i32;
+
+                match u64::try_from(value) {
+                    Ok(value) => visitor.visit_u64(value),
+                    Err(_) => visitor.visit_i64(i64::from(value)),
+                }
+            }
+            tag::NEW_FLOAT => {
+                let bytes = self.read_bytes(8)?;
+                let mut buf = [0; 8];
+                buf.copy_from_slice(bytes);
+
+                visitor.visit_f64(f64::from_bits(u64::from_be_bytes(buf)))
+            }
+            tag::FLOAT => visitor.visit_f64(self.read_float()?),
+            tag @ tag::SMALL_BIG | tag @ tag::LARGE_BIG => {
+                let integer = self.read_big(tag)?;
+
+                Deserializer::visit_integer(integer, visitor)
+            }
+            tag @ tag::ATOM
+            | tag @ tag::ATOM_UTF8
+            | tag @ tag::SMALL_ATOM
+            | tag @ tag::SMALL_ATOM_UTF8 => match self.read_atom(tag)? {
+                "nil" => visitor.visit_unit(),
+                "true" => visitor.visit_bool(true),
+                "false" => visitor.visit_bool(false),
+                atom => visitor.visit_borrowed_str(atom),
+            },
+            tag::BINARY => {
+                let len = self.read_u32()?;
+                let len = self.read_len(len)?;
+                let bytes = self.read_bytes(len)?;
+
+                match str::from_utf8(bytes) {
+                    Ok(string) => visitor.visit_borrowed_str(string),
+                    Err(_) => visitor.visit_borrowed_bytes(bytes),
+                }
+            }
+            tag::STRING => {
+                // Erlang strings are lists of bytes encoded compactly.
+                let len = usize::from(self.read_u16()?);
+                let bytes = self.read_bytes(len)?;
+                let mut access = SeqDeserializer::new(bytes.iter().copied());
+                let value = visitor.visit_seq(&mut access)?;
+                access.end()?;
+
+                Ok(value)
+            }
+            tag::NIL => self.visit_seq(0, visitor),
+            tag::LIST => {
+                let len = self.read_u32()?;
+                let len = self.read_len(len)?;
+                let value = self.visit_seq(len, visitor)?;
+                self.read_list_tail()?;
+
+                Ok(value)
+            }
+            tag::SMALL_TUPLE => {
+                let len = usize::from(self.read_u8()?);
+
+                self.visit_seq(len, visitor)
+            }
+            tag::LARGE_TUPLE => {
+                let len = self.read_u32()?;
+                let len = self.read_len(len)?;
+
+                self.visit_seq(len, visitor)
+            }
+            tag::MAP => {
+                let len = self.read_u32()?;
+                let len = self.read_len(len)?;
+                let mut access = Elements {
+                    deserializer: self,
+                    remaining: len,
+                };
+                let value = visitor.visit_map(&mut access)?;
+
+                if access.remaining == 0 {
+                    Ok(value)
+                } else {
+                    Err(EtfError::invalid_length(len, &"fewer entries in map"))
+                }
+            }
+            tag => Err(EtfError::new(EtfErrorType::TagUnknown { tag })),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        if self.is_nil()? {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        if self.peek_tag()? != tag::BINARY {
+            return self.deserialize_any(visitor);
+        }
+
+        self.read_u8()?;
+        let len = self.read_u32()?;
+        let len = self.read_len(len)?;
+
+        visitor.visit_borrowed_bytes(self.read_bytes(len)?)
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        // Variants with data are encoded as a map with a single entry from the
+        // name of the variant to its data, like in JSON.
+        if self.peek_tag()? == tag::MAP {
+            self.read_u8()?;
+
+            return match self.read_u32()? {
+                1 => visitor.visit_enum(Variant { deserializer: self }),
+                len => Err(EtfError::invalid_length(
+                    len as usize,
+                    &"map with a single entry",
+                )),
+            };
+        }
+
+        let name = <&str>::deserialize(&mut *self)?;
+        let deserializer: StrDeserializer<'_, EtfError> = name.into_deserializer();
+
+        visitor.visit_enum(deserializer)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        unit unit_struct seq tuple tuple_struct map struct identifier
+        ignored_any
+    }
+}
+
+/// Access to the elements of a sequence or the entries of a map.
+struct Elements<'a, 'de> {
+    deserializer: &'a mut Deserializer<'de>,
+    remaining: usize,
+}
+
+impl<'de> SeqAccess<'de> for Elements<'_, 'de> {
+    type Error = EtfError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Self::Error> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+
+        self.remaining -= 1;
+
+        seed.deserialize(&mut *self.deserializer).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+impl<'de> MapAccess<'de> for Elements<'_, 'de> {
+    type Error = EtfError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+
+        self.remaining -= 1;
+
+        seed.deserialize(&mut *self.deserializer).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        seed.deserialize(&mut *self.deserializer)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+/// Access to an enum variant encoded as a map with a single entry.
+struct Variant<'a, 'de> {
+    deserializer: &'a mut Deserializer<'de>,
+}
+
+impl<'de> EnumAccess<'de> for Variant<'_, 'de> {
+    type Error = EtfError;
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), Self::Error> {
+        let variant = seed.deserialize(&mut *self.deserializer)?;
+
+        Ok((variant, self))
+    }
+}
+
+impl<'de> VariantAccess<'de> for Variant<'_, 'de> {
+    type Error = EtfError;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        Deserialize::deserialize(self.deserializer)
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> Result<T::Value, Self::Error> {
+        seed.deserialize(self.deserializer)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _: usize, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserializer.deserialize_any(visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserializer.deserialize_any(visitor)
+    }
+}
@@ -0,0 +1,699 @@
+//! Encoding of payloads in Erlang's [External Term Format].
+//!
+//! Discord sends snowflakes as integers and keys of maps as atoms when
+//! payloads are encoded as terms, both of which are supported by the
+//! deserializer.
+//!
+//! [External Term Format]: https://erlang.org/doc/apps/erts/erl_ext_dist.html
+
+mod de;
+mod ser;
+
+pub use self::{de::Deserializer, ser::Serializer};
+
+use super::json::{GatewayEventParsingError, GatewayEventParsingErrorType};
+use serde::{de::DeserializeSeed, Deserialize, Serialize};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::gateway::event::{GatewayEvent, GatewayEventDeserializer};
+
+/// Tags identifying the type of terms.
+mod tag {
+    pub const VERSION: u8 = 131;
+
+    pub const NEW_FLOAT: u8 = 70;
+    pub const SMALL_INTEGER: u8 = 97;
+    pub const INTEGER: u8 = 98;
+    pub const FLOAT: u8 = 99;
+    pub const ATOM: u8 = 100;
+    pub const SMALL_TUPLE: u8 = 104;
+    pub const LARGE_TUPLE: u8 = 105;
+    pub const NIL: u8 = 106;
+    pub const STRING: u8 = 107;
+    pub const LIST: u8 = 108;
+    pub const BINARY: u8 = 109;
+    pub const SMALL_BIG: u8 = 110;
+    pub const LARGE_BIG: u8 = 111;
+    pub const SMALL_ATOM: u8 = 115;
+    pub const MAP: u8 = 116;
+    pub const ATOM_UTF8: u8 = 118;
+    pub const SMALL_ATOM_UTF8: u8 = 119;
+}
+
+/// Encoding or decoding a term failed.
+#[derive(Debug)]
+pub struct EtfError {
+    kind: EtfErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl EtfError {
+    const fn new(kind: EtfErrorType) -> Self {
+        Self { kind, source: None }
+    }
+}
+
+impl Display for EtfError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            EtfErrorType::AtomInvalid => f.write_str("atom is not valid utf-8"),
+            EtfErrorType::Eof => f.write_str("term ended unexpectedly"),
+            EtfErrorType::FloatInvalid => f.write_str("float is not a valid number"),
+            EtfErrorType::IntegerTooLarge => f.write_str("integer doesn't fit in 64 bits"),
+            EtfErrorType::LengthTooLarge => f.write_str("length doesn't fit in 32 bits"),
+            EtfErrorType::ListImproper => f.write_str("list doesn't end with an empty list"),
+            EtfErrorType::Message { message } => f.write_str(message),
+            EtfErrorType::TagUnknown { tag } => {
+                f.write_str("tag ")?;
+                Display::fmt(tag, f)?;
+
+                f.write_str(" is unknown or unsupported")
+            }
+            EtfErrorType::TrailingBytes => f.write_str("bytes remain after the term"),
+            EtfErrorType::VersionInvalid { version } => {
+                f.write_str("version ")?;
+                Display::fmt(version, f)?;
+
+                f.write_str(" is not supported")
+            }
+        }
+    }
+}
+
+impl Error for EtfError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+impl serde::de::Error for EtfError {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self::new(EtfErrorType::Message {
+            message: msg.to_string(),
+        })
+    }
+}
+
+impl serde::ser::Error for EtfError {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self::new(EtfErrorType::Message {
+            message: msg.to_string(),
+        })
+    }
+}
+
+/// Type of [`EtfError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum EtfErrorType {
+    /// Name of an atom is not valid UTF-8.
+    AtomInvalid,
+    /// Input ended in the middle of a term.
+    Eof,
+    /// Float encoded as a string is not a valid number.
+    FloatInvalid,
+    /// Big integer doesn't fit in a 64-bit integer.
+    IntegerTooLarge,
+    /// Number of bytes or elements doesn't fit in 32 bits.
+    LengthTooLarge,
+    /// List ends with a tail other than an empty list.
+    ListImproper,
+    /// Error reported by the type being serialized or deserialized.
+    Message {
+        /// Message of the error.
+        message: String,
+    },
+    /// Tag of a term is unknown or not supported.
+    TagUnknown {
+        /// Tag of the term.
+        tag: u8,
+    },
+    /// Input has bytes after the term.
+    TrailingBytes,
+    /// Input doesn't start with the supported version number.
+    VersionInvalid {
+        /// Version number of the input.
+        version: u8,
+    },
+}
+
+/// Fields of a gateway event needed before deserializing its data.
+#[derive(Deserialize)]
+struct Header<'a> {
+    op: u8,
+    s: Option<u64>,
+    #[serde(borrow)]
+    t: Option<&'a str>,
+}
+
+/// Deserialize a value from a term.
+///
+/// # Errors
+///
+/// Returns an error if the input isn't a term representing the value.
+pub fn from_slice<'a, T: Deserialize<'a>>(input: &'a [u8]) -> Result<T, EtfError> {
+    let mut deserializer = Deserializer::from_slice(input)?;
+    let value = T::deserialize(&mut deserializer)?;
+    deserializer.end()?;
+
+    Ok(value)
+}
+
+/// Serialize a value as a term.
+///
+/// # Errors
+///
+/// Returns an error if the value fails to serialize.
+pub fn to_vec<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, EtfError> {
+    let mut serializer = Serializer::new();
+    value.serialize(&mut serializer)?;
+
+    Ok(serializer.into_inner())
+}
+
+/// Read the opcode, sequence, and event type of a gateway event.
+///
+/// # Errors
+///
+/// Returns an error if the input isn't a map with at least an opcode.
+pub fn header(input: &[u8]) -> Result<(u8, Option<u64>, Option<&str>), EtfError> {
+    let header = from_slice::<Header<'_>>(input)?;
+
+    Ok((header.op, header.s, header.t))
+}
+
+/// Parse a gateway event from a term with headers.
+///
+/// # Errors
+///
+/// Returns a [`GatewayEventParsingErrorType::PayloadInvalid`] error type if
+/// the payload isn't a term.
+///
+/// Returns a [`GatewayEventParsingErrorType::Deserializing`] error type if the
+/// payload failed to deserialize.
+pub fn parse_gateway_event(
+    op: u8,
+    sequence: Option<u64>,
+    event_type: Option<&str>,
+    input: &[u8],
+) -> Result<GatewayEvent, GatewayEventParsingError> {
+    let gateway_deserializer = GatewayEventDeserializer::new(op, sequence, event_type);
+
+    let mut deserializer =
+        Deserializer::from_slice(input).map_err(|source| GatewayEventParsingError {
+            kind: GatewayEventParsingErrorType::PayloadInvalid,
+            source: Some(Box::new(source)),
+        })?;
+
+    gateway_deserializer
+        .deserialize(&mut deserializer)
+        .and_then(|event| deserializer.end().map(|()| event))
+        .map_err(|source| GatewayEventParsingError {
+            kind: GatewayEventParsingErrorType::Deserializing,
+            source: Some(Box::new(source)),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::json, from_slice, header, parse_gateway_event, tag, to_vec, EtfError, EtfErrorType,
+    };
+    use serde_json::{Map, Value};
+    use static_assertions::assert_impl_all;
+    use std::{convert::TryFrom, error::Error, fmt::Debug};
+    use twilight_model::{
+        gateway::{
+            event::GatewayEventDeserializer,
+            payload::{
+                identify::{Identify, IdentifyInfo, IdentifyProperties},
+                resume::Resume,
+                RequestGuildMembers,
+            },
+            Intents,
+        },
+        id::{GuildId, UserId},
+    };
+
+    assert_impl_all!(EtfErrorType: Debug, Send, Sync);
+    assert_impl_all!(EtfError: Error, Send, Sync);
+
+    /// Encode a JSON value like Discord does, with atoms as the keys of maps,
+    /// integers in their smallest encoding, snowflakes as integers, and empty
+    /// lists as nil.
+    #[allow(clippy::cast_possible_truncation)]
+    fn discord_term(value: &Value) -> Vec<u8> {
+        fn atom(name: &str, buf: &mut Vec<u8>) {
+            buf.push(tag::ATOM);
+            buf.extend_from_slice(&(name.len() as u16).to_be_bytes());
+            buf.extend_from_slice(name.as_bytes());
+        }
+
+        fn encode(value: &Value, buf: &mut Vec<u8>) {
+            match value {
+                Value::Null => atom("nil", buf),
+                Value::Bool(value) => atom(if *value { "true" } else { "false" }, buf),
+                Value::Number(number) => match number.as_u64() {
+                    Some(number) if u8::try_from(number).is_ok() => {
+                        buf.push(tag::SMALL_INTEGER);
+                        buf.push(number as u8);
+                    }
+                    Some(number) if i32::try_from(number).is_ok() => {
+                        buf.push(tag::INTEGER);
+                        buf.extend_from_slice(&(number as i32).to_be_bytes());
+                    }
+                    Some(number) => {
+                        buf.push(tag::SMALL_BIG);
+                        buf.push(8);
+                        buf.push(0);
+                        buf.extend_from_slice(&number.to_le_bytes());
+                    }
+                    None => {
+                        buf.push(tag::NEW_FLOAT);
+                        buf.extend_from_slice(&number.as_f64().unwrap().to_be_bytes());
+                    }
+                },
+                Value::String(string) => match string.parse::<u64>() {
+                    Ok(snowflake) if string.len() >= 17 => {
+                        encode(&Value::from(snowflake), buf);
+                    }
+                    _ => {
+                        buf.push(tag::BINARY);
+                        buf.extend_from_slice(&(string.len() as u32).to_be_bytes());
+                        buf.extend_from_slice(string.as_bytes());
+                    }
+                },
+                Value::Array(values) if values.is_empty() => buf.push(tag::NIL),
+                Value::Array(values) => {
+                    buf.push(tag::LIST);
+                    buf.extend_from_slice(&(values.len() as u32).to_be_bytes());
+
+                    for value in values {
+                        encode(value, buf);
+                    }
+
+                    buf.push(tag::NIL);
+                }
+                Value::Object(map) => {
+                    buf.push(tag::MAP);
+                    buf.extend_from_slice(&(map.len() as u32).to_be_bytes());
+
+                    for (key, value) in map {
+                        atom(key, buf);
+                        encode(value, buf);
+                    }
+                }
+            }
+        }
+
+        let mut buf = vec![tag::VERSION];
+        encode(value, &mut buf);
+
+        buf
+    }
+
+    /// Random number generator, seeded for the tests to be reproducible.
+    struct Rng(u64);
+
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_possible_wrap,
+        clippy::cast_precision_loss
+    )]
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+
+            self.0
+        }
+
+        fn value(&mut self, depth: u8) -> Value {
+            let kinds = if depth == 0 { 6 } else { 8 };
+
+            match self.next() % kinds {
+                0 => Value::Null,
+                1 => Value::Bool(self.next() % 2 == 0),
+                2 => Value::from(self.next() >> (self.next() % 64)),
+                3 => Value::from(-((self.next() >> (self.next() % 63 + 1)) as i64) - 1),
+                4 => Value::from(self.next() as f64 / 7.0),
+                5 => Value::String(self.string()),
+                6 => Value::Array(
+                    (0..self.next() % 5)
+                        .map(|_| self.value(depth - 1))
+                        .collect(),
+                ),
+                _ => Value::Object(
+                    (0..self.next() % 5)
+                        .map(|_| (self.string(), self.value(depth - 1)))
+                        .collect::<Map<_, _>>(),
+                ),
+            }
+        }
+
+        fn string(&mut self) -> String {
+            const CHARS: &[char] = &['a', 'z', '0', '_', ' ', 'é', '雪', '🦀'];
+
+            (0..self.next() % 8)
+                .map(|_| CHARS[(self.next() % CHARS.len() as u64) as usize])
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_big_integers() {
+        for value in &[
+            0,
+            255,
+            256,
+            i64::from(i32::MAX),
+            i64::from(i32::MAX) + 1,
+            i64::from(i32::MIN),
+            i64::from(i32::MIN) - 1,
+            i64::MIN,
+            i64::MAX,
+        ] {
+            assert_eq!(*value, from_slice::<i64>(&to_vec(value).unwrap()).unwrap());
+        }
+
+        let snowflake = 851_390_838_209_712_138_u64;
+        let bytes = to_vec(&snowflake).unwrap();
+        assert_eq!(&[tag::VERSION, tag::SMALL_BIG, 8, 0], &bytes[..4]);
+        assert_eq!(snowflake, from_slice::<u64>(&bytes).unwrap());
+        assert_eq!(
+            u64::MAX,
+            from_slice::<u64>(&to_vec(&u64::MAX).unwrap()).unwrap()
+        );
+
+        // Big integers with more digits are accepted if the extra digits are
+        // zeroes.
+        let mut bytes = vec![tag::VERSION, tag::LARGE_BIG, 0, 0, 0, 10, 0];
+        bytes.extend_from_slice(&snowflake.to_le_bytes());
+        bytes.extend_from_slice(&[0, 0]);
+        assert_eq!(snowflake, from_slice::<u64>(&bytes).unwrap());
+
+        *bytes.last_mut().unwrap() = 1;
+        assert!(matches!(
+            from_slice::<u64>(&bytes).unwrap_err().kind,
+            EtfErrorType::IntegerTooLarge
+        ));
+    }
+
+    #[test]
+    fn test_commands_match_json() {
+        let identify = Identify::new(IdentifyInfo {
+            compress: false,
+            intents: Intents::GUILDS | Intents::GUILD_MEMBERS,
+            large_threshold: 250,
+            presence: None,
+            properties: IdentifyProperties::new("twilight.rs", "twilight.rs", "linux", "", ""),
+            shard: Some([3, 4]),
+            token: "token".to_owned(),
+        });
+        let resume = Resume::new(5_000_000_000, "session id", "token");
+        let request_guild_members = RequestGuildMembers::builder(GuildId(851_390_838_209_712_138))
+            .nonce("nonce")
+            .presences(true)
+            .user_ids(vec![UserId(1), UserId(u64::MAX)])
+            .unwrap();
+
+        let bytes = to_vec(&identify).unwrap();
+        assert_eq!(identify, from_slice(&bytes).unwrap());
+        assert_eq!(
+            serde_json::to_value(&identify).unwrap(),
+            from_slice::<Value>(&bytes).unwrap()
+        );
+
+        let bytes = to_vec(&resume).unwrap();
+        assert_eq!(
+            serde_json::to_value(&resume).unwrap(),
+            from_slice::<Value>(&bytes).unwrap()
+        );
+
+        let bytes = to_vec(&request_guild_members).unwrap();
+        assert_eq!(request_guild_members, from_slice(&bytes).unwrap());
+        assert_eq!(
+            serde_json::to_value(&request_guild_members).unwrap(),
+            from_slice::<Value>(&bytes).unwrap()
+        );
+    }
+
+    const READY: &str = r#"{
+        "t": "READY",
+        "s": 1,
+        "op": 0,
+        "d": {
+            "v": 9,
+            "user": {
+                "verified": true,
+                "username": "bot",
+                "mfa_enabled": false,
+                "id": "851390838209712142",
+                "flags": 0,
+                "email": null,
+                "discriminator": "0001",
+                "bot": true,
+                "avatar": null
+            },
+            "session_id": "3b5c8e1f0a9d4e2b7c6f1a0d9e8b7c6f",
+            "shard": [0, 1],
+            "guilds": [{"unavailable": true, "id": "851390838209712140"}],
+            "application": {"id": "851390838209712142", "flags": 565248}
+        }
+    }"#;
+
+    const GUILD_CREATE: &str = r#"{
+        "t": "GUILD_CREATE",
+        "s": 2,
+        "op": 0,
+        "d": {
+            "id": "851390838209712140",
+            "name": "guild",
+            "icon": null,
+            "description": null,
+            "splash": null,
+            "discovery_splash": null,
+            "banner": null,
+            "features": ["COMMUNITY", "NEWS"],
+            "owner_id": "851390838209712144",
+            "application_id": null,
+            "afk_channel_id": null,
+            "afk_timeout": 300,
+            "system_channel_id": "851390838209712139",
+            "system_channel_flags": 0,
+            "rules_channel_id": null,
+            "widget_enabled": false,
+            "verification_level": 1,
+            "default_message_notifications": 1,
+            "explicit_content_filter": 2,
+            "mfa_level": 0,
+            "nsfw_level": 0,
+            "premium_tier": 1,
+            "premium_subscription_count": 2,
+            "preferred_locale": "en-US",
+            "vanity_url_code": null,
+            "max_members": 250000,
+            "max_video_channel_users": 25,
+            "joined_at": "2021-06-08T00:00:00.000000+00:00",
+            "large": false,
+            "unavailable": false,
+            "member_count": 2,
+            "emojis": [],
+            "stickers": [],
+            "stage_instances": [],
+            "guild_scheduled_events": [],
+            "threads": [],
+            "presences": [],
+            "voice_states": [],
+            "roles": [{
+                "id": "851390838209712140",
+                "name": "@everyone",
+                "color": 0,
+                "hoist": false,
+                "icon": null,
+                "unicode_emoji": null,
+                "position": 0,
+                "permissions": "1071698660929",
+                "managed": false,
+                "mentionable": false
+            }],
+            "channels": [{
+                "id": "851390838209712139",
+                "type": 0,
+                "name": "general",
+                "position": 0,
+                "permission_overwrites": [{
+                    "id": "851390838209712140",
+                    "type": 0,
+                    "allow": "0",
+                    "deny": "2048"
+                }],
+                "topic": null,
+                "nsfw": false,
+                "last_message_id": "851390838209712138",
+                "rate_limit_per_user": 0,
+                "parent_id": null
+            }],
+            "members": [{
+                "user": {
+                    "username": "owner",
+                    "id": "851390838209712144",
+                    "discriminator": "1234",
+                    "avatar": "a_0123456789abcdef0123456789abcdef"
+                },
+                "roles": [],
+                "nick": null,
+                "joined_at": "2021-06-08T00:00:00.000000+00:00",
+                "deaf": false,
+                "mute": false
+            }]
+        }
+    }"#;
+
+    const MESSAGE_CREATE: &str = r#"{
+        "t": "MESSAGE_CREATE",
+        "s": 3,
+        "op": 0,
+        "d": {
+            "id": "851390838209712145",
+            "type": 0,
+            "channel_id": "851390838209712139",
+            "guild_id": "851390838209712140",
+            "author": {
+                "username": "owner",
+                "id": "851390838209712144",
+                "discriminator": "1234",
+                "avatar": null,
+                "public_flags": 0
+            },
+            "member": {
+                "roles": ["851390838209712146"],
+                "premium_since": null,
+                "nick": "nickname",
+                "joined_at": "2021-06-08T00:00:00.000000+00:00",
+                "deaf": false,
+                "mute": false
+            },
+            "content": "hello",
+            "timestamp": "2021-06-08T00:00:00.000000+00:00",
+            "edited_timestamp": null,
+            "tts": false,
+            "mention_everyone": false,
+            "mentions": [],
+            "mention_roles": [],
+            "attachments": [],
+            "embeds": [{
+                "type": "rich",
+                "title": "title",
+                "color": 16711680,
+                "fields": [{"name": "name", "value": "value", "inline": true}]
+            }],
+            "pinned": false,
+            "flags": 0,
+            "nonce": "851390838209712147",
+            "referenced_message": null
+        }
+    }"#;
+
+    const INTERACTION_CREATE: &str = r#"{
+        "t": "INTERACTION_CREATE",
+        "s": 4,
+        "op": 0,
+        "d": {
+            "id": "851390838209712148",
+            "type": 2,
+            "application_id": "851390838209712142",
+            "guild_id": "851390838209712140",
+            "channel_id": "851390838209712139",
+            "token": "interaction token",
+            "version": 1,
+            "locale": "en-US",
+            "guild_locale": "en-US",
+            "member": {
+                "user": {
+                    "username": "owner",
+                    "id": "851390838209712144",
+                    "discriminator": "1234",
+                    "avatar": null
+                },
+                "roles": [],
+                "permissions": "1071698660929",
+                "nick": null,
+                "joined_at": "2021-06-08T00:00:00.000000+00:00",
+                "deaf": false,
+                "mute": false
+            },
+            "data": {
+                "id": "851390838209712149",
+                "name": "roll",
+                "type": 1,
+                "options": [
+                    {"name": "sides", "type": 4, "value": 20},
+                    {"name": "secret", "type": 5, "value": true},
+                    {"name": "label", "type": 3, "value": "d20"}
+                ]
+            }
+        }
+    }"#;
+
+    #[test]
+    fn test_events_match_json() {
+        const PAYLOADS: &[&str] = &[
+            r#"{"op":10,"d":{"heartbeat_interval":41250},"s":null,"t":null}"#,
+            r#"{"op":11,"d":null,"s":null,"t":null}"#,
+            r#"{"op":9,"d":false,"s":null,"t":null}"#,
+            r#"{"op":1,"d":12,"s":null,"t":null}"#,
+            r#"{"t":"MESSAGE_DELETE","s":3,"op":0,"d":{"id":"851390838209712138","channel_id":"851390838209712139","guild_id":"851390838209712140"}}"#,
+            r#"{"t":"MESSAGE_DELETE_BULK","s":4,"op":0,"d":{"ids":["851390838209712138","851390838209712141"],"channel_id":"851390838209712139"}}"#,
+            r#"{"t":"GUILD_ROLE_DELETE","s":5,"op":0,"d":{"guild_id":"851390838209712140","role_id":"18446744073709551615"}}"#,
+            r#"{"t":"CHANNEL_PINS_UPDATE","s":6,"op":0,"d":{"channel_id":"851390838209712139","last_pin_timestamp":null}}"#,
+            READY,
+            GUILD_CREATE,
+            MESSAGE_CREATE,
+            INTERACTION_CREATE,
+        ];
+
+        for payload in PAYLOADS {
+            let mut json = payload.as_bytes().to_vec();
+            let (op, seq, event_type) = GatewayEventDeserializer::from_json(payload)
+                .unwrap()
+                .into_parts();
+            let expected = json::parse_gateway_event(op, seq, event_type, &mut json).unwrap();
+
+            let term = discord_term(&serde_json::from_str(payload).unwrap());
+            assert_eq!((op, seq, event_type), header(&term).unwrap());
+            let event = parse_gateway_event(op, seq, event_type, &term).unwrap();
+
+            assert_eq!(format!("{:?}", expected), format!("{:?}", event));
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    #[test]
+    fn test_fuzz() {
+        let mut rng = Rng(0x5EED_CAFE_F00D_D00D);
+
+        for _ in 0..500 {
+            let value = rng.value(4);
+            let mut bytes = to_vec(&value).unwrap();
+            assert_eq!(value, from_slice::<Value>(&bytes).unwrap());
+
+            // Terms are prefix-free, so no truncation of a term is one.
+            for len in 0..bytes.len() {
+                assert!(from_slice::<Value>(&bytes[..len]).is_err());
+            }
+
+            // Corrupted terms must be rejected or decoded, but never panic.
+            for _ in 0..8 {
+                let index = (rng.next() % bytes.len() as u64) as usize;
+                bytes[index] = rng.next() as u8;
+                let _ = from_slice::<Value>(&bytes);
+            }
+        }
+    }
+}
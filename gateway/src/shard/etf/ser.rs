@@ -0,0 +1,443 @@
+//! Serializer of terms in the External Term Format.
+
+use super::{tag, EtfError, EtfErrorType};
+use serde::ser::{
+    Serialize, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple,
+    SerializeTupleStruct, SerializeTupleVariant, Serializer as SerializerTrait,
+};
+use std::convert::TryFrom;
+
+/// Serializer writing terms to a buffer.
+///
+/// Structs and maps are encoded as maps with binary keys, sequences and
+/// tuples as lists, and `None` and unit values as the `nil` atom.
+#[derive(Debug)]
+pub struct Serializer {
+    output: Vec<u8>,
+}
+
+impl Serializer {
+    /// Create a serializer whose output starts with the version number of the
+    /// format.
+    pub fn new() -> Self {
+        Self {
+            output: vec![tag::VERSION],
+        }
+    }
+
+    /// Consume the serializer, returning the encoded term.
+    pub fn into_inner(self) -> Vec<u8> {
+        self.output
+    }
+
+    fn write_atom(&mut self, name: &str) {
+        self.output.push(tag::SMALL_ATOM_UTF8);
+        #[allow(clippy::cast_possible_truncation)]
+        self.output.push(name.len() as u8);
+        self.output.extend_from_slice(name.as_bytes());
+    }
+
+    fn write_binary(&mut self, bytes: &[u8]) -> Result<(), EtfError> {
+        let len =
+            u32::try_from(bytes.len()).map_err(|_| EtfError::new(EtfErrorType::LengthTooLarge))?;
+
+        self.output.push(tag::BINARY);
+        self.output.extend_from_slice(&len.to_be_bytes());
+        self.output.extend_from_slice(bytes);
+
+        Ok(())
+    }
+
+    fn write_integer(&mut self, negative: bool, magnitude: u64) {
+        if !negative && u8::try_from(magnitude).is_ok() {
+            self.output.push(tag::SMALL_INTEGER);
+            #[allow(clippy::cast_possible_truncation)]
+            self.output.push(magnitude as u8);
+
+            return;
+        }
+
+        let fits_i32 = if negative {
+            magnitude <= i32::MAX as u64 + 1
+        } else {
+            i32::try_from(magnitude).is_ok()
+        };
+
+        if fits_i32 {
+            #[allow(clippy::cast_possible_truncation)]
+            let value = if negative {
+                (magnitude as u32).wrapping_neg()
+            } else {
+                magnitude as u32
+            };
+
+            self.output.push(tag::INTEGER);
+            self.output.extend_from_slice(&value.to_be_bytes());
+
+            return;
+        }
+
+        let digits = magnitude.to_le_bytes();
+        let len = 8 - magnitude.leading_zeros() as usize / 8;
+
+        self.output.push(tag::SMALL_BIG);
+        #[allow(clippy::cast_possible_truncation)]
+        self.output.push(len as u8);
+        self.output.push(u8::from(negative));
+        self.output.extend_from_slice(&digits[..len]);
+    }
+
+    fn write_signed(&mut self, value: i64) {
+        // The absolute value of `i64::MIN` wraps to itself, which is still
+        // the right magnitude once reinterpreted as unsigned.
+        #[allow(clippy::cast_sign_loss)]
+        self.write_integer(value < 0, value.wrapping_abs() as u64);
+    }
+
+    /// Start a term with a number of elements, returning the position of
+    /// the number so that it can be written once known.
+    fn start(&mut self, tag: u8) -> Compound<'_> {
+        self.output.push(tag);
+        let position = self.output.len();
+        self.output.extend_from_slice(&[0; 4]);
+
+        Compound {
+            len: 0,
+            position,
+            serializer: self,
+        }
+    }
+
+    /// Start a map with a single entry keyed by the name of an enum variant.
+    fn start_variant(&mut self, variant: &str) -> Result<(), EtfError> {
+        self.output.push(tag::MAP);
+        self.output.extend_from_slice(&1_u32.to_be_bytes());
+
+        self.write_binary(variant.as_bytes())
+    }
+}
+
+/// Term made of a number of elements not known until it ends.
+#[derive(Debug)]
+pub struct Compound<'a> {
+    len: u32,
+    position: usize,
+    serializer: &'a mut Serializer,
+}
+
+impl Compound<'_> {
+    fn element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), EtfError> {
+        self.len = self
+            .len
+            .checked_add(1)
+            .ok_or_else(|| EtfError::new(EtfErrorType::LengthTooLarge))?;
+
+        value.serialize(&mut *self.serializer)
+    }
+
+    fn end_list(self) {
+        let output = &mut self.serializer.output;
+
+        // Empty lists are encoded as the empty list rather than a list with
+        // no elements and an empty list as its tail.
+        if self.len == 0 {
+            output.truncate(self.position - 1);
+        } else {
+            output[self.position..self.position + 4].copy_from_slice(&self.len.to_be_bytes());
+        }
+
+        output.push(tag::NIL);
+    }
+
+    fn end_map(self) {
+        // Entries count as one element each for their key and value.
+        self.serializer.output[self.position..self.position + 4]
+            .copy_from_slice(&(self.len / 2).to_be_bytes());
+    }
+}
+
+impl<'a> SerializerTrait for &'a mut Serializer {
+    type Ok = ();
+    type Error = EtfError;
+
+    type SerializeSeq = Compound<'a>;
+    type SerializeTuple = Compound<'a>;
+    type SerializeTupleStruct = Compound<'a>;
+    type SerializeTupleVariant = Compound<'a>;
+    type SerializeMap = Compound<'a>;
+    type SerializeStruct = Compound<'a>;
+    type SerializeStructVariant = Compound<'a>;
+
+    fn serialize_bool(self, v: bool) -> Result<(), EtfError> {
+        self.write_atom(if v { "true" } else { "false" });
+
+        Ok(())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<(), EtfError> {
+        self.serialize_i64(i64::from(v))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<(), EtfError> {
+        self.serialize_i64(i64::from(v))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<(), EtfError> {
+        self.serialize_i64(i64::from(v))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<(), EtfError> {
+        self.write_signed(v);
+
+        Ok(())
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<(), EtfError> {
+        self.serialize_u64(u64::from(v))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<(), EtfError> {
+        self.serialize_u64(u64::from(v))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<(), EtfError> {
+        self.serialize_u64(u64::from(v))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<(), EtfError> {
+        self.write_integer(false, v);
+
+        Ok(())
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<(), EtfError> {
+        self.serialize_f64(f64::from(v))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<(), EtfError> {
+        self.output.push(tag::NEW_FLOAT);
+        self.output.extend_from_slice(&v.to_bits().to_be_bytes());
+
+        Ok(())
+    }
+
+    fn serialize_char(self, v: char) -> Result<(), EtfError> {
+        self.write_binary(v.encode_utf8(&mut [0; 4]).as_bytes())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<(), EtfError> {
+        self.write_binary(v.as_bytes())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<(), EtfError> {
+        self.write_binary(v)
+    }
+
+    fn serialize_none(self) -> Result<(), EtfError> {
+        self.serialize_unit()
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), EtfError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), EtfError> {
+        self.write_atom("nil");
+
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<(), EtfError> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<(), EtfError> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), EtfError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<(), EtfError> {
+        self.start_variant(variant)?;
+
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, EtfError> {
+        Ok(self.start(tag::LIST))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, EtfError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, EtfError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, EtfError> {
+        self.start_variant(variant)?;
+
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, EtfError> {
+        Ok(self.start(tag::MAP))
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, EtfError> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, EtfError> {
+        self.start_variant(variant)?;
+
+        self.serialize_map(Some(len))
+    }
+}
+
+impl SerializeSeq for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), EtfError> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), EtfError> {
+        self.end_list();
+
+        Ok(())
+    }
+}
+
+impl SerializeTuple for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), EtfError> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), EtfError> {
+        SerializeSeq::end(self)
+    }
+}
+
+impl SerializeTupleStruct for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), EtfError> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), EtfError> {
+        SerializeSeq::end(self)
+    }
+}
+
+impl SerializeTupleVariant for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), EtfError> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), EtfError> {
+        SerializeSeq::end(self)
+    }
+}
+
+impl SerializeMap for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), EtfError> {
+        self.element(key)
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), EtfError> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), EtfError> {
+        self.end_map();
+
+        Ok(())
+    }
+}
+
+impl SerializeStruct for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), EtfError> {
+        self.element(key)?;
+
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), EtfError> {
+        SerializeMap::end(self)
+    }
+}
+
+impl SerializeStructVariant for Compound<'_> {
+    type Ok = ();
+    type Error = EtfError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), EtfError> {
+        SerializeStruct::serialize_field(self, key, value)
+    }
+
+    fn end(self) -> Result<(), EtfError> {
+        SerializeMap::end(self)
+    }
+}
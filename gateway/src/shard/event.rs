@@ -12,13 +12,13 @@
 //! [`EventType`]: ::twilight_model::gateway::event::EventType
 //! [`ShardBuilder::event_types`]: crate::shard::ShardBuilder::event_types
 
+use super::event_queue::EventReceiver;
 use crate::EventTypeFlags;
 use futures_util::stream::Stream;
 use std::{
     pin::Pin,
     task::{Context, Poll},
 };
-use tokio::sync::mpsc::UnboundedReceiver;
 use twilight_model::gateway::event::Event;
 
 /// A stream of events from a [`Shard`].
@@ -39,11 +39,11 @@ use twilight_model::gateway::event::Event;
 #[derive(Debug)]
 pub struct Events {
     event_types: EventTypeFlags,
-    rx: UnboundedReceiver<Event>,
+    rx: EventReceiver,
 }
 
 impl Events {
-    pub(super) const fn new(event_types: EventTypeFlags, rx: UnboundedReceiver<Event>) -> Self {
+    pub(super) const fn new(event_types: EventTypeFlags, rx: EventReceiver) -> Self {
         Self { event_types, rx }
     }
 
@@ -0,0 +1,396 @@
+//! Queue of events between a shard's processor and its event stream.
+
+use std::{
+    collections::VecDeque,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    task::{Context, Poll, Waker},
+};
+use tokio::sync::Notify;
+use twilight_model::gateway::event::Event;
+
+/// What a shard does with an event when its event queue is full.
+///
+/// Configured alongside the capacity of the queue via
+/// [`ShardBuilder::event_queue`] and [`ClusterBuilder::event_queue`].
+///
+/// The dropping policies never drop [critical events], which report changes
+/// to the state of the shard, so the queue may exceed its capacity by them.
+///
+/// [`ClusterBuilder::event_queue`]: crate::cluster::ClusterBuilder::event_queue
+/// [`ShardBuilder::event_queue`]: super::ShardBuilder::event_queue
+/// [critical events]: EventQueuePolicy::is_critical
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum EventQueuePolicy {
+    /// Wait for the event stream to make room, which stops the shard from
+    /// reading from the gateway in the meantime.
+    ///
+    /// Discord disconnects shards that don't read their messages for too
+    /// long, so the event stream must not be stalled for long periods of
+    /// time.
+    Block,
+    /// Drop the oldest non-critical event in the queue to make room.
+    DropOldest,
+    /// Drop the new event if it isn't critical.
+    DropNewest,
+}
+
+impl EventQueuePolicy {
+    /// Whether an event is critical and never dropped.
+    ///
+    /// Critical events are those reporting changes to the connection of the
+    /// shard, such as [`Event::Ready`] and [`Event::ShardDisconnected`].
+    pub const fn is_critical(event: &Event) -> bool {
+        matches!(
+            event,
+            Event::GatewayInvalidateSession(_)
+                | Event::GatewayReconnect
+                | Event::Ready(_)
+                | Event::Resumed
+                | Event::ShardConnected(_)
+                | Event::ShardConnecting(_)
+                | Event::ShardDisconnected(_)
+                | Event::ShardIdentifying(_)
+                | Event::ShardReconnecting(_)
+                | Event::ShardResuming(_)
+        )
+    }
+}
+
+impl Default for EventQueuePolicy {
+    fn default() -> Self {
+        Self::Block
+    }
+}
+
+/// Snapshot of the state of a shard's event queue.
+///
+/// Returned by [`Shard::event_queue`] and [`Cluster::event_queues`].
+///
+/// [`Cluster::event_queues`]: crate::Cluster::event_queues
+/// [`Shard::event_queue`]: super::Shard::event_queue
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventQueueStats {
+    capacity: Option<usize>,
+    dropped: u64,
+    len: usize,
+}
+
+impl EventQueueStats {
+    /// Maximum number of events in the queue, if it is bounded.
+    pub const fn capacity(&self) -> Option<usize> {
+        self.capacity
+    }
+
+    /// Total number of events dropped because the queue was full.
+    pub const fn dropped(&self) -> u64 {
+        self.dropped
+    }
+
+    /// Number of events waiting in the queue to be received by the event
+    /// stream.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether no events are waiting in the queue.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+#[derive(Debug)]
+struct State {
+    /// Whether the receiver has been dropped.
+    closed: bool,
+    events: VecDeque<Event>,
+    /// Number of senders alive.
+    senders: usize,
+    /// Waker of the receiver waiting for an event.
+    waker: Option<Waker>,
+}
+
+/// State shared by the senders and the receiver of a queue.
+#[derive(Debug)]
+pub struct Shared {
+    capacity: Option<usize>,
+    dropped: AtomicU64,
+    policy: EventQueuePolicy,
+    /// Notified when room is made in the queue or the receiver is dropped.
+    space: Notify,
+    state: Mutex<State>,
+}
+
+impl Shared {
+    /// Snapshot of the state of the queue.
+    pub fn stats(&self) -> EventQueueStats {
+        EventQueueStats {
+            capacity: self.capacity,
+            dropped: self.dropped.load(Ordering::Relaxed),
+            len: self
+                .state
+                .lock()
+                .expect("event queue poisoned")
+                .events
+                .len(),
+        }
+    }
+}
+
+/// Create a queue of events, unbounded if no capacity is provided.
+///
+/// A capacity of 0 is treated as a capacity of 1.
+pub fn channel(
+    capacity: Option<usize>,
+    policy: EventQueuePolicy,
+) -> (EventSender, EventReceiver, Arc<Shared>) {
+    let shared = Arc::new(Shared {
+        capacity: capacity.map(|capacity| capacity.max(1)),
+        dropped: AtomicU64::new(0),
+        policy,
+        space: Notify::new(),
+        state: Mutex::new(State {
+            closed: false,
+            events: VecDeque::new(),
+            senders: 1,
+            waker: None,
+        }),
+    });
+
+    (
+        EventSender {
+            shared: Arc::clone(&shared),
+        },
+        EventReceiver {
+            shared: Arc::clone(&shared),
+        },
+        shared,
+    )
+}
+
+/// Sending half of a queue of events.
+///
+/// The receiver is done once all senders have been dropped.
+#[derive(Debug)]
+pub struct EventSender {
+    shared: Arc<Shared>,
+}
+
+impl EventSender {
+    /// Send an event, applying the queue's policy if it is full.
+    ///
+    /// Events are discarded if the receiver has been dropped.
+    pub async fn send(&self, event: Event) {
+        loop {
+            {
+                let mut state = self.shared.state.lock().expect("event queue poisoned");
+
+                if state.closed {
+                    return;
+                }
+
+                let full = self
+                    .shared
+                    .capacity
+                    .map_or(false, |capacity| state.events.len() >= capacity);
+
+                if !full {
+                    Self::push(&mut state, event);
+
+                    return;
+                }
+
+                match self.shared.policy {
+                    EventQueuePolicy::Block => {}
+                    _ if EventQueuePolicy::is_critical(&event) => {
+                        Self::push(&mut state, event);
+
+                        return;
+                    }
+                    EventQueuePolicy::DropOldest => {
+                        let oldest = state
+                            .events
+                            .iter()
+                            .position(|event| !EventQueuePolicy::is_critical(event));
+
+                        self.shared.dropped.fetch_add(1, Ordering::Relaxed);
+
+                        if let Some(index) = oldest {
+                            state.events.remove(index);
+                            Self::push(&mut state, event);
+                        }
+
+                        return;
+                    }
+                    EventQueuePolicy::DropNewest => {
+                        self.shared.dropped.fetch_add(1, Ordering::Relaxed);
+
+                        return;
+                    }
+                }
+            }
+
+            self.shared.space.notified().await;
+        }
+    }
+
+    fn push(state: &mut State, event: Event) {
+        state.events.push_back(event);
+
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl Clone for EventSender {
+    fn clone(&self) -> Self {
+        self.shared
+            .state
+            .lock()
+            .expect("event queue poisoned")
+            .senders += 1;
+
+        Self {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl Drop for EventSender {
+    fn drop(&mut self) {
+        let mut state = self.shared.state.lock().expect("event queue poisoned");
+        state.senders -= 1;
+
+        if state.senders == 0 {
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+/// Receiving half of a queue of events.
+#[derive(Debug)]
+pub struct EventReceiver {
+    shared: Arc<Shared>,
+}
+
+impl EventReceiver {
+    /// Poll for the next event, resolving to `None` once the queue is empty
+    /// and all senders have been dropped.
+    pub fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Option<Event>> {
+        let mut state = self.shared.state.lock().expect("event queue poisoned");
+
+        if let Some(event) = state.events.pop_front() {
+            drop(state);
+
+            if self.shared.policy == EventQueuePolicy::Block {
+                self.shared.space.notify_one();
+            }
+
+            return Poll::Ready(Some(event));
+        }
+
+        if state.senders == 0 {
+            return Poll::Ready(None);
+        }
+
+        state.waker = Some(cx.waker().clone());
+
+        Poll::Pending
+    }
+
+    /// Receive the next event.
+    #[cfg(test)]
+    pub async fn recv(&mut self) -> Option<Event> {
+        futures_util::future::poll_fn(|cx| self.poll_recv(cx)).await
+    }
+}
+
+impl Drop for EventReceiver {
+    fn drop(&mut self) {
+        {
+            let mut state = self.shared.state.lock().expect("event queue poisoned");
+            state.closed = true;
+            state.events.clear();
+        }
+
+        self.shared.space.notify_waiters();
+        self.shared.space.notify_one();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{channel, EventQueuePolicy, EventQueueStats};
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash, time::Duration};
+    use tokio::time;
+    use twilight_model::gateway::event::{shard::Connected, Event};
+
+    assert_impl_all!(EventQueuePolicy: Clone, Copy, Debug, Default, Eq, Hash, Send, Sync);
+    assert_impl_all!(EventQueueStats: Clone, Debug, Eq, Send, Sync);
+
+    fn connected() -> Event {
+        Event::ShardConnected(Connected {
+            heartbeat_interval: 1,
+            shard_id: 0,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_drop_newest() {
+        let (tx, mut rx, shared) = channel(Some(1), EventQueuePolicy::DropNewest);
+        tx.send(Event::GatewayHeartbeatAck).await;
+        tx.send(Event::GatewayHeartbeat(1)).await;
+        tx.send(connected()).await;
+
+        let stats = shared.stats();
+        assert_eq!(Some(1), stats.capacity());
+        assert_eq!(1, stats.dropped());
+        assert_eq!(2, stats.len());
+
+        assert!(matches!(rx.recv().await, Some(Event::GatewayHeartbeatAck)));
+        assert!(matches!(rx.recv().await, Some(Event::ShardConnected(_))));
+    }
+
+    #[tokio::test]
+    async fn test_drop_oldest() {
+        let (tx, mut rx, shared) = channel(Some(2), EventQueuePolicy::DropOldest);
+        tx.send(connected()).await;
+        tx.send(Event::GatewayHeartbeatAck).await;
+        tx.send(Event::GatewayHeartbeat(1)).await;
+
+        assert_eq!(1, shared.stats().dropped());
+        assert!(matches!(rx.recv().await, Some(Event::ShardConnected(_))));
+        assert!(matches!(rx.recv().await, Some(Event::GatewayHeartbeat(1))));
+    }
+
+    #[tokio::test]
+    async fn test_block() {
+        let (tx, mut rx, shared) = channel(Some(1), EventQueuePolicy::Block);
+        tx.send(Event::GatewayHeartbeat(1)).await;
+
+        let blocked = time::timeout(
+            Duration::from_millis(10),
+            tx.send(Event::GatewayHeartbeat(2)),
+        );
+        assert!(blocked.await.is_err());
+
+        let sender = tokio::spawn(async move {
+            tx.send(Event::GatewayHeartbeat(3)).await;
+        });
+
+        assert!(matches!(rx.recv().await, Some(Event::GatewayHeartbeat(1))));
+        sender.await.unwrap();
+        assert!(matches!(rx.recv().await, Some(Event::GatewayHeartbeat(3))));
+        assert_eq!(0, shared.stats().dropped());
+
+        // The sender has been dropped, ending the queue.
+        assert!(rx.recv().await.is_none());
+    }
+}
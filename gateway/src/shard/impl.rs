@@ -3,13 +3,14 @@ use super::{
     config::Config,
     emitter::Emitter,
     event::Events,
-    json,
-    processor::{ConnectingErrorType, Latency, Session, ShardProcessor},
+    event_queue::{EventQueueStats, Shared as EventQueue},
+    member_requests::{GuildMembers, MemberRequests},
+    payload,
+    processor::{CommandRatelimit, ConnectingErrorType, Latency, Session, ShardProcessor},
     raw_message::Message,
     stage::Stage,
 };
 use crate::Intents;
-use futures_util::stream::StreamExt;
 use once_cell::sync::OnceCell;
 use serde::{Deserialize, Serialize};
 use std::{
@@ -17,11 +18,13 @@ use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
     sync::{atomic::Ordering, Arc, Mutex},
+    time::Duration,
 };
-use tokio::{sync::watch::Receiver as WatchReceiver, task::JoinHandle};
+use tokio::{sync::watch::Receiver as WatchReceiver, task::JoinHandle, time};
 use tokio_tungstenite::tungstenite::protocol::{
     frame::coding::CloseCode, CloseFrame as TungsteniteCloseFrame,
 };
+use twilight_model::gateway::payload::RequestGuildMembers;
 
 /// Sending a command failed.
 #[derive(Debug)]
@@ -55,6 +58,7 @@ impl CommandError {
         let (kind, source) = error.into_parts();
 
         let new_kind = match kind {
+            SendErrorType::Ratelimited => CommandErrorType::Ratelimited,
             SendErrorType::Sending => CommandErrorType::Sending,
             SendErrorType::SessionInactive => CommandErrorType::SessionInactive,
         };
@@ -69,10 +73,13 @@ impl CommandError {
 impl Display for CommandError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match &self.kind {
+            CommandErrorType::Ratelimited => {
+                f.write_str("no command can be sent without exceeding the ratelimit")
+            }
             CommandErrorType::Sending => {
                 f.write_str("sending the message over the websocket failed")
             }
-            CommandErrorType::Serializing => f.write_str("serializing the value as json failed"),
+            CommandErrorType::Serializing => f.write_str("serializing the value failed"),
             CommandErrorType::SessionInactive => Display::fmt(&SessionInactiveError, f),
         }
     }
@@ -90,15 +97,87 @@ impl Error for CommandError {
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum CommandErrorType {
+    /// Sending the payload now would exceed the ratelimit of commands.
+    ///
+    /// Only returned by [`Shard::try_command`].
+    Ratelimited,
     /// Sending the payload over the WebSocket failed. This is indicative of a
     /// shutdown shard.
     Sending,
-    /// Serializing the payload as JSON failed.
+    /// Serializing the payload failed.
     Serializing,
     /// Shard's session is inactive because the shard hasn't been started.
     SessionInactive,
 }
 
+/// Requesting the members of a guild failed.
+#[derive(Debug)]
+pub struct RequestMembersError {
+    kind: RequestMembersErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl RequestMembersError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &RequestMembersErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        RequestMembersErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for RequestMembersError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            RequestMembersErrorType::Interrupted => {
+                f.write_str("the shard was shut down before all members were received")
+            }
+            RequestMembersErrorType::Sending => f.write_str("sending the request failed"),
+            RequestMembersErrorType::TimedOut => {
+                f.write_str("not all members were received before the timeout")
+            }
+        }
+    }
+}
+
+impl Error for RequestMembersError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`RequestMembersError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum RequestMembersErrorType {
+    /// Shard was shut down before all chunks of members were received.
+    Interrupted,
+    /// Sending the request failed.
+    ///
+    /// The source is a [`CommandError`].
+    Sending,
+    /// Not all chunks of members were received before the timeout.
+    TimedOut,
+}
+
 /// Shard's session is inactive.
 ///
 /// This means that the shard has not yet been started.
@@ -144,6 +223,9 @@ impl SendError {
 impl Display for SendError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match &self.kind {
+            SendErrorType::Ratelimited => {
+                f.write_str("no message can be sent without exceeding the ratelimit")
+            }
             SendErrorType::Sending { .. } => {
                 f.write_str("sending the message over the websocket failed")
             }
@@ -164,6 +246,10 @@ impl Error for SendError {
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum SendErrorType {
+    /// Sending the message now would exceed the ratelimit of commands.
+    ///
+    /// Only returned by [`Shard::try_send`].
+    Ratelimited,
     /// Sending the payload over the WebSocket failed. This is indicative of a
     /// shard that isn't properly running.
     Sending,
@@ -311,6 +397,8 @@ pub struct ResumeSession {
 struct ShardRef {
     config: Arc<Config>,
     emitter: Mutex<Option<Emitter>>,
+    event_queue: Arc<EventQueue>,
+    member_requests: Arc<MemberRequests>,
     processor_handle: OnceCell<JoinHandle<()>>,
     session: OnceCell<WatchReceiver<Arc<Session>>>,
 }
@@ -413,11 +501,17 @@ impl Shard {
         let config = Arc::new(config);
         let event_types = config.event_types();
 
-        let (emitter, rx) = Emitter::new(event_types);
+        let (emitter, rx, event_queue) = Emitter::new(
+            event_types,
+            config.event_queue_capacity(),
+            config.event_queue_policy(),
+        );
 
         let this = Self(Arc::new(ShardRef {
             config,
             emitter: Mutex::new(Some(emitter)),
+            event_queue,
+            member_requests: Arc::new(MemberRequests::default()),
             processor_handle: OnceCell::new(),
             session: OnceCell::new(),
         }));
@@ -498,24 +592,24 @@ impl Shard {
             })?;
 
         let config = Arc::clone(&self.0.config);
-        let (processor, wrx) =
-            ShardProcessor::new(config, url, emitter)
-                .await
-                .map_err(|source| {
-                    let (kind, source) = source.into_parts();
-
-                    let new_kind = match kind {
-                        ConnectingErrorType::Establishing => ShardStartErrorType::Establishing,
-                        ConnectingErrorType::ParsingUrl { url } => {
-                            ShardStartErrorType::ParsingGatewayUrl { url }
-                        }
-                    };
-
-                    ShardStartError {
-                        source,
-                        kind: new_kind,
+        let member_requests = Arc::clone(&self.0.member_requests);
+        let (processor, wrx) = ShardProcessor::new(config, url, emitter, member_requests)
+            .await
+            .map_err(|source| {
+                let (kind, source) = source.into_parts();
+
+                let new_kind = match kind {
+                    ConnectingErrorType::Establishing => ShardStartErrorType::Establishing,
+                    ConnectingErrorType::ParsingUrl { url } => {
+                        ShardStartErrorType::ParsingGatewayUrl { url }
                     }
-                })?;
+                };
+
+                ShardStartError {
+                    source,
+                    kind: new_kind,
+                }
+            })?;
 
         let handle = tokio::spawn(async move {
             processor.run().await;
@@ -549,8 +643,35 @@ impl Shard {
         })
     }
 
+    /// Retrieve the state of the ratelimit of commands sent by the shard,
+    /// such as the number of commands that can be sent without waiting.
+    ///
+    /// Discord allows 120 commands to be sent every 60 seconds, of which some
+    /// are reserved for heartbeating and identifying or resuming the session.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SessionInactiveError`] if the shard's session is inactive.
+    pub fn command_ratelimit(&self) -> Result<CommandRatelimit, SessionInactiveError> {
+        let session = self.session()?;
+
+        Ok(session.ratelimit.ratelimit())
+    }
+
+    /// Retrieve the state of the queue of events waiting to be received by the
+    /// shard's event stream, such as its length and the number of dropped
+    /// events.
+    ///
+    /// Refer to [`ShardBuilder::event_queue`] for bounding the queue.
+    pub fn event_queue(&self) -> EventQueueStats {
+        self.0.event_queue.stats()
+    }
+
     /// Send a command over the gateway.
     ///
+    /// If the ratelimit of commands has been reached then this waits until a
+    /// command can be sent. Use [`try_command`] to return an error instead.
+    ///
     /// # Errors
     ///
     /// Returns a [`CommandErrorType::Sending`] error type if the message could
@@ -558,23 +679,139 @@ impl Shard {
     /// restarting.
     ///
     /// Returns a [`CommandErrorType::Serializing`] error type if the provided
-    /// value failed to serialize into JSON.
+    /// value failed to serialize.
     ///
     /// Returns a [`CommandErrorType::SessionInactive`] error type if the shard
     /// has not been started.
+    ///
+    /// [`try_command`]: Self::try_command
     pub async fn command(&self, value: &impl serde::Serialize) -> Result<(), CommandError> {
-        let json = json::to_vec(value).map_err(|source| CommandError {
+        let bytes = payload::to_vec(value).map_err(|source| CommandError {
             source: Some(Box::new(source)),
             kind: CommandErrorType::Serializing,
         })?;
 
-        self.send(Message::Binary(json))
+        self.send(Message::Binary(bytes))
             .await
             .map_err(CommandError::from_send)
     }
 
+    /// Send a command over the gateway if it can be sent without waiting for
+    /// the ratelimit.
+    ///
+    /// Refer to [`command_ratelimit`] for the remaining capacity.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CommandErrorType::Ratelimited`] error type if sending the
+    /// command would exceed the ratelimit.
+    ///
+    /// Returns a [`CommandErrorType::Sending`] error type if the message could
+    /// not be sent over the websocket. This indicates the shard is currently
+    /// restarting.
+    ///
+    /// Returns a [`CommandErrorType::Serializing`] error type if the provided
+    /// value failed to serialize.
+    ///
+    /// Returns a [`CommandErrorType::SessionInactive`] error type if the shard
+    /// has not been started.
+    ///
+    /// [`command_ratelimit`]: Self::command_ratelimit
+    pub fn try_command(&self, value: &impl serde::Serialize) -> Result<(), CommandError> {
+        let bytes = payload::to_vec(value).map_err(|source| CommandError {
+            source: Some(Box::new(source)),
+            kind: CommandErrorType::Serializing,
+        })?;
+
+        self.try_send(Message::Binary(bytes))
+            .map_err(CommandError::from_send)
+    }
+
+    /// Request members of a guild and wait until all of them are received.
+    ///
+    /// Discord sends the members in chunks, which are collected by matching
+    /// their nonce. The nonce of the request is replaced with one generated
+    /// by the shard. Chunks are still emitted as [`Event::MemberChunk`]s if
+    /// the shard's event types include them.
+    ///
+    /// The request is sent through [`command`], waiting for the ratelimit if
+    /// needed. The timeout starts once the request has been sent.
+    ///
+    /// This requires the [`GUILD_MEMBERS`] intent, and the
+    /// [`GUILD_PRESENCES`] intent to request presences.
+    ///
+    /// # Examples
+    ///
+    /// Request all members of a guild:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::{env, time::Duration};
+    /// use twilight_gateway::{Intents, Shard};
+    /// use twilight_model::{gateway::payload::RequestGuildMembers, id::GuildId};
+    ///
+    /// let token = env::var("DISCORD_TOKEN")?;
+    /// let (shard, _) = Shard::new(token, Intents::GUILD_MEMBERS);
+    /// shard.start().await?;
+    ///
+    /// let request = RequestGuildMembers::builder(GuildId(1)).query("", None);
+    /// let members = shard
+    ///     .request_members(request, Duration::from_secs(30))
+    ///     .await?;
+    ///
+    /// println!("guild has {} members", members.members().len());
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RequestMembersErrorType::Interrupted`] error type if the
+    /// shard was shut down before all members were received.
+    ///
+    /// Returns a [`RequestMembersErrorType::Sending`] error type if sending
+    /// the request failed.
+    ///
+    /// Returns a [`RequestMembersErrorType::TimedOut`] error type if not all
+    /// members were received before the timeout.
+    ///
+    /// [`Event::MemberChunk`]: crate::Event::MemberChunk
+    /// [`GUILD_MEMBERS`]: crate::Intents::GUILD_MEMBERS
+    /// [`GUILD_PRESENCES`]: crate::Intents::GUILD_PRESENCES
+    /// [`command`]: Self::command
+    pub async fn request_members(
+        &self,
+        mut request: RequestGuildMembers,
+        timeout: Duration,
+    ) -> Result<GuildMembers, RequestMembersError> {
+        // The request stops being collected once the registration is
+        // dropped, including when this future is.
+        let (registration, rx) = self.0.member_requests.register();
+        request.d.nonce = Some(registration.nonce().to_owned());
+
+        self.command(&request)
+            .await
+            .map_err(|source| RequestMembersError {
+                kind: RequestMembersErrorType::Sending,
+                source: Some(Box::new(source)),
+            })?;
+
+        time::timeout(timeout, rx)
+            .await
+            .map_err(|source| RequestMembersError {
+                kind: RequestMembersErrorType::TimedOut,
+                source: Some(Box::new(source)),
+            })?
+            .map_err(|source| RequestMembersError {
+                kind: RequestMembersErrorType::Interrupted,
+                source: Some(Box::new(source)),
+            })
+    }
+
     /// Send a raw websocket message.
     ///
+    /// If the ratelimit of commands has been reached then this waits until a
+    /// message can be sent. Use [`try_send`] to return an error instead.
+    ///
     /// # Examples
     ///
     /// Send a ping message:
@@ -625,27 +862,46 @@ impl Shard {
     /// not been started.
     ///
     /// [`shutdown`]: Self::shutdown
+    /// [`try_send`]: Self::try_send
     pub async fn send(&self, message: Message) -> Result<(), SendError> {
         let session = self.session().map_err(|source| SendError {
             source: Some(Box::new(source)),
             kind: SendErrorType::SessionInactive,
         })?;
 
-        // Only tick the ratelimiter if there wasn't an error sending it over
-        // the tx. If tx sending fails then the message couldn't be sent anyway,
-        // which does not affect ratelimiting of external sending.
-        match session.tx.send(message.into_tungstenite()) {
-            Ok(()) => {
-                // Tick ratelimiter.
-                session.ratelimit.lock().await.next().await;
+        session.ratelimit.acquire().await;
 
-                Ok(())
-            }
-            Err(source) => Err(SendError {
-                source: Some(Box::new(source)),
-                kind: SendErrorType::Sending,
-            }),
+        Self::send_session(&session, message)
+    }
+
+    /// Send a raw websocket message if it can be sent without waiting for the
+    /// ratelimit.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SendErrorType::Ratelimited`] error type if sending the
+    /// message would exceed the ratelimit.
+    ///
+    /// Returns a [`SendErrorType::Sending`] error type if there is an issue
+    /// with sending via the shard's session. This may occur when the shard is
+    /// between sessions.
+    ///
+    /// Returns [`SendErrorType::SessionInactive`] error type when the shard has
+    /// not been started.
+    pub fn try_send(&self, message: Message) -> Result<(), SendError> {
+        let session = self.session().map_err(|source| SendError {
+            source: Some(Box::new(source)),
+            kind: SendErrorType::SessionInactive,
+        })?;
+
+        if !session.ratelimit.try_acquire() {
+            return Err(SendError {
+                source: None,
+                kind: SendErrorType::Ratelimited,
+            });
         }
+
+        Self::send_session(&session, message)
     }
 
     /// Send a raw command over the gateway.
@@ -660,7 +916,7 @@ impl Shard {
     /// restarting.
     ///
     /// Returns a [`CommandErrorType::Serializing`] error type if the provided
-    /// value failed to serialize into JSON.
+    /// value failed to serialize.
     ///
     /// Returns a [`CommandErrorType::SessionInactive`] error type if the shard
     /// has not been started.
@@ -683,6 +939,8 @@ impl Shard {
             processor_handle.abort();
         }
 
+        self.0.member_requests.clear();
+
         if let Ok(session) = self.session() {
             // Since we're shutting down now, we don't care if it sends or not.
             let _res = session.close(Some(TungsteniteCloseFrame {
@@ -706,6 +964,8 @@ impl Shard {
             processor_handle.abort();
         }
 
+        self.0.member_requests.clear();
+
         let shard_id = self.config().shard()[0];
 
         let session = match self.session() {
@@ -741,21 +1001,48 @@ impl Shard {
 
         Ok(Arc::clone(&session.borrow()))
     }
+
+    /// Send a message over a session whose ratelimit was already acquired.
+    ///
+    /// The acquired slot is given back if the message couldn't be sent.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SendErrorType::Sending`] error type if the session's
+    /// channel has been closed.
+    fn send_session(session: &Session, message: Message) -> Result<(), SendError> {
+        session
+            .tx
+            .send(message.into_tungstenite())
+            .map_err(|source| {
+                session.ratelimit.release();
+
+                SendError {
+                    source: Some(Box::new(source)),
+                    kind: SendErrorType::Sending,
+                }
+            })
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::{
-        CommandError, CommandErrorType, Information, ResumeSession, SendError, SendErrorType,
+        CommandError, CommandErrorType, Information, Message, RequestMembersError,
+        RequestMembersErrorType, ResumeSession, SendError, SendErrorType, Session,
         SessionInactiveError, Shard, ShardStartError, ShardStartErrorType,
     };
+    use crate::Intents;
     use static_assertions::{assert_fields, assert_impl_all};
-    use std::{error::Error, fmt::Debug};
+    use std::{error::Error, fmt::Debug, sync::Arc};
+    use tokio::sync::{mpsc, watch};
 
     assert_impl_all!(CommandErrorType: Debug, Send, Sync);
     assert_impl_all!(CommandError: Error, Send, Sync);
     assert_impl_all!(Information: Clone, Debug, Send, Sync);
     assert_impl_all!(ResumeSession: Clone, Debug, Send, Sync);
+    assert_impl_all!(RequestMembersErrorType: Debug, Send, Sync);
+    assert_impl_all!(RequestMembersError: Error, Send, Sync);
     assert_impl_all!(SendErrorType: Debug, Send, Sync);
     assert_impl_all!(SendError: Error, Send, Sync);
     assert_impl_all!(SessionInactiveError: Error, Send, Sync);
@@ -763,4 +1050,28 @@ mod tests {
     assert_impl_all!(ShardStartErrorType: Debug, Send, Sync);
     assert_impl_all!(ShardStartError: Error, Send, Sync);
     assert_impl_all!(Shard: Clone, Debug, Send, Sync);
+
+    #[tokio::test]
+    async fn test_failed_send_releases_ratelimit() -> Result<(), Box<dyn Error>> {
+        let (shard, _events) = Shard::new("token", Intents::empty());
+        // A session whose connection has been closed.
+        let (tx, _) = mpsc::unbounded_channel();
+        let (_session_tx, session_rx) = watch::channel(Arc::new(Session::new(tx)));
+        let _session = shard.0.session.set(session_rx);
+        let ratelimit = shard.command_ratelimit()?;
+
+        let error = shard
+            .send(Message::Text("test".to_owned()))
+            .await
+            .unwrap_err();
+        assert!(matches!(error.kind(), SendErrorType::Sending));
+        let error = shard
+            .try_send(Message::Text("test".to_owned()))
+            .unwrap_err();
+        assert!(matches!(error.kind(), SendErrorType::Sending));
+
+        assert_eq!(ratelimit, shard.command_ratelimit()?);
+
+        Ok(())
+    }
 }
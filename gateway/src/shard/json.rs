@@ -19,10 +19,10 @@ impl Display for GatewayEventParsingError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match &self.kind {
             GatewayEventParsingErrorType::Deserializing => {
-                f.write_str("deserializing gateway event as json failed")
+                f.write_str("deserializing gateway event failed")
             }
             GatewayEventParsingErrorType::PayloadInvalid => {
-                f.write_str("payload is an invalid json structure")
+                f.write_str("payload is an invalid structure")
             }
         }
     }
@@ -0,0 +1,244 @@
+//! Collection of the member chunks received in response to member requests
+//! made through [`Shard::request_members`].
+//!
+//! [`Shard::request_members`]: super::Shard::request_members
+
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+};
+use tokio::sync::oneshot::{self, Receiver, Sender};
+use twilight_model::{
+    gateway::{payload::MemberChunk, presence::Presence},
+    guild::Member,
+    id::{GuildId, UserId},
+};
+
+/// Members of a guild received in response to a member request.
+///
+/// Returned by [`Shard::request_members`] and [`Cluster::request_members`]
+/// once every chunk of the response has been received.
+///
+/// [`Cluster::request_members`]: crate::Cluster::request_members
+/// [`Shard::request_members`]: super::Shard::request_members
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GuildMembers {
+    guild_id: GuildId,
+    members: Vec<Member>,
+    not_found: Vec<UserId>,
+    presences: Vec<Presence>,
+}
+
+impl GuildMembers {
+    const fn new(guild_id: GuildId) -> Self {
+        Self {
+            guild_id,
+            members: Vec::new(),
+            not_found: Vec::new(),
+            presences: Vec::new(),
+        }
+    }
+
+    /// ID of the guild the members are in.
+    pub const fn guild_id(&self) -> GuildId {
+        self.guild_id
+    }
+
+    /// Members that were received.
+    pub fn members(&self) -> &[Member] {
+        &self.members
+    }
+
+    /// IDs of the requested users that aren't members of the guild.
+    pub fn not_found(&self) -> &[UserId] {
+        &self.not_found
+    }
+
+    /// Presences of the members, if they were requested.
+    pub fn presences(&self) -> &[Presence] {
+        &self.presences
+    }
+
+    /// Consume the members, returning the owned members, IDs of users that
+    /// weren't found, and presences.
+    #[must_use = "consuming the members into their parts has no effect if left unused"]
+    pub fn into_parts(self) -> (Vec<Member>, Vec<UserId>, Vec<Presence>) {
+        (self.members, self.not_found, self.presences)
+    }
+}
+
+/// Member request waiting for its chunks.
+#[derive(Debug)]
+struct Pending {
+    /// Members received so far, or `None` if no chunk has been received.
+    members: Option<GuildMembers>,
+    /// Number of chunks received.
+    received: u32,
+    tx: Sender<GuildMembers>,
+}
+
+/// Member requests of a shard waiting for their chunks, by nonce.
+#[derive(Debug, Default)]
+pub struct MemberRequests {
+    next_nonce: AtomicU64,
+    pending: Mutex<HashMap<String, Pending>>,
+}
+
+impl MemberRequests {
+    /// Register a new request, returning its generated nonce and a receiver
+    /// of its members once all chunks have been received.
+    ///
+    /// The request is removed once the returned registration is dropped.
+    pub fn register(self: &Arc<Self>) -> (Registration, Receiver<GuildMembers>) {
+        // Discord limits nonces to 32 bytes, which the prefix and a `u64`
+        // stay under.
+        let nonce = format!(
+            "twilight-{}",
+            self.next_nonce.fetch_add(1, Ordering::Relaxed)
+        );
+        let (tx, rx) = oneshot::channel();
+
+        self.pending().insert(
+            nonce.clone(),
+            Pending {
+                members: None,
+                received: 0,
+                tx,
+            },
+        );
+
+        let registration = Registration {
+            nonce,
+            requests: Arc::clone(self),
+        };
+
+        (registration, rx)
+    }
+
+    /// Whether no requests are waiting for chunks.
+    pub fn is_empty(&self) -> bool {
+        self.pending().is_empty()
+    }
+
+    /// Add a chunk to the request with its nonce, if there is one.
+    ///
+    /// The request's members are sent once its last chunk is received.
+    pub fn receive(&self, chunk: &MemberChunk) {
+        let nonce = match chunk.nonce.as_deref() {
+            Some(nonce) => nonce,
+            None => return,
+        };
+
+        let mut pending = self.pending();
+
+        let request = match pending.get_mut(nonce) {
+            Some(request) => request,
+            None => return,
+        };
+
+        let members = request
+            .members
+            .get_or_insert_with(|| GuildMembers::new(chunk.guild_id));
+        members.members.extend_from_slice(&chunk.members);
+        members.not_found.extend_from_slice(&chunk.not_found);
+        members.presences.extend_from_slice(&chunk.presences);
+        request.received += 1;
+
+        if request.received < chunk.chunk_count {
+            return;
+        }
+
+        if let Some(request) = pending.remove(nonce) {
+            if let Some(members) = request.members {
+                // The receiver may have timed out in the meantime.
+                let _res = request.tx.send(members);
+            }
+        }
+    }
+
+    /// Remove every request, making them fail.
+    pub fn clear(&self) {
+        self.pending().clear();
+    }
+
+    fn pending(&self) -> std::sync::MutexGuard<'_, HashMap<String, Pending>> {
+        self.pending.lock().expect("member requests poisoned")
+    }
+}
+
+/// Registration of a request, removing it when dropped.
+#[derive(Debug)]
+pub struct Registration {
+    nonce: String,
+    requests: Arc<MemberRequests>,
+}
+
+impl Registration {
+    /// Nonce to set on the request.
+    pub fn nonce(&self) -> &str {
+        &self.nonce
+    }
+}
+
+impl Drop for Registration {
+    fn drop(&mut self) {
+        self.requests.pending().remove(&self.nonce);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GuildMembers, MemberRequests};
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, sync::Arc};
+    use tokio::sync::oneshot::error::TryRecvError;
+    use twilight_model::{
+        gateway::payload::MemberChunk,
+        id::{GuildId, UserId},
+    };
+
+    assert_impl_all!(GuildMembers: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(MemberRequests: Debug, Send, Sync);
+
+    fn chunk(nonce: Option<&str>, chunk_index: u32, not_found: u64) -> MemberChunk {
+        MemberChunk {
+            chunk_count: 2,
+            chunk_index,
+            guild_id: GuildId(1),
+            members: Vec::new(),
+            nonce: nonce.map(ToOwned::to_owned),
+            not_found: vec![UserId(not_found)],
+            presences: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_collect_chunks() {
+        let requests = Arc::new(MemberRequests::default());
+        let (first, mut first_rx) = requests.register();
+        let (second, mut second_rx) = requests.register();
+        assert_ne!(first.nonce(), second.nonce());
+
+        // Chunks of other requests are ignored.
+        requests.receive(&chunk(None, 0, 1));
+        requests.receive(&chunk(Some("other"), 0, 2));
+        requests.receive(&chunk(Some(first.nonce()), 1, 3));
+        assert_eq!(Err(TryRecvError::Empty), first_rx.try_recv());
+
+        requests.receive(&chunk(Some(first.nonce()), 0, 4));
+        let members = first_rx.try_recv().expect("all chunks received");
+        assert_eq!(GuildId(1), members.guild_id());
+        assert_eq!(&[UserId(3), UserId(4)], members.not_found());
+        assert_eq!(Err(TryRecvError::Empty), second_rx.try_recv());
+
+        // Dropping the registration removes the request.
+        assert!(!requests.is_empty());
+        drop(first);
+        drop(second);
+        assert!(requests.is_empty());
+        assert_eq!(Err(TryRecvError::Closed), second_rx.try_recv());
+    }
+}
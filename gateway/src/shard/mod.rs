@@ -25,9 +25,14 @@ pub mod stage;
 mod builder;
 mod config;
 mod emitter;
+#[cfg(feature = "etf")]
+mod etf;
 mod event;
+mod event_queue;
 mod r#impl;
 mod json;
+mod member_requests;
+mod payload;
 mod processor;
 
 pub use self::{
@@ -36,10 +41,13 @@ pub use self::{
     },
     config::Config,
     event::Events,
-    processor::heartbeat::Latency,
+    event_queue::{EventQueuePolicy, EventQueueStats},
+    member_requests::GuildMembers,
+    processor::{heartbeat::Latency, CommandRatelimit},
     r#impl::{
-        CommandError, CommandErrorType, Information, ResumeSession, SendError, SendErrorType,
-        SessionInactiveError, Shard, ShardStartError, ShardStartErrorType,
+        CommandError, CommandErrorType, Information, RequestMembersError, RequestMembersErrorType,
+        ResumeSession, SendError, SendErrorType, SessionInactiveError, Shard, ShardStartError,
+        ShardStartErrorType,
     },
     stage::Stage,
 };
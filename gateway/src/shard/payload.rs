@@ -0,0 +1,17 @@
+//! Encoding of payloads sent to and received from the gateway.
+//!
+//! Payloads are encoded as JSON, or in Erlang's External Term Format if the
+//! `etf` feature is enabled.
+
+#[cfg(feature = "etf")]
+pub use super::etf::{from_slice, parse_gateway_event, to_vec};
+#[cfg(not(feature = "etf"))]
+pub use super::json::{from_slice, parse_gateway_event, to_vec};
+
+/// Value of the `encoding` query parameter of gateway URLs.
+#[cfg(feature = "etf")]
+pub const ENCODING: &str = "etf";
+
+/// Value of the `encoding` query parameter of gateway URLs.
+#[cfg(not(feature = "etf"))]
+pub const ENCODING: &str = "json";
@@ -1,19 +1,29 @@
-#[cfg(feature = "compression")]
+#[cfg(all(feature = "compression", not(feature = "zstd")))]
 mod inflater;
+#[cfg(feature = "zstd")]
+mod zstd_inflater;
 
 use super::r#impl::ReceivingEventError;
 
-#[cfg(feature = "compression")]
+#[cfg(all(feature = "compression", not(feature = "zstd")))]
 use inflater::Inflater;
+#[cfg(feature = "zstd")]
+use zstd_inflater::ZstdInflater as Inflater;
 
-/// Interface for working with buffers variable on the `compression` feature flag.
+/// Interface for working with buffers variable on the `compression` and
+/// `zstd` feature flags.
+///
+/// zstd-stream compression is used if the `zstd` feature is enabled,
+/// falling back to zlib-stream compression if the `compression` feature is
+/// enabled. The choice is made at compile time: the zlib inflater isn't
+/// built when `zstd` is enabled.
 #[derive(Debug)]
 pub struct Compression {
     /// Inflater for use with compression.
-    #[cfg(feature = "compression")]
+    #[cfg(any(feature = "compression", feature = "zstd"))]
     inner: Inflater,
     /// Buffer for use without compression.
-    #[cfg(not(feature = "compression"))]
+    #[cfg(not(any(feature = "compression", feature = "zstd")))]
     inner: Vec<u8>,
 }
 
@@ -21,14 +31,14 @@ impl Compression {
     /// Create a new buffer, abstracting over an inflater if the `compression`
     /// feature is enabled or a simple `Vec` if the feature is disabled.
     #[cfg_attr(
-        not(feature = "compression"),
+        not(any(feature = "compression", feature = "zstd")),
         allow(clippy::missing_const_for_fn, unused_variables)
     )]
     pub fn new(shard_id: [u64; 2]) -> Self {
         Self {
-            #[cfg(feature = "compression")]
+            #[cfg(any(feature = "compression", feature = "zstd"))]
             inner: Inflater::new(shard_id),
-            #[cfg(not(feature = "compression"))]
+            #[cfg(not(any(feature = "compression", feature = "zstd")))]
             inner: Vec::new(),
         }
     }
@@ -41,12 +51,12 @@ impl Compression {
     /// When compression is disabled this will mutably reference the standard
     /// buffer.
     pub fn buffer_slice_mut(&mut self) -> &mut [u8] {
-        #[cfg(feature = "compression")]
+        #[cfg(any(feature = "compression", feature = "zstd"))]
         {
             self.inner.buffer_mut()
         }
 
-        #[cfg(not(feature = "compression"))]
+        #[cfg(not(any(feature = "compression", feature = "zstd")))]
         self.inner.as_mut_slice()
     }
 
@@ -57,22 +67,31 @@ impl Compression {
 
     /// Extend the buffer with bytes from a Binary websocket message.
     ///
-    /// If compression is disabled then this will do nothing.
+    /// If compression and the `etf` feature are disabled then this will do
+    /// nothing.
     ///
     /// Returns whether the inner buffer was extended.
     #[cfg_attr(
-        not(feature = "compression"),
+        not(any(feature = "compression", feature = "zstd", feature = "etf")),
         allow(clippy::unused_self, unused_variables)
     )]
     pub fn extend_binary(&mut self, bytes: &[u8]) -> bool {
-        #[cfg(feature = "compression")]
+        #[cfg(any(feature = "compression", feature = "zstd"))]
         {
             self.inner.extend(bytes);
 
             true
         }
 
-        #[cfg(not(feature = "compression"))]
+        #[cfg(all(not(any(feature = "compression", feature = "zstd")), feature = "etf"))]
+        {
+            // Terms are always sent in Binary payloads.
+            self.inner.extend_from_slice(bytes);
+
+            true
+        }
+
+        #[cfg(not(any(feature = "compression", feature = "zstd", feature = "etf")))]
         // Binary payloads are not received when compression is disabled.
         false
     }
@@ -82,16 +101,19 @@ impl Compression {
     /// If compression is enabled then this will do nothing.
     ///
     /// Returns whether the inner buffer was extended.
-    #[cfg_attr(feature = "compression", allow(clippy::unused_self, unused_variables))]
+    #[cfg_attr(
+        any(feature = "compression", feature = "zstd"),
+        allow(clippy::unused_self, unused_variables)
+    )]
     pub fn extend_text(&mut self, bytes: &[u8]) -> bool {
-        #[cfg(not(feature = "compression"))]
+        #[cfg(not(any(feature = "compression", feature = "zstd")))]
         {
             self.inner.extend_from_slice(bytes);
 
             true
         }
 
-        #[cfg(feature = "compression")]
+        #[cfg(any(feature = "compression", feature = "zstd"))]
         // Text payloads are not received when compression is enabled.
         false
     }
@@ -105,7 +127,9 @@ impl Compression {
     /// If compression is enabled and a message has *not* completed then a
     /// successful `None` is returned.
     ///
-    /// If compression is disabled then a successful `None` is returned.
+    /// If compression is disabled then a successful `None` is returned, unless
+    /// the `etf` feature is enabled, in which case the buffer holds a complete
+    /// message and is returned.
     ///
     /// # Errors
     ///
@@ -113,11 +137,11 @@ impl Compression {
     /// `ReceivingEventErrorType::Decompressing` error type if decompressing the
     /// message failed.
     #[cfg_attr(
-        not(feature = "compression"),
+        not(any(feature = "compression", feature = "zstd")),
         allow(clippy::unnecessary_wraps, clippy::unused_self)
     )]
     pub fn message_mut(&mut self) -> Result<Option<&mut [u8]>, ReceivingEventError> {
-        #[cfg(feature = "compression")]
+        #[cfg(any(feature = "compression", feature = "zstd"))]
         {
             use super::r#impl::ReceivingEventErrorType;
 
@@ -127,16 +151,21 @@ impl Compression {
             })
         }
 
-        #[cfg(not(feature = "compression"))]
+        #[cfg(all(not(any(feature = "compression", feature = "zstd")), feature = "etf"))]
+        {
+            Ok(Some(self.inner.as_mut_slice()))
+        }
+
+        #[cfg(not(any(feature = "compression", feature = "zstd", feature = "etf")))]
         Ok(None)
     }
 
     /// Reset the buffer for a new gateway session.
     pub fn reset(&mut self) {
-        #[cfg(feature = "compression")]
+        #[cfg(any(feature = "compression", feature = "zstd"))]
         self.inner.reset();
 
-        #[cfg(not(feature = "compression"))]
+        #[cfg(not(any(feature = "compression", feature = "zstd")))]
         self.clear();
     }
 }
@@ -144,11 +173,18 @@ impl Compression {
 /// Add a toggle to a gateway connection URL depending on whether compression is
 /// enabled.
 ///
-/// If compression is enabled then the `compress` query parameter is appended
-/// with a value of `zlib-stream`.
-#[cfg_attr(not(feature = "compression"), allow(unused_variables))]
+/// If the `zstd` feature is enabled then the `compress` query parameter is
+/// appended with a value of `zstd-stream`, otherwise if compression is enabled
+/// it is appended with a value of `zlib-stream`.
+#[cfg_attr(
+    not(any(feature = "compression", feature = "zstd")),
+    allow(unused_variables)
+)]
 pub fn add_url_feature(buf: &mut String) {
-    #[cfg(feature = "compression")]
+    #[cfg(feature = "zstd")]
+    buf.push_str("&compress=zstd-stream");
+
+    #[cfg(all(feature = "compression", not(feature = "zstd")))]
     buf.push_str("&compress=zlib-stream");
 }
 
@@ -159,12 +195,17 @@ mod tests {
         let mut buf = String::new();
         super::add_url_feature(&mut buf);
 
-        #[cfg(feature = "compression")]
+        #[cfg(feature = "zstd")]
+        {
+            assert_eq!("&compress=zstd-stream", buf);
+        }
+
+        #[cfg(all(feature = "compression", not(feature = "zstd")))]
         {
             assert_eq!("&compress=zlib-stream", buf);
         }
 
-        #[cfg(not(feature = "compression"))]
+        #[cfg(not(any(feature = "compression", feature = "zstd")))]
         assert!(buf.is_empty());
     }
 }
@@ -0,0 +1,296 @@
+use std::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    io::Error as IoError,
+    mem,
+    sync::Mutex,
+    time::Instant,
+};
+use zstd::stream::raw::{Decoder, InBuffer, Operation, OutBuffer};
+
+const INTERNAL_BUFFER_SIZE: usize = 32 * 1024;
+
+/// Streaming decompressor of `zstd-stream` transport compression.
+///
+/// Discord compresses every message of a connection with the same stream,
+/// flushing it at the end of each message, so each Binary websocket message
+/// holds a complete payload.
+pub struct ZstdInflater {
+    /// Decoder of the stream.
+    ///
+    /// zstd's decompression context isn't `Sync`, which shard processors
+    /// must be. The decoder is only used through mutable references, so the
+    /// mutex is never locked.
+    decoder: Mutex<Decoder<'static>>,
+    compressed: Vec<u8>,
+    internal_buffer: Vec<u8>,
+    buffer: Vec<u8>,
+    last_resize: Instant,
+    shard: [u64; 2],
+    total_in: u64,
+    total_out: u64,
+}
+
+impl ZstdInflater {
+    /// Create a new inflater for a shard.
+    ///
+    /// # Panics
+    ///
+    /// Panics if zstd fails to allocate a decompression context.
+    pub fn new(shard: [u64; 2]) -> Self {
+        Self {
+            buffer: Vec::with_capacity(INTERNAL_BUFFER_SIZE),
+            compressed: Vec::new(),
+            decoder: Mutex::new(
+                Decoder::new().expect("zstd decompression context allocation failed"),
+            ),
+            internal_buffer: Vec::with_capacity(INTERNAL_BUFFER_SIZE),
+            last_resize: Instant::now(),
+            shard,
+            total_in: 0,
+            total_out: 0,
+        }
+    }
+
+    /// Return a mutable reference to the buffer.
+    pub fn buffer_mut(&mut self) -> &mut [u8] {
+        self.buffer.as_mut_slice()
+    }
+
+    /// Extend the internal compressed buffer with bytes.
+    pub fn extend(&mut self, slice: &[u8]) {
+        self.compressed.extend_from_slice(slice);
+    }
+
+    /// Decompress the next message if a payload was received.
+    ///
+    /// Returns `None` if no payload was received.
+    ///
+    /// # Errors
+    ///
+    /// Returns zstd's error if the payload isn't part of a valid stream.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace"))]
+    pub fn msg(&mut self) -> Result<Option<&mut [u8]>, IoError> {
+        if self.compressed.is_empty() {
+            return Ok(None);
+        }
+
+        let decoder = self
+            .decoder
+            .get_mut()
+            .expect("zstd decoder is never locked");
+        let mut input = InBuffer::around(&self.compressed);
+
+        loop {
+            let before = input.pos();
+            self.internal_buffer.clear();
+
+            let mut output = OutBuffer::around(&mut self.internal_buffer);
+            decoder.run(&mut input, &mut output)?;
+
+            self.buffer.extend_from_slice(&self.internal_buffer[..]);
+
+            let not_at_capacity = self.internal_buffer.len() < self.internal_buffer.capacity();
+            let stalled = self.internal_buffer.is_empty() && input.pos() == before;
+
+            // The decoder may hold more output if it filled the internal
+            // buffer, even once all of the input has been read.
+            if (not_at_capacity && input.pos() == self.compressed.len()) || stalled {
+                break;
+            }
+        }
+
+        self.total_in += self.compressed.len() as u64;
+        self.total_out += self.buffer.len() as u64;
+
+        #[cfg(feature = "tracing")]
+        tracing::trace!(
+            bytes_in = self.compressed.len(),
+            bytes_out = self.buffer.len(),
+            shard_id = self.shard[0],
+            shard_total = self.shard[1],
+            "payload lengths",
+        );
+
+        self.compressed.clear();
+
+        #[cfg(feature = "tracing")]
+        {
+            // It doesn't matter if we lose precision for logging.
+            #[allow(clippy::cast_precision_loss)]
+            let saved_percentage = self.total_in as f64 / self.total_out as f64;
+            let saved_percentage_readable = saved_percentage * 100.0;
+            let saved_kib = self.total_out.saturating_sub(self.total_in) / 1_024;
+
+            tracing::trace!(
+                saved_kib = saved_kib,
+                saved_percentage = %saved_percentage_readable,
+                shard_id = self.shard[0],
+                shard_total = self.shard[1],
+                total_in = self.total_in,
+                total_out = self.total_out,
+                "data saved",
+            );
+        }
+
+        #[cfg(feature = "metrics")]
+        self.inflater_metrics();
+
+        #[cfg(feature = "tracing")]
+        tracing::trace!("capacity: {}", self.buffer.capacity());
+
+        Ok(Some(&mut self.buffer))
+    }
+
+    /// Clear the buffer and shrink it if the capacity is too large.
+    ///
+    /// If at least 60 seconds have passed since the last shrink then the
+    /// capacities will be shrunk to the lengths.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace"))]
+    pub fn clear(&mut self) {
+        self.shrink();
+
+        self.compressed.clear();
+        self.internal_buffer.clear();
+        self.buffer.clear();
+    }
+
+    /// Reset the state of the inflater back to its default state, which
+    /// starts a new stream.
+    pub fn reset(&mut self) {
+        let _old_inflater = mem::replace(self, Self::new(self.shard));
+    }
+
+    /// Log metrics about the inflater.
+    #[cfg(feature = "metrics")]
+    #[allow(clippy::cast_precision_loss)]
+    fn inflater_metrics(&self) {
+        metrics::gauge!(
+            format!("Inflater-Capacity-{}", self.shard[0]),
+            self.buffer.capacity() as f64
+        );
+        metrics::gauge!(
+            format!("Inflater-In-{}", self.shard[0]),
+            self.total_in as f64
+        );
+        metrics::gauge!(
+            format!("Inflater-Out-{}", self.shard[0]),
+            self.total_out as f64
+        );
+    }
+
+    /// Shrink the capacity of the compressed buffer and payload buffer if at
+    /// least 60 seconds have passed since the last shrink.
+    fn shrink(&mut self) {
+        if self.last_resize.elapsed().as_secs() < 60 {
+            return;
+        }
+
+        self.compressed.shrink_to_fit();
+        self.buffer.shrink_to_fit();
+
+        #[cfg(feature = "tracing")]
+        tracing::trace!(
+            capacity = self.compressed.capacity(),
+            shard_id = self.shard[0],
+            shard_total = self.shard[1],
+            "compressed capacity",
+        );
+        #[cfg(feature = "tracing")]
+        tracing::trace!(
+            capacity = self.buffer.capacity(),
+            shard_id = self.shard[0],
+            shard_total = self.shard[1],
+            "buffer capacity",
+        );
+
+        self.last_resize = Instant::now();
+    }
+}
+
+impl Debug for ZstdInflater {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("ZstdInflater")
+            .field("decoder", &"zstd decoder")
+            .field("compressed", &self.compressed)
+            .field("internal_buffer", &self.internal_buffer)
+            .field("buffer", &self.buffer)
+            .field("last_resize", &self.last_resize)
+            .field("shard", &self.shard)
+            .field("total_in", &self.total_in)
+            .field("total_out", &self.total_out)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ZstdInflater;
+    use std::{error::Error, io::Write};
+    use zstd::stream::write::Encoder;
+
+    const HELLO: &[u8] = br#"{"t":null,"s":null,"op":10,"d":{"heartbeat_interval":41250}}"#;
+    const HEARTBEAT_ACK: &[u8] = br#"{"t":null,"s":null,"op":11,"d":null}"#;
+    const SHARD: [u64; 2] = [2, 5];
+
+    /// Compress messages like Discord does, flushing one stream after each
+    /// message.
+    fn compress(messages: &[&[u8]]) -> Result<Vec<Vec<u8>>, Box<dyn Error>> {
+        let mut encoder = Encoder::new(Vec::new(), 0)?;
+        let mut compressed = Vec::new();
+
+        for message in messages {
+            encoder.write_all(message)?;
+            encoder.flush()?;
+            compressed.push(encoder.get_mut().split_off(0));
+        }
+
+        Ok(compressed)
+    }
+
+    #[test]
+    fn test_inflater() -> Result<(), Box<dyn Error>> {
+        let compressed = compress(&[HELLO, HEARTBEAT_ACK])?;
+
+        let mut inflater = ZstdInflater::new(SHARD);
+        assert_eq!(None, inflater.msg()?);
+
+        inflater.extend(&compressed[0]);
+        assert!(!inflater.compressed.is_empty());
+        assert_eq!(Some(HELLO), inflater.msg()?.as_deref());
+        assert!(inflater.compressed.is_empty());
+        assert_eq!(HELLO, inflater.buffer_mut());
+
+        // The second message relies on the state of the stream from the
+        // first.
+        inflater.clear();
+        assert!(inflater.buffer.is_empty());
+        inflater.extend(&compressed[1]);
+        assert_eq!(Some(HEARTBEAT_ACK), inflater.msg()?.as_deref());
+        assert_eq!(
+            (HELLO.len() + HEARTBEAT_ACK.len()) as u64,
+            inflater.total_out
+        );
+
+        // Resetting starts a new stream.
+        inflater.clear();
+        inflater.reset();
+        assert_eq!(0, inflater.total_in);
+        inflater.extend(&compressed[0]);
+        assert_eq!(Some(HELLO), inflater.msg()?.as_deref());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_large_message() -> Result<(), Box<dyn Error>> {
+        // Larger than the internal buffer, requiring several decoding steps.
+        let message = HELLO.repeat(4_096);
+        let compressed = compress(&[&message])?;
+
+        let mut inflater = ZstdInflater::new(SHARD);
+        inflater.extend(&compressed[0]);
+        assert_eq!(Some(message.as_slice()), inflater.msg()?.as_deref());
+
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
 use super::{
-    super::json,
+    super::payload,
+    ratelimiter::CommandRatelimiter,
     session::{SessionSendError, SessionSendErrorType},
 };
 use serde::{Deserialize, Serialize};
@@ -178,6 +179,7 @@ impl Default for Heartbeats {
 pub struct Heartbeater {
     heartbeats: Arc<Heartbeats>,
     interval: u64,
+    ratelimiter: Arc<CommandRatelimiter>,
     seq: Arc<AtomicU64>,
     tx: UnboundedSender<TungsteniteMessage>,
 }
@@ -186,12 +188,14 @@ impl Heartbeater {
     pub fn new(
         heartbeats: Arc<Heartbeats>,
         interval: u64,
+        ratelimiter: Arc<CommandRatelimiter>,
         seq: Arc<AtomicU64>,
         tx: UnboundedSender<TungsteniteMessage>,
     ) -> Self {
         Self {
             heartbeats,
             interval,
+            ratelimiter,
             seq,
             tx,
         }
@@ -233,7 +237,7 @@ impl Heartbeater {
 
             let seq = self.seq.load(Ordering::Acquire);
             let heartbeat = Heartbeat::new(seq);
-            let bytes = json::to_vec(&heartbeat).map_err(|source| SessionSendError {
+            let bytes = payload::to_vec(&heartbeat).map_err(|source| SessionSendError {
                 kind: SessionSendErrorType::Serializing,
                 source: Some(Box::new(source)),
             })?;
@@ -241,6 +245,7 @@ impl Heartbeater {
             #[cfg(feature = "tracing")]
             tracing::debug!(seq, "sending heartbeat");
 
+            self.ratelimiter.acquire_reserved();
             self.tx
                 .send(TungsteniteMessage::Binary(bytes))
                 .map_err(|source| SessionSendError {
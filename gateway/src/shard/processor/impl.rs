@@ -2,7 +2,9 @@ use super::{
     super::{
         config::Config,
         emitter::{EmitJsonErrorType, Emitter},
-        json::{self, GatewayEventParsingError, GatewayEventParsingErrorType},
+        json::{GatewayEventParsingError, GatewayEventParsingErrorType},
+        member_requests::MemberRequests,
+        payload,
         stage::Stage,
         ShardStream,
     },
@@ -17,7 +19,6 @@ use std::{
     env::consts::OS,
     error::Error,
     fmt::{Debug, Display, Formatter, Result as FmtResult},
-    str,
     sync::{atomic::Ordering, Arc},
     time::Duration,
 };
@@ -32,17 +33,24 @@ use tokio_tungstenite::tungstenite::{
 use twilight_model::gateway::{
     event::{
         shard::{Connected, Connecting, Disconnected, Identifying, Reconnecting, Resuming},
-        DispatchEvent, Event, GatewayEvent, GatewayEventDeserializer,
+        DispatchEvent, Event, GatewayEvent,
     },
     payload::{
         identify::{Identify, IdentifyInfo, IdentifyProperties},
         resume::Resume,
-        Ready,
+        MemberChunk, Ready,
     },
     Intents, OpCode,
 };
 use url::Url;
 
+#[cfg(feature = "etf")]
+use super::super::etf;
+#[cfg(not(feature = "etf"))]
+use std::str;
+#[cfg(not(feature = "etf"))]
+use twilight_model::gateway::event::GatewayEventDeserializer;
+
 /// Connecting to the gateway failed.
 #[derive(Debug)]
 pub struct ConnectingError {
@@ -114,7 +122,7 @@ impl Display for ProcessError {
 
                 f.write_str(") pair is unknown")
             }
-            ProcessErrorType::ParsingPayload => f.write_str("payload could not be parsed as json"),
+            ProcessErrorType::ParsingPayload => f.write_str("payload could not be parsed"),
             ProcessErrorType::PayloadNotUtf8 { .. } => {
                 f.write_str("the payload from Discord wasn't UTF-8 valid")
             }
@@ -148,6 +156,7 @@ enum ProcessErrorType {
     ParsingPayload,
     /// The binary payload received from Discord wasn't validly encoded as
     /// UTF-8.
+    #[cfg_attr(feature = "etf", allow(dead_code))]
     PayloadNotUtf8,
     /// A close message tried to be sent but the receiving half was dropped.
     /// This typically means that the shard is shutdown.
@@ -261,12 +270,18 @@ struct ReadyMinimal {
     d: Ready,
 }
 
+#[derive(Deserialize)]
+struct MemberChunkMinimal {
+    d: MemberChunk,
+}
+
 /// Runs in the background and processes incoming events, and then broadcasts
 /// to all listeners.
 #[derive(Debug)]
 pub struct ShardProcessor {
     pub config: Arc<Config>,
     pub emitter: Emitter,
+    pub member_requests: Arc<MemberRequests>,
     pub rx: UnboundedReceiver<Message>,
     pub session: Arc<Session>,
     compression: Compression,
@@ -280,6 +295,7 @@ impl ShardProcessor {
         config: Arc<Config>,
         mut url: String,
         emitter: Emitter,
+        member_requests: Arc<MemberRequests>,
     ) -> Result<(Self, WatchReceiver<Arc<Session>>), ConnectingError> {
         //if we got resume info we don't need to wait
         let shard_id = config.shard();
@@ -304,14 +320,17 @@ impl ShardProcessor {
         // and encoding".
         //
         // <https://discord.com/developers/docs/topics/gateway#connecting-gateway-url-query-string-params>
-        url.push_str("&encoding=json");
+        url.push_str("&encoding=");
+        url.push_str(payload::ENCODING);
 
         compression::add_url_feature(&mut url);
 
-        emitter.event(Event::ShardConnecting(Connecting {
-            gateway: url.clone(),
-            shard_id: config.shard()[0],
-        }));
+        emitter
+            .event(Event::ShardConnecting(Connecting {
+                gateway: url.clone(),
+                shard_id: config.shard()[0],
+            }))
+            .await;
         let stream = Self::connect(&url).await?;
         let (forwarder, rx, tx) = SocketForwarder::new(stream);
         tokio::spawn(async move {
@@ -332,6 +351,7 @@ impl ShardProcessor {
             compression: Compression::new(shard_id),
             config,
             emitter,
+            member_requests,
             rx,
             session,
             url: url.into_boxed_str(),
@@ -392,49 +412,90 @@ impl ShardProcessor {
         }
     }
 
-    #[allow(clippy::too_many_lines)]
-    async fn process(&mut self) -> Result<(), ProcessError> {
-        let (op, seq, event_type) = {
-            let buffer = self.compression.buffer_slice_mut();
-            let json = str::from_utf8_mut(buffer).map_err(|source| ProcessError {
+    /// Read the opcode, sequence, and event type of the payload in the
+    /// buffer.
+    ///
+    /// The event type is owned since lifetimes and mutability requirements
+    /// conflict if an immutable reference to the buffer is held. It should be
+    /// a good trade-off either way.
+    #[cfg(not(feature = "etf"))]
+    fn payload_header(&mut self) -> Result<(u8, Option<u64>, Option<String>), ProcessError> {
+        let json = str::from_utf8_mut(self.compression.buffer_slice_mut()).map_err(|source| {
+            ProcessError {
                 kind: ProcessErrorType::PayloadNotUtf8,
                 source: Some(Box::new(source)),
-            })?;
+            }
+        })?;
 
-            #[cfg(feature = "tracing")]
-            tracing::trace!(%json, "Received JSON");
+        #[cfg(feature = "tracing")]
+        tracing::trace!(%json, "Received JSON");
 
-            let emitter = self.emitter.clone();
+        if let Some(deserializer) = GatewayEventDeserializer::from_json(json) {
+            let (op, seq, event_type) = deserializer.into_parts();
 
-            let (op, seq, event_type) =
-                if let Some(deserializer) = GatewayEventDeserializer::from_json(json) {
-                    let (op, seq, event_type) = deserializer.into_parts();
+            return Ok((op, seq, event_type.map(ToOwned::to_owned)));
+        }
 
-                    // Unfortunately lifetimes and mutability requirements
-                    // conflict here if we return an immutable reference to the
-                    // event type, so we're going to have to take ownership of
-                    // this if we don't want to do anything too dangerous. It
-                    // should be a good trade-off either way.
-                    (op, seq, event_type.map(ToOwned::to_owned))
-                } else {
-                    #[cfg(feature = "tracing")]
-                    tracing::warn!(
-                        json = ?self.compression.buffer_slice_mut(),
-                        shard_id = self.config.shard()[0],
-                        shard_total = self.config.shard()[1],
-                        seq = self.session.seq(),
-                        stage = ?self.session.stage(),
-                        "received payload without opcode",
-                    );
-
-                    return Err(ProcessError {
-                        kind: ProcessErrorType::ParsingPayload,
-                        source: Some(Box::new(GatewayEventParsingError {
-                            kind: GatewayEventParsingErrorType::PayloadInvalid,
-                            source: None,
-                        })),
-                    });
-                };
+        #[cfg(feature = "tracing")]
+        tracing::warn!(
+            json = ?self.compression.buffer_slice_mut(),
+            shard_id = self.config.shard()[0],
+            shard_total = self.config.shard()[1],
+            seq = self.session.seq(),
+            stage = ?self.session.stage(),
+            "received payload without opcode",
+        );
+
+        Err(ProcessError {
+            kind: ProcessErrorType::ParsingPayload,
+            source: Some(Box::new(GatewayEventParsingError {
+                kind: GatewayEventParsingErrorType::PayloadInvalid,
+                source: None,
+            })),
+        })
+    }
+
+    /// Read the opcode, sequence, and event type of the payload in the
+    /// buffer.
+    ///
+    /// The event type is owned since lifetimes and mutability requirements
+    /// conflict if an immutable reference to the buffer is held.
+    #[cfg(feature = "etf")]
+    fn payload_header(&mut self) -> Result<(u8, Option<u64>, Option<String>), ProcessError> {
+        let buffer = self.compression.buffer_slice_mut();
+
+        #[cfg(feature = "tracing")]
+        tracing::trace!(len = buffer.len(), "Received ETF");
+
+        match etf::header(buffer) {
+            Ok((op, seq, event_type)) => Ok((op, seq, event_type.map(ToOwned::to_owned))),
+            Err(source) => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(
+                    shard_id = self.config.shard()[0],
+                    shard_total = self.config.shard()[1],
+                    seq = self.session.seq(),
+                    stage = ?self.session.stage(),
+                    "received payload without opcode",
+                );
+
+                Err(ProcessError {
+                    kind: ProcessErrorType::ParsingPayload,
+                    source: Some(Box::new(GatewayEventParsingError {
+                        kind: GatewayEventParsingErrorType::PayloadInvalid,
+                        source: Some(Box::new(source)),
+                    })),
+                })
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_lines)]
+    async fn process(&mut self) -> Result<(), ProcessError> {
+        let (op, seq, event_type) = {
+            let (op, seq, event_type) = self.payload_header()?;
+            let buffer = self.compression.buffer_slice_mut();
+            let emitter = self.emitter.clone();
 
             // We can do a few little optimisation tricks here. For the
             // "heartbeat ack" and "reconnect" opcodes we can construct
@@ -454,7 +515,7 @@ impl ShardProcessor {
                 } else if op == OpCode::Reconnect as u8 {
                     GatewayEvent::Reconnect
                 } else {
-                    json::parse_gateway_event(op, seq, event_type.as_deref(), buffer).map_err(
+                    payload::parse_gateway_event(op, seq, event_type.as_deref(), buffer).map_err(
                         |source| ProcessError {
                             kind: ProcessErrorType::ParsingPayload,
                             source: Some(Box::new(source)),
@@ -463,7 +524,7 @@ impl ShardProcessor {
                 };
 
                 self.process_gateway_event(&gateway_event).await?;
-                emitter.event(Event::from(gateway_event));
+                emitter.event(Event::from(gateway_event)).await;
 
                 if let Some(seq) = seq {
                     self.session.set_seq(seq);
@@ -478,28 +539,52 @@ impl ShardProcessor {
             })?;
 
             if event_type.as_deref() == Some("RESUMED") {
-                self.process_resumed(seq);
+                self.process_resumed(seq).await;
 
                 if emitter.wants(EventTypeFlags::RESUMED) {
                     let gateway_event =
                         GatewayEvent::Dispatch(seq, Box::new(DispatchEvent::Resumed));
 
-                    emitter.event(Event::from(gateway_event));
+                    emitter.event(Event::from(gateway_event)).await;
                 }
 
                 return Ok(());
             } else if event_type.as_deref() == Some("READY") {
-                let ready = json::from_slice::<ReadyMinimal>(self.compression.buffer_slice_mut())
-                    .map_err(|source| ProcessError {
-                    kind: ProcessErrorType::ParsingPayload,
-                    source: Some(Box::new(GatewayEventParsingError {
-                        kind: GatewayEventParsingErrorType::Deserializing,
-                        source: Some(Box::new(source)),
-                    })),
-                })?;
+                let ready =
+                    payload::from_slice::<ReadyMinimal>(self.compression.buffer_slice_mut())
+                        .map_err(|source| ProcessError {
+                            kind: ProcessErrorType::ParsingPayload,
+                            source: Some(Box::new(GatewayEventParsingError {
+                                kind: GatewayEventParsingErrorType::Deserializing,
+                                source: Some(Box::new(source)),
+                            })),
+                        })?;
+
+                self.process_ready(&ready.d).await;
+                emitter.event(Event::Ready(Box::new(ready.d))).await;
+
+                return Ok(());
+            } else if event_type.as_deref() == Some("GUILD_MEMBERS_CHUNK")
+                && !self.member_requests.is_empty()
+            {
+                // Chunks are only deserialized here while member requests are
+                // waiting for them, as they may otherwise not be wanted.
+                let chunk =
+                    payload::from_slice::<MemberChunkMinimal>(self.compression.buffer_slice_mut())
+                        .map_err(|source| ProcessError {
+                            kind: ProcessErrorType::ParsingPayload,
+                            source: Some(Box::new(GatewayEventParsingError {
+                                kind: GatewayEventParsingErrorType::Deserializing,
+                                source: Some(Box::new(source)),
+                            })),
+                        })?;
 
-                self.process_ready(&ready.d);
-                emitter.event(Event::Ready(Box::new(ready.d)));
+                self.session.set_seq(seq);
+                self.member_requests.receive(&chunk.d);
+
+                if emitter.wants(EventTypeFlags::MEMBER_CHUNK) {
+                    emitter.event(Event::MemberChunk(chunk.d)).await;
+                }
 
                 return Ok(());
             }
@@ -512,7 +597,8 @@ impl ShardProcessor {
         let buffer = self.compression.buffer_slice_mut();
 
         self.emitter
-            .json(op, Some(seq), event_type.as_deref(), buffer)
+            .payload(op, Some(seq), event_type.as_deref(), buffer)
+            .await
             .map_err(|source| {
                 let (kind, source) = source.into_parts();
 
@@ -530,7 +616,7 @@ impl ShardProcessor {
             })
     }
 
-    fn process_ready(&mut self, ready: &Ready) {
+    async fn process_ready(&mut self, ready: &Ready) {
         #[cfg(feature = "metrics")]
         metrics::counter!("GatewayEvent", 1, "GatewayEvent" => "Dispatch");
 
@@ -538,22 +624,26 @@ impl ShardProcessor {
         self.session
             .set_id(ready.session_id.clone().into_boxed_str());
 
-        self.emitter.event(Event::ShardConnected(Connected {
-            heartbeat_interval: self.session.heartbeat_interval(),
-            shard_id: self.config.shard()[0],
-        }));
+        self.emitter
+            .event(Event::ShardConnected(Connected {
+                heartbeat_interval: self.session.heartbeat_interval(),
+                shard_id: self.config.shard()[0],
+            }))
+            .await;
     }
 
-    fn process_resumed(&self, seq: u64) {
+    async fn process_resumed(&self, seq: u64) {
         #[cfg(feature = "metrics")]
         metrics::counter!("GatewayEvent", 1, "GatewayEvent" => "Dispatch");
 
         self.session.set_seq(seq);
         self.session.set_stage(Stage::Connected);
-        self.emitter.event(Event::ShardConnected(Connected {
-            heartbeat_interval: self.session.heartbeat_interval(),
-            shard_id: self.config.shard()[0],
-        }));
+        self.emitter
+            .event(Event::ShardConnected(Connected {
+                heartbeat_interval: self.session.heartbeat_interval(),
+                shard_id: self.config.shard()[0],
+            }))
+            .await;
         self.session.heartbeats.receive();
     }
 
@@ -756,7 +846,7 @@ impl ShardProcessor {
 
                 if extended {
                     match self.compression.message_mut() {
-                        Ok(Some(bytes)) => self.emitter.bytes(bytes),
+                        Ok(Some(bytes)) => self.emitter.bytes(bytes).await,
                         Ok(None) => return Ok(false),
                         Err(source) => {
                             return Err(ReceivingEventError {
@@ -778,7 +868,7 @@ impl ShardProcessor {
                 let extended = self.compression.extend_text(json.as_bytes());
 
                 if extended {
-                    self.emitter.bytes(json.as_bytes());
+                    self.emitter.bytes(json.as_bytes()).await;
                 }
 
                 Ok(extended)
@@ -893,10 +983,12 @@ impl ShardProcessor {
             presence: self.config.presence().cloned(),
             token: self.config.token().to_owned(),
         });
-        self.emitter.event(Event::ShardIdentifying(Identifying {
-            shard_id: self.config.shard()[0],
-            shard_total: self.config.shard()[1],
-        }));
+        self.emitter
+            .event(Event::ShardIdentifying(Identifying {
+                shard_id: self.config.shard()[0],
+                shard_total: self.config.shard()[1],
+            }))
+            .await;
 
         self.send(identify).await
     }
@@ -922,9 +1014,11 @@ impl ShardProcessor {
             // Await allowance when doing a full reconnect.
             self.config.queue.request(self.config.shard()).await;
 
-            self.emitter.event(Event::ShardReconnecting(Reconnecting {
-                shard_id: self.config.shard()[0],
-            }));
+            self.emitter
+                .event(Event::ShardReconnecting(Reconnecting {
+                    shard_id: self.config.shard()[0],
+                }))
+                .await;
 
             let stream = match Self::connect(&self.url).await {
                 Ok(s) => s,
@@ -945,10 +1039,12 @@ impl ShardProcessor {
             break;
         }
 
-        self.emitter.event(Event::ShardConnecting(Connecting {
-            gateway: self.url.clone().into_string(),
-            shard_id: self.config.shard()[0],
-        }));
+        self.emitter
+            .event(Event::ShardConnecting(Connecting {
+                gateway: self.url.clone().into_string(),
+                shard_id: self.config.shard()[0],
+            }))
+            .await;
     }
 
     /// Resume a session if possible, defaulting to instantiating a new
@@ -990,10 +1086,12 @@ impl ShardProcessor {
 
     /// Attempt to resume a session.
     async fn try_resume(&mut self) -> Result<(), ConnectingError> {
-        self.emitter.event(Event::ShardResuming(Resuming {
-            seq: self.session.seq(),
-            shard_id: self.config.shard()[0],
-        }));
+        self.emitter
+            .event(Event::ShardResuming(Resuming {
+                seq: self.session.seq(),
+                shard_id: self.config.shard()[0],
+            }))
+            .await;
 
         let stream = Self::connect(&self.url).await?;
 
@@ -1024,11 +1122,13 @@ impl ShardProcessor {
     }
 
     async fn emit_disconnected(&self, code: Option<u16>, reason: Option<String>) {
-        self.emitter.event(Event::ShardDisconnected(Disconnected {
-            code,
-            reason,
-            shard_id: self.config.shard()[0],
-        }));
+        self.emitter
+            .event(Event::ShardDisconnected(Disconnected {
+                code,
+                reason,
+                shard_id: self.config.shard()[0],
+            }))
+            .await;
     }
 }
 
@@ -2,12 +2,13 @@ pub mod heartbeat;
 
 mod compression;
 mod r#impl;
+mod ratelimiter;
 mod session;
 mod socket_forwarder;
-mod throttle;
 
 pub use self::{
     heartbeat::Latency,
     r#impl::{ConnectingError, ConnectingErrorType, ShardProcessor},
+    ratelimiter::CommandRatelimit,
     session::Session,
 };
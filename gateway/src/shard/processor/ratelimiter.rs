@@ -0,0 +1,303 @@
+//! Ratelimiter of the commands sent over a gateway connection.
+//!
+//! Discord allows 120 commands to be sent every 60 seconds, and closes
+//! connections exceeding it. Each command uses a slot for 60 seconds after
+//! being sent, like a token bucket whose tokens refill a minute after use.
+//!
+//! Some of the slots are reserved for the commands the shard itself needs to
+//! keep the connection alive, which are heartbeats and identifying or resuming
+//! the session. The remaining slots are used by the commands sent by users.
+
+use std::{collections::VecDeque, convert::TryFrom, sync::Mutex as MutexSync, time::Duration};
+use tokio::{
+    sync::Mutex,
+    time::{self, Instant},
+};
+
+/// Number of commands allowed to be sent per [`RESET_PERIOD`].
+const COMMANDS_PER_RESET: u8 = 120;
+
+/// Duration after which a command no longer counts towards the ratelimit.
+const RESET_PERIOD: Duration = Duration::from_secs(60);
+
+/// Information about the ratelimit of the commands sent by a [`Shard`].
+///
+/// This is obtained through [`Shard::command_ratelimit`].
+///
+/// [`Shard`]: crate::shard::Shard
+/// [`Shard::command_ratelimit`]: crate::shard::Shard::command_ratelimit
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CommandRatelimit {
+    available: u8,
+    max: u8,
+    next_refill: Option<Duration>,
+}
+
+impl CommandRatelimit {
+    /// Number of commands that can be sent right now without waiting.
+    pub const fn available(&self) -> u8 {
+        self.available
+    }
+
+    /// Maximum number of commands that can be sent every 60 seconds.
+    ///
+    /// This is less than the 120 commands Discord allows, as some are
+    /// reserved for heartbeating and identifying or resuming the session.
+    pub const fn max(&self) -> u8 {
+        self.max
+    }
+
+    /// Time until a command that was sent stops counting towards the
+    /// ratelimit, if any were sent within the last 60 seconds.
+    pub const fn next_refill(&self) -> Option<Duration> {
+        self.next_refill
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    /// When the commands sent by users were sent, from oldest to newest.
+    commands: VecDeque<Instant>,
+    /// Interval between heartbeats in milliseconds, or 0 if unknown.
+    heartbeat_interval: u64,
+    /// When the commands sent by the shard were sent, from oldest to newest.
+    reserved: VecDeque<Instant>,
+}
+
+impl State {
+    /// Forget the commands that no longer count towards the ratelimit.
+    fn prune(&mut self, now: Instant) {
+        for sent in &mut [&mut self.commands, &mut self.reserved] {
+            while sent.front().map_or(false, |instant| {
+                now.duration_since(*instant) >= RESET_PERIOD
+            }) {
+                sent.pop_front();
+            }
+        }
+    }
+
+    /// Number of slots reserved for the commands sent by the shard.
+    ///
+    /// This is the number of heartbeats sent per period and an additional
+    /// slot for identifying or resuming, or for heartbeats requested by
+    /// Discord.
+    fn reserved_slots(&self) -> u8 {
+        let period = u64::try_from(RESET_PERIOD.as_millis()).unwrap_or(u64::MAX);
+        // Round up, as a heartbeat may be sent at both ends of a period.
+        let heartbeats = period
+            .saturating_add(self.heartbeat_interval.saturating_sub(1))
+            .checked_div(self.heartbeat_interval)
+            .unwrap_or(0);
+
+        u8::try_from(heartbeats + 1).unwrap_or(COMMANDS_PER_RESET)
+    }
+
+    /// Maximum number of commands users may send per period.
+    fn max(&self) -> u8 {
+        COMMANDS_PER_RESET.saturating_sub(self.reserved_slots())
+    }
+
+    /// Number of commands users may send right now.
+    ///
+    /// The shard may exceed its reservation, such as when Discord requests
+    /// heartbeats, in which case fewer commands are available.
+    fn available(&self) -> u8 {
+        let reserved = usize::from(self.reserved_slots()).max(self.reserved.len());
+        let used = reserved + self.commands.len();
+
+        u8::try_from(usize::from(COMMANDS_PER_RESET).saturating_sub(used)).unwrap_or(0)
+    }
+
+    /// Time until the oldest command stops counting towards the ratelimit.
+    fn next_refill(&self, now: Instant) -> Option<Duration> {
+        let oldest = match (self.commands.front(), self.reserved.front()) {
+            (Some(command), Some(reserved)) => command.min(reserved),
+            (Some(oldest), None) | (None, Some(oldest)) => oldest,
+            (None, None) => return None,
+        };
+
+        Some((*oldest + RESET_PERIOD).saturating_duration_since(now))
+    }
+}
+
+/// Ratelimiter of the commands sent over a gateway connection.
+#[derive(Debug, Default)]
+pub struct CommandRatelimiter {
+    /// Queue of users waiting for a slot, so that they're served in order.
+    queue: Mutex<()>,
+    state: MutexSync<State>,
+}
+
+impl CommandRatelimiter {
+    /// Create a new ratelimiter with no commands sent yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wait until a command can be sent by a user, using a slot for it.
+    pub async fn acquire(&self) {
+        let _queue = self.queue.lock().await;
+
+        loop {
+            let wait = {
+                let now = Instant::now();
+                let mut state = self.state();
+                state.prune(now);
+
+                if state.available() > 0 {
+                    state.commands.push_back(now);
+
+                    return;
+                }
+
+                state.next_refill(now).unwrap_or(RESET_PERIOD)
+            };
+
+            time::sleep(wait).await;
+        }
+    }
+
+    /// Use a slot for a command sent by a user if one is available without
+    /// waiting.
+    ///
+    /// Returns whether a slot was available.
+    pub fn try_acquire(&self) -> bool {
+        // Users waiting in the queue have priority.
+        let _queue = match self.queue.try_lock() {
+            Ok(queue) => queue,
+            Err(_) => return false,
+        };
+
+        let now = Instant::now();
+        let mut state = self.state();
+        state.prune(now);
+
+        if state.available() == 0 {
+            return false;
+        }
+
+        state.commands.push_back(now);
+
+        true
+    }
+
+    /// Give back the slot of the most recent command sent by a user, such as
+    /// when sending it failed.
+    pub fn release(&self) {
+        self.state().commands.pop_back();
+    }
+
+    /// Use a reserved slot for a command sent by the shard, such as a
+    /// heartbeat.
+    ///
+    /// This never waits, as the shard must be able to keep the connection
+    /// alive.
+    pub fn acquire_reserved(&self) {
+        let now = Instant::now();
+        let mut state = self.state();
+        state.prune(now);
+        state.reserved.push_back(now);
+    }
+
+    /// Information about the current state of the ratelimit.
+    pub fn ratelimit(&self) -> CommandRatelimit {
+        let now = Instant::now();
+        let mut state = self.state();
+        state.prune(now);
+
+        CommandRatelimit {
+            available: state.available(),
+            max: state.max(),
+            next_refill: state.next_refill(now),
+        }
+    }
+
+    /// Set the interval between heartbeats in milliseconds, which determines
+    /// the number of reserved slots.
+    pub fn set_heartbeat_interval(&self, heartbeat_interval: u64) {
+        self.state().heartbeat_interval = heartbeat_interval;
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().expect("command ratelimiter poisoned")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CommandRatelimit, CommandRatelimiter, COMMANDS_PER_RESET, RESET_PERIOD};
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash, time::Duration};
+    use tokio::time::{self, Instant};
+
+    assert_impl_all!(CommandRatelimit: Clone, Debug, Eq, Hash, Send, Sync);
+    assert_impl_all!(CommandRatelimiter: Debug, Send, Sync);
+
+    #[tokio::test]
+    async fn test_reserved_slots() {
+        let ratelimiter = CommandRatelimiter::new();
+        assert_eq!(COMMANDS_PER_RESET - 1, ratelimiter.ratelimit().max());
+
+        // Heartbeating every 41.25 seconds sends 2 heartbeats per minute.
+        ratelimiter.set_heartbeat_interval(41_250);
+        let ratelimit = ratelimiter.ratelimit();
+        assert_eq!(COMMANDS_PER_RESET - 3, ratelimit.max());
+        assert_eq!(ratelimit.max(), ratelimit.available());
+        assert!(ratelimit.next_refill().is_none());
+
+        // Reserved slots don't use the slots of users until they're
+        // exhausted.
+        for _ in 0..3 {
+            ratelimiter.acquire_reserved();
+        }
+
+        assert_eq!(COMMANDS_PER_RESET - 3, ratelimiter.ratelimit().available());
+        ratelimiter.acquire_reserved();
+        assert_eq!(COMMANDS_PER_RESET - 4, ratelimiter.ratelimit().available());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_exhaust_and_refill() {
+        let ratelimiter = CommandRatelimiter::new();
+        ratelimiter.set_heartbeat_interval(41_250);
+        let max = ratelimiter.ratelimit().max();
+
+        for _ in 0..max {
+            assert!(ratelimiter.try_acquire());
+        }
+
+        let ratelimit = ratelimiter.ratelimit();
+        assert_eq!(0, ratelimit.available());
+        assert_eq!(Some(RESET_PERIOD), ratelimit.next_refill());
+        assert!(!ratelimiter.try_acquire());
+
+        // Heartbeats can still be sent.
+        ratelimiter.acquire_reserved();
+
+        time::advance(Duration::from_secs(30)).await;
+        assert_eq!(
+            Some(Duration::from_secs(30)),
+            ratelimiter.ratelimit().next_refill()
+        );
+
+        // Waiting completes once the slots are refilled.
+        let start = Instant::now();
+        ratelimiter.acquire().await;
+        assert_eq!(Duration::from_secs(30), start.elapsed());
+        assert_eq!(max - 1, ratelimiter.ratelimit().available());
+    }
+
+    #[tokio::test]
+    async fn test_release() {
+        let ratelimiter = CommandRatelimiter::new();
+        let ratelimit = ratelimiter.ratelimit();
+
+        ratelimiter.acquire().await;
+        assert!(ratelimiter.try_acquire());
+        assert_eq!(ratelimit.max() - 2, ratelimiter.ratelimit().available());
+
+        ratelimiter.release();
+        ratelimiter.release();
+        assert_eq!(ratelimit, ratelimiter.ratelimit());
+    }
+}
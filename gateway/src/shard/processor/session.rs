@@ -1,7 +1,7 @@
 use super::{
-    super::{json, stage::Stage},
+    super::{payload, stage::Stage},
     heartbeat::{Heartbeater, Heartbeats},
-    throttle::Throttle,
+    ratelimiter::CommandRatelimiter,
 };
 use serde::ser::Serialize;
 use std::{
@@ -12,13 +12,9 @@ use std::{
         atomic::{AtomicU64, AtomicU8, Ordering},
         Arc, Mutex as MutexSync,
     },
-    time::Duration,
 };
 use tokio::{
-    sync::{
-        mpsc::{error::SendError, UnboundedSender},
-        Mutex,
-    },
+    sync::mpsc::{error::SendError, UnboundedSender},
     task::JoinHandle,
 };
 use tokio_tungstenite::tungstenite::{protocol::CloseFrame, Message as TungsteniteMessage};
@@ -40,7 +36,7 @@ impl SessionSendError {
 impl Display for SessionSendError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match &self.kind {
-            SessionSendErrorType::Serializing => f.write_str("failed to serialize payload as json"),
+            SessionSendErrorType::Serializing => f.write_str("failed to serialize payload"),
             SessionSendErrorType::Sending => f.write_str("failed to send message over websocket"),
         }
     }
@@ -72,7 +68,7 @@ pub struct Session {
     pub seq: Arc<AtomicU64>,
     pub stage: AtomicU8,
     pub tx: UnboundedSender<TungsteniteMessage>,
-    pub ratelimit: Mutex<Throttle>,
+    pub ratelimit: Arc<CommandRatelimiter>,
 }
 
 impl Session {
@@ -85,13 +81,16 @@ impl Session {
             seq: Arc::new(AtomicU64::new(0)),
             stage: AtomicU8::new(Stage::default() as u8),
             tx,
-            // 520 instead of 500 to make sure that it can heartbeat.
-            ratelimit: Mutex::new(Throttle::new(Duration::from_millis(520))),
+            ratelimit: Arc::new(CommandRatelimiter::new()),
         }
     }
 
     /// Sends a payload as a message over the socket.
     ///
+    /// This uses one of the ratelimit slots reserved for the shard, so it
+    /// must only be used for payloads needed to maintain the session, such
+    /// as identifying, resuming, and heartbeating.
+    ///
     /// # Errors
     ///
     /// Returns a [`SessionSendErrorType::Serializing`] error type when there is
@@ -101,11 +100,12 @@ impl Session {
     /// receiving channel has hung up. This will only happen when the shard has
     /// either not started or has already shutdown.
     pub fn send(&self, payload: impl Serialize) -> Result<(), SessionSendError> {
-        let bytes = json::to_vec(&payload).map_err(|source| SessionSendError {
+        let bytes = payload::to_vec(&payload).map_err(|source| SessionSendError {
             kind: SessionSendErrorType::Serializing,
             source: Some(Box::new(source)),
         })?;
 
+        self.ratelimit.acquire_reserved();
         self.tx
             .send(TungsteniteMessage::Binary(bytes))
             .map_err(|source| SessionSendError {
@@ -130,6 +130,8 @@ impl Session {
     pub fn set_heartbeat_interval(&self, new_heartbeat_interval: u64) {
         self.heartbeat_interval
             .store(new_heartbeat_interval, Ordering::Release);
+        self.ratelimit
+            .set_heartbeat_interval(new_heartbeat_interval);
     }
 
     /// Returns the current sequence.
@@ -179,8 +181,10 @@ impl Session {
         let interval = self.heartbeat_interval();
         let seq = Arc::clone(&self.seq);
         let heartbeats = Arc::clone(&self.heartbeats);
+        let ratelimiter = Arc::clone(&self.ratelimit);
 
-        let heartbeater = Heartbeater::new(heartbeats, interval, seq, self.tx.clone()).run();
+        let heartbeater =
+            Heartbeater::new(heartbeats, interval, ratelimiter, seq, self.tx.clone()).run();
         let handle = tokio::spawn(heartbeater);
 
         if let Some(old) = self
@@ -0,0 +1,379 @@
+//! Verification of the signatures of interaction requests.
+
+use hyper::HeaderMap;
+use ring::signature::{UnparsedPublicKey, ED25519};
+use std::{
+    error::Error,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+};
+
+/// Name of the header containing the hex encoded signature of a request.
+pub const SIGNATURE_HEADER: &str = "x-signature-ed25519";
+
+/// Name of the header containing the timestamp a request was signed with.
+pub const TIMESTAMP_HEADER: &str = "x-signature-timestamp";
+
+/// Length of an Ed25519 public key in bytes.
+const PUBLIC_KEY_LENGTH: usize = 32;
+
+/// Length of an Ed25519 signature in bytes.
+const SIGNATURE_LENGTH: usize = 64;
+
+/// The error returned when a public key could not be parsed.
+#[derive(Debug)]
+pub struct PublicKeyError {
+    kind: PublicKeyErrorType,
+}
+
+impl PublicKeyError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &PublicKeyErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (PublicKeyErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, None)
+    }
+}
+
+impl Display for PublicKeyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            PublicKeyErrorType::Hex => f.write_str("public key is not valid hex"),
+            PublicKeyErrorType::Length { len } => {
+                f.write_str("public key is ")?;
+                Display::fmt(len, f)?;
+
+                f.write_str(" bytes long instead of 32")
+            }
+        }
+    }
+}
+
+impl Error for PublicKeyError {}
+
+/// Type of [`PublicKeyError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum PublicKeyErrorType {
+    /// Public key contains characters other than hex digits.
+    Hex,
+    /// Public key isn't 32 bytes long.
+    Length {
+        /// Length of the provided key in bytes.
+        len: usize,
+    },
+}
+
+/// The error returned when the signature of a request could not be verified.
+#[derive(Debug)]
+pub struct VerifyError {
+    kind: VerifyErrorType,
+}
+
+impl VerifyError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &VerifyErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (VerifyErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, None)
+    }
+}
+
+impl Display for VerifyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            VerifyErrorType::SignatureInvalid => f.write_str("signature is invalid"),
+            VerifyErrorType::SignatureMalformed => {
+                f.write_str("signature header is not a hex encoded signature")
+            }
+            VerifyErrorType::SignatureMissing => f.write_str("signature header is missing"),
+            VerifyErrorType::TimestampMissing => f.write_str("timestamp header is missing"),
+        }
+    }
+}
+
+impl Error for VerifyError {}
+
+/// Type of [`VerifyError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum VerifyErrorType {
+    /// Signature doesn't match the timestamp and body of the request.
+    SignatureInvalid,
+    /// Signature header isn't a hex encoded 64 byte signature.
+    SignatureMalformed,
+    /// Signature header is missing.
+    SignatureMissing,
+    /// Timestamp header is missing.
+    TimestampMissing,
+}
+
+/// Public key of an application, used to verify that interaction requests
+/// were sent by Discord.
+///
+/// The public key of an application is shown on its page in the developer
+/// portal.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct PublicKey([u8; PUBLIC_KEY_LENGTH]);
+
+impl PublicKey {
+    /// Create a public key from its bytes.
+    pub const fn from_bytes(bytes: [u8; PUBLIC_KEY_LENGTH]) -> Self {
+        Self(bytes)
+    }
+
+    /// Parse a hex encoded public key, as shown in the developer portal.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PublicKeyErrorType::Hex`] error type if the key contains
+    /// characters other than hex digits.
+    ///
+    /// Returns a [`PublicKeyErrorType::Length`] error type if the key isn't
+    /// 32 bytes long.
+    pub fn from_hex(hex: &str) -> Result<Self, PublicKeyError> {
+        let bytes = decode_hex(hex.trim()).ok_or(PublicKeyError {
+            kind: PublicKeyErrorType::Hex,
+        })?;
+
+        if bytes.len() != PUBLIC_KEY_LENGTH {
+            return Err(PublicKeyError {
+                kind: PublicKeyErrorType::Length { len: bytes.len() },
+            });
+        }
+
+        let mut key = [0; PUBLIC_KEY_LENGTH];
+        key.copy_from_slice(&bytes);
+
+        Ok(Self(key))
+    }
+
+    /// Bytes of the public key.
+    pub const fn as_bytes(&self) -> &[u8; PUBLIC_KEY_LENGTH] {
+        &self.0
+    }
+
+    /// Verify the signature of a request via its headers and body.
+    ///
+    /// The signature in the [`SIGNATURE_HEADER`] must be the signature of the
+    /// value of the [`TIMESTAMP_HEADER`] followed by the body.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`VerifyErrorType::SignatureMissing`] or
+    /// [`VerifyErrorType::TimestampMissing`] error type if a header is
+    /// missing.
+    ///
+    /// Returns a [`VerifyErrorType::SignatureMalformed`] error type if the
+    /// signature isn't a hex encoded 64 byte signature.
+    ///
+    /// Returns a [`VerifyErrorType::SignatureInvalid`] error type if the
+    /// signature doesn't match.
+    pub fn verify(&self, headers: &HeaderMap, body: &[u8]) -> Result<(), VerifyError> {
+        let signature = headers.get(SIGNATURE_HEADER).ok_or(VerifyError {
+            kind: VerifyErrorType::SignatureMissing,
+        })?;
+        let timestamp = headers.get(TIMESTAMP_HEADER).ok_or(VerifyError {
+            kind: VerifyErrorType::TimestampMissing,
+        })?;
+
+        let signature = signature
+            .to_str()
+            .ok()
+            .and_then(decode_hex)
+            .filter(|signature| signature.len() == SIGNATURE_LENGTH)
+            .ok_or(VerifyError {
+                kind: VerifyErrorType::SignatureMalformed,
+            })?;
+
+        let mut message = Vec::with_capacity(timestamp.len() + body.len());
+        message.extend_from_slice(timestamp.as_bytes());
+        message.extend_from_slice(body);
+
+        UnparsedPublicKey::new(&ED25519, &self.0)
+            .verify(&message, &signature)
+            .map_err(|_| VerifyError {
+                kind: VerifyErrorType::SignatureInvalid,
+            })
+    }
+}
+
+impl Debug for PublicKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("PublicKey(")?;
+
+        for byte in &self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+
+        f.write_str(")")
+    }
+}
+
+/// Decode a string of hex digits, returning `None` if it contains other
+/// characters or an odd number of digits.
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    const fn digit(byte: u8) -> Option<u8> {
+        match byte {
+            b'0'..=b'9' => Some(byte - b'0'),
+            b'a'..=b'f' => Some(byte - b'a' + 10),
+            b'A'..=b'F' => Some(byte - b'A' + 10),
+            _ => None,
+        }
+    }
+
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+
+    hex.as_bytes()
+        .chunks(2)
+        .map(|pair| Some(digit(pair[0])? << 4 | digit(pair[1])?))
+        .collect()
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::{
+        PublicKey, PublicKeyError, PublicKeyErrorType, VerifyError, VerifyErrorType,
+        SIGNATURE_HEADER, TIMESTAMP_HEADER,
+    };
+    use hyper::{header::HeaderValue, HeaderMap};
+    use ring::signature::{Ed25519KeyPair, KeyPair};
+    use static_assertions::assert_impl_all;
+    use std::{
+        error::Error,
+        fmt::{Debug, Write},
+    };
+
+    assert_impl_all!(PublicKey: Clone, Copy, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(PublicKeyErrorType: Debug, Send, Sync);
+    assert_impl_all!(PublicKeyError: Error, Send, Sync);
+    assert_impl_all!(VerifyErrorType: Debug, Send, Sync);
+    assert_impl_all!(VerifyError: Error, Send, Sync);
+
+    /// Key pair standing in for Discord's signing key.
+    pub fn key_pair() -> Ed25519KeyPair {
+        Ed25519KeyPair::from_seed_unchecked(&[7; 32]).expect("seed is 32 bytes")
+    }
+
+    /// Public key of the [`key_pair`].
+    pub fn public_key() -> PublicKey {
+        let mut bytes = [0; 32];
+        bytes.copy_from_slice(key_pair().public_key().as_ref());
+
+        PublicKey::from_bytes(bytes)
+    }
+
+    /// Headers of a request with a body signed by the [`key_pair`].
+    pub fn signed_headers(timestamp: &str, body: &[u8]) -> HeaderMap {
+        let mut message = timestamp.as_bytes().to_vec();
+        message.extend_from_slice(body);
+
+        let mut signature = String::new();
+
+        for byte in key_pair().sign(&message).as_ref() {
+            write!(signature, "{:02x}", byte).expect("writing to a string can't fail");
+        }
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            SIGNATURE_HEADER,
+            HeaderValue::from_str(&signature).expect("hex is a valid header value"),
+        );
+        headers.insert(
+            TIMESTAMP_HEADER,
+            HeaderValue::from_str(timestamp).expect("timestamp is a valid header value"),
+        );
+
+        headers
+    }
+
+    #[test]
+    fn test_from_hex() -> Result<(), Box<dyn Error>> {
+        let hex = format!("{:?}", public_key());
+        let hex = hex
+            .trim_start_matches("PublicKey(")
+            .trim_end_matches(')')
+            .to_uppercase();
+
+        assert_eq!(public_key(), PublicKey::from_hex(&hex)?);
+        assert!(matches!(
+            PublicKey::from_hex("abcdefgh").unwrap_err().kind(),
+            PublicKeyErrorType::Hex
+        ));
+        assert!(matches!(
+            PublicKey::from_hex("abc").unwrap_err().kind(),
+            PublicKeyErrorType::Hex
+        ));
+        assert!(matches!(
+            PublicKey::from_hex("abcd").unwrap_err().kind(),
+            PublicKeyErrorType::Length { len: 2 }
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify() {
+        let body = br#"{"type":1}"#;
+        let headers = signed_headers("1600000000", body);
+
+        assert!(public_key().verify(&headers, body).is_ok());
+        assert!(matches!(
+            public_key().verify(&headers, b"{}").unwrap_err().kind(),
+            VerifyErrorType::SignatureInvalid
+        ));
+
+        let mut other = headers.clone();
+        other.insert(TIMESTAMP_HEADER, HeaderValue::from_static("1600000001"));
+        assert!(matches!(
+            public_key().verify(&other, body).unwrap_err().kind(),
+            VerifyErrorType::SignatureInvalid
+        ));
+
+        let mut other = headers.clone();
+        other.insert(SIGNATURE_HEADER, HeaderValue::from_static("abcd"));
+        assert!(matches!(
+            public_key().verify(&other, body).unwrap_err().kind(),
+            VerifyErrorType::SignatureMalformed
+        ));
+
+        let mut other = headers.clone();
+        other.remove(SIGNATURE_HEADER);
+        assert!(matches!(
+            public_key().verify(&other, body).unwrap_err().kind(),
+            VerifyErrorType::SignatureMissing
+        ));
+
+        let mut other = headers;
+        other.remove(TIMESTAMP_HEADER);
+        assert!(matches!(
+            public_key().verify(&other, body).unwrap_err().kind(),
+            VerifyErrorType::TimestampMissing
+        ));
+    }
+}
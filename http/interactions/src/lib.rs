@@ -0,0 +1,45 @@
+//! # twilight-http-interactions
+//!
+//! Server for the interactions endpoint of a Discord application, receiving
+//! interactions over HTTP instead of the gateway.
+//!
+//! Discord signs every request to the endpoint with Ed25519. The
+//! [`InteractionServer`] verifies the `X-Signature-Ed25519` and
+//! `X-Signature-Timestamp` headers against the [`PublicKey`] of the
+//! application, answers pings with pongs, and passes all other
+//! [`Interaction`]s to a [`Handler`] whose [`InteractionResponse`] is sent as
+//! the response to the request.
+//!
+//! The server is a [`hyper`] service, so it can be served directly or be
+//! mounted behind a router. Requests can also be verified without the server
+//! via [`PublicKey::verify`].
+//!
+//! [`Interaction`]: twilight_model::application::interaction::Interaction
+//! [`InteractionResponse`]: twilight_model::application::callback::InteractionResponse
+
+#![deny(
+    clippy::all,
+    clippy::missing_const_for_fn,
+    clippy::pedantic,
+    future_incompatible,
+    missing_docs,
+    nonstandard_style,
+    rust_2018_idioms,
+    broken_intra_doc_links,
+    unsafe_code,
+    unused,
+    warnings
+)]
+#![allow(
+    clippy::missing_errors_doc,
+    clippy::module_name_repetitions,
+    clippy::must_use_candidate
+)]
+
+pub mod key;
+pub mod server;
+
+pub use self::{
+    key::{PublicKey, SIGNATURE_HEADER, TIMESTAMP_HEADER},
+    server::{Handler, InteractionServer},
+};
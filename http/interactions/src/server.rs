@@ -0,0 +1,352 @@
+//! Server verifying interaction requests and responding to them via a
+//! [`Handler`].
+
+use crate::key::PublicKey;
+use hyper::{
+    body::HttpBody,
+    header::{HeaderValue, CONTENT_TYPE},
+    service::Service,
+    Body, Method, Request, Response, StatusCode,
+};
+use std::{
+    convert::Infallible,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+use twilight_model::application::{callback::InteractionResponse, interaction::Interaction};
+
+/// Maximum length of a request body in bytes.
+///
+/// Interactions are far smaller than this, so larger bodies are rejected
+/// before their signature is verified.
+const MAX_BODY_LENGTH: usize = 1024 * 1024;
+
+/// Future returned by [`Handler::handle`].
+pub type HandlerFuture = Pin<Box<dyn Future<Output = InteractionResponse> + Send + 'static>>;
+
+/// Future returned by the [`Service`] implementation of
+/// [`InteractionServer`].
+pub type ServiceFuture =
+    Pin<Box<dyn Future<Output = Result<Response<Body>, Infallible>> + Send + 'static>>;
+
+/// Handler responding to the interactions received by an
+/// [`InteractionServer`].
+///
+/// The response is sent as the response to the request, so it must be
+/// returned within the 3 second window Discord allows. Work taking longer
+/// should be deferred by returning a deferred response and following up via
+/// the HTTP API.
+///
+/// Pings are answered by the server and never passed to the handler.
+///
+/// Implemented for functions and closures taking an [`Interaction`] and
+/// returning a future resolving to an [`InteractionResponse`].
+pub trait Handler: Send + Sync {
+    /// Respond to an interaction.
+    fn handle(&self, interaction: Interaction) -> HandlerFuture;
+}
+
+impl<F, Fut> Handler for F
+where
+    F: Fn(Interaction) -> Fut + Send + Sync,
+    Fut: Future<Output = InteractionResponse> + Send + 'static,
+{
+    fn handle(&self, interaction: Interaction) -> HandlerFuture {
+        Box::pin(self(interaction))
+    }
+}
+
+/// Server for the interactions endpoint of an application.
+///
+/// Requests are verified against the [`PublicKey`] of the application and
+/// rejected with a `401 Unauthorized` status if their signature is invalid.
+/// Pings are answered with a pong and all other interactions are passed to
+/// the [`Handler`], whose response is returned.
+///
+/// The server implements hyper's [`Service`], so it can be served directly
+/// or mounted behind a router. Cloning the server is cheap.
+///
+/// # Examples
+///
+/// Serve an endpoint responding to every command with a message:
+///
+/// ```rust,no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+/// use hyper::{service::make_service_fn, Server};
+/// use std::convert::Infallible;
+/// use twilight_http_interactions::{InteractionServer, PublicKey};
+/// use twilight_model::application::{
+///     callback::{CallbackData, InteractionResponse},
+///     interaction::Interaction,
+/// };
+///
+/// let public_key = PublicKey::from_hex(&std::env::var("PUBLIC_KEY")?)?;
+///
+/// let server = InteractionServer::new(public_key, |_: Interaction| async {
+///     InteractionResponse::ChannelMessageWithSource(CallbackData {
+///         allowed_mentions: None,
+///         components: None,
+///         content: Some("pong!".to_owned()),
+///         embeds: Vec::new(),
+///         flags: None,
+///         tts: None,
+///     })
+/// });
+///
+/// let service = make_service_fn(move |_| {
+///     let server = server.clone();
+///
+///     async move { Ok::<_, Infallible>(server) }
+/// });
+///
+/// Server::bind(&([0, 0, 0, 0], 3000).into())
+///     .serve(service)
+///     .await?;
+/// # Ok(()) }
+/// ```
+#[derive(Clone)]
+pub struct InteractionServer {
+    handler: Arc<dyn Handler>,
+    public_key: PublicKey,
+}
+
+impl InteractionServer {
+    /// Create a server verifying requests against the public key of the
+    /// application and responding to interactions via a handler.
+    pub fn new(public_key: PublicKey, handler: impl Handler + 'static) -> Self {
+        Self {
+            handler: Arc::new(handler),
+            public_key,
+        }
+    }
+
+    /// Public key requests are verified against.
+    pub const fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    /// Handle a request to the interactions endpoint.
+    ///
+    /// Errors are returned as responses with an appropriate status code:
+    ///
+    /// - `405 Method Not Allowed` if the method isn't `POST`;
+    /// - `413 Payload Too Large` if the body is unreasonably large;
+    /// - `401 Unauthorized` if the signature is missing or invalid;
+    /// - `400 Bad Request` if the body isn't an interaction.
+    pub async fn handle(&self, request: Request<Body>) -> Response<Body> {
+        if request.method() != Method::POST {
+            return status_response(StatusCode::METHOD_NOT_ALLOWED);
+        }
+
+        let (parts, mut body) = request.into_parts();
+
+        let mut bytes = Vec::new();
+
+        while let Some(chunk) = body.data().await {
+            let chunk = match chunk {
+                Ok(chunk) => chunk,
+                Err(_) => return status_response(StatusCode::BAD_REQUEST),
+            };
+
+            if bytes.len() + chunk.len() > MAX_BODY_LENGTH {
+                return status_response(StatusCode::PAYLOAD_TOO_LARGE);
+            }
+
+            bytes.extend_from_slice(&chunk);
+        }
+
+        if self.public_key.verify(&parts.headers, &bytes).is_err() {
+            return status_response(StatusCode::UNAUTHORIZED);
+        }
+
+        let interaction = match serde_json::from_slice::<Interaction>(&bytes) {
+            Ok(interaction) => interaction,
+            Err(_) => return status_response(StatusCode::BAD_REQUEST),
+        };
+
+        let response = match interaction {
+            Interaction::Ping(_) => InteractionResponse::Pong,
+            interaction => self.handler.handle(interaction).await,
+        };
+
+        match serde_json::to_vec(&response) {
+            Ok(body) => {
+                let mut response = Response::new(Body::from(body));
+                response
+                    .headers_mut()
+                    .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+                response
+            }
+            Err(_) => status_response(StatusCode::INTERNAL_SERVER_ERROR),
+        }
+    }
+}
+
+impl Debug for InteractionServer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("InteractionServer")
+            .field("handler", &"<handler>")
+            .field("public_key", &self.public_key)
+            .finish()
+    }
+}
+
+impl Service<Request<Body>> for InteractionServer {
+    type Response = Response<Body>;
+    type Error = Infallible;
+    type Future = ServiceFuture;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: Request<Body>) -> Self::Future {
+        let server = self.clone();
+
+        Box::pin(async move { Ok(server.handle(request).await) })
+    }
+}
+
+fn status_response(status: StatusCode) -> Response<Body> {
+    let mut response = Response::new(Body::empty());
+    *response.status_mut() = status;
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Handler, InteractionServer};
+    use crate::key::tests::{public_key, signed_headers};
+    use hyper::{body, service::Service, Body, Request, Response, StatusCode};
+    use serde_json::Value;
+    use static_assertions::{assert_impl_all, assert_obj_safe};
+    use std::{convert::Infallible, error::Error, fmt::Debug};
+    use twilight_model::application::{
+        callback::{CallbackData, InteractionResponse},
+        interaction::Interaction,
+    };
+
+    assert_obj_safe!(Handler);
+    assert_impl_all!(
+        InteractionServer: Clone,
+        Debug,
+        Send,
+        Service<Request<Body>, Response = Response<Body>, Error = Infallible>,
+        Sync
+    );
+
+    const COMMAND: &str = r#"{
+        "application_id": "1",
+        "channel_id": "2",
+        "data": {"id": "3", "name": "ping"},
+        "guild_id": "4",
+        "id": "5",
+        "member": {
+            "deaf": false,
+            "joined_at": "2020-01-01T00:00:00.000000+00:00",
+            "mute": false,
+            "permissions": "0",
+            "roles": [],
+            "user": {"avatar": null, "discriminator": "0001", "id": "6", "username": "user"}
+        },
+        "token": "token",
+        "type": 2,
+        "version": 1
+    }"#;
+
+    fn server() -> InteractionServer {
+        InteractionServer::new(public_key(), |interaction: Interaction| async move {
+            let content = match interaction {
+                Interaction::ApplicationCommand(command) => command.data.name,
+                _ => "unknown".to_owned(),
+            };
+
+            InteractionResponse::ChannelMessageWithSource(CallbackData {
+                allowed_mentions: None,
+                components: None,
+                content: Some(content),
+                embeds: Vec::new(),
+                flags: None,
+                tts: None,
+            })
+        })
+    }
+
+    fn request(body: &str) -> Result<Request<Body>, Box<dyn Error>> {
+        let mut request = Request::post("/interactions").body(Body::from(body.to_owned()))?;
+        *request.headers_mut() = signed_headers("1600000000", body.as_bytes());
+
+        Ok(request)
+    }
+
+    async fn json(response: Response<Body>) -> Result<Value, Box<dyn Error>> {
+        let body = body::to_bytes(response.into_body()).await?;
+
+        Ok(serde_json::from_slice(&body)?)
+    }
+
+    #[tokio::test]
+    async fn test_ping() -> Result<(), Box<dyn Error>> {
+        let response = server()
+            .handle(request(
+                r#"{"application_id":"1","id":"2","token":"a","type":1}"#,
+            )?)
+            .await;
+
+        assert_eq!(StatusCode::OK, response.status());
+        assert_eq!(serde_json::json!({ "type": 1 }), json(response).await?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_command() -> Result<(), Box<dyn Error>> {
+        let response = server().call(request(COMMAND)?).await?;
+
+        assert_eq!(StatusCode::OK, response.status());
+
+        let value = json(response).await?;
+        assert_eq!(4, value["type"]);
+        assert_eq!("ping", value["data"]["content"]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_rejected() -> Result<(), Box<dyn Error>> {
+        let server = server();
+
+        let mut tampered = request(COMMAND)?;
+        *tampered.body_mut() = Body::from(COMMAND.replace("ping", "pong"));
+        assert_eq!(
+            StatusCode::UNAUTHORIZED,
+            server.handle(tampered).await.status()
+        );
+
+        let unsigned = Request::post("/interactions").body(Body::from(COMMAND))?;
+        assert_eq!(
+            StatusCode::UNAUTHORIZED,
+            server.handle(unsigned).await.status()
+        );
+
+        let mut get = request(COMMAND)?;
+        *get.method_mut() = hyper::Method::GET;
+        assert_eq!(
+            StatusCode::METHOD_NOT_ALLOWED,
+            server.handle(get).await.status()
+        );
+
+        assert_eq!(
+            StatusCode::BAD_REQUEST,
+            server.handle(request("{}")?).await.status()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+//! Ratelimiting HTTP proxy for the Discord REST API.
+//!
+//! The proxy accepts requests in the same form as the API, such as
+//! `POST /api/v9/channels/123/messages`, queues them through a single
+//! [`InMemoryRatelimiter`], and forwards them upstream along with their
+//! headers, including the `Authorization` header. Services pointing their
+//! clients at the proxy therefore share one view of the ratelimits. Clients
+//! should disable their own ratelimiter, such as via
+//! `ClientBuilder::ratelimiter(None)`.
+//!
+//! The state of the ratelimiter, including the number of queued requests and
+//! the state of every active bucket, is exposed as JSON at
+//! `GET /_proxy/status`.
+//!
+//! # Configuration
+//!
+//! The proxy is configured via environment variables:
+//!
+//! - `HOST`: address to listen on, defaulting to `0.0.0.0`;
+//! - `PORT`: port to listen on, defaulting to `3000`;
+//! - `UPSTREAM_URL`: base URL requests are forwarded to, defaulting to
+//!   `https://discord.com`. This is useful to point the proxy at a local
+//!   server for testing.
+//!
+//! Logging is configured via the `RUST_LOG` environment variable.
+//!
+//! [`InMemoryRatelimiter`]: twilight_http::ratelimiting::InMemoryRatelimiter
+
+#![deny(
+    clippy::all,
+    clippy::missing_const_for_fn,
+    clippy::pedantic,
+    future_incompatible,
+    nonstandard_style,
+    rust_2018_idioms,
+    unsafe_code,
+    unused,
+    warnings
+)]
+#![allow(
+    clippy::missing_errors_doc,
+    clippy::module_name_repetitions,
+    clippy::must_use_candidate
+)]
+
+mod path;
+mod proxy;
+
+use self::proxy::Proxy;
+use hyper::{
+    service::{make_service_fn, service_fn},
+    Server,
+};
+use std::{convert::Infallible, env, error::Error, net::SocketAddr, sync::Arc};
+use tracing_subscriber::EnvFilter;
+use twilight_http::transport::HyperTransport;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
+    tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::from_default_env())
+        .init();
+
+    let host = env::var("HOST").unwrap_or_else(|_| "0.0.0.0".to_owned());
+    let port = env::var("PORT").unwrap_or_else(|_| "3000".to_owned());
+    let upstream = env::var("UPSTREAM_URL").unwrap_or_else(|_| "https://discord.com".to_owned());
+    let address: SocketAddr = format!("{}:{}", host, port).parse()?;
+
+    let proxy = Arc::new(Proxy::new(Box::new(HyperTransport::new()), &upstream));
+
+    let service = make_service_fn(move |_| {
+        let proxy = Arc::clone(&proxy);
+
+        async move {
+            Ok::<_, Infallible>(service_fn(move |request| {
+                let proxy = Arc::clone(&proxy);
+
+                async move { Ok::<_, Infallible>(proxy.handle(request).await) }
+            }))
+        }
+    });
+
+    tracing::info!("listening on {}, forwarding to {}", address, upstream);
+
+    Server::bind(&address).serve(service).await?;
+
+    Ok(())
+}
@@ -0,0 +1,183 @@
+use hyper::{Method as HyperMethod, Uri};
+use std::{
+    convert::TryFrom,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_http::{
+    request::Method,
+    routing::{Path, PathParseError},
+};
+
+/// The error returned when the ratelimit path of a request could not be
+/// determined.
+#[derive(Debug)]
+pub struct ParsePathError {
+    kind: ParsePathErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl ParsePathError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ParsePathErrorType {
+        &self.kind
+    }
+}
+
+impl Display for ParsePathError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            ParsePathErrorType::MethodUnsupported { method } => {
+                f.write_str("method ")?;
+                f.write_str(method.as_str())?;
+
+                f.write_str(" is not supported")
+            }
+            ParsePathErrorType::NotApi => f.write_str("path is not a path of the API"),
+            ParsePathErrorType::PathUnknown => f.write_str("path is not a known API path"),
+        }
+    }
+}
+
+impl Error for ParsePathError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`ParsePathError`] that occurred.
+#[derive(Debug)]
+pub enum ParsePathErrorType {
+    /// Method of the request is not used by the API.
+    MethodUnsupported {
+        /// Method of the request.
+        method: HyperMethod,
+    },
+    /// Path doesn't start with `/api`.
+    NotApi,
+    /// Path is not a known path of the API.
+    ///
+    /// The source is a [`PathParseError`].
+    PathUnknown,
+}
+
+/// Parse the method and URI of a request to the API into its ratelimit path.
+///
+/// The path of the URI must start with `/api`, optionally followed by an API
+/// version such as `/v9`. The query is ignored.
+pub fn parse(method: &HyperMethod, uri: &Uri) -> Result<Path, ParsePathError> {
+    let method = match *method {
+        HyperMethod::DELETE => Method::Delete,
+        HyperMethod::GET => Method::Get,
+        HyperMethod::PATCH => Method::Patch,
+        HyperMethod::POST => Method::Post,
+        HyperMethod::PUT => Method::Put,
+        _ => {
+            return Err(ParsePathError {
+                kind: ParsePathErrorType::MethodUnsupported {
+                    method: method.clone(),
+                },
+                source: None,
+            })
+        }
+    };
+
+    let path = uri
+        .path()
+        .strip_prefix("/api")
+        .filter(|path| path.is_empty() || path.starts_with('/'))
+        .ok_or(ParsePathError {
+            kind: ParsePathErrorType::NotApi,
+            source: None,
+        })?;
+    let path = strip_version(path).trim_matches('/');
+
+    Path::try_from((method, path)).map_err(|source: PathParseError| ParsePathError {
+        kind: ParsePathErrorType::PathUnknown,
+        source: Some(Box::new(source)),
+    })
+}
+
+/// Strip the API version, such as `/v9`, from the start of a path.
+fn strip_version(path: &str) -> &str {
+    let rest = match path.strip_prefix("/v") {
+        Some(rest) => rest,
+        None => return path,
+    };
+
+    let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+
+    if digits > 0 && (rest.len() == digits || rest[digits..].starts_with('/')) {
+        &rest[digits..]
+    } else {
+        path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ParsePathError, ParsePathErrorType};
+    use hyper::{Method as HyperMethod, Uri};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+    use twilight_http::{request::Method, routing::Path};
+
+    assert_impl_all!(ParsePathErrorType: Debug, Send, Sync);
+    assert_impl_all!(ParsePathError: Error, Send, Sync);
+
+    fn parse(method: &HyperMethod, uri: &str) -> Result<Path, ParsePathError> {
+        super::parse(method, &uri.parse::<Uri>().expect("valid uri"))
+    }
+
+    #[test]
+    fn test_parse() -> Result<(), Box<dyn Error>> {
+        assert_eq!(
+            Path::ChannelsIdMessages(1),
+            parse(&HyperMethod::POST, "/api/v9/channels/1/messages")?
+        );
+        assert_eq!(
+            Path::ChannelsIdPins(2),
+            parse(&HyperMethod::GET, "/api/channels/2/pins?limit=5")?
+        );
+        assert_eq!(
+            Path::ChannelsIdMessagesId(Method::Delete, 3),
+            parse(
+                &HyperMethod::DELETE,
+                "http://localhost/api/v10/channels/3/messages/4/"
+            )?
+        );
+        assert_eq!(
+            Path::VoiceRegions,
+            parse(&HyperMethod::GET, "/api/v9/voice/regions")?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(matches!(
+            parse(&HyperMethod::OPTIONS, "/api/v9/gateway").unwrap_err().kind(),
+            ParsePathErrorType::MethodUnsupported { method } if *method == HyperMethod::OPTIONS
+        ));
+        assert!(matches!(
+            parse(&HyperMethod::GET, "/apis/v9/gateway")
+                .unwrap_err()
+                .kind(),
+            ParsePathErrorType::NotApi
+        ));
+        assert!(matches!(
+            parse(&HyperMethod::GET, "/v9/gateway").unwrap_err().kind(),
+            ParsePathErrorType::NotApi
+        ));
+        assert!(matches!(
+            parse(&HyperMethod::GET, "/api/v9/unknown")
+                .unwrap_err()
+                .kind(),
+            ParsePathErrorType::PathUnknown
+        ));
+    }
+}
@@ -0,0 +1,256 @@
+use crate::path::{self, ParsePathErrorType};
+use hyper::{
+    header::{HeaderValue, CONTENT_TYPE, HOST},
+    Body, Method, Request, Response, StatusCode, Uri,
+};
+use serde_json::{json, Value};
+use std::convert::TryFrom;
+use twilight_http::{
+    ratelimiting::{BucketState, InMemoryRatelimiter, RatelimitHeaders, Ratelimiter},
+    transport::Transport,
+};
+
+/// Path of the endpoint exposing the state of the ratelimiter.
+pub const STATUS_PATH: &str = "/_proxy/status";
+
+/// Proxy queueing requests to the API through a shared ratelimiter before
+/// forwarding them upstream.
+#[derive(Debug)]
+pub struct Proxy {
+    ratelimiter: InMemoryRatelimiter,
+    transport: Box<dyn Transport>,
+    upstream: String,
+}
+
+impl Proxy {
+    /// Create a proxy forwarding requests to an upstream base URL, such as
+    /// `https://discord.com`.
+    pub fn new(transport: Box<dyn Transport>, upstream: &str) -> Self {
+        Self {
+            ratelimiter: InMemoryRatelimiter::new(),
+            transport,
+            upstream: upstream.trim_end_matches('/').to_owned(),
+        }
+    }
+
+    /// Handle a request, either by forwarding it upstream or by responding
+    /// with the status of the ratelimiter.
+    pub async fn handle(&self, request: Request<Body>) -> Response<Body> {
+        if request.method() == Method::GET && request.uri().path() == STATUS_PATH {
+            return json_response(StatusCode::OK, &self.status().await);
+        }
+
+        self.forward(request).await
+    }
+
+    async fn forward(&self, mut request: Request<Body>) -> Response<Body> {
+        let path = match path::parse(request.method(), request.uri()) {
+            Ok(path) => path,
+            Err(source) => {
+                tracing::debug!("rejecting request to {}: {}", request.uri(), source);
+
+                let status = match source.kind() {
+                    ParsePathErrorType::MethodUnsupported { .. } => StatusCode::METHOD_NOT_ALLOWED,
+                    ParsePathErrorType::NotApi => StatusCode::NOT_FOUND,
+                    ParsePathErrorType::PathUnknown => StatusCode::NOT_IMPLEMENTED,
+                };
+
+                return error_response(status, &source.to_string());
+            }
+        };
+
+        let path_and_query = request
+            .uri()
+            .path_and_query()
+            .map_or("/", |path_and_query| path_and_query.as_str());
+
+        let uri = match Uri::try_from(format!("{}{}", self.upstream, path_and_query)) {
+            Ok(uri) => uri,
+            Err(source) => {
+                tracing::warn!("failed to build upstream uri: {}", source);
+
+                return error_response(StatusCode::BAD_REQUEST, "invalid request uri");
+            }
+        };
+
+        *request.uri_mut() = uri;
+        request.headers_mut().remove(HOST);
+
+        tracing::debug!("waiting for ticket of path {:?}", path);
+
+        let tx = match self.ratelimiter.ticket(path.clone()).await {
+            Ok(tx) => tx,
+            Err(_) => {
+                return error_response(StatusCode::INTERNAL_SERVER_ERROR, "ratelimiter stopped")
+            }
+        };
+
+        let response = match self.transport.send(request).await {
+            Ok(response) => response,
+            Err(source) => {
+                tracing::warn!("failed to forward request to {:?}: {}", path, source);
+                let _sent = tx.send(None);
+
+                return error_response(StatusCode::BAD_GATEWAY, "failed to reach upstream");
+            }
+        };
+
+        let headers = match RatelimitHeaders::try_from(response.headers()) {
+            Ok(headers) => Some(headers),
+            Err(source) => {
+                tracing::warn!("invalid ratelimit headers for {:?}: {}", path, source);
+
+                None
+            }
+        };
+
+        let _sent = tx.send(headers);
+
+        response
+    }
+
+    /// Status of the ratelimiter: whether it is globally locked, the number
+    /// of queued requests, and the state of every active bucket.
+    async fn status(&self) -> Value {
+        let buckets = self.ratelimiter.buckets();
+
+        json!({
+            "buckets": buckets.iter().map(bucket_status).collect::<Vec<_>>(),
+            "globally_locked": self.ratelimiter.is_globally_locked().await,
+            "queued": buckets.iter().map(BucketState::queued).sum::<usize>(),
+        })
+    }
+}
+
+fn bucket_status(bucket: &BucketState) -> Value {
+    json!({
+        "hash": bucket.hash(),
+        "limit": bucket.limit(),
+        "major_parameter": bucket.major_parameter(),
+        "path": bucket.path().map(|path| format!("{:?}", path)),
+        "queued": bucket.queued(),
+        "remaining": bucket.remaining(),
+        "reset_after": bucket
+            .time_remaining()
+            .map(|duration| u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)),
+    })
+}
+
+fn error_response(status: StatusCode, message: &str) -> Response<Body> {
+    json_response(status, &json!({ "message": message }))
+}
+
+fn json_response(status: StatusCode, value: &Value) -> Response<Body> {
+    let mut response = Response::new(Body::from(value.to_string()));
+    *response.status_mut() = status;
+    response
+        .headers_mut()
+        .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Proxy, STATUS_PATH};
+    use hyper::{body, header::AUTHORIZATION, Body, Request, StatusCode};
+    use serde_json::Value;
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, time::Duration};
+    use twilight_http::{
+        request::Method,
+        routing::Path,
+        transport::{MockResponse, MockTransport},
+    };
+
+    assert_impl_all!(Proxy: Send, Sync);
+
+    fn proxy(transport: &MockTransport) -> Proxy {
+        Proxy::new(Box::new(transport.clone()), "http://upstream/")
+    }
+
+    #[tokio::test]
+    async fn test_forward() -> Result<(), Box<dyn Error>> {
+        let transport = MockTransport::new();
+        transport.on_path(
+            Method::Get,
+            Path::ChannelsIdPins(1),
+            MockResponse::new(200).body(b"[]".to_vec()),
+        );
+        let proxy = proxy(&transport);
+
+        let request = Request::get("/api/v9/channels/1/pins")
+            .header(AUTHORIZATION, "Bot token")
+            .body(Body::empty())?;
+        let response = proxy.handle(request).await;
+
+        assert_eq!(StatusCode::OK, response.status());
+        assert_eq!(b"[]", &body::to_bytes(response.into_body()).await?[..]);
+
+        let requests = transport.requests();
+        assert_eq!(1, requests.len());
+        assert_eq!("http://upstream/api/v9/channels/1/pins", requests[0].url());
+        assert_eq!("Bot token", requests[0].headers()[AUTHORIZATION]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_unknown_path() -> Result<(), Box<dyn Error>> {
+        let transport = MockTransport::new();
+        let proxy = proxy(&transport);
+
+        let request = Request::get("/api/v9/unknown").body(Body::empty())?;
+        let response = proxy.handle(request).await;
+
+        assert_eq!(StatusCode::NOT_IMPLEMENTED, response.status());
+        assert!(transport.requests().is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_not_api() -> Result<(), Box<dyn Error>> {
+        let transport = MockTransport::new();
+        let proxy = proxy(&transport);
+
+        let request = Request::get("/index.html").body(Body::empty())?;
+        let response = proxy.handle(request).await;
+
+        assert_eq!(StatusCode::NOT_FOUND, response.status());
+        assert!(transport.requests().is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_status() -> Result<(), Box<dyn Error>> {
+        let transport = MockTransport::new();
+        transport.on_path(
+            Method::Post,
+            Path::ChannelsIdMessages(1),
+            MockResponse::new(200)
+                .bucket("abc")
+                .ratelimit(5, 4, Duration::from_secs(5)),
+        );
+        let proxy = proxy(&transport);
+
+        let request = Request::post("/api/v9/channels/1/messages").body(Body::empty())?;
+        proxy.handle(request).await;
+        // Give the bucket's task time to process the headers.
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        let request = Request::get(STATUS_PATH).body(Body::empty())?;
+        let response = proxy.handle(request).await;
+        assert_eq!(StatusCode::OK, response.status());
+
+        let status: Value = serde_json::from_slice(&body::to_bytes(response.into_body()).await?)?;
+        assert_eq!(Value::Bool(false), status["globally_locked"]);
+        assert_eq!(0, status["queued"]);
+        assert_eq!("abc", status["buckets"][0]["hash"]);
+        assert_eq!(5, status["buckets"][0]["limit"]);
+        assert_eq!(4, status["buckets"][0]["remaining"]);
+
+        Ok(())
+    }
+}
@@ -1,8 +1,12 @@
 use serde::{
-    de::{Error as DeError, Visitor},
+    de::{Error as DeError, MapAccess, Visitor},
+    ser::SerializeMap,
     Deserialize, Deserializer, Serialize, Serializer,
 };
-use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[non_exhaustive]
@@ -744,6 +748,11 @@ impl Display for ApiError {
 #[non_exhaustive]
 pub struct GeneralApiError {
     pub code: ErrorCode,
+    /// Fields of the request body that failed validation.
+    ///
+    /// Present for [`ErrorCode::InvalidFormBodyOrContentType`] errors.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub errors: Option<FieldErrors>,
     pub message: String,
 }
 
@@ -757,6 +766,190 @@ impl Display for GeneralApiError {
     }
 }
 
+/// Validation failure of a field of a request body.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[non_exhaustive]
+pub struct FieldError {
+    /// Code of the failure, such as `BASE_TYPE_MAX_LENGTH`.
+    pub code: String,
+    /// Human readable message provided by the API.
+    pub message: String,
+}
+
+impl Display for FieldError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(&self.code)?;
+        f.write_str(": ")?;
+
+        f.write_str(&self.message)
+    }
+}
+
+/// Tree of the validation failures of a field of a request body and of the
+/// fields nested within it.
+///
+/// Nested fields are keyed by their name, or by their index for elements of
+/// arrays. A failure of the `value` of the fourth field of the first embed of
+/// a message has the path `embeds.0.fields.3.value`.
+///
+/// # Examples
+///
+/// Print which fields of a message were rejected:
+///
+/// ```rust,no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use twilight_http::{api_error::ApiError, error::ErrorType, Client};
+/// use twilight_model::id::ChannelId;
+///
+/// let client = Client::new("my token".to_owned());
+///
+/// if let Err(source) = client.create_message(ChannelId(1)).content("hi")?.exec().await {
+///     if let ErrorType::Response {
+///         error: ApiError::General(general),
+///         ..
+///     } = source.kind()
+///     {
+///         if let Some(errors) = &general.errors {
+///             for (path, error) in errors.flatten() {
+///                 println!("{} was rejected: {}", path, error.message);
+///             }
+///         }
+///     }
+/// }
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FieldErrors {
+    children: BTreeMap<String, FieldErrors>,
+    errors: Vec<FieldError>,
+}
+
+impl FieldErrors {
+    /// Validation failures of the field itself.
+    pub fn errors(&self) -> &[FieldError] {
+        &self.errors
+    }
+
+    /// Failures of a field nested directly within this one, by its name or
+    /// index.
+    pub fn child(&self, key: &str) -> Option<&Self> {
+        self.children.get(key)
+    }
+
+    /// Iterator over the names or indices of the nested fields and their
+    /// failures.
+    pub fn children(&self) -> impl Iterator<Item = (&str, &Self)> {
+        self.children
+            .iter()
+            .map(|(key, child)| (key.as_str(), child))
+    }
+
+    /// Failures of a nested field by its dot separated path, such as
+    /// `embeds.0.fields.3.value`.
+    ///
+    /// An empty path returns this field.
+    pub fn get(&self, path: &str) -> Option<&Self> {
+        if path.is_empty() {
+            return Some(self);
+        }
+
+        path.split('.')
+            .try_fold(self, |field, key| field.child(key))
+    }
+
+    /// Validation failures of a nested field by its dot separated path.
+    ///
+    /// Returns an empty slice if the field has no failures of its own.
+    pub fn errors_at(&self, path: &str) -> &[FieldError] {
+        self.get(path).map_or(&[], Self::errors)
+    }
+
+    /// Whether neither the field nor any nested field has failures.
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty() && self.children.values().all(Self::is_empty)
+    }
+
+    /// List the failures of the field and all nested fields along with the
+    /// path of the field they belong to.
+    ///
+    /// Failures of the field itself have an empty path.
+    pub fn flatten(&self) -> Vec<(String, &FieldError)> {
+        let mut flattened = Vec::new();
+        self.flatten_into(&mut String::new(), &mut flattened);
+
+        flattened
+    }
+
+    fn flatten_into<'a>(
+        &'a self,
+        path: &mut String,
+        flattened: &mut Vec<(String, &'a FieldError)>,
+    ) {
+        flattened.extend(self.errors.iter().map(|error| (path.clone(), error)));
+
+        for (key, child) in &self.children {
+            let len = path.len();
+
+            if !path.is_empty() {
+                path.push('.');
+            }
+
+            path.push_str(key);
+            child.flatten_into(path, flattened);
+            path.truncate(len);
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for FieldErrors {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FieldErrorsVisitor;
+
+        impl<'de> Visitor<'de> for FieldErrorsVisitor {
+            type Value = FieldErrors;
+
+            fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+                f.write_str("a map of field errors")
+            }
+
+            fn visit_map<M: MapAccess<'de>>(self, mut map: M) -> Result<Self::Value, M::Error> {
+                let mut field = FieldErrors::default();
+
+                while let Some(key) = map.next_key::<String>()? {
+                    if key == "_errors" {
+                        field.errors = map.next_value()?;
+                    } else {
+                        let child = map.next_value()?;
+                        field.children.insert(key, child);
+                    }
+                }
+
+                Ok(field)
+            }
+        }
+
+        deserializer.deserialize_map(FieldErrorsVisitor)
+    }
+}
+
+impl Serialize for FieldErrors {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let len = self.children.len() + usize::from(!self.errors.is_empty());
+        let mut map = serializer.serialize_map(Some(len))?;
+
+        if !self.errors.is_empty() {
+            map.serialize_entry("_errors", &self.errors)?;
+        }
+
+        for (key, child) in &self.children {
+            map.serialize_entry(key, child)?;
+        }
+
+        map.end()
+    }
+}
+
 /// Sending a message failed because the provided fields contained invalid
 /// input.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -849,14 +1042,16 @@ impl PartialEq for RatelimitedApiError {
 #[cfg(test)]
 mod tests {
     use super::{
-        ApiError, ErrorCode, GeneralApiError, MessageApiError, MessageApiErrorEmbedField,
-        RatelimitedApiError,
+        ApiError, ErrorCode, FieldError, FieldErrors, GeneralApiError, MessageApiError,
+        MessageApiErrorEmbedField, RatelimitedApiError,
     };
     use serde_test::Token;
     use static_assertions::assert_impl_all;
-    use std::{convert::TryFrom, fmt::Debug};
+    use std::{convert::TryFrom, error::Error, fmt::Debug};
 
     assert_impl_all!(ErrorCode: Clone, Copy, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(FieldError: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(FieldErrors: Clone, Debug, Default, Eq, PartialEq, Send, Sync);
 
     #[derive(Clone, Copy)]
     struct AssertErrorCode<'a> {
@@ -879,6 +1074,7 @@ mod tests {
     fn test_api_error_deser() {
         let expected = GeneralApiError {
             code: ErrorCode::UnknownAccount,
+            errors: None,
             message: "Unknown account".to_owned(),
         };
 
@@ -898,6 +1094,77 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_api_error_field_errors() -> Result<(), Box<dyn Error>> {
+        let body = br#"{
+            "code": 50035,
+            "errors": {
+                "embeds": {
+                    "0": {
+                        "fields": {
+                            "3": {
+                                "value": {
+                                    "_errors": [{
+                                        "code": "BASE_TYPE_MAX_LENGTH",
+                                        "message": "Must be 1024 or fewer in length."
+                                    }]
+                                }
+                            }
+                        },
+                        "title": {
+                            "_errors": [{
+                                "code": "BASE_TYPE_REQUIRED",
+                                "message": "This field is required"
+                            }]
+                        }
+                    }
+                }
+            },
+            "message": "Invalid Form Body"
+        }"#;
+
+        let error = match serde_json::from_slice::<ApiError>(body)? {
+            ApiError::General(error) => error,
+            other => panic!("unexpected error: {:?}", other),
+        };
+        assert_eq!(ErrorCode::InvalidFormBodyOrContentType, error.code);
+
+        let errors = error.errors.expect("errors are present");
+        assert!(!errors.is_empty());
+        assert!(errors.errors().is_empty());
+        assert_eq!(
+            "BASE_TYPE_MAX_LENGTH",
+            errors.errors_at("embeds.0.fields.3.value")[0].code
+        );
+        assert!(errors.errors_at("embeds.0.fields.2").is_empty());
+        assert_eq!(Some(&errors), errors.get(""));
+        assert!(errors.get("embeds.1").is_none());
+
+        let embed = errors.get("embeds.0").expect("embed has errors");
+        assert_eq!(
+            vec!["fields", "title"],
+            embed.children().map(|(key, _)| key).collect::<Vec<_>>()
+        );
+
+        let paths = errors
+            .flatten()
+            .into_iter()
+            .map(|(path, error)| (path, error.code.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                ("embeds.0.fields.3.value".to_owned(), "BASE_TYPE_MAX_LENGTH"),
+                ("embeds.0.title".to_owned(), "BASE_TYPE_REQUIRED"),
+            ],
+            paths
+        );
+
+        let serialized = serde_json::to_value(&errors)?;
+        assert_eq!(errors, serde_json::from_value(serialized)?);
+
+        Ok(())
+    }
+
     #[test]
     fn test_api_error_message() {
         let expected = ApiError::Message(MessageApiError {
@@ -1,5 +1,10 @@
-use super::{Client, State};
-use crate::ratelimiting::Ratelimiter;
+use super::{Client, RetryPolicy, State};
+use crate::{
+    middleware::Middleware,
+    oauth::TokenProvider,
+    ratelimiting::{InMemoryRatelimiter, Ratelimiter},
+    transport::{HyperTransport, Transport},
+};
 use hyper::header::HeaderMap;
 use std::{
     sync::{
@@ -15,12 +20,16 @@ use twilight_model::{channel::message::allowed_mentions::AllowedMentions, id::Ap
 pub struct ClientBuilder {
     pub(crate) application_id: AtomicU64,
     pub(crate) default_allowed_mentions: Option<AllowedMentions>,
+    pub(crate) middleware: Vec<Box<dyn Middleware>>,
     pub(crate) proxy: Option<Box<str>>,
-    pub(crate) ratelimiter: Option<Ratelimiter>,
+    pub(crate) ratelimiter: Option<Box<dyn Ratelimiter>>,
     remember_invalid_token: bool,
+    pub(crate) retry_policy: RetryPolicy,
     pub(crate) default_headers: Option<HeaderMap>,
     pub(crate) timeout: Duration,
     pub(crate) token: Option<Box<str>>,
+    pub(crate) token_provider: Option<Arc<dyn TokenProvider>>,
+    pub(crate) transport: Option<Box<dyn Transport>>,
     pub(crate) use_http: bool,
 }
 
@@ -32,29 +41,23 @@ impl ClientBuilder {
 
     /// Build the [`Client`].
     pub fn build(self) -> Client {
-        #[cfg(feature = "rustls-native-roots")]
-        let connector = hyper_rustls::HttpsConnector::with_native_roots();
-        #[cfg(all(feature = "rustls-webpki-roots", not(feature = "rustls-native-roots")))]
-        let connector = hyper_rustls::HttpsConnector::with_webpki_roots();
-        #[cfg(all(
-            feature = "hyper-tls",
-            not(feature = "rustls-native-roots"),
-            not(feature = "rustls-webpki-roots")
-        ))]
-        let connector = hyper_tls::HttpsConnector::new();
-
-        let http = hyper::client::Builder::default().build(connector);
+        let transport = self
+            .transport
+            .unwrap_or_else(|| Box::new(HyperTransport::new()));
 
         Client {
             state: Arc::new(State {
-                http,
+                transport,
                 default_headers: self.default_headers,
+                middleware: self.middleware,
                 proxy: self.proxy,
                 ratelimiter: self.ratelimiter,
                 remember_invalid_token: self.remember_invalid_token,
+                retry_policy: self.retry_policy,
                 timeout: self.timeout,
                 token_invalid: Arc::new(AtomicBool::new(false)),
                 token: self.token,
+                token_provider: self.token_provider,
                 application_id: self.application_id,
                 default_allowed_mentions: self.default_allowed_mentions,
                 use_http: self.use_http,
@@ -78,6 +81,19 @@ impl ClientBuilder {
         self
     }
 
+    /// Add a middleware to the end of the client's middleware chain.
+    ///
+    /// Middleware is called with requests in the order it was added and with
+    /// responses in reverse order. Refer to the [`middleware`] module for
+    /// more information.
+    ///
+    /// [`middleware`]: crate::middleware
+    pub fn middleware(mut self, middleware: Box<dyn Middleware>) -> Self {
+        self.middleware.push(middleware);
+
+        self
+    }
+
     /// Set the proxy to use for all HTTP(S) requests.
     ///
     /// **Note** that this isn't currently a traditional proxy, but is for
@@ -110,15 +126,32 @@ impl ClientBuilder {
     /// If the argument is `None` then the client's ratelimiter will be skipped
     /// before making a request.
     ///
-    /// If this method is not called at all then a default ratelimiter will be
-    /// created by [`ClientBuilder::build`].
+    /// If this method is not called at all then a default
+    /// [`InMemoryRatelimiter`] will be created by [`ClientBuilder::build`].
     #[allow(clippy::missing_const_for_fn)]
-    pub fn ratelimiter(mut self, ratelimiter: Option<Ratelimiter>) -> Self {
+    pub fn ratelimiter(mut self, ratelimiter: Option<Box<dyn Ratelimiter>>) -> Self {
         self.ratelimiter = ratelimiter;
 
         self
     }
 
+    /// Set the policy determining whether and when failed requests are
+    /// retried.
+    ///
+    /// The policy can be overridden for individual requests via
+    /// [`RequestBuilder::retry_policy`] or [`ResponseFuture::retry_policy`].
+    ///
+    /// Defaults to [`RetryPolicy::none`], which never retries requests.
+    ///
+    /// [`RequestBuilder::retry_policy`]: crate::request::RequestBuilder::retry_policy
+    /// [`ResponseFuture::retry_policy`]: crate::response::ResponseFuture::retry_policy
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+
+        self
+    }
+
     /// Set the timeout for HTTP requests.
     ///
     /// The default is 10 seconds.
@@ -163,6 +196,35 @@ impl ClientBuilder {
 
         self
     }
+
+    /// Set the provider of the authorization used for HTTP requests, such as
+    /// a [`RefreshingToken`].
+    ///
+    /// The provider is asked for the authorization of every request instead
+    /// of using the [token].
+    ///
+    /// [`RefreshingToken`]: crate::oauth::RefreshingToken
+    /// [token]: Self::token
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn token_provider(mut self, token_provider: Arc<dyn TokenProvider>) -> Self {
+        self.token_provider = Some(token_provider);
+
+        self
+    }
+
+    /// Set the transport used to send requests.
+    ///
+    /// If this method is not called then a [`HyperTransport`] will be created
+    /// by [`ClientBuilder::build`]. A [`MockTransport`] can be used to test
+    /// bots without connecting to Discord.
+    ///
+    /// [`MockTransport`]: crate::transport::MockTransport
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn transport(mut self, transport: Box<dyn Transport>) -> Self {
+        self.transport = Some(transport);
+
+        self
+    }
 }
 
 impl Default for ClientBuilder {
@@ -171,11 +233,15 @@ impl Default for ClientBuilder {
             application_id: AtomicU64::default(),
             default_allowed_mentions: None,
             default_headers: None,
+            middleware: Vec::new(),
             proxy: None,
-            ratelimiter: Some(Ratelimiter::new()),
+            ratelimiter: Some(Box::new(InMemoryRatelimiter::new())),
             remember_invalid_token: true,
+            retry_policy: RetryPolicy::none(),
             timeout: Duration::from_secs(10),
             token: None,
+            token_provider: None,
+            transport: None,
             use_http: false,
         }
     }
@@ -1,10 +1,15 @@
 mod builder;
+mod retry;
 
-pub use self::builder::ClientBuilder;
+pub use self::{builder::ClientBuilder, retry::RetryPolicy};
+
+pub(crate) use self::retry::RetryReason;
 
 use crate::{
     error::{Error, ErrorType},
-    ratelimiting::Ratelimiter,
+    middleware::Middleware,
+    oauth::TokenProvider,
+    ratelimiting::{Priority, Ratelimiter},
     request::{
         application::{
             command::{
@@ -13,7 +18,7 @@ use crate::{
                 CreateGuildCommand, DeleteGlobalCommand, DeleteGuildCommand, GetCommandPermissions,
                 GetGlobalCommand, GetGlobalCommands, GetGuildCommand, GetGuildCommandPermissions,
                 GetGuildCommands, SetCommandPermissions, SetGlobalCommands, SetGuildCommands,
-                UpdateCommandPermissions, UpdateGlobalCommand, UpdateGuildCommand,
+                SyncCommands, UpdateCommandPermissions, UpdateGlobalCommand, UpdateGuildCommand,
             },
             interaction::{
                 CreateFollowupMessage, DeleteFollowupMessage, DeleteOriginalResponse,
@@ -37,13 +42,18 @@ use crate::{
             update_guild_channel_positions::Position,
         },
         prelude::*,
-        GetUserApplicationInfo, Method, Request,
+        GetCurrentAuthorizationInformation, GetUserApplicationInfo, Method, Request, StreamedForm,
+    },
+    response::{
+        future::{InvalidToken, Resend},
+        ResponseFuture,
     },
-    response::{future::InvalidToken, ResponseFuture},
+    routing::Path,
+    transport::{Transport, TransportFuture},
     API_VERSION,
 };
 use hyper::{
-    client::{Client as HyperClient, HttpConnector},
+    body::Bytes,
     header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_LENGTH, CONTENT_TYPE, USER_AGENT},
     Body,
 };
@@ -56,32 +66,30 @@ use std::{
     },
     time::Duration,
 };
-use tokio::time;
 use twilight_model::{
     application::{
         callback::InteractionResponse,
         command::{permissions::CommandPermissions, Command},
     },
     channel::{
-        message::allowed_mentions::AllowedMentions, thread::AutoArchiveDuration, ChannelType,
+        message::{allowed_mentions::AllowedMentions, sticker::StickerId},
+        thread::AutoArchiveDuration,
+        ChannelType,
     },
     guild::Permissions,
     id::{
         ApplicationId, ChannelId, CommandId, EmojiId, GuildId, IntegrationId, InteractionId,
-        MessageId, RoleId, UserId, WebhookId,
+        MessageId, RoleId, ScheduledEventId, UserId, WebhookId,
     },
+    scheduled_event::EntityType,
 };
 
-#[cfg(feature = "hyper-rustls")]
-type HttpsConnector<T> = hyper_rustls::HttpsConnector<T>;
-#[cfg(all(feature = "hyper-tls", not(feature = "hyper-rustls")))]
-type HttpsConnector<T> = hyper_tls::HttpsConnector<T>;
-
 struct State {
-    http: HyperClient<HttpsConnector<HttpConnector>, Body>,
+    transport: Box<dyn Transport>,
     default_headers: Option<HeaderMap>,
+    middleware: Vec<Box<dyn Middleware>>,
     proxy: Option<Box<str>>,
-    ratelimiter: Option<Ratelimiter>,
+    ratelimiter: Option<Box<dyn Ratelimiter>>,
     /// Whether to short-circuit when a 401 has been encountered with the client
     /// authorization.
     ///
@@ -89,9 +97,11 @@ struct State {
     ///
     /// [`token_invalid`]: Self::token_invalid
     remember_invalid_token: bool,
+    retry_policy: RetryPolicy,
     timeout: Duration,
     token_invalid: Arc<AtomicBool>,
     token: Option<Box<str>>,
+    token_provider: Option<Arc<dyn TokenProvider>>,
     use_http: bool,
     pub(crate) application_id: AtomicU64,
     pub(crate) default_allowed_mentions: Option<AllowedMentions>,
@@ -100,11 +110,14 @@ struct State {
 impl Debug for State {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         f.debug_struct("State")
-            .field("http", &self.http)
+            .field("transport", &self.transport)
             .field("default_headers", &self.default_headers)
+            .field("middleware", &self.middleware)
             .field("proxy", &self.proxy)
             .field("ratelimiter", &self.ratelimiter)
+            .field("retry_policy", &self.retry_policy)
             .field("token", &self.token)
+            .field("token_provider", &self.token_provider)
             .field("use_http", &self.use_http)
             .finish()
     }
@@ -132,6 +145,10 @@ impl Debug for State {
 /// # Ok(()) }
 /// ```
 ///
+/// Access tokens can be obtained and refreshed via the [`oauth`] module.
+///
+/// [`oauth`]: crate::oauth
+///
 /// # Cloning
 ///
 /// The client internally wraps its data within an Arc. This means that the
@@ -239,8 +256,13 @@ impl Client {
     ///
     /// This will return `None` only if ratelimit handling
     /// has been explicitly disabled in the [`ClientBuilder`].
-    pub fn ratelimiter(&self) -> Option<Ratelimiter> {
-        self.state.ratelimiter.clone()
+    pub fn ratelimiter(&self) -> Option<&dyn Ratelimiter> {
+        self.state.ratelimiter.as_deref()
+    }
+
+    /// Middleware registered with the client, in order of registration.
+    pub(crate) fn middleware(&self) -> &[Box<dyn Middleware>] {
+        &self.state.middleware
     }
 
     /// Get the audit log for a guild.
@@ -498,6 +520,12 @@ impl Client {
         GetChannelWebhooks::new(self, channel_id)
     }
 
+    /// Get information about the authorization of the client's OAuth2
+    /// access token, such as its scopes and the user who granted it.
+    pub const fn current_authorization(&self) -> GetCurrentAuthorizationInformation<'_> {
+        GetCurrentAuthorizationInformation::new(self)
+    }
+
     /// Get information about the current user.
     pub const fn current_user(&self) -> GetCurrentUser<'_> {
         GetCurrentUser::new(self)
@@ -650,6 +678,149 @@ impl Client {
         UpdateEmoji::new(self, guild_id, emoji_id)
     }
 
+    /// Get the sticker packs available to Nitro subscribers.
+    pub const fn nitro_sticker_packs(&self) -> GetNitroStickerPacks<'_> {
+        GetNitroStickerPacks::new(self)
+    }
+
+    /// Get a sticker by its ID.
+    pub const fn sticker(&self, sticker_id: StickerId) -> GetSticker<'_> {
+        GetSticker::new(self, sticker_id)
+    }
+
+    /// Get the stickers of a guild.
+    pub const fn guild_stickers(&self, guild_id: GuildId) -> GetGuildStickers<'_> {
+        GetGuildStickers::new(self, guild_id)
+    }
+
+    /// Get a sticker of a guild by the guild's ID and the sticker's ID.
+    pub const fn guild_sticker(
+        &self,
+        guild_id: GuildId,
+        sticker_id: StickerId,
+    ) -> GetGuildSticker<'_> {
+        GetGuildSticker::new(self, guild_id, sticker_id)
+    }
+
+    /// Create a sticker in a guild.
+    ///
+    /// The name must be between 2 and 30 characters, the description must be
+    /// empty or between 2 and 100 characters, and the tags must be between 2
+    /// and 200 characters in length. The file must be a PNG, APNG, or Lottie
+    /// JSON file of at most 512 KiB, and can't be empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StickerValidationErrorType::NameInvalid`],
+    /// [`StickerValidationErrorType::DescriptionInvalid`],
+    /// [`StickerValidationErrorType::TagsInvalid`],
+    /// [`StickerValidationErrorType::FileEmpty`], or
+    /// [`StickerValidationErrorType::FileTooLarge`] error type if the
+    /// respective field is invalid.
+    ///
+    /// [`StickerValidationErrorType::DescriptionInvalid`]: crate::request::guild::sticker::StickerValidationErrorType::DescriptionInvalid
+    /// [`StickerValidationErrorType::FileEmpty`]: crate::request::guild::sticker::StickerValidationErrorType::FileEmpty
+    /// [`StickerValidationErrorType::FileTooLarge`]: crate::request::guild::sticker::StickerValidationErrorType::FileTooLarge
+    /// [`StickerValidationErrorType::NameInvalid`]: crate::request::guild::sticker::StickerValidationErrorType::NameInvalid
+    /// [`StickerValidationErrorType::TagsInvalid`]: crate::request::guild::sticker::StickerValidationErrorType::TagsInvalid
+    pub fn create_guild_sticker<'a>(
+        &'a self,
+        guild_id: GuildId,
+        name: &'a str,
+        description: &'a str,
+        tags: &'a str,
+        file: &'a [u8],
+    ) -> Result<CreateGuildSticker<'a>, StickerValidationError> {
+        CreateGuildSticker::new(self, guild_id, name, description, tags, file)
+    }
+
+    /// Update a sticker of a guild.
+    pub const fn update_guild_sticker(
+        &self,
+        guild_id: GuildId,
+        sticker_id: StickerId,
+    ) -> UpdateGuildSticker<'_> {
+        UpdateGuildSticker::new(self, guild_id, sticker_id)
+    }
+
+    /// Delete a sticker of a guild.
+    pub const fn delete_guild_sticker(
+        &self,
+        guild_id: GuildId,
+        sticker_id: StickerId,
+    ) -> DeleteGuildSticker<'_> {
+        DeleteGuildSticker::new(self, guild_id, sticker_id)
+    }
+
+    /// Get the scheduled events of a guild.
+    pub const fn guild_scheduled_events(&self, guild_id: GuildId) -> GetGuildScheduledEvents<'_> {
+        GetGuildScheduledEvents::new(self, guild_id)
+    }
+
+    /// Get a scheduled event of a guild.
+    pub const fn guild_scheduled_event(
+        &self,
+        guild_id: GuildId,
+        scheduled_event_id: ScheduledEventId,
+    ) -> GetGuildScheduledEvent<'_> {
+        GetGuildScheduledEvent::new(self, guild_id, scheduled_event_id)
+    }
+
+    /// Create a scheduled event in a guild.
+    ///
+    /// The name must be between 1 and 100 characters in length, and the
+    /// start time is an ISO 8601 timestamp. Refer to
+    /// [`CreateGuildScheduledEvent`] for the fields required by each
+    /// [`EntityType`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ScheduledEventValidationErrorType::NameInvalid`] error type
+    /// if the name is invalid.
+    ///
+    /// [`ScheduledEventValidationErrorType::NameInvalid`]: crate::request::guild::scheduled_event::ScheduledEventValidationErrorType::NameInvalid
+    pub fn create_guild_scheduled_event<'a>(
+        &'a self,
+        guild_id: GuildId,
+        entity_type: EntityType,
+        name: &'a str,
+        scheduled_start_time: &'a str,
+    ) -> Result<CreateGuildScheduledEvent<'a>, ScheduledEventValidationError> {
+        CreateGuildScheduledEvent::new(self, guild_id, entity_type, name, scheduled_start_time)
+    }
+
+    /// Update a scheduled event of a guild.
+    pub const fn update_guild_scheduled_event(
+        &self,
+        guild_id: GuildId,
+        scheduled_event_id: ScheduledEventId,
+    ) -> UpdateGuildScheduledEvent<'_> {
+        UpdateGuildScheduledEvent::new(self, guild_id, scheduled_event_id)
+    }
+
+    /// Delete a scheduled event of a guild.
+    pub const fn delete_guild_scheduled_event(
+        &self,
+        guild_id: GuildId,
+        scheduled_event_id: ScheduledEventId,
+    ) -> DeleteGuildScheduledEvent<'_> {
+        DeleteGuildScheduledEvent::new(self, guild_id, scheduled_event_id)
+    }
+
+    /// Get the users subscribed to a scheduled event of a guild.
+    ///
+    /// Use [`GetGuildScheduledEventUsers::paginate`] to stream every
+    /// subscribed user.
+    ///
+    /// [`GetGuildScheduledEventUsers::paginate`]: crate::request::guild::scheduled_event::GetGuildScheduledEventUsers::paginate
+    pub const fn guild_scheduled_event_users(
+        &self,
+        guild_id: GuildId,
+        scheduled_event_id: ScheduledEventId,
+    ) -> GetGuildScheduledEventUsers<'_> {
+        GetGuildScheduledEventUsers::new(self, guild_id, scheduled_event_id)
+    }
+
     /// Get information about the gateway, optionally with additional information detailing the
     /// number of shards to use and sessions remaining.
     ///
@@ -1200,6 +1371,20 @@ impl Client {
         DeleteMessages::new(self, channel_id, message_ids)
     }
 
+    /// Delete the messages of a channel matching a filter, falling back to
+    /// deleting messages one by one when they are too old to be bulk deleted.
+    ///
+    /// Returns a stream reporting the progress of the purge once executed.
+    /// Refer to [`PurgeMessages`] for more information.
+    ///
+    /// Requires the [`MANAGE_MESSAGES`] permission to delete the messages of
+    /// other users.
+    ///
+    /// [`MANAGE_MESSAGES`]: twilight_model::guild::Permissions::MANAGE_MESSAGES
+    pub const fn purge_messages(&self, channel_id: ChannelId) -> PurgeMessages<'_> {
+        PurgeMessages::new(self, channel_id)
+    }
+
     /// Update a message by [`ChannelId`] and [`MessageId`].
     ///
     /// You can pass `None` to any of the methods to remove the associated field.
@@ -2228,6 +2413,34 @@ impl Client {
         ))
     }
 
+    /// Synchronize the commands in a guild with local definitions, only
+    /// creating, updating, and deleting the commands that changed.
+    ///
+    /// Refer to [`SyncCommands`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InteractionErrorType::ApplicationIdNotPresent`]
+    /// error type if an application ID has not been configured via
+    /// [`Client::set_application_id`].
+    ///
+    /// Returns an [`InteractionErrorType::CommandNameValidationFailed`],
+    /// [`InteractionErrorType::CommandDescriptionValidationFailed`], or
+    /// [`InteractionErrorType::CommandOptionsRequiredFirst`] error type, or
+    /// one of the localization validation error types, if a command would
+    /// be rejected by the command request builders.
+    pub fn sync_guild_commands<'a>(
+        &'a self,
+        guild_id: GuildId,
+        commands: &'a [Command],
+    ) -> Result<SyncCommands<'a>, InteractionError> {
+        let application_id = self.application_id().ok_or(InteractionError {
+            kind: InteractionErrorType::ApplicationIdNotPresent,
+        })?;
+
+        SyncCommands::new(self, application_id, Some(guild_id), commands)
+    }
+
     /// Create a new chat input global command.
     ///
     /// The name must be between 1 and 32 characters in length. The description
@@ -2387,6 +2600,33 @@ impl Client {
         Ok(SetGlobalCommands::new(self, application_id, commands))
     }
 
+    /// Synchronize the global commands with local definitions, only creating,
+    /// updating, and deleting the commands that changed.
+    ///
+    /// Refer to [`SyncCommands`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InteractionErrorType::ApplicationIdNotPresent`]
+    /// error type if an application ID has not been configured via
+    /// [`Client::set_application_id`].
+    ///
+    /// Returns an [`InteractionErrorType::CommandNameValidationFailed`],
+    /// [`InteractionErrorType::CommandDescriptionValidationFailed`], or
+    /// [`InteractionErrorType::CommandOptionsRequiredFirst`] error type, or
+    /// one of the localization validation error types, if a command would
+    /// be rejected by the command request builders.
+    pub fn sync_global_commands<'a>(
+        &'a self,
+        commands: &'a [Command],
+    ) -> Result<SyncCommands<'a>, InteractionError> {
+        let application_id = self.application_id().ok_or(InteractionError {
+            kind: InteractionErrorType::ApplicationIdNotPresent,
+        })?;
+
+        SyncCommands::new(self, application_id, None, commands)
+    }
+
     /// Fetch command permissions for a command from the current application
     /// in a guild.
     ///
@@ -2501,7 +2741,7 @@ impl Client {
 
     #[allow(clippy::too_many_lines)]
     fn try_request<T>(&self, request: Request) -> Result<ResponseFuture<T>, Error> {
-        if self.state.remember_invalid_token && self.state.token_invalid.load(Ordering::Relaxed) {
+        if self.is_token_invalid() {
             return Err(Error {
                 kind: ErrorType::Unauthorized,
                 source: None,
@@ -2514,7 +2754,9 @@ impl Client {
             headers: req_headers,
             method,
             path,
+            priority,
             ratelimit_path,
+            retry_policy,
             use_authorization_token,
         } = request;
 
@@ -2525,11 +2767,15 @@ impl Client {
         #[cfg(feature = "tracing")]
         tracing::debug!("URL: {:?}", url);
 
-        let mut builder = hyper::Request::builder()
-            .method(method.into_hyper())
-            .uri(&url);
+        let mut headers = HeaderMap::new();
+
+        let token_provider = self
+            .state
+            .token_provider
+            .as_ref()
+            .filter(|_| use_authorization_token);
 
-        if use_authorization_token {
+        if use_authorization_token && token_provider.is_none() {
             if let Some(ref token) = self.state.token {
                 let value = HeaderValue::from_str(token).map_err(|source| {
                     #[allow(clippy::borrow_interior_mutable_const)]
@@ -2541,9 +2787,7 @@ impl Client {
                     }
                 })?;
 
-                if let Some(headers) = builder.headers_mut() {
-                    headers.insert(AUTHORIZATION, value);
-                }
+                headers.insert(AUTHORIZATION, value);
             }
         }
 
@@ -2555,101 +2799,164 @@ impl Client {
             ") Twilight-rs",
         ));
 
-        if let Some(headers) = builder.headers_mut() {
-            if let Some(form) = &form {
-                if let Ok(content_type) = HeaderValue::try_from(form.content_type()) {
-                    headers.insert(CONTENT_TYPE, content_type);
-                }
-            } else if let Some(bytes) = &body {
-                let len = bytes.len();
-                headers.insert(CONTENT_LENGTH, HeaderValue::from(len));
-
-                let content_type = HeaderValue::from_static("application/json");
+        if let Some(form) = &form {
+            if let Ok(content_type) = HeaderValue::try_from(form.content_type()) {
                 headers.insert(CONTENT_TYPE, content_type);
             }
+        } else if let Some(bytes) = &body {
+            let len = bytes.len();
+            headers.insert(CONTENT_LENGTH, HeaderValue::from(len));
 
-            #[cfg(feature = "decompression")]
-            headers.insert(
-                hyper::header::ACCEPT_ENCODING,
-                HeaderValue::from_static("br"),
-            );
+            let content_type = HeaderValue::from_static("application/json");
+            headers.insert(CONTENT_TYPE, content_type);
+        }
 
-            headers.insert(USER_AGENT, user_agent);
+        #[cfg(feature = "decompression")]
+        headers.insert(
+            hyper::header::ACCEPT_ENCODING,
+            HeaderValue::from_static("br"),
+        );
 
-            if let Some(req_headers) = req_headers {
-                for (maybe_name, value) in req_headers {
-                    if let Some(name) = maybe_name {
-                        headers.insert(name, value);
-                    }
+        headers.insert(USER_AGENT, user_agent);
+
+        if let Some(req_headers) = req_headers {
+            for (maybe_name, value) in req_headers {
+                if let Some(name) = maybe_name {
+                    headers.insert(name, value);
                 }
             }
+        }
 
-            if let Some(default_headers) = &self.state.default_headers {
-                for (name, value) in default_headers {
-                    headers.insert(name, HeaderValue::from(value));
-                }
+        if let Some(default_headers) = &self.state.default_headers {
+            for (name, value) in default_headers {
+                headers.insert(name, HeaderValue::from(value));
             }
         }
 
-        let req = if let Some(form) = form {
-            let form_bytes = form.build();
-            if let Some(headers) = builder.headers_mut() {
+        let mut stream = None;
+
+        let body = if let Some(form) = form {
+            if form.is_streamed() {
+                let streamed = form.finish();
+                headers.insert(CONTENT_LENGTH, HeaderValue::from(streamed.len()));
+                stream = Some(streamed);
+
+                Bytes::new()
+            } else {
+                let form_bytes = form.into_buffer();
                 headers.insert(CONTENT_LENGTH, HeaderValue::from(form_bytes.len()));
-            };
-            builder
-                .body(Body::from(form_bytes))
-                .map_err(|source| Error {
-                    kind: ErrorType::BuildingRequest,
-                    source: Some(Box::new(source)),
-                })?
+
+                Bytes::from(form_bytes)
+            }
         } else if let Some(bytes) = body {
-            builder.body(Body::from(bytes)).map_err(|source| Error {
-                kind: ErrorType::BuildingRequest,
-                source: Some(Box::new(source)),
-            })?
-        } else if method == Method::Put || method == Method::Post || method == Method::Patch {
-            if let Some(headers) = builder.headers_mut() {
+            Bytes::from(bytes)
+        } else {
+            if method == Method::Put || method == Method::Post || method == Method::Patch {
                 headers.insert(CONTENT_LENGTH, HeaderValue::from(0));
             }
 
-            builder.body(Body::empty()).map_err(|source| Error {
-                kind: ErrorType::BuildingRequest,
-                source: Some(Box::new(source)),
-            })?
-        } else {
-            builder.body(Body::empty()).map_err(|source| Error {
-                kind: ErrorType::BuildingRequest,
-                source: Some(Box::new(source)),
-            })?
+            Bytes::new()
         };
 
-        let inner = self.state.http.request(req);
-
-        let invalid_token = if self.state.remember_invalid_token {
-            InvalidToken::Remember(Arc::clone(&self.state.token_invalid))
-        } else {
-            InvalidToken::Forget
+        let prepared = PreparedRequest {
+            body,
+            headers,
+            method,
+            priority,
+            ratelimit_path,
+            stream,
+            url,
         };
+        let policy = retry_policy.unwrap_or_else(|| self.state.retry_policy.clone());
+        let resend = Resend::new(self.clone(), policy, prepared);
 
         // Clippy suggests bad code; an `Option::map_or_else` won't work here
         // due to move semantics in both cases.
         #[allow(clippy::option_if_let_else)]
-        if let Some(ratelimiter) = self.state.ratelimiter.as_ref() {
-            let rx = ratelimiter.ticket(ratelimit_path);
-
-            Ok(ResponseFuture::ratelimit(
-                None,
-                invalid_token,
-                rx,
-                self.state.timeout,
-                inner,
+        if let Some(provider) = token_provider {
+            Ok(ResponseFuture::authorizing(
+                resend,
+                Arc::clone(provider).authorization(),
             ))
         } else {
-            Ok(ResponseFuture::new(
-                invalid_token,
-                time::timeout(self.state.timeout, inner),
-                None,
-            ))
+            Ok(ResponseFuture::new(resend))
+        }
+    }
+
+    /// Whether the client has encountered an Unauthorized response and
+    /// remembers it.
+    fn is_token_invalid(&self) -> bool {
+        self.state.remember_invalid_token && self.state.token_invalid.load(Ordering::Relaxed)
+    }
+
+    /// How to handle Unauthorized responses to requests sent by the client.
+    pub(crate) fn invalid_token(&self) -> InvalidToken {
+        if self.state.remember_invalid_token {
+            InvalidToken::Remember(Arc::clone(&self.state.token_invalid))
+        } else {
+            InvalidToken::Forget
+        }
+    }
+
+    /// Timeout of a single attempt of a request.
+    pub(crate) fn request_timeout(&self) -> Duration {
+        self.state.timeout
+    }
+
+    /// Send a single attempt of a prepared request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ErrorType::Unauthorized`] error type if the configured
+    /// token has become invalid.
+    ///
+    /// Returns an [`ErrorType::BuildingRequest`] error type if the request
+    /// could not be built.
+    pub(crate) fn send_prepared(
+        &self,
+        prepared: &PreparedRequest,
+    ) -> Result<TransportFuture, Error> {
+        if self.is_token_invalid() {
+            return Err(Error {
+                kind: ErrorType::Unauthorized,
+                source: None,
+            });
         }
+
+        let mut builder = hyper::Request::builder()
+            .method(prepared.method.into_hyper())
+            .uri(&prepared.url);
+
+        if let Some(headers) = builder.headers_mut() {
+            headers.extend(prepared.headers.clone());
+        }
+
+        let body = match &prepared.stream {
+            Some(stream) => Body::wrap_stream(stream.stream()),
+            None => Body::from(prepared.body.clone()),
+        };
+
+        let req = builder.body(body).map_err(|source| Error {
+            kind: ErrorType::BuildingRequest,
+            source: Some(Box::new(source)),
+        })?;
+
+        Ok(self.state.transport.send(req))
     }
 }
+
+/// Request with its URL, headers and body prepared, allowing it to be sent
+/// multiple times.
+#[derive(Clone, Debug)]
+pub(crate) struct PreparedRequest {
+    pub(crate) body: Bytes,
+    pub(crate) headers: HeaderMap,
+    pub(crate) method: Method,
+    pub(crate) priority: Priority,
+    pub(crate) ratelimit_path: Path,
+    /// Multipart form streamed as the body instead of [`body`].
+    ///
+    /// [`body`]: Self::body
+    pub(crate) stream: Option<StreamedForm>,
+    pub(crate) url: String,
+}
@@ -0,0 +1,424 @@
+use crate::request::Method;
+use hyper::{header::HeaderMap, StatusCode};
+use rand::Rng;
+use std::{convert::TryFrom, time::Duration};
+
+/// Reason a request attempt failed that may warrant retrying it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum RetryReason {
+    /// The request was ratelimited.
+    ///
+    /// Contains the amount of time the API asked to wait, if any.
+    Ratelimited(Option<Duration>),
+    /// The API responded with a server error.
+    ///
+    /// Contains the amount of time the API asked to wait, if any.
+    ServerError(Option<Duration>),
+    /// Sending the request or receiving the response failed, or the request
+    /// timed out.
+    Transport,
+}
+
+impl RetryReason {
+    /// Determine the reason from the status code and headers of a failed
+    /// response, if the response may be retried at all.
+    pub fn from_response(status: StatusCode, headers: &HeaderMap) -> Option<Self> {
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            // Both headers are in seconds; use whichever asks to wait longer.
+            let wait = header_secs(headers, "retry-after")
+                .into_iter()
+                .chain(header_secs(headers, "x-ratelimit-reset-after"))
+                .max();
+
+            return Some(Self::Ratelimited(wait));
+        }
+
+        match status {
+            StatusCode::INTERNAL_SERVER_ERROR
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT => {
+                Some(Self::ServerError(header_secs(headers, "retry-after")))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Parse a header containing a number of seconds, which may be fractional.
+fn header_secs(headers: &HeaderMap, name: &str) -> Option<Duration> {
+    let secs = headers.get(name)?.to_str().ok()?.parse::<f64>().ok()?;
+
+    if !secs.is_finite() || secs < 0. {
+        return None;
+    }
+
+    Some(Duration::from_secs_f64(secs))
+}
+
+/// Policy determining whether and when failed requests are retried.
+///
+/// Requests are retried when:
+///
+/// - the request was ratelimited, regardless of its method, since the API did
+///   not process the request;
+/// - the API responded with a 500, 502, 503 or 504 status code and the method
+///   is [idempotent];
+/// - sending the request failed or it timed out and the method is
+///   [idempotent].
+///
+/// Retried requests re-enter the client's ratelimiter queue for the request's
+/// [`Path`].
+///
+/// The delay before the next attempt is the amount of time the API asked to
+/// wait via the `Retry-After` or `X-RateLimit-Reset-After` headers, if
+/// present. Otherwise an exponential backoff is used, which starts at the
+/// [base delay] and doubles after each attempt up to the [maximum delay], with
+/// random jitter applied if [enabled].
+///
+/// # Examples
+///
+/// Retry requests up to 5 times, starting with a delay of 250 milliseconds:
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use twilight_http::{client::RetryPolicy, Client};
+///
+/// let policy = RetryPolicy::new()
+///     .max_attempts(5)
+///     .backoff(Duration::from_millis(250), Duration::from_secs(10));
+///
+/// let client = Client::builder()
+///     .retry_policy(policy)
+///     .token("my token".to_owned())
+///     .build();
+/// ```
+///
+/// # Overriding the policy of a request
+///
+/// The client's policy can be overridden for an individual request by
+/// calling [`ResponseFuture::retry_policy`] on the future returned by the
+/// request's `exec` method, which works for every request builder such as
+/// [`Client::create_message`]. Requests created through [`Request::builder`]
+/// may instead use [`RequestBuilder::retry_policy`].
+///
+/// Don't retry creating a message, regardless of the client's policy:
+///
+/// ```rust,no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use twilight_http::{client::RetryPolicy, Client};
+/// use twilight_model::id::ChannelId;
+///
+/// let client = Client::new("my token".to_owned());
+/// client
+///     .create_message(ChannelId(1))
+///     .content("hello")?
+///     .exec()
+///     .retry_policy(RetryPolicy::none())
+///     .await?;
+/// # Ok(()) }
+/// ```
+///
+/// [`Client::create_message`]: crate::Client::create_message
+/// [`Path`]: crate::routing::Path
+/// [`Request::builder`]: crate::request::Request::builder
+/// [`RequestBuilder::retry_policy`]: crate::request::RequestBuilder::retry_policy
+/// [`ResponseFuture::retry_policy`]: crate::response::ResponseFuture::retry_policy
+/// [base delay]: Self::backoff
+/// [enabled]: Self::jitter
+/// [idempotent]: Self::idempotent_methods
+/// [maximum delay]: Self::backoff
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RetryPolicy {
+    base_delay: Duration,
+    idempotent_methods: Vec<Method>,
+    jitter: bool,
+    max_attempts: u16,
+    max_delay: Duration,
+    retry_ratelimited: bool,
+}
+
+impl RetryPolicy {
+    /// Create a new retry policy with the default configuration.
+    ///
+    /// Requests are attempted up to 3 times with a base delay of 500
+    /// milliseconds, a maximum delay of 10 seconds and jitter enabled. `GET`,
+    /// `PUT` and `DELETE` requests are considered idempotent.
+    pub fn new() -> Self {
+        Self {
+            base_delay: Duration::from_millis(500),
+            idempotent_methods: vec![Method::Delete, Method::Get, Method::Put],
+            jitter: true,
+            max_attempts: 3,
+            max_delay: Duration::from_secs(10),
+            retry_ratelimited: true,
+        }
+    }
+
+    /// Create a policy that never retries requests.
+    ///
+    /// This is the policy used by the client by default.
+    pub fn none() -> Self {
+        Self::new().max_attempts(1)
+    }
+
+    /// Set the delay before the first retry and the maximum delay between
+    /// attempts.
+    ///
+    /// Ratelimited requests asking to wait longer than the maximum delay are
+    /// not retried.
+    ///
+    /// Defaults to 500 milliseconds and 10 seconds.
+    pub const fn backoff(mut self, base_delay: Duration, max_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self.max_delay = max_delay;
+
+        self
+    }
+
+    /// Set the methods that are safe to retry when the API responded with a
+    /// server error or the request could not be sent.
+    ///
+    /// Defaults to `DELETE`, `GET` and `PUT`.
+    pub fn idempotent_methods(mut self, methods: &[Method]) -> Self {
+        self.idempotent_methods = methods.to_vec();
+
+        self
+    }
+
+    /// Set whether to randomize backoff delays.
+    ///
+    /// This avoids many clients retrying at the same time. Delays taken from
+    /// response headers are never randomized.
+    ///
+    /// Defaults to true.
+    pub const fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+
+        self
+    }
+
+    /// Set the maximum number of attempts, including the first one.
+    ///
+    /// A value of 0 or 1 disables retries.
+    ///
+    /// Defaults to 3.
+    pub const fn max_attempts(mut self, max_attempts: u16) -> Self {
+        self.max_attempts = max_attempts;
+
+        self
+    }
+
+    /// Set whether to retry ratelimited requests.
+    ///
+    /// Defaults to true.
+    pub const fn retry_ratelimited(mut self, retry_ratelimited: bool) -> Self {
+        self.retry_ratelimited = retry_ratelimited;
+
+        self
+    }
+
+    /// Determine how long to wait before attempting a request again, if at
+    /// all.
+    ///
+    /// `attempts` is the number of attempts that have already been made.
+    pub(crate) fn delay(
+        &self,
+        attempts: u16,
+        method: Method,
+        reason: RetryReason,
+    ) -> Option<Duration> {
+        if attempts >= self.max_attempts {
+            return None;
+        }
+
+        let requested = match reason {
+            RetryReason::Ratelimited(requested) => {
+                if !self.retry_ratelimited {
+                    return None;
+                }
+
+                requested
+            }
+            RetryReason::ServerError(requested) => {
+                if !self.idempotent_methods.contains(&method) {
+                    return None;
+                }
+
+                requested
+            }
+            RetryReason::Transport => {
+                if !self.idempotent_methods.contains(&method) {
+                    return None;
+                }
+
+                None
+            }
+        };
+
+        if let Some(requested) = requested {
+            return if requested > self.max_delay {
+                None
+            } else {
+                Some(requested)
+            };
+        }
+
+        Some(self.backoff_delay(attempts))
+    }
+
+    /// Exponential backoff delay after a number of attempts.
+    fn backoff_delay(&self, attempts: u16) -> Duration {
+        let exponent = u32::from(attempts.saturating_sub(1)).min(16);
+        let delay = self
+            .base_delay
+            .checked_mul(1 << exponent)
+            .map_or(self.max_delay, |delay| delay.min(self.max_delay));
+
+        if !self.jitter {
+            return delay;
+        }
+
+        // Keep at least half of the delay so that retries are still spaced
+        // out, and randomize the other half.
+        let half = u64::try_from(delay.as_millis() / 2).unwrap_or(u64::MAX);
+        let jitter = rand::thread_rng().gen_range(0..=half);
+
+        delay - Duration::from_millis(half) + Duration::from_millis(jitter)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RetryPolicy, RetryReason};
+    use crate::request::Method;
+    use hyper::{
+        header::{HeaderMap, HeaderValue},
+        StatusCode,
+    };
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, time::Duration};
+
+    assert_impl_all!(RetryPolicy: Clone, Debug, Default, Eq, PartialEq, Send, Sync);
+
+    #[test]
+    fn test_reason_from_response() {
+        let mut headers = HeaderMap::new();
+        headers.insert("retry-after", HeaderValue::from_static("2"));
+        headers.insert("x-ratelimit-reset-after", HeaderValue::from_static("2.5"));
+
+        assert_eq!(
+            Some(RetryReason::Ratelimited(Some(Duration::from_millis(2500)))),
+            RetryReason::from_response(StatusCode::TOO_MANY_REQUESTS, &headers),
+        );
+        assert_eq!(
+            Some(RetryReason::ServerError(Some(Duration::from_secs(2)))),
+            RetryReason::from_response(StatusCode::BAD_GATEWAY, &headers),
+        );
+        assert_eq!(
+            Some(RetryReason::ServerError(None)),
+            RetryReason::from_response(StatusCode::SERVICE_UNAVAILABLE, &HeaderMap::new()),
+        );
+        assert!(RetryReason::from_response(StatusCode::NOT_FOUND, &headers).is_none());
+    }
+
+    #[test]
+    fn test_max_attempts() {
+        let policy = RetryPolicy::new().max_attempts(2);
+
+        assert!(policy
+            .delay(1, Method::Get, RetryReason::Transport)
+            .is_some());
+        assert!(policy
+            .delay(2, Method::Get, RetryReason::Transport)
+            .is_none());
+        assert!(RetryPolicy::none()
+            .delay(1, Method::Get, RetryReason::Transport)
+            .is_none());
+    }
+
+    #[test]
+    fn test_idempotent_methods() {
+        let policy = RetryPolicy::new();
+
+        assert!(policy
+            .delay(1, Method::Post, RetryReason::ServerError(None))
+            .is_none());
+        assert!(policy
+            .delay(1, Method::Post, RetryReason::Transport)
+            .is_none());
+        assert!(policy
+            .delay(1, Method::Post, RetryReason::Ratelimited(None))
+            .is_some());
+
+        let policy = policy.idempotent_methods(&[Method::Post]);
+        assert!(policy
+            .delay(1, Method::Post, RetryReason::ServerError(None))
+            .is_some());
+        assert!(policy
+            .delay(1, Method::Get, RetryReason::ServerError(None))
+            .is_none());
+    }
+
+    #[test]
+    fn test_backoff() {
+        let policy = RetryPolicy::new()
+            .max_attempts(10)
+            .backoff(Duration::from_millis(100), Duration::from_millis(350))
+            .jitter(false);
+
+        assert_eq!(
+            Some(Duration::from_millis(100)),
+            policy.delay(1, Method::Get, RetryReason::Transport)
+        );
+        assert_eq!(
+            Some(Duration::from_millis(200)),
+            policy.delay(2, Method::Get, RetryReason::Transport)
+        );
+        assert_eq!(
+            Some(Duration::from_millis(350)),
+            policy.delay(3, Method::Get, RetryReason::Transport)
+        );
+
+        let jittered = policy.jitter(true);
+
+        for _ in 0..100 {
+            let delay = jittered
+                .delay(2, Method::Get, RetryReason::Transport)
+                .expect("retried");
+            assert!(delay >= Duration::from_millis(100) && delay <= Duration::from_millis(200));
+        }
+    }
+
+    #[test]
+    fn test_requested_delay() {
+        let policy = RetryPolicy::new().backoff(Duration::from_millis(100), Duration::from_secs(5));
+
+        assert_eq!(
+            Some(Duration::from_secs(3)),
+            policy.delay(
+                1,
+                Method::Post,
+                RetryReason::Ratelimited(Some(Duration::from_secs(3)))
+            ),
+        );
+        assert!(policy
+            .delay(
+                1,
+                Method::Post,
+                RetryReason::Ratelimited(Some(Duration::from_secs(60)))
+            )
+            .is_none());
+        assert!(policy
+            .retry_ratelimited(false)
+            .delay(1, Method::Post, RetryReason::Ratelimited(None))
+            .is_none());
+    }
+}
@@ -42,6 +42,9 @@ impl Error {
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match &self.kind {
+            ErrorType::Authorizing => {
+                f.write_str("failed to retrieve the authorization of the request")
+            }
             ErrorType::BuildingRequest => f.write_str("failed to build the request"),
             ErrorType::ChunkingResponse => f.write_str("Chunking the response failed"),
             ErrorType::CreatingHeader { name, .. } => {
@@ -94,6 +97,11 @@ impl StdError for Error {
 #[non_exhaustive]
 /// Type of [`Error`] that occurred.
 pub enum ErrorType {
+    /// Client's [`TokenProvider`] failed to provide the authorization of the
+    /// request.
+    ///
+    /// [`TokenProvider`]: crate::oauth::TokenProvider
+    Authorizing,
     BuildingRequest,
     ChunkingResponse,
     CreatingHeader {
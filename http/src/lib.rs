@@ -111,10 +111,13 @@
 pub mod api_error;
 pub mod client;
 pub mod error;
+pub mod middleware;
+pub mod oauth;
 pub mod ratelimiting;
 pub mod request;
 pub mod response;
 pub mod routing;
+pub mod transport;
 
 mod json;
 
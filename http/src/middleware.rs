@@ -0,0 +1,412 @@
+//! Middleware to inspect and modify requests and responses of a [`Client`].
+//!
+//! Middleware is registered via [`ClientBuilder::middleware`] and is called
+//! in order of registration for every attempt of a request, before a ticket
+//! is requested from the client's ratelimiter. The modified request is sent
+//! once it has been granted a ticket.
+//! Responses are passed to middleware in reverse order, before the client
+//! handles them. This means that middleware sees the response before
+//! ratelimit headers are processed, an Unauthorized status marks the token as
+//! invalid, or the request is retried.
+//!
+//! Middleware may short-circuit a request by returning a response from
+//! [`Middleware::request`]. The request is then not sent, does not consume a
+//! ratelimit ticket, and only earlier middleware is called with the response.
+//!
+//! # Examples
+//!
+//! Add a header containing a unique ID to every request and log how long it
+//! took to receive a response:
+//!
+//! ```rust,no_run
+//! use hyper::header::HeaderValue;
+//! use std::sync::atomic::{AtomicU64, Ordering};
+//! use twilight_http::{
+//!     middleware::{Middleware, MiddlewareRequest, MiddlewareResponse},
+//!     Client,
+//! };
+//!
+//! #[derive(Debug, Default)]
+//! struct RequestIds(AtomicU64);
+//!
+//! impl Middleware for RequestIds {
+//!     fn request(&self, request: &mut MiddlewareRequest) -> Option<MiddlewareResponse> {
+//!         let id = self.0.fetch_add(1, Ordering::Relaxed);
+//!         request
+//!             .headers_mut()
+//!             .insert("x-request-id", HeaderValue::from(id));
+//!
+//!         None
+//!     }
+//!
+//!     fn response(&self, request: &MiddlewareRequest, response: &mut MiddlewareResponse) {
+//!         println!(
+//!             "{:?} took {:?}: {}",
+//!             request.path(),
+//!             response.elapsed(),
+//!             response.status(),
+//!         );
+//!     }
+//! }
+//!
+//! let client = Client::builder()
+//!     .middleware(Box::new(RequestIds::default()))
+//!     .token("my token".to_owned())
+//!     .build();
+//! ```
+//!
+//! [`Client`]: crate::Client
+//! [`ClientBuilder::middleware`]: crate::client::ClientBuilder::middleware
+
+use crate::{client::PreparedRequest, request::Method, response::StatusCode, routing::Path};
+use hyper::{
+    body::Bytes,
+    header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_LENGTH},
+};
+use std::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    time::Duration,
+};
+
+/// Hook into the requests sent and responses received by a [`Client`].
+///
+/// Refer to the [module-level documentation] for when middleware is called.
+///
+/// [`Client`]: crate::Client
+/// [module-level documentation]: self
+pub trait Middleware: Debug + Send + Sync {
+    /// Inspect or modify an attempt of a request before it is sent.
+    ///
+    /// Returning a response short-circuits the request: it is not sent and
+    /// the response is used instead.
+    ///
+    /// By default the request is passed along unmodified.
+    fn request(&self, request: &mut MiddlewareRequest) -> Option<MiddlewareResponse> {
+        let _ = request;
+
+        None
+    }
+
+    /// Inspect or modify the response to an attempt of a request.
+    ///
+    /// By default the response is passed along unmodified.
+    fn response(&self, request: &MiddlewareRequest, response: &mut MiddlewareResponse) {
+        let _ = (request, response);
+    }
+}
+
+/// Attempt of a request about to be sent, passed to [`Middleware`].
+///
+/// The `Debug` implementation redacts the value of the `Authorization` header.
+#[derive(Clone)]
+pub struct MiddlewareRequest {
+    attempt: u16,
+    pub(crate) prepared: PreparedRequest,
+}
+
+impl MiddlewareRequest {
+    pub(crate) const fn new(attempt: u16, prepared: PreparedRequest) -> Self {
+        Self { attempt, prepared }
+    }
+
+    /// Number of the attempt, starting at 1 and increasing each time the
+    /// request is retried.
+    pub const fn attempt(&self) -> u16 {
+        self.attempt
+    }
+
+    /// Body of the request.
+    ///
+    /// Empty if the body is a multipart form containing [`FileUpload`]s, which
+    /// is streamed when the request is sent.
+    ///
+    /// [`FileUpload`]: crate::request::FileUpload
+    pub fn body(&self) -> &[u8] {
+        &self.prepared.body
+    }
+
+    /// Headers of the request.
+    pub const fn headers(&self) -> &HeaderMap {
+        &self.prepared.headers
+    }
+
+    /// Mutable reference to the headers of the request.
+    pub fn headers_mut(&mut self) -> &mut HeaderMap {
+        &mut self.prepared.headers
+    }
+
+    /// Method of the request.
+    pub const fn method(&self) -> Method {
+        self.prepared.method
+    }
+
+    /// Ratelimit path of the request.
+    ///
+    /// This is the path the request was queued by in the client's
+    /// ratelimiter, which makes it suitable for grouping requests by route.
+    pub const fn path(&self) -> &Path {
+        &self.prepared.ratelimit_path
+    }
+
+    /// Replace the body of the request.
+    ///
+    /// The `Content-Length` header is updated if present.
+    pub fn set_body(&mut self, body: Vec<u8>) {
+        if self.prepared.headers.contains_key(CONTENT_LENGTH) {
+            self.prepared
+                .headers
+                .insert(CONTENT_LENGTH, HeaderValue::from(body.len()));
+        }
+
+        self.prepared.body = Bytes::from(body);
+        self.prepared.stream = None;
+    }
+
+    /// Full URL of the request.
+    pub fn url(&self) -> &str {
+        &self.prepared.url
+    }
+}
+
+impl Debug for MiddlewareRequest {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let mut headers = self.prepared.headers.clone();
+
+        if headers.contains_key(AUTHORIZATION) {
+            headers.insert(AUTHORIZATION, HeaderValue::from_static("<redacted>"));
+        }
+
+        f.debug_struct("MiddlewareRequest")
+            .field("attempt", &self.attempt)
+            .field("body", &self.prepared.body)
+            .field("headers", &headers)
+            .field("method", &self.prepared.method)
+            .field("path", &self.prepared.ratelimit_path)
+            .field("url", &self.prepared.url)
+            .finish()
+    }
+}
+
+/// Response to an attempt of a request, passed to [`Middleware`].
+///
+/// The body has been read in its entirety and, if it was compressed,
+/// decompressed.
+#[derive(Clone, Debug)]
+pub struct MiddlewareResponse {
+    body: Vec<u8>,
+    elapsed: Duration,
+    headers: HeaderMap,
+    status: u16,
+}
+
+impl MiddlewareResponse {
+    /// Create a response with a status code and body.
+    ///
+    /// This can be returned from [`Middleware::request`] to short-circuit a
+    /// request. The status code must be between 100 and 999, otherwise the
+    /// request fails with an [`ErrorType::BuildingRequest`] error type.
+    ///
+    /// [`ErrorType::BuildingRequest`]: crate::error::ErrorType::BuildingRequest
+    pub fn new(status: u16, body: Vec<u8>) -> Self {
+        Self {
+            body,
+            elapsed: Duration::default(),
+            headers: HeaderMap::new(),
+            status,
+        }
+    }
+
+    pub(crate) const fn received(
+        status: u16,
+        headers: HeaderMap,
+        body: Vec<u8>,
+        elapsed: Duration,
+    ) -> Self {
+        Self {
+            body,
+            elapsed,
+            headers,
+            status,
+        }
+    }
+
+    /// Body of the response.
+    pub fn body(&self) -> &[u8] {
+        &self.body
+    }
+
+    /// Mutable reference to the body of the response.
+    pub fn body_mut(&mut self) -> &mut Vec<u8> {
+        &mut self.body
+    }
+
+    /// Time between sending the request and receiving the response headers.
+    ///
+    /// This is zero for short-circuited requests.
+    pub const fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Headers of the response.
+    pub const fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// Mutable reference to the headers of the response.
+    pub fn headers_mut(&mut self) -> &mut HeaderMap {
+        &mut self.headers
+    }
+
+    /// Replace the status code of the response.
+    pub fn set_status(&mut self, status: u16) {
+        self.status = status;
+    }
+
+    /// Status code of the response.
+    pub const fn status(&self) -> StatusCode {
+        StatusCode::new(self.status)
+    }
+
+    /// Convert the response into one handled by the client.
+    pub(crate) fn into_hyper(self) -> Result<hyper::Response<hyper::Body>, hyper::http::Error> {
+        let mut builder = hyper::Response::builder().status(self.status);
+
+        if let Some(headers) = builder.headers_mut() {
+            headers.extend(self.headers);
+            headers.insert(CONTENT_LENGTH, HeaderValue::from(self.body.len()));
+        }
+
+        builder.body(hyper::Body::from(self.body))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Middleware, MiddlewareRequest, MiddlewareResponse};
+    use crate::{
+        client::PreparedRequest, error::ErrorType, ratelimiting::Priority, request::Method,
+        routing::Path, Client,
+    };
+    use hyper::{
+        body::Bytes,
+        header::{HeaderMap, HeaderValue, AUTHORIZATION},
+    };
+    use static_assertions::{assert_impl_all, assert_obj_safe};
+    use std::{
+        error::Error,
+        fmt::Debug,
+        sync::{Arc, Mutex},
+    };
+    use twilight_model::id::{ChannelId, MessageId};
+
+    assert_obj_safe!(Middleware);
+    assert_impl_all!(MiddlewareRequest: Clone, Debug, Send, Sync);
+    assert_impl_all!(MiddlewareResponse: Clone, Debug, Send, Sync);
+
+    /// Responds to every request with a fixed response.
+    #[derive(Debug)]
+    struct Respond(u16, &'static str);
+
+    impl Middleware for Respond {
+        fn request(&self, _: &mut MiddlewareRequest) -> Option<MiddlewareResponse> {
+            Some(MiddlewareResponse::new(self.0, self.1.as_bytes().to_vec()))
+        }
+    }
+
+    /// Records the order in which it was called.
+    #[derive(Debug)]
+    struct Record(&'static str, Arc<Mutex<Vec<String>>>);
+
+    impl Middleware for Record {
+        fn request(&self, request: &mut MiddlewareRequest) -> Option<MiddlewareResponse> {
+            request
+                .headers_mut()
+                .insert("x-layer", HeaderValue::from_static(self.0));
+            self.1.lock().unwrap().push(format!("request {}", self.0));
+
+            None
+        }
+
+        fn response(&self, request: &MiddlewareRequest, response: &mut MiddlewareResponse) {
+            self.1.lock().unwrap().push(format!(
+                "response {} {} {:?}",
+                self.0,
+                response.status(),
+                request.headers().get("x-layer"),
+            ));
+        }
+    }
+
+    #[test]
+    fn test_request_debug_redacts_token() {
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTHORIZATION, HeaderValue::from_static("Bot secret"));
+
+        let request = MiddlewareRequest::new(
+            1,
+            PreparedRequest {
+                body: Bytes::new(),
+                headers,
+                method: Method::Get,
+                priority: Priority::Normal,
+                ratelimit_path: Path::UsersId,
+                stream: None,
+                url: "https://discord.com/api/v9/users/@me".to_owned(),
+            },
+        );
+
+        let debug = format!("{:?}", request);
+        assert!(!debug.contains("secret"));
+        assert!(debug.contains("<redacted>"));
+    }
+
+    #[tokio::test]
+    async fn test_short_circuit() -> Result<(), Box<dyn Error>> {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let client = Client::builder()
+            .middleware(Box::new(Record("first", Arc::clone(&calls))))
+            .middleware(Box::new(Record("second", Arc::clone(&calls))))
+            .middleware(Box::new(Respond(204, "")))
+            .token("token".to_owned())
+            .build();
+
+        let response = client
+            .delete_message(ChannelId(1), MessageId(2))
+            .exec()
+            .await?;
+        assert_eq!(204, response.status().raw());
+        assert_eq!(
+            [
+                "request first",
+                "request second",
+                "response second 204 Some(\"second\")",
+                "response first 204 Some(\"second\")",
+            ],
+            calls.lock().unwrap().as_slice(),
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_short_circuit_error() {
+        let client = Client::builder()
+            .middleware(Box::new(Respond(
+                404,
+                r#"{"code":10008,"message":"Unknown Message"}"#,
+            )))
+            .token("token".to_owned())
+            .build();
+
+        let error = client
+            .delete_message(ChannelId(1), MessageId(2))
+            .exec()
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            error.kind(),
+            ErrorType::Response { status, .. } if status.raw() == 404
+        ));
+    }
+}
@@ -0,0 +1,228 @@
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use std::fmt::Write;
+use twilight_model::{
+    guild::Permissions,
+    id::{ApplicationId, GuildId},
+    oauth::Scope,
+};
+
+/// Whether the user is prompted to authorize an application that they have
+/// already authorized.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Prompt {
+    /// Always prompt the user to authorize the application.
+    Consent,
+    /// Skip the prompt if the user has already authorized the application
+    /// with the requested scopes.
+    None,
+}
+
+impl Prompt {
+    /// Name of the prompt as used by the API.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Consent => "consent",
+            Self::None => "none",
+        }
+    }
+}
+
+/// Build the URL to redirect a user to in order to authorize an application.
+///
+/// Once the user has authorized the application they are redirected to the
+/// redirect URI with a `code` query parameter, which may be exchanged for an
+/// access token via [`OAuthClient::exchange_code`].
+///
+/// Created via [`OAuthClient::authorization_url`].
+///
+/// # Examples
+///
+/// ```
+/// use twilight_http::{oauth::OAuthClient, Client};
+/// use twilight_model::{id::ApplicationId, oauth::Scope};
+///
+/// let oauth = OAuthClient::new(Client::builder().build(), ApplicationId(1), "secret".to_owned());
+///
+/// let url = oauth
+///     .authorization_url(&[Scope::Identify, Scope::Guilds])
+///     .redirect_uri("https://example.com/callback")
+///     .state("15773059ghq9183habn")
+///     .build();
+///
+/// assert_eq!(
+///     "https://discord.com/api/oauth2/authorize?response_type=code&client_id=1\
+///     &scope=identify%20guilds&redirect_uri=https%3A%2F%2Fexample%2Ecom%2Fcallback\
+///     &state=15773059ghq9183habn",
+///     url,
+/// );
+/// ```
+///
+/// [`OAuthClient::authorization_url`]: super::OAuthClient::authorization_url
+/// [`OAuthClient::exchange_code`]: super::OAuthClient::exchange_code
+#[derive(Clone, Debug)]
+#[must_use = "authorization URLs must be built"]
+pub struct AuthorizationUrl<'a> {
+    client_id: ApplicationId,
+    disable_guild_select: Option<bool>,
+    guild_id: Option<GuildId>,
+    permissions: Option<Permissions>,
+    prompt: Option<Prompt>,
+    redirect_uri: Option<&'a str>,
+    scopes: &'a [Scope],
+    state: Option<&'a str>,
+}
+
+impl<'a> AuthorizationUrl<'a> {
+    pub(crate) const fn new(client_id: ApplicationId, scopes: &'a [Scope]) -> Self {
+        Self {
+            client_id,
+            disable_guild_select: None,
+            guild_id: None,
+            permissions: None,
+            prompt: None,
+            redirect_uri: None,
+            scopes,
+            state: None,
+        }
+    }
+
+    /// Whether to prevent the user from changing the guild pre-selected via
+    /// [`guild_id`].
+    ///
+    /// [`guild_id`]: Self::guild_id
+    pub const fn disable_guild_select(mut self, disable_guild_select: bool) -> Self {
+        self.disable_guild_select = Some(disable_guild_select);
+
+        self
+    }
+
+    /// Pre-select the guild to add the application's bot or webhook to.
+    pub const fn guild_id(mut self, guild_id: GuildId) -> Self {
+        self.guild_id = Some(guild_id);
+
+        self
+    }
+
+    /// Set the permissions to request for the application's bot.
+    ///
+    /// Only used with the [`Scope::Bot`] scope.
+    pub const fn permissions(mut self, permissions: Permissions) -> Self {
+        self.permissions = Some(permissions);
+
+        self
+    }
+
+    /// Set whether the user is prompted again if they have already authorized
+    /// the application.
+    pub const fn prompt(mut self, prompt: Prompt) -> Self {
+        self.prompt = Some(prompt);
+
+        self
+    }
+
+    /// Set the URI to redirect the user to once they authorized the
+    /// application.
+    ///
+    /// Must be one of the redirect URIs registered for the application.
+    pub const fn redirect_uri(mut self, redirect_uri: &'a str) -> Self {
+        self.redirect_uri = Some(redirect_uri);
+
+        self
+    }
+
+    /// Set a unique value that is passed back in the redirect, used to
+    /// protect against cross-site request forgery.
+    pub const fn state(mut self, state: &'a str) -> Self {
+        self.state = Some(state);
+
+        self
+    }
+
+    /// Build the URL.
+    pub fn build(&self) -> String {
+        let mut url = String::from("https://discord.com/api/oauth2/authorize?response_type=code");
+
+        // Writing to a string can't fail.
+        let _ = write!(url, "&client_id={}", self.client_id);
+
+        let scopes = self
+            .scopes
+            .iter()
+            .map(|scope| scope.as_str())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let _ = write!(
+            url,
+            "&scope={}",
+            utf8_percent_encode(&scopes, NON_ALPHANUMERIC)
+        );
+
+        if let Some(redirect_uri) = self.redirect_uri {
+            let _ = write!(
+                url,
+                "&redirect_uri={}",
+                utf8_percent_encode(redirect_uri, NON_ALPHANUMERIC)
+            );
+        }
+
+        if let Some(state) = self.state {
+            let _ = write!(
+                url,
+                "&state={}",
+                utf8_percent_encode(state, NON_ALPHANUMERIC)
+            );
+        }
+
+        if let Some(prompt) = self.prompt {
+            let _ = write!(url, "&prompt={}", prompt.as_str());
+        }
+
+        if let Some(permissions) = self.permissions {
+            let _ = write!(url, "&permissions={}", permissions.bits());
+        }
+
+        if let Some(guild_id) = self.guild_id {
+            let _ = write!(url, "&guild_id={}", guild_id);
+        }
+
+        if let Some(disable_guild_select) = self.disable_guild_select {
+            let _ = write!(url, "&disable_guild_select={}", disable_guild_select);
+        }
+
+        url
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AuthorizationUrl, Prompt};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::{
+        guild::Permissions,
+        id::{ApplicationId, GuildId},
+        oauth::Scope,
+    };
+
+    assert_impl_all!(AuthorizationUrl<'_>: Clone, Debug, Send, Sync);
+    assert_impl_all!(Prompt: Clone, Copy, Debug, Eq, Send, Sync);
+
+    #[test]
+    fn test_bot_authorization_url() {
+        let url =
+            AuthorizationUrl::new(ApplicationId(1), &[Scope::Bot, Scope::ApplicationsCommands])
+                .permissions(Permissions::SEND_MESSAGES | Permissions::EMBED_LINKS)
+                .guild_id(GuildId(2))
+                .disable_guild_select(true)
+                .prompt(Prompt::None)
+                .build();
+
+        assert_eq!(
+            "https://discord.com/api/oauth2/authorize?response_type=code&client_id=1\
+            &scope=bot%20applications%2Ecommands&prompt=none&permissions=18432&guild_id=2\
+            &disable_guild_select=true",
+            url
+        );
+    }
+}
@@ -0,0 +1,42 @@
+use super::OAuthClient;
+use crate::{response::ResponseFuture, routing::Route};
+use twilight_model::oauth::{AccessTokenResponse, Scope};
+
+/// Request an access token for the user owning the application.
+///
+/// Useful for testing `OAuth2` flows without a user having to authorize the
+/// application. Tokens granted this way have no refresh token. If the
+/// application is owned by a team, only the [`Scope::Identify`] and
+/// [`Scope::ApplicationsCommandsUpdate`] scopes may be requested.
+#[must_use = "requests must be configured and executed"]
+pub struct ClientCredentials<'a> {
+    oauth: &'a OAuthClient,
+    scopes: &'a [Scope],
+}
+
+impl<'a> ClientCredentials<'a> {
+    pub(crate) const fn new(oauth: &'a OAuthClient, scopes: &'a [Scope]) -> Self {
+        Self { oauth, scopes }
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<AccessTokenResponse> {
+        let scopes = self
+            .scopes
+            .iter()
+            .map(|scope| scope.as_str())
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let request = self
+            .oauth
+            .form()
+            .field("grant_type", "client_credentials")
+            .field("scope", &scopes)
+            .request(&Route::CreateAccessToken);
+
+        self.oauth.http().request(request)
+    }
+}
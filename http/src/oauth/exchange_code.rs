@@ -0,0 +1,41 @@
+use super::OAuthClient;
+use crate::{response::ResponseFuture, routing::Route};
+use twilight_model::oauth::AccessTokenResponse;
+
+/// Exchange an authorization code for an access token.
+///
+/// The code is received as a query parameter of the redirect URI once a user
+/// authorized the application via an [`AuthorizationUrl`].
+///
+/// [`AuthorizationUrl`]: super::AuthorizationUrl
+#[must_use = "requests must be configured and executed"]
+pub struct ExchangeCode<'a> {
+    code: &'a str,
+    oauth: &'a OAuthClient,
+    redirect_uri: &'a str,
+}
+
+impl<'a> ExchangeCode<'a> {
+    pub(crate) const fn new(oauth: &'a OAuthClient, code: &'a str, redirect_uri: &'a str) -> Self {
+        Self {
+            code,
+            oauth,
+            redirect_uri,
+        }
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<AccessTokenResponse> {
+        let request = self
+            .oauth
+            .form()
+            .field("grant_type", "authorization_code")
+            .field("code", self.code)
+            .field("redirect_uri", self.redirect_uri)
+            .request(&Route::CreateAccessToken);
+
+        self.oauth.http().request(request)
+    }
+}
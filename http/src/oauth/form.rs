@@ -0,0 +1,80 @@
+use crate::{
+    request::{Request, RequestBuilder},
+    routing::Route,
+};
+use hyper::header::{HeaderValue, CONTENT_TYPE};
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use std::iter;
+
+/// Characters percent-encoded in form names and values.
+///
+/// This is everything but the `application/x-www-form-urlencoded` unreserved
+/// set of alphanumerics, `*`, `-`, `.`, and `_`.
+const FORM: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'*')
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_');
+
+/// Body of a request encoded as `application/x-www-form-urlencoded`.
+pub(super) struct UrlEncodedForm {
+    buffer: String,
+}
+
+impl UrlEncodedForm {
+    pub const fn new() -> Self {
+        Self {
+            buffer: String::new(),
+        }
+    }
+
+    /// Append a field to the form.
+    pub fn field(mut self, name: &str, value: &str) -> Self {
+        if !self.buffer.is_empty() {
+            self.buffer.push('&');
+        }
+
+        self.buffer.extend(utf8_percent_encode(name, FORM));
+        self.buffer.push('=');
+        self.buffer.extend(utf8_percent_encode(value, FORM));
+
+        self
+    }
+
+    /// Build a request to a route with the form as its body.
+    ///
+    /// `OAuth2` token requests are authenticated by the client ID and secret in
+    /// the form, so the client's authorization token is not used.
+    pub fn request(self, route: &Route<'_>) -> Request {
+        let content_type = HeaderValue::from_static("application/x-www-form-urlencoded");
+
+        RequestBuilder::new(route)
+            .body(self.buffer.into_bytes())
+            .headers(iter::once((CONTENT_TYPE, content_type)))
+            .use_authorization_token(false)
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UrlEncodedForm;
+    use crate::routing::Route;
+
+    #[test]
+    fn test_encoding() {
+        let request = UrlEncodedForm::new()
+            .field("grant_type", "authorization_code")
+            .field("redirect_uri", "https://example.com/callback?a=b")
+            .request(&Route::CreateAccessToken);
+
+        assert_eq!(
+            Some(
+                b"grant_type=authorization_code&redirect_uri=https%3A%2F%2Fexample.com%2Fcallback%3Fa%3Db"
+                    .as_ref()
+            ),
+            request.body.as_deref()
+        );
+        assert!(!request.use_authorization_token);
+    }
+}
@@ -0,0 +1,238 @@
+//! `OAuth2` flows to authorize users and obtain access tokens.
+//!
+//! An [`OAuthClient`] builds [authorization URLs] to send users to, exchanges
+//! the authorization codes they are redirected back with for access tokens,
+//! refreshes and revokes tokens, and performs client credentials grants.
+//! Token requests are sent through a regular [`Client`] and so pass through
+//! its ratelimiter and middleware.
+//!
+//! Granted tokens can be used with a [`Client`] by passing them to
+//! [`ClientBuilder::token`] prefixed with `"Bearer "`, or by wrapping them in
+//! a [`RefreshingToken`] set as the client's [`TokenProvider`] to have them
+//! refreshed before they expire.
+//!
+//! # Examples
+//!
+//! Exchange an authorization code for an access token and retrieve the user
+//! who authorized the application:
+//!
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use twilight_http::{oauth::OAuthClient, Client};
+//! use twilight_model::id::ApplicationId;
+//!
+//! let oauth = OAuthClient::new(Client::builder().build(), ApplicationId(1), "secret".to_owned());
+//!
+//! let token = oauth
+//!     .exchange_code("code", "https://example.com/callback")
+//!     .exec()
+//!     .await?
+//!     .model()
+//!     .await?;
+//!
+//! let client = Client::new(format!("Bearer {}", token.access_token));
+//! let authorization = client.current_authorization().exec().await?.model().await?;
+//!
+//! if let Some(user) = authorization.user {
+//!     println!("authorized by {}", user.name);
+//! }
+//! # Ok(()) }
+//! ```
+//!
+//! [`Client`]: crate::Client
+//! [`ClientBuilder::token`]: crate::client::ClientBuilder::token
+//! [authorization URLs]: AuthorizationUrl
+
+mod authorization_url;
+mod client_credentials;
+mod exchange_code;
+mod form;
+mod provider;
+mod refresh_token;
+mod revoke_token;
+mod token;
+
+pub use self::{
+    authorization_url::{AuthorizationUrl, Prompt},
+    client_credentials::ClientCredentials,
+    exchange_code::ExchangeCode,
+    provider::{
+        AuthorizationFuture, RefreshError, RefreshErrorType, RefreshingToken, TokenProvider,
+    },
+    refresh_token::RefreshToken,
+    revoke_token::{RevokeToken, TokenTypeHint},
+    token::AccessToken,
+};
+
+use self::form::UrlEncodedForm;
+use crate::client::Client;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use twilight_model::{id::ApplicationId, oauth::Scope};
+
+/// Client to perform the `OAuth2` flows of an application.
+///
+/// Refer to the [module-level documentation] for more information.
+///
+/// [module-level documentation]: self
+#[derive(Clone)]
+pub struct OAuthClient {
+    client_id: ApplicationId,
+    client_secret: Box<str>,
+    http: Client,
+}
+
+impl OAuthClient {
+    /// Create a client for an application, sending requests via an HTTP
+    /// client.
+    ///
+    /// The HTTP client doesn't need a token, as token requests are
+    /// authenticated by the application's client ID and secret.
+    pub fn new(http: Client, client_id: ApplicationId, client_secret: String) -> Self {
+        Self {
+            client_id,
+            client_secret: client_secret.into_boxed_str(),
+            http,
+        }
+    }
+
+    /// ID of the application.
+    pub const fn client_id(&self) -> ApplicationId {
+        self.client_id
+    }
+
+    /// HTTP client used to send requests.
+    pub const fn http(&self) -> &Client {
+        &self.http
+    }
+
+    /// Build the URL to redirect a user to in order to authorize the
+    /// application with the given scopes.
+    pub const fn authorization_url<'a>(&self, scopes: &'a [Scope]) -> AuthorizationUrl<'a> {
+        AuthorizationUrl::new(self.client_id, scopes)
+    }
+
+    /// Request an access token for the user owning the application.
+    pub const fn client_credentials<'a>(&'a self, scopes: &'a [Scope]) -> ClientCredentials<'a> {
+        ClientCredentials::new(self, scopes)
+    }
+
+    /// Exchange an authorization code for an access token.
+    ///
+    /// The redirect URI must be the same as the one in the authorization URL
+    /// the code was obtained with.
+    pub const fn exchange_code<'a>(
+        &'a self,
+        code: &'a str,
+        redirect_uri: &'a str,
+    ) -> ExchangeCode<'a> {
+        ExchangeCode::new(self, code, redirect_uri)
+    }
+
+    /// Exchange a refresh token for a new access token.
+    pub const fn refresh_token<'a>(&'a self, refresh_token: &'a str) -> RefreshToken<'a> {
+        RefreshToken::new(self, refresh_token)
+    }
+
+    /// Revoke an access token or refresh token.
+    pub const fn revoke_token<'a>(&'a self, token: &'a str) -> RevokeToken<'a> {
+        RevokeToken::new(self, token)
+    }
+
+    /// Form authenticating a token request with the application's client ID
+    /// and secret.
+    fn form(&self) -> UrlEncodedForm {
+        UrlEncodedForm::new()
+            .field("client_id", &self.client_id.to_string())
+            .field("client_secret", &self.client_secret)
+    }
+}
+
+impl Debug for OAuthClient {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("OAuthClient")
+            .field("client_id", &self.client_id)
+            .field("client_secret", &"<redacted>")
+            .field("http", &self.http)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{OAuthClient, TokenTypeHint};
+    use crate::{
+        routing::Route,
+        transport::{MockResponse, MockTransport},
+        Client,
+    };
+    use hyper::header::{AUTHORIZATION, CONTENT_TYPE};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+    use twilight_model::{id::ApplicationId, oauth::Scope};
+
+    assert_impl_all!(OAuthClient: Clone, Debug, Send, Sync);
+
+    fn client(transport: &MockTransport) -> OAuthClient {
+        let http = Client::builder()
+            .token("bot token".to_owned())
+            .transport(Box::new(transport.clone()))
+            .build();
+
+        OAuthClient::new(http, ApplicationId(1), "secret".to_owned())
+    }
+
+    #[tokio::test]
+    async fn test_client_credentials() -> Result<(), Box<dyn Error>> {
+        let transport = MockTransport::new();
+        transport.on_route(
+            &Route::CreateAccessToken,
+            MockResponse::new(200).body(
+                br#"{"access_token":"access","expires_in":604800,"scope":"identify connections","token_type":"Bearer"}"#.to_vec(),
+            ),
+        );
+
+        let token = client(&transport)
+            .client_credentials(&[Scope::Identify, Scope::Connections])
+            .exec()
+            .await?
+            .model()
+            .await?;
+        assert_eq!(vec![Scope::Identify, Scope::Connections], token.scope);
+        assert!(token.refresh_token.is_none());
+
+        let requests = transport.requests();
+        assert_eq!(
+            b"client_id=1&client_secret=secret&grant_type=client_credentials&scope=identify%20connections"
+                .as_ref(),
+            requests[0].body()
+        );
+        assert_eq!(
+            "application/x-www-form-urlencoded",
+            requests[0].headers()[CONTENT_TYPE]
+        );
+        assert!(!requests[0].headers().contains_key(AUTHORIZATION));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_revoke_token() -> Result<(), Box<dyn Error>> {
+        let transport = MockTransport::new();
+        transport.on_route(&Route::RevokeAccessToken, MockResponse::new(200));
+
+        client(&transport)
+            .revoke_token("refresh")
+            .token_type_hint(TokenTypeHint::RefreshToken)
+            .exec()
+            .await?;
+
+        assert_eq!(
+            b"client_id=1&client_secret=secret&token=refresh&token_type_hint=refresh_token"
+                .as_ref(),
+            transport.requests()[0].body()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,339 @@
+use super::{AccessToken, OAuthClient};
+use crate::{error::Error as HttpError, response::DeserializeBodyError};
+use std::{
+    error::Error,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    time::Duration,
+};
+use tokio::sync::Mutex;
+
+/// Future resolving to the value of the `Authorization` header of a request.
+pub type AuthorizationFuture =
+    Pin<Box<dyn Future<Output = Result<String, Box<dyn Error + Send + Sync>>> + Send + 'static>>;
+
+/// Provide the authorization of requests sent by a [`Client`].
+///
+/// Providers are set via [`ClientBuilder::token_provider`] and are asked for
+/// the authorization of every request that uses the client's authorization,
+/// which allows them to refresh tokens before they expire.
+///
+/// [`Client`]: crate::Client
+/// [`ClientBuilder::token_provider`]: crate::client::ClientBuilder::token_provider
+pub trait TokenProvider: Debug + Send + Sync {
+    /// Retrieve the value of the `Authorization` header, such as
+    /// `"Bearer 6qrZcUqja7812RVdnEKjpzOL4CvHBFG"`.
+    fn authorization(self: Arc<Self>) -> AuthorizationFuture;
+}
+
+/// The error returned when an access token could not be refreshed.
+#[derive(Debug)]
+pub struct RefreshError {
+    kind: RefreshErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl RefreshError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &RefreshErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (RefreshErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for RefreshError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            RefreshErrorType::Deserializing => {
+                f.write_str("failed to deserialize the access token")
+            }
+            RefreshErrorType::MissingRefreshToken => {
+                f.write_str("the access token has no refresh token")
+            }
+            RefreshErrorType::Request => f.write_str("failed to request a new access token"),
+        }
+    }
+}
+
+impl Error for RefreshError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`RefreshError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum RefreshErrorType {
+    /// Deserializing the new access token failed.
+    ///
+    /// The source is a [`DeserializeBodyError`].
+    Deserializing,
+    /// Access token has no refresh token, such as tokens granted via
+    /// client credentials.
+    MissingRefreshToken,
+    /// Requesting a new access token failed.
+    ///
+    /// The source is an [`Error`].
+    ///
+    /// [`Error`]: crate::Error
+    Request,
+}
+
+/// Access token that is refreshed before it expires.
+///
+/// The token is refreshed when it is retrieved and expires within the
+/// [refresh margin]. Concurrent retrievals wait for a single refresh.
+///
+/// # Examples
+///
+/// Use a token that is refreshed as needed for all requests of a client:
+///
+/// ```rust,no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::sync::Arc;
+/// use twilight_http::{oauth::{OAuthClient, RefreshingToken}, Client};
+/// use twilight_model::id::ApplicationId;
+///
+/// let oauth = OAuthClient::new(Client::builder().build(), ApplicationId(1), "secret".to_owned());
+/// let response = oauth
+///     .exchange_code("code", "https://example.com/callback")
+///     .exec()
+///     .await?
+///     .model()
+///     .await?;
+///
+/// let token = Arc::new(RefreshingToken::new(oauth, response.into()));
+/// let client = Client::builder().token_provider(token).build();
+///
+/// let user = client.current_user().exec().await?.model().await?;
+/// # Ok(()) }
+/// ```
+///
+/// [refresh margin]: Self::refresh_margin
+#[derive(Debug)]
+pub struct RefreshingToken {
+    oauth: OAuthClient,
+    refresh_margin: Duration,
+    token: Mutex<AccessToken>,
+}
+
+impl RefreshingToken {
+    /// Create a token refreshed via an OAuth client.
+    ///
+    /// The OAuth client should use a different [`Client`] than the one using
+    /// this token as its [`TokenProvider`].
+    ///
+    /// [`Client`]: crate::Client
+    pub fn new(oauth: OAuthClient, token: AccessToken) -> Self {
+        Self {
+            oauth,
+            refresh_margin: Duration::from_secs(60),
+            token: Mutex::new(token),
+        }
+    }
+
+    /// Set how long before the token expires it is refreshed.
+    ///
+    /// Defaults to 60 seconds.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn refresh_margin(mut self, refresh_margin: Duration) -> Self {
+        self.refresh_margin = refresh_margin;
+
+        self
+    }
+
+    /// Retrieve the current access token, refreshing it first if it expires
+    /// within the refresh margin.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RefreshErrorType::MissingRefreshToken`] error type if the
+    /// token needs to be refreshed but has no refresh token.
+    ///
+    /// Returns a [`RefreshErrorType::Request`] error type if requesting a new
+    /// access token failed.
+    ///
+    /// Returns a [`RefreshErrorType::Deserializing`] error type if the new
+    /// access token could not be deserialized.
+    pub async fn token(&self) -> Result<AccessToken, RefreshError> {
+        let mut token = self.token.lock().await;
+
+        if token.expires_within(self.refresh_margin) {
+            *token = self.refreshed(&token).await?;
+        }
+
+        Ok(token.clone())
+    }
+
+    /// Refresh the access token regardless of when it expires.
+    ///
+    /// # Errors
+    ///
+    /// Refer to [`token`] for the returned errors.
+    ///
+    /// [`token`]: Self::token
+    pub async fn refresh(&self) -> Result<AccessToken, RefreshError> {
+        let mut token = self.token.lock().await;
+        *token = self.refreshed(&token).await?;
+
+        Ok(token.clone())
+    }
+
+    async fn refreshed(&self, token: &AccessToken) -> Result<AccessToken, RefreshError> {
+        let refresh_token = token.refresh_token().ok_or(RefreshError {
+            kind: RefreshErrorType::MissingRefreshToken,
+            source: None,
+        })?;
+
+        let response = self
+            .oauth
+            .refresh_token(refresh_token)
+            .exec()
+            .await
+            .map_err(|source: HttpError| RefreshError {
+                kind: RefreshErrorType::Request,
+                source: Some(Box::new(source)),
+            })?;
+
+        let response = response
+            .model()
+            .await
+            .map_err(|source: DeserializeBodyError| RefreshError {
+                kind: RefreshErrorType::Deserializing,
+                source: Some(Box::new(source)),
+            })?;
+
+        Ok(AccessToken::new(response))
+    }
+}
+
+impl TokenProvider for RefreshingToken {
+    fn authorization(self: Arc<Self>) -> AuthorizationFuture {
+        Box::pin(async move {
+            let token = self.token().await?;
+
+            Ok(token.authorization())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RefreshError, RefreshErrorType, RefreshingToken, TokenProvider};
+    use crate::{
+        oauth::{AccessToken, OAuthClient},
+        routing::Route,
+        transport::{MockResponse, MockTransport},
+        Client,
+    };
+    use hyper::header::AUTHORIZATION;
+    use static_assertions::{assert_impl_all, assert_obj_safe};
+    use std::{error::Error, fmt::Debug, sync::Arc, time::Instant};
+    use twilight_model::{
+        id::ApplicationId,
+        oauth::{AccessTokenResponse, Scope},
+    };
+
+    assert_impl_all!(RefreshErrorType: Debug, Send, Sync);
+    assert_impl_all!(RefreshError: Error, Send, Sync);
+    assert_impl_all!(RefreshingToken: Debug, Send, Sync, TokenProvider);
+    assert_obj_safe!(TokenProvider);
+
+    fn expired_token(refresh_token: Option<&str>) -> AccessToken {
+        let response = AccessTokenResponse {
+            access_token: "expired".to_owned(),
+            expires_in: 0,
+            refresh_token: refresh_token.map(ToOwned::to_owned),
+            scope: vec![Scope::Identify],
+            token_type: "Bearer".to_owned(),
+        };
+
+        AccessToken::received_at(response, Instant::now())
+    }
+
+    #[tokio::test]
+    async fn test_refresh_before_request() -> Result<(), Box<dyn Error>> {
+        let oauth_transport = MockTransport::new();
+        oauth_transport.on_route(
+            &Route::CreateAccessToken,
+            MockResponse::new(200).body(
+                br#"{"access_token":"fresh","expires_in":604800,"refresh_token":"next","scope":"identify","token_type":"Bearer"}"#.to_vec(),
+            ),
+        );
+        let oauth = OAuthClient::new(
+            Client::builder()
+                .transport(Box::new(oauth_transport.clone()))
+                .build(),
+            ApplicationId(1),
+            "secret".to_owned(),
+        );
+        let token = Arc::new(RefreshingToken::new(oauth, expired_token(Some("refresh"))));
+
+        let transport = MockTransport::new();
+        transport.on_route(
+            &Route::GetCurrentUser,
+            MockResponse::new(200).body(
+                br#"{"avatar":null,"discriminator":"0001","id":"1","username":"user"}"#.to_vec(),
+            ),
+        );
+        let client = Client::builder()
+            .token_provider(Arc::clone(&token) as Arc<dyn TokenProvider>)
+            .transport(Box::new(transport.clone()))
+            .build();
+
+        client.current_user().exec().await?;
+        client.current_user().exec().await?;
+
+        let refreshes = oauth_transport.requests();
+        assert_eq!(1, refreshes.len());
+        assert_eq!(
+            b"client_id=1&client_secret=secret&grant_type=refresh_token&refresh_token=refresh"
+                .as_ref(),
+            refreshes[0].body()
+        );
+
+        let requests = transport.requests();
+        assert_eq!(2, requests.len());
+        assert!(requests
+            .iter()
+            .all(|request| request.headers()[AUTHORIZATION] == "Bearer fresh"));
+        assert_eq!("fresh", token.token().await?.access_token());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_missing_refresh_token() {
+        let oauth = OAuthClient::new(
+            Client::builder()
+                .transport(Box::new(MockTransport::new()))
+                .build(),
+            ApplicationId(1),
+            "secret".to_owned(),
+        );
+        let token = RefreshingToken::new(oauth, expired_token(None));
+
+        assert!(matches!(
+            token.token().await.unwrap_err().kind(),
+            RefreshErrorType::MissingRefreshToken
+        ));
+    }
+}
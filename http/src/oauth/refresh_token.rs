@@ -0,0 +1,36 @@
+use super::OAuthClient;
+use crate::{response::ResponseFuture, routing::Route};
+use twilight_model::oauth::AccessTokenResponse;
+
+/// Exchange a refresh token for a new access token.
+///
+/// The previous access token and refresh token are invalidated once the new
+/// access token has been granted.
+#[must_use = "requests must be configured and executed"]
+pub struct RefreshToken<'a> {
+    oauth: &'a OAuthClient,
+    refresh_token: &'a str,
+}
+
+impl<'a> RefreshToken<'a> {
+    pub(crate) const fn new(oauth: &'a OAuthClient, refresh_token: &'a str) -> Self {
+        Self {
+            oauth,
+            refresh_token,
+        }
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<AccessTokenResponse> {
+        let request = self
+            .oauth
+            .form()
+            .field("grant_type", "refresh_token")
+            .field("refresh_token", self.refresh_token)
+            .request(&Route::CreateAccessToken);
+
+        self.oauth.http().request(request)
+    }
+}
@@ -0,0 +1,67 @@
+use super::OAuthClient;
+use crate::{
+    response::{marker::EmptyBody, ResponseFuture},
+    routing::Route,
+};
+
+/// Type of a token to revoke.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum TokenTypeHint {
+    /// Token is an access token.
+    AccessToken,
+    /// Token is a refresh token.
+    RefreshToken,
+}
+
+impl TokenTypeHint {
+    /// Name of the token type as used by the API.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::AccessToken => "access_token",
+            Self::RefreshToken => "refresh_token",
+        }
+    }
+}
+
+/// Revoke an access token or refresh token.
+///
+/// Revoking either token of a grant invalidates both of them.
+#[must_use = "requests must be configured and executed"]
+pub struct RevokeToken<'a> {
+    oauth: &'a OAuthClient,
+    token: &'a str,
+    token_type_hint: Option<TokenTypeHint>,
+}
+
+impl<'a> RevokeToken<'a> {
+    pub(crate) const fn new(oauth: &'a OAuthClient, token: &'a str) -> Self {
+        Self {
+            oauth,
+            token,
+            token_type_hint: None,
+        }
+    }
+
+    /// Set the type of the token to revoke.
+    pub const fn token_type_hint(mut self, token_type_hint: TokenTypeHint) -> Self {
+        self.token_type_hint = Some(token_type_hint);
+
+        self
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<EmptyBody> {
+        let mut form = self.oauth.form().field("token", self.token);
+
+        if let Some(token_type_hint) = self.token_type_hint {
+            form = form.field("token_type_hint", token_type_hint.as_str());
+        }
+
+        self.oauth
+            .http()
+            .request(form.request(&Route::RevokeAccessToken))
+    }
+}
@@ -0,0 +1,131 @@
+use std::time::{Duration, Instant};
+use twilight_model::oauth::{AccessTokenResponse, Scope};
+
+/// Access token granted to the application along with when it expires.
+///
+/// # Examples
+///
+/// Exchange an authorization code for an access token and check whether it
+/// needs to be refreshed:
+///
+/// ```rust,no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::time::Duration;
+/// use twilight_http::{oauth::{AccessToken, OAuthClient}, Client};
+/// use twilight_model::id::ApplicationId;
+///
+/// let oauth = OAuthClient::new(Client::builder().build(), ApplicationId(1), "secret".to_owned());
+///
+/// let response = oauth
+///     .exchange_code("code", "https://example.com/callback")
+///     .exec()
+///     .await?
+///     .model()
+///     .await?;
+/// let token = AccessToken::new(response);
+///
+/// if token.expires_within(Duration::from_secs(60)) {
+///     println!("token needs to be refreshed");
+/// }
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccessToken {
+    expires_at: Instant,
+    response: AccessTokenResponse,
+}
+
+impl AccessToken {
+    /// Create an access token from a response received just now.
+    pub fn new(response: AccessTokenResponse) -> Self {
+        Self::received_at(response, Instant::now())
+    }
+
+    /// Create an access token from a response received at a given time.
+    pub fn received_at(response: AccessTokenResponse, received_at: Instant) -> Self {
+        Self {
+            expires_at: received_at + Duration::from_secs(response.expires_in),
+            response,
+        }
+    }
+
+    /// Token to authorize requests with.
+    pub fn access_token(&self) -> &str {
+        &self.response.access_token
+    }
+
+    /// Value of the `Authorization` header to authorize requests with.
+    pub fn authorization(&self) -> String {
+        format!("Bearer {}", self.response.access_token)
+    }
+
+    /// When the token expires.
+    pub const fn expires_at(&self) -> Instant {
+        self.expires_at
+    }
+
+    /// Whether the token expires within the given duration.
+    pub fn expires_within(&self, duration: Duration) -> bool {
+        Instant::now() + duration >= self.expires_at
+    }
+
+    /// Whether the token has expired.
+    pub fn is_expired(&self) -> bool {
+        Instant::now() >= self.expires_at
+    }
+
+    /// Token to request a new access token with.
+    pub fn refresh_token(&self) -> Option<&str> {
+        self.response.refresh_token.as_deref()
+    }
+
+    /// Scopes the token has been granted.
+    pub fn scopes(&self) -> &[Scope] {
+        &self.response.scope
+    }
+
+    /// Consume the token, returning the response it was created from.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn into_response(self) -> AccessTokenResponse {
+        self.response
+    }
+}
+
+impl From<AccessTokenResponse> for AccessToken {
+    fn from(response: AccessTokenResponse) -> Self {
+        Self::new(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AccessToken;
+    use static_assertions::assert_impl_all;
+    use std::{
+        fmt::Debug,
+        time::{Duration, Instant},
+    };
+    use twilight_model::oauth::{AccessTokenResponse, Scope};
+
+    assert_impl_all!(AccessToken: Clone, Debug, Eq, Send, Sync);
+
+    #[test]
+    fn test_expiry() {
+        let response = AccessTokenResponse {
+            access_token: "access".to_owned(),
+            expires_in: 600,
+            refresh_token: None,
+            scope: vec![Scope::Identify],
+            token_type: "Bearer".to_owned(),
+        };
+        let received_at = Instant::now();
+        let token = AccessToken::received_at(response, received_at);
+
+        assert_eq!(received_at + Duration::from_secs(600), token.expires_at());
+        assert!(!token.is_expired());
+        assert!(!token.expires_within(Duration::from_secs(60)));
+        assert!(token.expires_within(Duration::from_secs(600)));
+        assert_eq!("Bearer access", token.authorization());
+    }
+}
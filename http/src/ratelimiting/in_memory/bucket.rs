@@ -0,0 +1,642 @@
+use super::{BucketKey, GlobalLockPair};
+use crate::ratelimiting::{headers::RatelimitHeaders, Priority, TicketSender};
+use crate::routing::Path;
+use std::{
+    collections::{
+        hash_map::{Entry, HashMap},
+        VecDeque,
+    },
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+use tokio::{
+    sync::{
+        oneshot::{self, Sender},
+        Notify,
+    },
+    time::{sleep, timeout},
+};
+
+#[derive(Clone, Debug)]
+pub enum TimeRemaining {
+    Finished,
+    NotStarted,
+    Some(Duration),
+}
+
+#[derive(Debug)]
+pub struct Bucket {
+    pub key: BucketKey,
+    pub limit: AtomicU64,
+    pub queue: BucketQueue,
+    pub remaining: AtomicU64,
+    pub reset_after: AtomicU64,
+    pub started_at: Mutex<Option<Instant>>,
+}
+
+impl Bucket {
+    pub fn new(key: BucketKey) -> Self {
+        Self {
+            key,
+            limit: AtomicU64::new(u64::max_value()),
+            queue: BucketQueue::default(),
+            remaining: AtomicU64::new(u64::max_value()),
+            reset_after: AtomicU64::new(u64::max_value()),
+            started_at: Mutex::new(None),
+        }
+    }
+
+    pub fn limit(&self) -> u64 {
+        self.limit.load(Ordering::Relaxed)
+    }
+
+    pub fn remaining(&self) -> u64 {
+        self.remaining.load(Ordering::Relaxed)
+    }
+
+    pub fn reset_after(&self) -> u64 {
+        self.reset_after.load(Ordering::Relaxed)
+    }
+
+    pub fn time_remaining(&self) -> TimeRemaining {
+        let reset_after = self.reset_after();
+        let started_at = match *self.started_at.lock().expect("bucket poisoned") {
+            Some(v) => v,
+            None => return TimeRemaining::NotStarted,
+        };
+        let elapsed = started_at.elapsed();
+
+        if elapsed > Duration::from_millis(reset_after) {
+            return TimeRemaining::Finished;
+        }
+
+        TimeRemaining::Some(Duration::from_millis(reset_after) - elapsed)
+    }
+
+    pub fn try_reset(&self) -> bool {
+        if self.started_at.lock().expect("bucket poisoned").is_none() {
+            return false;
+        }
+
+        if let TimeRemaining::Finished = self.time_remaining() {
+            self.remaining.store(self.limit(), Ordering::Relaxed);
+            *self.started_at.lock().expect("bucket poisoned") = None;
+
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn update(&self, ratelimits: Option<(u64, u64, u64)>) {
+        let bucket_limit = self.limit();
+
+        {
+            let mut started_at = self.started_at.lock().expect("bucket poisoned");
+
+            if started_at.is_none() {
+                started_at.replace(Instant::now());
+            }
+        }
+
+        if let Some((limit, remaining, reset_after)) = ratelimits {
+            if bucket_limit != limit && bucket_limit == u64::max_value() {
+                self.reset_after.store(reset_after, Ordering::SeqCst);
+                self.limit.store(limit, Ordering::SeqCst);
+            }
+
+            self.remaining.store(remaining, Ordering::Relaxed);
+        } else {
+            self.remaining.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Request waiting in a [`BucketQueue`] for a ticket.
+#[derive(Debug)]
+pub struct QueuedTicket {
+    pub path: Path,
+    pub priority: Priority,
+    /// Order in which the request was queued, used to find the request that
+    /// has waited the longest.
+    sequence: u64,
+    pub tx: Sender<TicketSender>,
+}
+
+impl QueuedTicket {
+    /// Whether the request may be sent while the ratelimiter is globally
+    /// locked.
+    ///
+    /// Discord doesn't apply the global ratelimit to routes authenticated by
+    /// an interaction's token, whatever the priority of the request. Requests
+    /// to webhooks through the bot's token still wait.
+    const fn bypasses_global(&self) -> bool {
+        matches!(
+            self.path,
+            Path::InteractionCallback(_)
+                | Path::WebhooksIdInteractionToken(_)
+                | Path::WebhooksIdInteractionTokenMessagesId(_)
+        )
+    }
+}
+
+#[derive(Debug, Default)]
+struct BucketQueueInner {
+    /// Queued requests of each priority, indexed from lowest to highest
+    /// priority.
+    queues: [VecDeque<QueuedTicket>; 3],
+    /// Sequence of the next queued request.
+    sequence: u64,
+    /// Number of consecutive requests served before the request that has
+    /// waited the longest.
+    skipped: usize,
+}
+
+/// Queue of requests waiting for a ticket, served from the highest priority
+/// to the lowest.
+///
+/// To avoid starving requests of lower priorities, the request that has
+/// waited the longest is served after [`STARVATION_LIMIT`] other requests
+/// have been served before it.
+///
+/// [`STARVATION_LIMIT`]: Self::STARVATION_LIMIT
+#[derive(Debug, Default)]
+pub struct BucketQueue {
+    inner: Mutex<BucketQueueInner>,
+    notify: Notify,
+}
+
+impl BucketQueue {
+    /// Number of requests that may be served before the request that has
+    /// waited the longest.
+    pub const STARVATION_LIMIT: usize = 8;
+
+    /// Number of requests waiting for a ticket.
+    pub fn len(&self) -> usize {
+        self.inner
+            .lock()
+            .expect("bucket queue poisoned")
+            .queues
+            .iter()
+            .map(VecDeque::len)
+            .sum()
+    }
+
+    pub fn push(&self, path: Path, priority: Priority, tx: Sender<TicketSender>) {
+        {
+            let mut inner = self.inner.lock().expect("bucket queue poisoned");
+            let sequence = inner.sequence;
+            inner.sequence += 1;
+
+            inner.queues[priority as usize].push_back(QueuedTicket {
+                path,
+                priority,
+                sequence,
+                tx,
+            });
+        }
+
+        self.notify.notify_one();
+    }
+
+    /// Queue requests taken from another queue, keeping their order and
+    /// priorities.
+    pub fn extend(&self, tickets: Vec<QueuedTicket>) {
+        if tickets.is_empty() {
+            return;
+        }
+
+        {
+            let mut inner = self.inner.lock().expect("bucket queue poisoned");
+
+            for mut ticket in tickets {
+                ticket.sequence = inner.sequence;
+                inner.sequence += 1;
+                inner.queues[ticket.priority as usize].push_back(ticket);
+            }
+        }
+
+        self.notify.notify_one();
+    }
+
+    /// Remove the requests to a path, returning them in the order they were
+    /// queued.
+    pub fn take_path(&self, path: &Path) -> Vec<QueuedTicket> {
+        let mut inner = self.inner.lock().expect("bucket queue poisoned");
+        let mut taken = Vec::new();
+
+        for queue in &mut inner.queues {
+            let (matching, rest) = queue.drain(..).partition(|ticket| &ticket.path == path);
+            *queue = rest;
+            taken.extend::<VecDeque<_>>(matching);
+        }
+
+        taken.sort_by_key(|ticket| ticket.sequence);
+
+        taken
+    }
+
+    /// Remove the request of the highest priority that bypasses the global
+    /// ratelimit, if any is queued.
+    fn pop_bypassing_global(&self) -> Option<QueuedTicket> {
+        let mut inner = self.inner.lock().expect("bucket queue poisoned");
+
+        inner.queues.iter_mut().rev().find_map(|queue| {
+            let position = queue.iter().position(QueuedTicket::bypasses_global)?;
+
+            queue.remove(position)
+        })
+    }
+
+    pub async fn pop(&self, timeout_duration: Duration) -> Option<QueuedTicket> {
+        let deadline = Instant::now() + timeout_duration;
+
+        loop {
+            if let Some(ticket) = self.try_pop() {
+                return Some(ticket);
+            }
+
+            let remaining = deadline.saturating_duration_since(Instant::now());
+
+            if timeout(remaining, self.notify.notified()).await.is_err() {
+                return self.try_pop();
+            }
+        }
+    }
+
+    fn try_pop(&self) -> Option<QueuedTicket> {
+        let mut inner = self.inner.lock().expect("bucket queue poisoned");
+
+        let highest = inner.queues.iter().rposition(|queue| !queue.is_empty())?;
+        let oldest = inner
+            .queues
+            .iter()
+            .enumerate()
+            .filter_map(|(index, queue)| queue.front().map(|ticket| (ticket.sequence, index)))
+            .min()
+            .map_or(highest, |(_, index)| index);
+
+        let index = if inner.skipped >= Self::STARVATION_LIMIT {
+            oldest
+        } else {
+            highest
+        };
+
+        if index == oldest {
+            inner.skipped = 0;
+        } else {
+            inner.skipped += 1;
+        }
+
+        inner.queues[index].pop_front()
+    }
+}
+
+pub(super) struct BucketQueueTask {
+    bucket: Arc<Bucket>,
+    buckets: Arc<Mutex<HashMap<BucketKey, Arc<Bucket>>>>,
+    global: Arc<GlobalLockPair>,
+    hashes: Arc<Mutex<HashMap<Path, String>>>,
+}
+
+impl BucketQueueTask {
+    const WAIT: Duration = Duration::from_secs(10);
+
+    pub fn new(
+        bucket: Arc<Bucket>,
+        buckets: Arc<Mutex<HashMap<BucketKey, Arc<Bucket>>>>,
+        global: Arc<GlobalLockPair>,
+        hashes: Arc<Mutex<HashMap<Path, String>>>,
+    ) -> Self {
+        Self {
+            bucket,
+            buckets,
+            global,
+            hashes,
+        }
+    }
+
+    pub async fn run(self) {
+        #[cfg(feature = "tracing")]
+        let span = tracing::debug_span!("background queue task", key=?self.bucket.key);
+
+        while let Some(ticket) = self.next().await {
+            if self.global.is_locked() && !ticket.bypasses_global() {
+                self.wait_for_global().await;
+            }
+
+            self.send(ticket).await;
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(parent: &span, "bucket appears finished, removing");
+
+        let mut hashes = self.hashes.lock().expect("ratelimit hashes poisoned");
+        let mut buckets = self.buckets.lock().expect("ratelimit buckets poisoned");
+
+        // The bucket may be stored under both its path and its hash. The
+        // hashes of the paths in the bucket are forgotten with it, so that
+        // they don't accumulate for every major parameter ever requested.
+        buckets.retain(|key, bucket| {
+            if !Arc::ptr_eq(bucket, &self.bucket) {
+                return true;
+            }
+
+            if let BucketKey::Hash {
+                hash,
+                major_parameter,
+            } = key
+            {
+                hashes.retain(|path, path_hash| {
+                    path_hash != hash || path.major_parameter() != *major_parameter
+                });
+            }
+
+            false
+        });
+    }
+
+    /// Grant a ticket to a request and wait for the headers of its response.
+    async fn send(&self, ticket: QueuedTicket) {
+        let (tx, rx) = oneshot::channel();
+
+        let QueuedTicket {
+            path, tx: queue_tx, ..
+        } = ticket;
+        let _sent = queue_tx.send(tx);
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(key=?self.bucket.key, "starting to wait for response headers",);
+
+        // TODO: Find a better way of handling nested types.
+        match timeout(Self::WAIT, rx).await {
+            Ok(Ok(Some(headers))) => self.handle_headers(&path, &headers).await,
+            // - None was sent through the channel (request aborted)
+            // - channel was closed
+            // - timeout reached
+            Ok(Err(_)) | Err(_) | Ok(Ok(None)) => {
+                #[cfg(feature = "tracing")]
+                tracing::debug!(key=?self.bucket.key, "receiver timed out");
+            }
+        }
+    }
+
+    /// Wait for the global ratelimit to reset, meanwhile sending the queued
+    /// requests that aren't subject to it.
+    async fn wait_for_global(&self) {
+        loop {
+            if let Some(ticket) = self.bucket.queue.pop_bypassing_global() {
+                self.wait_if_needed().await;
+                self.send(ticket).await;
+
+                continue;
+            }
+
+            if !self.global.is_locked() {
+                return;
+            }
+
+            // Wake up once either the global lock is released or another
+            // request is queued.
+            Race(
+                Box::pin(self.global.0.lock()),
+                Box::pin(self.bucket.queue.notify.notified()),
+            )
+            .await;
+        }
+    }
+
+    async fn handle_headers(&self, path: &Path, headers: &RatelimitHeaders) {
+        let mut bucket = Arc::clone(&self.bucket);
+
+        let ratelimits = match headers {
+            RatelimitHeaders::GlobalLimited { reset_after } => {
+                self.lock_global(Duration::from_secs(*reset_after)).await;
+
+                None
+            }
+            RatelimitHeaders::None => return,
+            RatelimitHeaders::Present {
+                bucket: hash,
+                global,
+                limit,
+                remaining,
+                reset_after,
+                ..
+            } => {
+                if let Some(hash) = hash {
+                    bucket = self.learn_hash(path, hash);
+                }
+
+                if *global {
+                    self.lock_global(Duration::from_secs(*reset_after)).await;
+                }
+
+                Some((*limit, *remaining, *reset_after))
+            }
+        };
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(key=?bucket.key, "updating bucket");
+        bucket.update(ratelimits);
+    }
+
+    /// Record the Discord bucket hash of a path, so that future requests to
+    /// the path are queued in the bucket of the hash.
+    ///
+    /// If no bucket exists for the hash yet then this bucket becomes it,
+    /// keeping the ratelimit information already known. Otherwise the path
+    /// joins the existing bucket, moving its queued requests into it.
+    ///
+    /// Returns the bucket of the hash, which the ratelimit information of the
+    /// response applies to.
+    fn learn_hash(&self, path: &Path, hash: &str) -> Arc<Bucket> {
+        let mut hashes = self.hashes.lock().expect("ratelimit hashes poisoned");
+        let known = hashes.get(path).map(String::as_str) == Some(hash);
+
+        if !known {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(?path, hash, "learned bucket hash of path");
+
+            hashes.insert(path.clone(), hash.to_owned());
+        }
+
+        let key = BucketKey::Hash {
+            hash: hash.to_owned(),
+            major_parameter: path.major_parameter(),
+        };
+
+        let mut buckets = self.buckets.lock().expect("ratelimit buckets poisoned");
+
+        let existing = match buckets.entry(key) {
+            Entry::Occupied(entry) => Arc::clone(entry.get()),
+            Entry::Vacant(entry) => {
+                entry.insert(Arc::clone(&self.bucket));
+
+                return Arc::clone(&self.bucket);
+            }
+        };
+
+        if known || Arc::ptr_eq(&existing, &self.bucket) {
+            return existing;
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(?path, key=?existing.key, "moving path into existing bucket");
+
+        // Requests that looked up the path's bucket before the hash was
+        // recorded are queued in the existing bucket too.
+        buckets.insert(BucketKey::Path(path.clone()), Arc::clone(&existing));
+        existing.queue.extend(self.bucket.queue.take_path(path));
+
+        existing
+    }
+
+    async fn lock_global(&self, wait: Duration) {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(key=?self.bucket.key, "request got global ratelimited");
+        self.global.lock();
+        let lock = self.global.0.lock().await;
+        sleep(wait).await;
+        self.global.unlock();
+
+        drop(lock);
+    }
+
+    async fn next(&self) -> Option<QueuedTicket> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(key=?self.bucket.key, "starting to get next in queue");
+
+        self.wait_if_needed().await;
+
+        self.bucket.queue.pop(Self::WAIT).await
+    }
+
+    async fn wait_if_needed(&self) {
+        #[cfg(feature = "tracing")]
+        let span = tracing::debug_span!("waiting for bucket to refresh", key=?self.bucket.key);
+
+        let wait = {
+            if self.bucket.remaining() > 0 {
+                return;
+            }
+
+            #[cfg(feature = "tracing")]
+            tracing::debug!(parent: &span, "0 tickets remaining, may have to wait");
+
+            match self.bucket.time_remaining() {
+                TimeRemaining::Finished => {
+                    self.bucket.try_reset();
+
+                    return;
+                }
+                TimeRemaining::NotStarted => return,
+                TimeRemaining::Some(dur) => dur,
+            }
+        };
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            parent: &span,
+            milliseconds=%wait.as_millis(),
+            "waiting for ratelimit to pass",
+        );
+
+        sleep(wait).await;
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(parent: &span, "done waiting for ratelimit to pass");
+
+        self.bucket.try_reset();
+    }
+}
+
+/// Future resolving once either of two futures resolves.
+struct Race<A, B>(A, B);
+
+impl<A: Future + Unpin, B: Future + Unpin> Future for Race<A, B> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if Pin::new(&mut self.0).poll(cx).is_ready() || Pin::new(&mut self.1).poll(cx).is_ready() {
+            return Poll::Ready(());
+        }
+
+        Poll::Pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BucketQueue;
+    use crate::{ratelimiting::Priority, routing::Path};
+    use std::time::Duration;
+    use tokio::sync::oneshot;
+
+    fn push(queue: &BucketQueue, id: u64, priority: Priority) {
+        let (tx, _rx) = oneshot::channel();
+        queue.push(Path::ChannelsIdMessages(id), priority, tx);
+    }
+
+    async fn pop(queue: &BucketQueue) -> Option<Path> {
+        queue
+            .pop(Duration::from_millis(10))
+            .await
+            .map(|ticket| ticket.path)
+    }
+
+    #[tokio::test]
+    async fn test_priority_order() {
+        let queue = BucketQueue::default();
+        push(&queue, 1, Priority::Background);
+        push(&queue, 2, Priority::Normal);
+        push(&queue, 3, Priority::Interactive);
+        push(&queue, 4, Priority::Normal);
+        assert_eq!(4, queue.len());
+
+        assert_eq!(Some(Path::ChannelsIdMessages(3)), pop(&queue).await);
+        assert_eq!(Some(Path::ChannelsIdMessages(2)), pop(&queue).await);
+        assert_eq!(Some(Path::ChannelsIdMessages(4)), pop(&queue).await);
+        assert_eq!(Some(Path::ChannelsIdMessages(1)), pop(&queue).await);
+        assert!(pop(&queue).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_starvation() {
+        let queue = BucketQueue::default();
+        push(&queue, 0, Priority::Background);
+
+        for id in 1..=(BucketQueue::STARVATION_LIMIT as u64 + 1) {
+            push(&queue, id, Priority::Interactive);
+        }
+
+        for id in 1..=(BucketQueue::STARVATION_LIMIT as u64) {
+            assert_eq!(Some(Path::ChannelsIdMessages(id)), pop(&queue).await);
+        }
+
+        assert_eq!(Some(Path::ChannelsIdMessages(0)), pop(&queue).await);
+    }
+
+    #[tokio::test]
+    async fn test_pop_waits_for_push() {
+        let queue = std::sync::Arc::new(BucketQueue::default());
+        let popper = std::sync::Arc::clone(&queue);
+        let handle = tokio::spawn(async move {
+            popper
+                .pop(Duration::from_secs(1))
+                .await
+                .map(|ticket| ticket.path)
+        });
+
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        push(&queue, 1, Priority::Normal);
+
+        assert_eq!(Some(Path::ChannelsIdMessages(1)), handle.await.unwrap());
+    }
+}
@@ -0,0 +1,584 @@
+//! In-memory based default [`Ratelimiter`] implementation used in
+//! `twilight-http`.
+//!
+//! [`Ratelimiter`]: super::Ratelimiter
+
+mod bucket;
+
+use self::bucket::{Bucket, BucketQueueTask, TimeRemaining};
+use super::{
+    IsGloballyLockedFuture, Priority, Ratelimiter, TicketReceiver, TicketSender,
+    TimeUntilAvailableFuture,
+};
+use crate::routing::Path;
+use std::{
+    collections::hash_map::{Entry, HashMap},
+    future,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+use tokio::sync::{
+    oneshot::{self, Sender},
+    Mutex as AsyncMutex,
+};
+
+/// Global lock. We use a pair to avoid actually locking the mutex every check.
+/// This allows futures to only wait on the global lock when a global ratelimit
+/// is in place by, in turn, waiting for a guard, and then each immediately
+/// dropping it.
+#[derive(Debug, Default)]
+struct GlobalLockPair(AsyncMutex<()>, AtomicBool);
+
+impl GlobalLockPair {
+    pub fn lock(&self) {
+        self.1.store(true, Ordering::Release);
+    }
+
+    pub fn unlock(&self) {
+        self.1.store(false, Ordering::Release);
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.1.load(Ordering::Relaxed)
+    }
+}
+
+/// Key identifying a bucket.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+enum BucketKey {
+    /// Discord bucket hash shared by one or more paths, scoped by the major
+    /// parameter of the paths.
+    Hash {
+        hash: String,
+        major_parameter: Option<u64>,
+    },
+    /// Path whose bucket hash isn't known yet.
+    Path(Path),
+}
+
+/// Snapshot of the state of a bucket of an [`InMemoryRatelimiter`].
+///
+/// Returned by [`InMemoryRatelimiter::buckets`].
+#[derive(Clone, Debug)]
+pub struct BucketState {
+    hash: Option<String>,
+    limit: Option<u64>,
+    major_parameter: Option<u64>,
+    path: Option<Path>,
+    queued: usize,
+    remaining: Option<u64>,
+    time_remaining: Option<Duration>,
+}
+
+impl BucketState {
+    fn new(key: &BucketKey, bucket: &Bucket) -> Self {
+        let (hash, major_parameter, path) = match key {
+            BucketKey::Hash {
+                hash,
+                major_parameter,
+            } => (Some(hash.clone()), *major_parameter, None),
+            BucketKey::Path(path) => (None, path.major_parameter(), Some(path.clone())),
+        };
+
+        let known = |value| Some(value).filter(|value| *value != u64::max_value());
+
+        Self {
+            hash,
+            limit: known(bucket.limit()),
+            major_parameter,
+            path,
+            queued: bucket.queue.len(),
+            remaining: known(bucket.remaining()),
+            time_remaining: match bucket.time_remaining() {
+                TimeRemaining::Finished | TimeRemaining::NotStarted => None,
+                TimeRemaining::Some(duration) => Some(duration),
+            },
+        }
+    }
+
+    /// Discord bucket hash of the bucket, if it has been learned.
+    pub fn hash(&self) -> Option<&str> {
+        self.hash.as_deref()
+    }
+
+    /// Number of requests that can be made to the bucket per period, if
+    /// known.
+    pub const fn limit(&self) -> Option<u64> {
+        self.limit
+    }
+
+    /// Major parameter the bucket is scoped by, if any.
+    pub const fn major_parameter(&self) -> Option<u64> {
+        self.major_parameter
+    }
+
+    /// Path of the bucket if its Discord bucket hash hasn't been learned yet.
+    pub const fn path(&self) -> Option<&Path> {
+        self.path.as_ref()
+    }
+
+    /// Number of requests queued in the bucket waiting for a ticket.
+    pub const fn queued(&self) -> usize {
+        self.queued
+    }
+
+    /// Number of requests remaining in the current period, if known.
+    pub const fn remaining(&self) -> Option<u64> {
+        self.remaining
+    }
+
+    /// Time until the current period of the bucket resets, if it has
+    /// started.
+    pub const fn time_remaining(&self) -> Option<Duration> {
+        self.time_remaining
+    }
+}
+
+/// Default ratelimiter implementation used in twilight that stores ratelimit
+/// information in an in-memory mapping.
+///
+/// Paths are initially ratelimited independently. Once a response includes
+/// the Discord bucket hash of a path, the ratelimiter remembers it and queues
+/// future requests to all paths sharing both the hash and the
+/// [major parameter] in the same bucket. The learned mapping can be inspected
+/// via [`bucket_hashes`].
+///
+/// Requests authenticated by an interaction's token, such as interaction
+/// callbacks and followup messages, aren't held back by a global ratelimit,
+/// as Discord doesn't apply it to interaction tokens. They're sent ahead of
+/// other requests in their bucket while it's globally locked.
+///
+/// This will meet most users' needs for simple ratelimiting, but for multi-
+/// process bots, consider either implementing your own [`Ratelimiter`] that
+/// uses a shared storage backend or use the `twilight-http-proxy` binary,
+/// which exposes the state of its buckets via [`buckets`].
+///
+/// [`buckets`]: Self::buckets
+/// [`bucket_hashes`]: Self::bucket_hashes
+/// [major parameter]: Path::major_parameter
+#[derive(Clone, Debug, Default)]
+pub struct InMemoryRatelimiter {
+    buckets: Arc<Mutex<HashMap<BucketKey, Arc<Bucket>>>>,
+    global: Arc<GlobalLockPair>,
+    hashes: Arc<Mutex<HashMap<Path, String>>>,
+}
+
+impl InMemoryRatelimiter {
+    /// Create a new in-memory ratelimiter.
+    ///
+    /// This is used by HTTP client to queue requests in order to avoid
+    /// hitting the API's ratelimits.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Discord bucket hash of a path, if it has been learned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mutex of the hashes is poisoned, which only happens if a
+    /// thread panicked while holding it.
+    pub fn bucket_hash(&self, path: &Path) -> Option<String> {
+        self.hashes
+            .lock()
+            .expect("ratelimit hashes poisoned")
+            .get(path)
+            .cloned()
+    }
+
+    /// Mapping of paths to the Discord bucket hashes learned from responses.
+    ///
+    /// This is a snapshot of the mapping and is primarily useful for
+    /// debugging. Hashes are forgotten along with their buckets once the
+    /// buckets have been idle for a few seconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mutex of the hashes is poisoned, which only happens if a
+    /// thread panicked while holding it.
+    pub fn bucket_hashes(&self) -> HashMap<Path, String> {
+        self.hashes
+            .lock()
+            .expect("ratelimit hashes poisoned")
+            .clone()
+    }
+
+    /// Snapshot of the state of every active bucket.
+    ///
+    /// Buckets are removed once they have been idle for a few seconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mutex of the buckets is poisoned, which only happens if a
+    /// thread panicked while holding it.
+    pub fn buckets(&self) -> Vec<BucketState> {
+        let buckets = self.buckets.lock().expect("ratelimit buckets poisoned");
+        let mut unique: Vec<(&BucketKey, &Arc<Bucket>)> = Vec::with_capacity(buckets.len());
+
+        // A bucket is stored under both its path and its hash once the hash
+        // has been learned, in which case the hash is reported.
+        for (key, bucket) in buckets.iter() {
+            match unique
+                .iter_mut()
+                .find(|(_, other)| Arc::ptr_eq(bucket, other))
+            {
+                Some(entry) => {
+                    if let BucketKey::Hash { .. } = key {
+                        entry.0 = key;
+                    }
+                }
+                None => unique.push((key, bucket)),
+            }
+        }
+
+        unique
+            .into_iter()
+            .map(|(key, bucket)| BucketState::new(key, bucket))
+            .collect()
+    }
+
+    /// Total number of requests queued across all buckets.
+    pub fn queued(&self) -> usize {
+        self.buckets().iter().map(BucketState::queued).sum()
+    }
+
+    /// Key of the bucket a path is ratelimited by.
+    fn key(&self, path: &Path) -> BucketKey {
+        match self.bucket_hash(path) {
+            Some(hash) => BucketKey::Hash {
+                hash,
+                major_parameter: path.major_parameter(),
+            },
+            None => BucketKey::Path(path.clone()),
+        }
+    }
+
+    fn entry(
+        &self,
+        path: Path,
+        priority: Priority,
+        tx: Sender<TicketSender>,
+    ) -> (Arc<Bucket>, bool) {
+        let key = self.key(&path);
+        // nb: not realisically point of contention
+        let mut buckets = self.buckets.lock().expect("ratelimit buckets poisoned");
+
+        match buckets.entry(key) {
+            Entry::Occupied(bucket) => {
+                #[cfg(feature = "tracing")]
+                tracing::debug!("got existing bucket: {:?}", bucket.key());
+
+                let bucket = bucket.into_mut();
+                bucket.queue.push(path, priority, tx);
+                #[cfg(feature = "tracing")]
+                tracing::debug!("added request into bucket queue: {:?}", bucket.key);
+
+                (Arc::clone(bucket), false)
+            }
+            Entry::Vacant(entry) => {
+                #[cfg(feature = "tracing")]
+                tracing::debug!("making new bucket: {:?}", entry.key());
+                let bucket = Bucket::new(entry.key().clone());
+                bucket.queue.push(path, priority, tx);
+
+                let bucket = Arc::new(bucket);
+                entry.insert(Arc::clone(&bucket));
+
+                (bucket, true)
+            }
+        }
+    }
+}
+
+impl Ratelimiter for InMemoryRatelimiter {
+    fn is_globally_locked(&self) -> IsGloballyLockedFuture<'_> {
+        Box::pin(future::ready(self.global.is_locked()))
+    }
+
+    fn ticket(&self, path: Path) -> TicketReceiver {
+        self.ticket_with_priority(path, Priority::Normal)
+    }
+
+    fn ticket_with_priority(&self, path: Path, priority: Priority) -> TicketReceiver {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            "getting bucket for path: {:?} with priority {:?}",
+            path,
+            priority
+        );
+
+        let (tx, rx) = oneshot::channel();
+        let (bucket, fresh) = self.entry(path, priority, tx);
+
+        if fresh {
+            tokio::spawn(
+                BucketQueueTask::new(
+                    bucket,
+                    Arc::clone(&self.buckets),
+                    Arc::clone(&self.global),
+                    Arc::clone(&self.hashes),
+                )
+                .run(),
+            );
+        }
+
+        rx
+    }
+
+    fn time_until_available<'a>(&'a self, path: &'a Path) -> TimeUntilAvailableFuture<'a> {
+        let key = self.key(path);
+        let buckets = self.buckets.lock().expect("ratelimit buckets poisoned");
+
+        let remaining = buckets
+            .get(&key)
+            .and_then(|bucket| match bucket.time_remaining() {
+                TimeRemaining::Finished | TimeRemaining::NotStarted => None,
+                TimeRemaining::Some(duration) => Some(duration),
+            });
+
+        Box::pin(future::ready(remaining))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BucketState, InMemoryRatelimiter};
+    use crate::{
+        ratelimiting::{Priority, RatelimitHeaders, Ratelimiter},
+        routing::Path,
+    };
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug, time::Duration};
+
+    assert_impl_all!(BucketState: Clone, Debug, Send, Sync);
+
+    fn headers(hash: &str, remaining: u64) -> RatelimitHeaders {
+        RatelimitHeaders::Present {
+            bucket: Some(hash.to_owned()),
+            global: false,
+            limit: 5,
+            remaining,
+            reset: 0,
+            reset_after: 5_000,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_bucket_hashes() -> Result<(), Box<dyn Error>> {
+        let ratelimiter = InMemoryRatelimiter::new();
+        let messages = Path::ChannelsIdMessages(1);
+        let pins = Path::ChannelsIdPins(1);
+
+        let tx = ratelimiter.ticket(messages.clone()).await?;
+        tx.send(Some(headers("abc", 0)))
+            .expect("ratelimiter stopped waiting for headers");
+        // Give the bucket's task time to process the headers.
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        assert_eq!(Some("abc".to_owned()), ratelimiter.bucket_hash(&messages));
+        assert!(ratelimiter.time_until_available(&messages).await.is_some());
+        assert!(ratelimiter.time_until_available(&pins).await.is_none());
+
+        let tx = ratelimiter.ticket(pins.clone()).await?;
+        tx.send(Some(headers("abc", 4)))
+            .expect("ratelimiter stopped waiting for headers");
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        // Both paths now share the exhausted bucket, but paths with another
+        // major parameter don't.
+        assert_eq!(2, ratelimiter.bucket_hashes().len());
+        assert!(ratelimiter.time_until_available(&pins).await.is_some());
+        assert!(ratelimiter
+            .time_until_available(&Path::ChannelsIdPins(2))
+            .await
+            .is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_bucket_hash_merges_queues() -> Result<(), Box<dyn Error>> {
+        let ratelimiter = InMemoryRatelimiter::new();
+        let messages = Path::ChannelsIdMessages(1);
+        let pins = Path::ChannelsIdPins(1);
+
+        // Each path has a request in flight and another one queued behind it.
+        let messages_tx = ratelimiter.ticket(messages.clone()).await?;
+        let mut messages_rx = ratelimiter.ticket(messages.clone());
+        let pins_tx = ratelimiter.ticket(pins.clone()).await?;
+        let mut pins_rx = ratelimiter.ticket(pins.clone());
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert_eq!(2, ratelimiter.buckets().len());
+
+        messages_tx
+            .send(Some(headers("abc", 0)))
+            .expect("ratelimiter stopped waiting for headers");
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        pins_tx
+            .send(Some(headers("abc", 0)))
+            .expect("ratelimiter stopped waiting for headers");
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        // The queued requests of both paths wait on the one exhausted bucket.
+        let buckets = ratelimiter.buckets();
+        assert_eq!(1, buckets.len());
+        assert_eq!(Some("abc"), buckets[0].hash());
+        assert_eq!(2, buckets[0].queued());
+        assert!(messages_rx.try_recv().is_err());
+        assert!(pins_rx.try_recv().is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_bucket_hash_shares_remaining() -> Result<(), Box<dyn Error>> {
+        let ratelimiter = InMemoryRatelimiter::new();
+
+        let tx = ratelimiter.ticket(Path::ChannelsIdMessages(1)).await?;
+        tx.send(Some(headers("abc", 3)))
+            .expect("ratelimiter stopped waiting for headers");
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        // The response of the second path updates the bucket both paths are
+        // now queued in.
+        let tx = ratelimiter.ticket(Path::ChannelsIdPins(1)).await?;
+        tx.send(Some(headers("abc", 1)))
+            .expect("ratelimiter stopped waiting for headers");
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        let buckets = ratelimiter.buckets();
+        assert_eq!(1, buckets.len());
+        assert_eq!(Some(1), buckets[0].remaining());
+
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_bucket_hashes_forgotten() -> Result<(), Box<dyn Error>> {
+        let ratelimiter = InMemoryRatelimiter::new();
+
+        let tx = ratelimiter.ticket(Path::ChannelsIdMessages(1)).await?;
+        tx.send(Some(headers("abc", 4)))
+            .expect("ratelimiter stopped waiting for headers");
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert_eq!(1, ratelimiter.bucket_hashes().len());
+
+        // Idle buckets are removed along with the hashes of their paths.
+        tokio::time::sleep(Duration::from_secs(11)).await;
+        assert!(ratelimiter.buckets().is_empty());
+        assert!(ratelimiter.bucket_hashes().is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_global_bypass() -> Result<(), Box<dyn Error>> {
+        let ratelimiter = InMemoryRatelimiter::new();
+
+        let tx = ratelimiter.ticket(Path::ChannelsIdMessages(1)).await?;
+        tx.send(Some(RatelimitHeaders::GlobalLimited { reset_after: 60 }))
+            .expect("ratelimiter stopped waiting for headers");
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert!(ratelimiter.is_globally_locked().await);
+
+        // Requests authenticated by an interaction's token are sent while
+        // globally locked, whatever their priority.
+        let callback =
+            ratelimiter.ticket_with_priority(Path::InteractionCallback(2), Priority::Interactive);
+        let followup =
+            ratelimiter.ticket_with_priority(Path::WebhooksIdInteractionToken(3), Priority::Normal);
+        let original = ratelimiter.ticket_with_priority(
+            Path::WebhooksIdInteractionTokenMessagesId(3),
+            Priority::Background,
+        );
+        tokio::time::timeout(Duration::from_millis(10), callback).await??;
+        tokio::time::timeout(Duration::from_millis(10), followup).await??;
+        tokio::time::timeout(Duration::from_millis(10), original).await??;
+
+        // Requests to webhooks through the bot's token wait on the lock.
+        let webhook = ratelimiter.ticket_with_priority(Path::WebhooksId(4), Priority::Interactive);
+        let webhook_message = ratelimiter
+            .ticket_with_priority(Path::WebhooksIdTokenMessagesId(4), Priority::Interactive);
+        assert!(tokio::time::timeout(Duration::from_millis(10), webhook)
+            .await
+            .is_err());
+        assert!(
+            tokio::time::timeout(Duration::from_millis(10), webhook_message)
+                .await
+                .is_err()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_global_bypass_behind_locked_ticket() -> Result<(), Box<dyn Error>> {
+        let ratelimiter = InMemoryRatelimiter::new();
+        let webhook = Path::WebhooksId(1);
+        let followup = Path::WebhooksIdInteractionToken(1);
+
+        // Both paths share a bucket.
+        for path in &[webhook.clone(), followup.clone()] {
+            let tx = ratelimiter.ticket(path.clone()).await?;
+            tx.send(Some(headers("abc", 5)))
+                .expect("ratelimiter stopped waiting for headers");
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        assert_eq!(1, ratelimiter.buckets().len());
+
+        let tx = ratelimiter.ticket(Path::ChannelsIdMessages(1)).await?;
+        tx.send(Some(RatelimitHeaders::GlobalLimited { reset_after: 60 }))
+            .expect("ratelimiter stopped waiting for headers");
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert!(ratelimiter.is_globally_locked().await);
+
+        // The request at the head of the queue waits on the global lock, but
+        // doesn't hold up the followup queued after it.
+        let mut webhook = ratelimiter.ticket(webhook);
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        let followup = ratelimiter.ticket_with_priority(followup, Priority::Background);
+
+        tokio::time::timeout(Duration::from_millis(10), followup).await??;
+        assert!(
+            tokio::time::timeout(Duration::from_millis(10), &mut webhook)
+                .await
+                .is_err()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_buckets() -> Result<(), Box<dyn Error>> {
+        let ratelimiter = InMemoryRatelimiter::new();
+        let messages = Path::ChannelsIdMessages(1);
+
+        let tx = ratelimiter.ticket(messages.clone()).await?;
+        tx.send(Some(headers("abc", 0)))
+            .expect("ratelimiter stopped waiting for headers");
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        // The bucket is exhausted, so this request stays queued.
+        let _rx = ratelimiter.ticket(messages);
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        let buckets = ratelimiter.buckets();
+        assert_eq!(1, buckets.len());
+
+        let bucket = &buckets[0];
+        assert_eq!(Some("abc"), bucket.hash());
+        assert_eq!(Some(5), bucket.limit());
+        assert_eq!(Some(1), bucket.major_parameter());
+        assert!(bucket.path().is_none());
+        assert_eq!(1, bucket.queued());
+        assert_eq!(Some(0), bucket.remaining());
+        assert!(bucket.time_remaining().is_some());
+        assert_eq!(1, ratelimiter.queued());
+
+        Ok(())
+    }
+}
@@ -1,136 +1,146 @@
+//! Ratelimiting functionality for HTTP requests.
+//!
+//! Discord ratelimits requests to the HTTP API both globally and per-route.
+//! The [`Ratelimiter`] trait abstracts over where ratelimit information is
+//! stored, which allows ratelimits to be shared between processes via
+//! something like a database.
+//!
+//! Two implementations are provided:
+//!
+//! - [`InMemoryRatelimiter`], the default used by the [`Client`], which stores
+//!   buckets in an in-process map;
+//! - [`SharedRatelimiter`], which stores buckets in a [`SharedStore`] that can
+//!   be handed to multiple ratelimiters to simulate multiple processes
+//!   coordinating over a shared backend.
+//!
+//! [`Client`]: crate::Client
+
 pub mod error;
+pub mod in_memory;
+pub mod shared;
 
-mod bucket;
 mod headers;
 
 pub use self::{
     error::{RatelimitError, RatelimitResult},
     headers::RatelimitHeaders,
+    in_memory::{BucketState, InMemoryRatelimiter},
+    shared::{SharedRatelimiter, SharedStore},
 };
 
-use self::bucket::{Bucket, BucketQueueTask, TimeRemaining};
 use crate::routing::Path;
-use std::{
-    collections::hash_map::{Entry, HashMap},
-    sync::{
-        atomic::{AtomicBool, Ordering},
-        Arc, Mutex,
-    },
-    time::Duration,
-};
-use tokio::sync::{
-    oneshot::{self, Receiver, Sender},
-    Mutex as AsyncMutex,
-};
-
-/// Global lock. We use a pair to avoid actually locking the mutex every check.
-/// This allows futures to only wait on the global lock when a global ratelimit
-/// is in place by, in turn, waiting for a guard, and then each immediately
-/// dropping it.
-#[derive(Debug, Default)]
-struct GlobalLockPair(AsyncMutex<()>, AtomicBool);
-
-impl GlobalLockPair {
-    pub fn lock(&self) {
-        self.1.store(true, Ordering::Release);
-    }
-
-    pub fn unlock(&self) {
-        self.1.store(false, Ordering::Release);
-    }
+use std::{fmt::Debug, future::Future, pin::Pin, time::Duration};
+use tokio::sync::oneshot::{Receiver, Sender};
+
+/// Future returned by [`Ratelimiter::is_globally_locked`].
+pub type IsGloballyLockedFuture<'a> = Pin<Box<dyn Future<Output = bool> + Send + 'a>>;
+
+/// Future returned by [`Ratelimiter::time_until_available`].
+pub type TimeUntilAvailableFuture<'a> = Pin<Box<dyn Future<Output = Option<Duration>> + Send + 'a>>;
+
+/// Receiver resolving to a [`TicketSender`] once a request may be performed.
+///
+/// Returned by [`Ratelimiter::ticket`].
+pub type TicketReceiver = Receiver<TicketSender>;
+
+/// Sender used to send the ratelimit headers of a response back to the
+/// [`Ratelimiter`] that issued the ticket.
+///
+/// `None` should be sent if the request was aborted or the headers could not
+/// be parsed.
+pub type TicketSender = Sender<Option<RatelimitHeaders>>;
+
+/// Priority of a request waiting for a [ticket].
+///
+/// Requests queued in the same bucket are served from the highest priority
+/// to the lowest, while requests of a lower priority that have waited for
+/// too long are eventually served to avoid starving them.
+///
+/// [ticket]: Ratelimiter::ticket
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Priority {
+    /// Bulk work that can wait behind everything else, such as mass role
+    /// edits.
+    Background,
+    /// Default priority of requests.
+    Normal,
+    /// Requests a user is actively waiting on, such as interaction
+    /// responses.
+    Interactive,
+}
 
-    pub fn is_locked(&self) -> bool {
-        self.1.load(Ordering::Relaxed)
+impl Default for Priority {
+    fn default() -> Self {
+        Self::Normal
     }
 }
 
-#[derive(Clone, Debug, Default)]
-pub struct Ratelimiter {
-    buckets: Arc<Mutex<HashMap<Path, Arc<Bucket>>>>,
-    global: Arc<GlobalLockPair>,
-}
+/// An implementation of a ratelimiter for the Discord REST API.
+///
+/// The [`Client`] retrieves a [ticket] for the ratelimit [`Path`] of every
+/// request it performs, waits for the ticket to be granted, and sends the
+/// ratelimit headers of the response back through the granted
+/// [`TicketSender`].
+///
+/// A default implementation can be found in [`InMemoryRatelimiter`].
+///
+/// [`Client`]: crate::Client
+/// [ticket]: Self::ticket
+pub trait Ratelimiter: Debug + Send + Sync {
+    /// Whether the ratelimiter is currently globally locked.
+    fn is_globally_locked(&self) -> IsGloballyLockedFuture<'_>;
+
+    /// Retrieve a ticket to perform a request to a path.
+    ///
+    /// The returned receiver resolves to a [`TicketSender`] once the request
+    /// may be performed. The ratelimit headers of the response, if any, must
+    /// then be sent through it so that the ratelimiter can update its state.
+    fn ticket(&self, path: Path) -> TicketReceiver;
 
-impl Ratelimiter {
-    /// Create a new ratelimiter.
+    /// Retrieve a ticket to perform a request to a path with a [`Priority`].
     ///
-    /// Most users won't need to use this directly. If you're creating your own
-    /// HTTP proxy then this is good to use for your own ratelimiting.
-    pub fn new() -> Self {
-        Self::default()
-    }
+    /// Ratelimiters that don't support priorities may ignore it, which is
+    /// what the default implementation does by calling [`ticket`].
+    ///
+    /// [`ticket`]: Self::ticket
+    fn ticket_with_priority(&self, path: Path, priority: Priority) -> TicketReceiver {
+        let _ = priority;
 
-    #[deprecated(since = "0.5.0", note = "use `ticket` instead, which is not async")]
-    pub async fn get(&self, path: Path) -> Receiver<Sender<Option<RatelimitHeaders>>> {
         self.ticket(path)
     }
 
-    pub fn ticket(&self, path: Path) -> Receiver<Sender<Option<RatelimitHeaders>>> {
-        #[cfg(feature = "tracing")]
-        tracing::debug!("getting bucket for path: {:?}", path);
-
-        let (tx, rx) = oneshot::channel();
-        let (bucket, fresh) = self.entry(path.clone(), tx);
-
-        if fresh {
-            tokio::spawn(
-                BucketQueueTask::new(
-                    bucket,
-                    Arc::clone(&self.buckets),
-                    Arc::clone(&self.global),
-                    path,
-                )
-                .run(),
-            );
-        }
-
-        rx
-    }
-
     /// Provide an estimate for the time left until a path can be used
     /// without being ratelimited.
     ///
-    /// This method is not guaranteed to be accurate and may return
-    /// None if either no ratelimit is known or buckets are remaining.
-    pub async fn time_until_available(&self, path: &Path) -> Option<Duration> {
-        let buckets = self.buckets.lock().expect("ratelimit buckets poisoned");
-
-        match buckets.get(path)?.time_remaining() {
-            TimeRemaining::Finished | TimeRemaining::NotStarted => None,
-            TimeRemaining::Some(duration) => Some(duration),
-        }
-    }
+    /// This method is not guaranteed to be accurate and may resolve to `None`
+    /// if either no ratelimit is known or tickets are remaining.
+    fn time_until_available<'a>(&'a self, path: &'a Path) -> TimeUntilAvailableFuture<'a>;
+}
 
-    fn entry(
-        &self,
-        path: Path,
-        tx: Sender<Sender<Option<RatelimitHeaders>>>,
-    ) -> (Arc<Bucket>, bool) {
-        // nb: not realisically point of contention
-        let mut buckets = self.buckets.lock().expect("ratelimit buckets poisoned");
-
-        match buckets.entry(path.clone()) {
-            Entry::Occupied(bucket) => {
-                #[cfg(feature = "tracing")]
-                tracing::debug!("got existing bucket: {:?}", path);
-
-                let bucket = bucket.into_mut();
-                bucket.queue.push(tx);
-                #[cfg(feature = "tracing")]
-                tracing::debug!("added request into bucket queue: {:?}", path);
-
-                (Arc::clone(bucket), false)
-            }
-            Entry::Vacant(entry) => {
-                #[cfg(feature = "tracing")]
-                tracing::debug!("making new bucket for path: {:?}", path);
-                let bucket = Bucket::new(path);
-                bucket.queue.push(tx);
-
-                let bucket = Arc::new(bucket);
-                entry.insert(Arc::clone(&bucket));
-
-                (bucket, true)
-            }
-        }
+#[cfg(test)]
+mod tests {
+    use super::{InMemoryRatelimiter, Priority, Ratelimiter, SharedRatelimiter};
+    use static_assertions::{assert_impl_all, assert_obj_safe};
+    use std::fmt::Debug;
+
+    assert_obj_safe!(Ratelimiter);
+    assert_impl_all!(
+        Priority: Clone,
+        Copy,
+        Debug,
+        Default,
+        Eq,
+        Ord,
+        Send,
+        Sync
+    );
+    assert_impl_all!(InMemoryRatelimiter: Clone, Debug, Default, Ratelimiter, Send, Sync);
+    assert_impl_all!(SharedRatelimiter: Clone, Debug, Ratelimiter, Send, Sync);
+
+    #[test]
+    fn test_priority_order() {
+        assert!(Priority::Background < Priority::Normal);
+        assert!(Priority::Normal < Priority::Interactive);
+        assert_eq!(Priority::Normal, Priority::default());
     }
 }
@@ -0,0 +1,412 @@
+//! [`Ratelimiter`] implementation whose ratelimit information is stored in a
+//! [`SharedStore`] that may be shared between multiple ratelimiters.
+//!
+//! This mirrors how a ratelimiter backed by an external storage backend such
+//! as Redis coordinates multiple processes using the same token, without
+//! requiring the backend. It is primarily useful for testing behavior that
+//! spans multiple clients.
+//!
+//! [`Ratelimiter`]: super::Ratelimiter
+
+use super::{
+    headers::RatelimitHeaders, IsGloballyLockedFuture, Ratelimiter, TicketReceiver,
+    TimeUntilAvailableFuture,
+};
+use crate::routing::Path;
+use std::{
+    collections::{HashMap, HashSet},
+    future,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::{
+    sync::oneshot,
+    time::{sleep, timeout, Instant},
+};
+
+/// Ratelimit information about a single path stored in a [`SharedStore`].
+#[derive(Clone, Debug)]
+struct SharedBucket {
+    limit: u64,
+    remaining: u64,
+    /// When the bucket's current window resets.
+    ///
+    /// This is `None` after the bucket has been refilled and until a response
+    /// in the new window has been received.
+    reset_at: Option<Instant>,
+}
+
+#[derive(Debug, Default)]
+struct SharedStoreState {
+    buckets: HashMap<Path, SharedBucket>,
+    global_reset_at: Option<Instant>,
+    /// Paths without a known ratelimit whose first request is in flight.
+    pending: HashSet<Path>,
+}
+
+/// Storage of ratelimit information shared between [`SharedRatelimiter`]s.
+///
+/// Cloning the store is cheap and all clones refer to the same state. Each
+/// clone can be given to a separate ratelimiter, such as one per client, to
+/// simulate multiple processes coordinating over a shared backend.
+#[derive(Clone, Debug, Default)]
+pub struct SharedStore {
+    state: Arc<Mutex<SharedStoreState>>,
+}
+
+impl SharedStore {
+    /// Amount of time to wait before checking a bucket again whose tickets are
+    /// all reserved by requests that haven't received a response yet, or a
+    /// path whose first request hasn't received a response yet.
+    const IN_FLIGHT_WAIT: Duration = Duration::from_millis(50);
+
+    /// Create a new, empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Try to reserve a ticket for a path.
+    ///
+    /// Returns the duration to wait before trying again if either the store
+    /// is globally locked or the path's bucket has no tickets remaining.
+    fn acquire(&self, path: &Path) -> Result<(), Duration> {
+        let mut guard = self.state.lock().expect("shared store poisoned");
+        let state = &mut *guard;
+        let now = Instant::now();
+
+        if let Some(global_reset_at) = state.global_reset_at {
+            if global_reset_at > now {
+                return Err(global_reset_at - now);
+            }
+
+            state.global_reset_at = None;
+        }
+
+        let bucket = match state.buckets.get_mut(path) {
+            Some(bucket) => bucket,
+            // Until the first response to a path is received its ratelimit is
+            // unknown, so only allow one request at a time.
+            None if state.pending.contains(path) => return Err(Self::IN_FLIGHT_WAIT),
+            None => {
+                state.pending.insert(path.clone());
+
+                return Ok(());
+            }
+        };
+
+        if bucket.reset_at.map_or(false, |reset_at| reset_at <= now) {
+            bucket.remaining = bucket.limit;
+            bucket.reset_at = None;
+        }
+
+        if bucket.remaining == 0 {
+            // If the window hasn't started yet then all tickets are reserved
+            // by requests in flight, so check back once they may have
+            // completed.
+            return Err(bucket
+                .reset_at
+                .map_or(Self::IN_FLIGHT_WAIT, |reset_at| reset_at - now));
+        }
+
+        bucket.remaining -= 1;
+
+        Ok(())
+    }
+
+    /// Give back a ticket reserved for a path whose request was aborted.
+    fn release(&self, path: &Path) {
+        let mut state = self.state.lock().expect("shared store poisoned");
+        state.pending.remove(path);
+
+        if let Some(bucket) = state.buckets.get_mut(path) {
+            bucket.remaining = bucket.limit.min(bucket.remaining + 1);
+        }
+    }
+
+    /// Whether the store is globally locked.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mutex of the store is poisoned, which only happens if a
+    /// thread panicked while holding it.
+    pub fn is_globally_locked(&self) -> bool {
+        let state = self.state.lock().expect("shared store poisoned");
+
+        state
+            .global_reset_at
+            .map_or(false, |reset_at| reset_at > Instant::now())
+    }
+
+    /// Estimate of the time left until a path can be used without being
+    /// ratelimited.
+    ///
+    /// Returns `None` if no ratelimit is known for the path or tickets are
+    /// remaining.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mutex of the store is poisoned, which only happens if a
+    /// thread panicked while holding it.
+    pub fn time_until_available(&self, path: &Path) -> Option<Duration> {
+        let state = self.state.lock().expect("shared store poisoned");
+        let bucket = state.buckets.get(path)?;
+        let now = Instant::now();
+
+        let reset_at = bucket.reset_at?;
+
+        if bucket.remaining > 0 || reset_at <= now {
+            return None;
+        }
+
+        Some(reset_at - now)
+    }
+
+    /// Update the store with the ratelimit headers of a response to a path.
+    fn update(&self, path: &Path, headers: &RatelimitHeaders) {
+        let mut state = self.state.lock().expect("shared store poisoned");
+        let now = Instant::now();
+        state.pending.remove(path);
+
+        match *headers {
+            RatelimitHeaders::GlobalLimited { reset_after } => {
+                state.global_reset_at = Some(now + Duration::from_secs(reset_after));
+            }
+            RatelimitHeaders::None => {}
+            RatelimitHeaders::Present {
+                global,
+                limit,
+                remaining,
+                reset_after,
+                ..
+            } => {
+                let reset_at = now + Duration::from_millis(reset_after);
+
+                if global {
+                    state.global_reset_at = Some(reset_at);
+                }
+
+                // Tickets reserved by other ratelimiters since the request was
+                // sent are not reflected in the headers, so never increase the
+                // number of remaining tickets within the same window.
+                let remaining = match state.buckets.get(path) {
+                    Some(bucket) if bucket.reset_at.map_or(true, |at| at > now) => {
+                        remaining.min(bucket.remaining)
+                    }
+                    _ => remaining,
+                };
+
+                state.buckets.insert(
+                    path.clone(),
+                    SharedBucket {
+                        limit,
+                        remaining,
+                        reset_at: Some(reset_at),
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// [`Ratelimiter`] storing its ratelimit information in a [`SharedStore`].
+///
+/// # Examples
+///
+/// Create two clients that share the same ratelimits, as if they were running
+/// in separate processes:
+///
+/// ```rust,no_run
+/// use twilight_http::{
+///     ratelimiting::{SharedRatelimiter, SharedStore},
+///     Client,
+/// };
+///
+/// let store = SharedStore::new();
+///
+/// let client_1 = Client::builder()
+///     .ratelimiter(Some(Box::new(SharedRatelimiter::new(store.clone()))))
+///     .token("my token".to_owned())
+///     .build();
+/// let client_2 = Client::builder()
+///     .ratelimiter(Some(Box::new(SharedRatelimiter::new(store))))
+///     .token("my token".to_owned())
+///     .build();
+/// ```
+///
+/// [`Ratelimiter`]: super::Ratelimiter
+#[derive(Clone, Debug)]
+pub struct SharedRatelimiter {
+    store: SharedStore,
+}
+
+impl SharedRatelimiter {
+    /// Amount of time to wait for the response headers of a request before
+    /// considering the request aborted.
+    const WAIT: Duration = Duration::from_secs(10);
+
+    /// Create a new ratelimiter using a store.
+    pub const fn new(store: SharedStore) -> Self {
+        Self { store }
+    }
+
+    /// Immutable reference to the store used by the ratelimiter.
+    pub const fn store(&self) -> &SharedStore {
+        &self.store
+    }
+}
+
+impl Ratelimiter for SharedRatelimiter {
+    fn is_globally_locked(&self) -> IsGloballyLockedFuture<'_> {
+        Box::pin(future::ready(self.store.is_globally_locked()))
+    }
+
+    fn ticket(&self, path: Path) -> TicketReceiver {
+        let (tx, rx) = oneshot::channel();
+        let store = self.store.clone();
+
+        tokio::spawn(async move {
+            while let Err(wait) = store.acquire(&path) {
+                #[cfg(feature = "tracing")]
+                tracing::debug!(?path, milliseconds=%wait.as_millis(), "waiting for ticket");
+
+                sleep(wait).await;
+            }
+
+            let (headers_tx, headers_rx) = oneshot::channel();
+
+            if tx.send(headers_tx).is_err() {
+                store.release(&path);
+
+                return;
+            }
+
+            match timeout(Self::WAIT, headers_rx).await {
+                Ok(Ok(Some(headers))) => store.update(&path, &headers),
+                // - None was sent through the channel (request aborted)
+                // - channel was closed
+                // - timeout reached
+                Ok(Err(_)) | Err(_) | Ok(Ok(None)) => store.release(&path),
+            }
+        });
+
+        rx
+    }
+
+    fn time_until_available<'a>(&'a self, path: &'a Path) -> TimeUntilAvailableFuture<'a> {
+        Box::pin(future::ready(self.store.time_until_available(path)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SharedRatelimiter, SharedStore};
+    use crate::{
+        ratelimiting::{RatelimitHeaders, Ratelimiter},
+        routing::Path,
+    };
+    use std::{error::Error, time::Duration};
+    use tokio::{
+        task,
+        time::{timeout, Instant},
+    };
+
+    fn headers(limit: u64, remaining: u64, reset_after: u64) -> RatelimitHeaders {
+        RatelimitHeaders::Present {
+            bucket: None,
+            global: false,
+            limit,
+            remaining,
+            reset: 0,
+            reset_after,
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_shared_bucket() -> Result<(), Box<dyn Error>> {
+        let store = SharedStore::new();
+        let first = SharedRatelimiter::new(store.clone());
+        let second = SharedRatelimiter::new(store);
+        let path = Path::ChannelsIdMessages(1);
+
+        let tx = first.ticket(path.clone()).await?;
+        tx.send(Some(headers(1, 0, 500)))
+            .expect("ratelimiter stopped waiting for headers");
+        // Let the ratelimiter's task process the headers.
+        task::yield_now().await;
+
+        assert_eq!(
+            Some(Duration::from_millis(500)),
+            second.time_until_available(&path).await
+        );
+        assert!(
+            timeout(Duration::from_millis(100), second.ticket(path.clone()))
+                .await
+                .is_err()
+        );
+
+        let started = Instant::now();
+        let _tx = second.ticket(path).await?;
+        assert_eq!(Duration::from_millis(400), started.elapsed());
+
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_first_request_serialized() -> Result<(), Box<dyn Error>> {
+        let store = SharedStore::new();
+        let first = SharedRatelimiter::new(store.clone());
+        let second = SharedRatelimiter::new(store);
+        let path = Path::ChannelsIdMessages(1);
+
+        // The path's ratelimit is unknown until the first response, so other
+        // requests wait for it.
+        let tx = first.ticket(path.clone()).await?;
+        let mut waiting = second.ticket(path.clone());
+        assert!(timeout(Duration::from_millis(100), &mut waiting)
+            .await
+            .is_err());
+
+        tx.send(Some(headers(2, 1, 500)))
+            .expect("ratelimiter stopped waiting for headers");
+        let _tx = timeout(Duration::from_millis(60), waiting).await??;
+
+        // Aborting the first request lets the next one through.
+        let path = Path::ChannelsIdPins(1);
+        let tx = first.ticket(path.clone()).await?;
+        let mut waiting = second.ticket(path);
+        assert!(timeout(Duration::from_millis(100), &mut waiting)
+            .await
+            .is_err());
+
+        drop(tx);
+        timeout(Duration::from_millis(60), waiting).await??;
+
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_global_lock() -> Result<(), Box<dyn Error>> {
+        let store = SharedStore::new();
+        let first = SharedRatelimiter::new(store.clone());
+        let second = SharedRatelimiter::new(store);
+
+        let tx = first.ticket(Path::ChannelsIdMessages(1)).await?;
+        tx.send(Some(RatelimitHeaders::GlobalLimited { reset_after: 1 }))
+            .expect("ratelimiter stopped waiting for headers");
+        task::yield_now().await;
+
+        assert!(second.is_globally_locked().await);
+        assert!(timeout(
+            Duration::from_millis(100),
+            second.ticket(Path::ChannelsIdMessages(2))
+        )
+        .await
+        .is_err());
+
+        let started = Instant::now();
+        let _tx = second.ticket(Path::ChannelsIdMessages(2)).await?;
+        assert_eq!(Duration::from_millis(900), started.elapsed());
+
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
 use super::super::{
     super::{InteractionError, InteractionErrorType},
-    CommandBorrowed,
+    validate_description_localizations, validate_name_localizations,
+    validate_options_localizations, CommandBorrowed,
 };
 use crate::{
     client::Client,
@@ -9,8 +10,12 @@ use crate::{
     response::ResponseFuture,
     routing::Route,
 };
+use std::collections::BTreeMap;
 use twilight_model::{
-    application::command::{Command, CommandOption, CommandType},
+    application::{
+        command::{Command, CommandOption, CommandType},
+        Locale,
+    },
     id::ApplicationId,
 };
 
@@ -26,8 +31,10 @@ pub struct CreateGlobalChatInputCommand<'a> {
     application_id: ApplicationId,
     default_permission: Option<bool>,
     description: &'a str,
+    description_localizations: Option<&'a BTreeMap<Locale, String>>,
     http: &'a Client,
     name: &'a str,
+    name_localizations: Option<&'a BTreeMap<Locale, String>>,
     options: Option<&'a [CommandOption]>,
 }
 
@@ -48,8 +55,10 @@ impl<'a> CreateGlobalChatInputCommand<'a> {
             application_id,
             default_permission: None,
             description,
+            description_localizations: None,
             http,
             name,
+            name_localizations: None,
             options: None,
         })
     }
@@ -63,7 +72,18 @@ impl<'a> CreateGlobalChatInputCommand<'a> {
     /// Returns an [`InteractionErrorType::CommandOptionsRequiredFirst`]
     /// if a required option was added after an optional option. The problem
     /// option's index is provided.
-    pub const fn command_options(
+    ///
+    /// Returns an
+    /// [`InteractionErrorType::CommandOptionNameLocalizationValidationFailed`]
+    /// or an
+    /// [`InteractionErrorType::CommandOptionDescriptionLocalizationValidationFailed`]
+    /// if a localized name or description of an option, including the options
+    /// of subcommands, is invalid.
+    ///
+    /// Returns an
+    /// [`InteractionErrorType::CommandOptionChoiceNameLocalizationValidationFailed`]
+    /// if a localized name of an option's choice is invalid.
+    pub fn command_options(
         mut self,
         options: &'a [CommandOption],
     ) -> Result<Self, InteractionError> {
@@ -86,6 +106,8 @@ impl<'a> CreateGlobalChatInputCommand<'a> {
             idx += 1;
         }
 
+        validate_options_localizations(options)?;
+
         self.options = Some(options);
 
         Ok(self)
@@ -98,6 +120,38 @@ impl<'a> CreateGlobalChatInputCommand<'a> {
         self
     }
 
+    /// Set the localization dictionary for the command description.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InteractionErrorType::CommandDescriptionLocalizationValidationFailed`]
+    /// if a localized description is not between 1 and 100 characters in length.
+    pub fn description_localizations(
+        mut self,
+        localizations: &'a BTreeMap<Locale, String>,
+    ) -> Result<Self, InteractionError> {
+        validate_description_localizations(localizations)?;
+        self.description_localizations = Some(localizations);
+
+        Ok(self)
+    }
+
+    /// Set the localization dictionary for the command name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InteractionErrorType::CommandNameLocalizationValidationFailed`]
+    /// if a localized name is not between 1 and 32 characters in length.
+    pub fn name_localizations(
+        mut self,
+        localizations: &'a BTreeMap<Locale, String>,
+    ) -> Result<Self, InteractionError> {
+        validate_name_localizations(localizations)?;
+        self.name_localizations = Some(localizations);
+
+        Ok(self)
+    }
+
     fn request(&self) -> Result<Request, HttpError> {
         Request::builder(&Route::CreateGlobalCommand {
             application_id: self.application_id.0,
@@ -106,8 +160,10 @@ impl<'a> CreateGlobalChatInputCommand<'a> {
             application_id: Some(self.application_id),
             default_permission: self.default_permission,
             description: Some(self.description),
+            description_localizations: self.description_localizations,
             kind: CommandType::ChatInput,
             name: self.name,
+            name_localizations: self.name_localizations,
             options: self.options,
         })
         .map(RequestBuilder::build)
@@ -1,4 +1,4 @@
-use super::super::CommandBorrowed;
+use super::super::{super::InteractionError, validate_name_localizations, CommandBorrowed};
 use crate::{
     client::Client,
     error::Error,
@@ -6,8 +6,12 @@ use crate::{
     response::ResponseFuture,
     routing::Route,
 };
+use std::collections::BTreeMap;
 use twilight_model::{
-    application::command::{Command, CommandType},
+    application::{
+        command::{Command, CommandType},
+        Locale,
+    },
     id::{ApplicationId, GuildId},
 };
 
@@ -25,6 +29,7 @@ pub struct CreateGuildMessageCommand<'a> {
     guild_id: GuildId,
     http: &'a Client,
     name: &'a str,
+    name_localizations: Option<&'a BTreeMap<Locale, String>>,
 }
 
 impl<'a> CreateGuildMessageCommand<'a> {
@@ -40,6 +45,7 @@ impl<'a> CreateGuildMessageCommand<'a> {
             guild_id,
             http,
             name,
+            name_localizations: None,
         }
     }
 
@@ -51,6 +57,24 @@ impl<'a> CreateGuildMessageCommand<'a> {
         self
     }
 
+    /// Set the localization dictionary for the command name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InteractionErrorType::CommandNameLocalizationValidationFailed`]
+    /// if a localized name is not between 1 and 32 characters in length.
+    ///
+    /// [`InteractionErrorType::CommandNameLocalizationValidationFailed`]: crate::request::application::InteractionErrorType::CommandNameLocalizationValidationFailed
+    pub fn name_localizations(
+        mut self,
+        localizations: &'a BTreeMap<Locale, String>,
+    ) -> Result<Self, InteractionError> {
+        validate_name_localizations(localizations)?;
+        self.name_localizations = Some(localizations);
+
+        Ok(self)
+    }
+
     fn request(&self) -> Result<Request, Error> {
         Request::builder(&Route::CreateGuildCommand {
             application_id: self.application_id.0,
@@ -60,8 +84,10 @@ impl<'a> CreateGuildMessageCommand<'a> {
             application_id: Some(self.application_id),
             default_permission: self.default_permission,
             description: None,
+            description_localizations: None,
             kind: CommandType::Message,
             name: self.name,
+            name_localizations: self.name_localizations,
             options: None,
         })
         .map(RequestBuilder::build)
@@ -11,6 +11,7 @@ use twilight_model::{application::command::Command, id::ApplicationId};
 pub struct GetGlobalCommands<'a> {
     application_id: ApplicationId,
     http: &'a Client,
+    with_localizations: bool,
 }
 
 impl<'a> GetGlobalCommands<'a> {
@@ -18,15 +19,29 @@ impl<'a> GetGlobalCommands<'a> {
         Self {
             application_id,
             http,
+            with_localizations: false,
         }
     }
 
+    /// Whether to include the full localization dictionaries of the commands.
+    ///
+    /// When disabled, only the name and description of each command matching
+    /// the locale of the request are returned.
+    ///
+    /// Defaults to false.
+    pub const fn with_localizations(mut self, with_localizations: bool) -> Self {
+        self.with_localizations = with_localizations;
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
     pub fn exec(self) -> ResponseFuture<ListBody<Command>> {
         let request = Request::from_route(&Route::GetGlobalCommands {
             application_id: self.application_id.0,
+            with_localizations: self.with_localizations,
         });
 
         self.http.request(request)
@@ -15,6 +15,7 @@ pub struct GetGuildCommands<'a> {
     application_id: ApplicationId,
     guild_id: GuildId,
     http: &'a Client,
+    with_localizations: bool,
 }
 
 impl<'a> GetGuildCommands<'a> {
@@ -27,9 +28,22 @@ impl<'a> GetGuildCommands<'a> {
             application_id,
             guild_id,
             http,
+            with_localizations: false,
         }
     }
 
+    /// Whether to include the full localization dictionaries of the commands.
+    ///
+    /// When disabled, only the name and description of each command matching
+    /// the locale of the request are returned.
+    ///
+    /// Defaults to false.
+    pub const fn with_localizations(mut self, with_localizations: bool) -> Self {
+        self.with_localizations = with_localizations;
+
+        self
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
@@ -37,6 +51,7 @@ impl<'a> GetGuildCommands<'a> {
         let request = Request::from_route(&Route::GetGuildCommands {
             application_id: self.application_id.0,
             guild_id: self.guild_id.0,
+            with_localizations: self.with_localizations,
         });
 
         self.http.request(request)
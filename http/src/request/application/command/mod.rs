@@ -1,5 +1,6 @@
 pub mod create_global_command;
 pub mod create_guild_command;
+pub mod sync_commands;
 
 mod delete_global_command;
 mod delete_guild_command;
@@ -17,20 +18,34 @@ mod update_global_command;
 mod update_guild_command;
 
 pub use self::{
-    create_global_command::CreateGlobalCommand, create_guild_command::CreateGuildCommand,
-    delete_global_command::DeleteGlobalCommand, delete_guild_command::DeleteGuildCommand,
-    get_command_permissions::GetCommandPermissions, get_global_command::GetGlobalCommand,
-    get_global_commands::GetGlobalCommands, get_guild_command::GetGuildCommand,
+    create_global_command::CreateGlobalCommand,
+    create_guild_command::CreateGuildCommand,
+    delete_global_command::DeleteGlobalCommand,
+    delete_guild_command::DeleteGuildCommand,
+    get_command_permissions::GetCommandPermissions,
+    get_global_command::GetGlobalCommand,
+    get_global_commands::GetGlobalCommands,
+    get_guild_command::GetGuildCommand,
     get_guild_command_permissions::GetGuildCommandPermissions,
-    get_guild_commands::GetGuildCommands, set_command_permissions::SetCommandPermissions,
-    set_global_commands::SetGlobalCommands, set_guild_commands::SetGuildCommands,
+    get_guild_commands::GetGuildCommands,
+    set_command_permissions::SetCommandPermissions,
+    set_global_commands::SetGlobalCommands,
+    set_guild_commands::SetGuildCommands,
+    sync_commands::{SyncCommands, SyncReport},
     update_command_permissions::UpdateCommandPermissions,
-    update_global_command::UpdateGlobalCommand, update_guild_command::UpdateGuildCommand,
+    update_global_command::UpdateGlobalCommand,
+    update_guild_command::UpdateGuildCommand,
 };
 
+use super::{InteractionError, InteractionErrorType};
+use crate::request::validate_inner;
 use serde::Serialize;
+use std::collections::BTreeMap;
 use twilight_model::{
-    application::command::{CommandOption, CommandType},
+    application::{
+        command::{Command, CommandOption, CommandOptionChoice, CommandType},
+        Locale,
+    },
     id::ApplicationId,
 };
 
@@ -45,17 +60,182 @@ struct CommandBorrowed<'a> {
     pub default_permission: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description_localizations: Option<&'a BTreeMap<Locale, String>>,
     pub kind: CommandType,
     pub name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_localizations: Option<&'a BTreeMap<Locale, String>>,
     #[serde(default)]
     pub options: Option<&'a [CommandOption]>,
 }
 
+/// Ensure that every localized command name is of a valid length.
+fn validate_name_localizations(
+    localizations: &BTreeMap<Locale, String>,
+) -> Result<(), InteractionError> {
+    for (locale, name) in localizations {
+        if !validate_inner::command_name(name) {
+            return Err(InteractionError {
+                kind: InteractionErrorType::CommandNameLocalizationValidationFailed {
+                    locale: *locale,
+                },
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Ensure that every localized command description is of a valid length.
+fn validate_description_localizations(
+    localizations: &BTreeMap<Locale, String>,
+) -> Result<(), InteractionError> {
+    for (locale, description) in localizations {
+        if !validate_inner::command_description(description) {
+            return Err(InteractionError {
+                kind: InteractionErrorType::CommandDescriptionLocalizationValidationFailed {
+                    locale: *locale,
+                },
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Ensure that every localized command option choice name is of a valid
+/// length.
+fn validate_choices_localizations(choices: &[CommandOptionChoice]) -> Result<(), InteractionError> {
+    for choice in choices {
+        let localizations = match choice {
+            CommandOptionChoice::String {
+                name_localizations, ..
+            }
+            | CommandOptionChoice::Int {
+                name_localizations, ..
+            } => name_localizations,
+        };
+
+        for (locale, name) in localizations.iter().flatten() {
+            if !validate_inner::command_option_choice_name(name) {
+                return Err(InteractionError {
+                    kind:
+                        InteractionErrorType::CommandOptionChoiceNameLocalizationValidationFailed {
+                            locale: *locale,
+                        },
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Ensure that the localized names and descriptions of command options, their
+/// nested options, and their choices are of a valid length.
+fn validate_options_localizations(options: &[CommandOption]) -> Result<(), InteractionError> {
+    for option in options {
+        let (name_localizations, description_localizations) = match option {
+            CommandOption::SubCommand(data) | CommandOption::SubCommandGroup(data) => {
+                validate_options_localizations(&data.options)?;
+
+                (&data.name_localizations, &data.description_localizations)
+            }
+            CommandOption::String(data) | CommandOption::Integer(data) => {
+                validate_choices_localizations(&data.choices)?;
+
+                (&data.name_localizations, &data.description_localizations)
+            }
+            CommandOption::Boolean(data)
+            | CommandOption::User(data)
+            | CommandOption::Channel(data)
+            | CommandOption::Role(data)
+            | CommandOption::Mentionable(data) => {
+                (&data.name_localizations, &data.description_localizations)
+            }
+        };
+
+        for (locale, name) in name_localizations.iter().flatten() {
+            if !validate_inner::command_name(name) {
+                return Err(InteractionError {
+                    kind: InteractionErrorType::CommandOptionNameLocalizationValidationFailed {
+                        locale: *locale,
+                    },
+                });
+            }
+        }
+
+        for (locale, description) in description_localizations.iter().flatten() {
+            if !validate_inner::command_description(description) {
+                return Err(InteractionError {
+                    kind:
+                        InteractionErrorType::CommandOptionDescriptionLocalizationValidationFailed {
+                            locale: *locale,
+                        },
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Ensure that a command definition passes the validation of the command
+/// request builders.
+fn validate_command(command: &Command) -> Result<(), InteractionError> {
+    if !validate_inner::command_name(&command.name) {
+        return Err(InteractionError {
+            kind: InteractionErrorType::CommandNameValidationFailed,
+        });
+    }
+
+    if command.kind == CommandType::ChatInput
+        && !validate_inner::command_description(&command.description)
+    {
+        return Err(InteractionError {
+            kind: InteractionErrorType::CommandDescriptionValidationFailed,
+        });
+    }
+
+    if let Some(localizations) = &command.name_localizations {
+        validate_name_localizations(localizations)?;
+    }
+
+    if let Some(localizations) = &command.description_localizations {
+        validate_description_localizations(localizations)?;
+    }
+
+    let mut optional_option_added = false;
+
+    for (index, option) in command.options.iter().enumerate() {
+        if option.is_required() && optional_option_added {
+            return Err(InteractionError {
+                kind: InteractionErrorType::CommandOptionsRequiredFirst { index },
+            });
+        }
+
+        optional_option_added |= !option.is_required();
+    }
+
+    validate_options_localizations(&command.options)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::CommandBorrowed;
+    use super::{
+        super::InteractionErrorType, validate_description_localizations,
+        validate_name_localizations, validate_options_localizations, CommandBorrowed,
+    };
+    use std::collections::BTreeMap;
     use twilight_model::{
-        application::command::{BaseCommandOptionData, Command, CommandOption, CommandType},
+        application::{
+            command::{
+                BaseCommandOptionData, ChoiceCommandOptionData, Command, CommandOption,
+                CommandOptionChoice, CommandType, OptionsCommandOptionData,
+            },
+            Locale,
+        },
         id::{ApplicationId, CommandId, GuildId},
     };
 
@@ -70,13 +250,17 @@ mod tests {
             application_id: Some(ApplicationId(1)),
             default_permission: Some(true),
             description: "command description".to_owned(),
+            description_localizations: None,
             guild_id: Some(GuildId(2)),
             kind: CommandType::ChatInput,
             name: "command name".to_owned(),
+            name_localizations: None,
             id: Some(CommandId(3)),
             options: Vec::from([CommandOption::Boolean(BaseCommandOptionData {
                 description: "command description".to_owned(),
+                description_localizations: None,
                 name: "command name".to_owned(),
+                name_localizations: None,
                 required: true,
             })]),
         };
@@ -85,9 +269,119 @@ mod tests {
             application_id: command.application_id,
             default_permission: command.default_permission,
             description: Some(&command.description),
+            description_localizations: command.description_localizations.as_ref(),
             kind: CommandType::ChatInput,
             name: &command.name,
+            name_localizations: command.name_localizations.as_ref(),
             options: Some(&command.options),
         };
     }
+
+    #[test]
+    fn test_validate_localizations() {
+        let mut localizations = BTreeMap::new();
+        localizations.insert(Locale::German, "etikett".to_owned());
+        localizations.insert(Locale::French, "e".repeat(33));
+
+        assert!(validate_description_localizations(&localizations).is_ok());
+        assert!(matches!(
+            validate_name_localizations(&localizations)
+                .unwrap_err()
+                .kind(),
+            InteractionErrorType::CommandNameLocalizationValidationFailed {
+                locale: Locale::French
+            }
+        ));
+
+        localizations.insert(Locale::French, String::new());
+        assert!(matches!(
+            validate_description_localizations(&localizations)
+                .unwrap_err()
+                .kind(),
+            InteractionErrorType::CommandDescriptionLocalizationValidationFailed {
+                locale: Locale::French
+            }
+        ));
+    }
+
+    #[test]
+    fn test_validate_options_localizations() {
+        let mut localizations = BTreeMap::new();
+        localizations.insert(Locale::German, "e".repeat(33));
+
+        let option = BaseCommandOptionData {
+            description: "description".to_owned(),
+            description_localizations: None,
+            name: "name".to_owned(),
+            name_localizations: Some(localizations.clone()),
+            required: false,
+        };
+        let subcommand = |option| {
+            CommandOption::SubCommand(OptionsCommandOptionData {
+                description: "description".to_owned(),
+                description_localizations: None,
+                name: "subcommand".to_owned(),
+                name_localizations: None,
+                options: Vec::from([option]),
+                required: false,
+            })
+        };
+
+        // The localized name is valid for a description, but too long for a
+        // name.
+        assert!(matches!(
+            validate_options_localizations(&[subcommand(CommandOption::User(option.clone()))])
+                .unwrap_err()
+                .kind(),
+            InteractionErrorType::CommandOptionNameLocalizationValidationFailed {
+                locale: Locale::German
+            }
+        ));
+
+        let option = BaseCommandOptionData {
+            description_localizations: Some(localizations.clone()),
+            name_localizations: None,
+            ..option
+        };
+        assert!(
+            validate_options_localizations(&[subcommand(CommandOption::User(option.clone()))])
+                .is_ok()
+        );
+
+        localizations.insert(Locale::German, "e".repeat(101));
+        let option = BaseCommandOptionData {
+            description_localizations: Some(localizations.clone()),
+            ..option
+        };
+        assert!(matches!(
+            validate_options_localizations(&[CommandOption::User(option)])
+                .unwrap_err()
+                .kind(),
+            InteractionErrorType::CommandOptionDescriptionLocalizationValidationFailed {
+                locale: Locale::German
+            }
+        ));
+
+        let option = CommandOption::String(ChoiceCommandOptionData {
+            autocomplete: false,
+            choices: Vec::from([CommandOptionChoice::String {
+                name: "choice".to_owned(),
+                name_localizations: Some(localizations),
+                value: "choice".to_owned(),
+            }]),
+            description: "description".to_owned(),
+            description_localizations: None,
+            name: "name".to_owned(),
+            name_localizations: None,
+            required: false,
+        });
+        assert!(matches!(
+            validate_options_localizations(&[subcommand(option)])
+                .unwrap_err()
+                .kind(),
+            InteractionErrorType::CommandOptionChoiceNameLocalizationValidationFailed {
+                locale: Locale::German
+            }
+        ));
+    }
 }
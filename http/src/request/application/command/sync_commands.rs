@@ -0,0 +1,887 @@
+use super::{super::InteractionError, validate_command};
+use crate::{
+    client::Client,
+    error::Error as HttpError,
+    request::{NullableField, Request, RequestBuilder},
+    response::{
+        marker::{EmptyBody, ListBody},
+        DeserializeBodyError, ResponseFuture,
+    },
+    routing::Route,
+};
+use serde::Serialize;
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    future::Future,
+    pin::Pin,
+};
+use twilight_model::{
+    application::{
+        command::{Command, CommandOption, CommandType},
+        Locale,
+    },
+    id::{ApplicationId, CommandId, GuildId},
+};
+
+/// Future returned by [`SyncCommands::exec`].
+pub type SyncCommandsFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<SyncReport, SyncCommandsError>> + Send + 'a>>;
+
+/// The error returned when commands could not be synchronized.
+#[derive(Debug)]
+pub struct SyncCommandsError {
+    kind: SyncCommandsErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl SyncCommandsError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &SyncCommandsErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (SyncCommandsErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+
+    fn deserializing(source: DeserializeBodyError) -> Self {
+        Self {
+            kind: SyncCommandsErrorType::Deserializing,
+            source: Some(Box::new(source)),
+        }
+    }
+
+    fn request(source: HttpError) -> Self {
+        Self {
+            kind: SyncCommandsErrorType::Request,
+            source: Some(Box::new(source)),
+        }
+    }
+}
+
+impl Display for SyncCommandsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            SyncCommandsErrorType::Deserializing => f.write_str("failed to deserialize commands"),
+            SyncCommandsErrorType::DuplicateCommand { name, .. } => {
+                f.write_str("command ")?;
+                f.write_str(name)?;
+
+                f.write_str(" is defined more than once")
+            }
+            SyncCommandsErrorType::Request => f.write_str("failed to request commands"),
+        }
+    }
+}
+
+impl Error for SyncCommandsError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`SyncCommandsError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SyncCommandsErrorType {
+    /// Deserializing the existing or changed commands failed.
+    Deserializing,
+    /// Multiple local commands have the same name and type.
+    ///
+    /// No requests are made in this case.
+    DuplicateCommand {
+        /// Type of the commands.
+        kind: CommandType,
+        /// Name of the commands.
+        name: String,
+    },
+    /// Requesting the existing commands or changing a command failed.
+    ///
+    /// Commands changed before the failed request remain changed.
+    ///
+    /// The source is an [`Error`].
+    ///
+    /// [`Error`]: crate::Error
+    Request,
+}
+
+/// Changes made, or that would be made in a dry run, to synchronize commands.
+///
+/// Returned by [`SyncCommands::exec`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SyncReport {
+    created: Vec<Command>,
+    deleted: Vec<Command>,
+    unchanged: Vec<Command>,
+    updated: Vec<Command>,
+}
+
+impl SyncReport {
+    /// Commands that were created.
+    ///
+    /// In a dry run these are the local definitions, which don't have an ID.
+    pub fn created(&self) -> &[Command] {
+        &self.created
+    }
+
+    /// Existing commands that were deleted because they aren't defined
+    /// locally.
+    pub fn deleted(&self) -> &[Command] {
+        &self.deleted
+    }
+
+    /// Existing commands that already matched their local definitions.
+    pub fn unchanged(&self) -> &[Command] {
+        &self.unchanged
+    }
+
+    /// Commands that were updated to match their local definitions.
+    ///
+    /// In a dry run these are the local definitions along with the ID of the
+    /// existing command.
+    pub fn updated(&self) -> &[Command] {
+        &self.updated
+    }
+
+    /// Whether any command was created, updated, or deleted.
+    pub fn has_changes(&self) -> bool {
+        !(self.created.is_empty() && self.deleted.is_empty() && self.updated.is_empty())
+    }
+}
+
+/// Fields of a command sent when creating or updating it.
+///
+#[derive(Serialize)]
+struct SyncCommandFields<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_permission: Option<bool>,
+    #[serde(skip_serializing_if = "str::is_empty")]
+    description: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description_localizations: Option<NullableField<&'a BTreeMap<Locale, String>>>,
+    name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name_localizations: Option<NullableField<&'a BTreeMap<Locale, String>>>,
+    options: &'a [CommandOption],
+    /// Type of the command, which can only be set when creating it.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    kind: Option<CommandType>,
+}
+
+impl<'a> SyncCommandFields<'a> {
+    /// Fields to create a command with.
+    fn create(command: &'a Command) -> Self {
+        Self {
+            default_permission: command.default_permission,
+            description: &command.description,
+            description_localizations: command
+                .description_localizations
+                .as_ref()
+                .map(|localizations| NullableField(Some(localizations))),
+            name: &command.name,
+            name_localizations: command
+                .name_localizations
+                .as_ref()
+                .map(|localizations| NullableField(Some(localizations))),
+            options: &command.options,
+            kind: Some(command.kind),
+        }
+    }
+
+    /// Fields to update a command with.
+    ///
+    /// Fields missing from the local definition are sent with their default
+    /// values, as Discord would otherwise keep their existing values.
+    fn update(command: &'a Command) -> Self {
+        Self {
+            default_permission: Some(command.default_permission.unwrap_or(true)),
+            description: &command.description,
+            description_localizations: Some(NullableField(
+                command.description_localizations.as_ref(),
+            )),
+            name: &command.name,
+            name_localizations: Some(NullableField(command.name_localizations.as_ref())),
+            options: &command.options,
+            kind: None,
+        }
+    }
+}
+
+/// Change required to synchronize a command.
+#[derive(Debug, Eq, PartialEq)]
+enum Change<'a> {
+    Create(&'a Command),
+    Delete(&'a Command),
+    Unchanged(&'a Command),
+    Update(CommandId, &'a Command),
+}
+
+/// Compute the changes turning the existing commands into the desired ones.
+///
+/// Commands are matched by their name and type. Deletions come first so
+/// that they free up room for new commands, followed by updates and
+/// creations in the order of the desired commands.
+fn diff<'a>(
+    existing: &'a [Command],
+    desired: &'a [Command],
+) -> Result<Vec<Change<'a>>, SyncCommandsError> {
+    let same = |a: &Command, b: &Command| a.name == b.name && a.kind == b.kind;
+
+    for (idx, command) in desired.iter().enumerate() {
+        if desired[..idx].iter().any(|other| same(other, command)) {
+            return Err(SyncCommandsError {
+                kind: SyncCommandsErrorType::DuplicateCommand {
+                    kind: command.kind,
+                    name: command.name.clone(),
+                },
+                source: None,
+            });
+        }
+    }
+
+    let mut changes = existing
+        .iter()
+        .filter(|command| !desired.iter().any(|other| same(other, command)))
+        .map(Change::Delete)
+        .collect::<Vec<_>>();
+
+    for command in desired {
+        let change = match existing.iter().find(|other| same(other, command)) {
+            Some(current) if !differs(current, command) => Change::Unchanged(current),
+            Some(current) => match current.id {
+                Some(id) => Change::Update(id, command),
+                None => Change::Create(command),
+            },
+            None => Change::Create(command),
+        };
+
+        changes.push(change);
+    }
+
+    Ok(changes)
+}
+
+/// Whether an existing command differs from its local definition.
+fn differs(current: &Command, desired: &Command) -> bool {
+    // Discord defaults the permission to true if it isn't provided.
+    current.default_permission.unwrap_or(true) != desired.default_permission.unwrap_or(true)
+        || current.description != desired.description
+        || localizations_differ(
+            current.description_localizations.as_ref(),
+            desired.description_localizations.as_ref(),
+        )
+        || localizations_differ(
+            current.name_localizations.as_ref(),
+            desired.name_localizations.as_ref(),
+        )
+        || current.options != desired.options
+}
+
+/// Whether two localization dictionaries differ, treating a missing
+/// dictionary the same as an empty one.
+fn localizations_differ(
+    current: Option<&BTreeMap<Locale, String>>,
+    desired: Option<&BTreeMap<Locale, String>>,
+) -> bool {
+    let is_empty = |map: Option<&BTreeMap<Locale, String>>| map.map_or(true, BTreeMap::is_empty);
+
+    if is_empty(current) && is_empty(desired) {
+        return false;
+    }
+
+    current != desired
+}
+
+/// Synchronize the global or guild commands of the application with local
+/// definitions, making as few requests as possible.
+///
+/// The existing commands are fetched and matched with the local definitions
+/// by their name and type, including nested options and choices. Commands
+/// that differ are updated, missing commands are created, and commands that
+/// aren't defined locally are deleted. Unlike [`SetGlobalCommands`] and
+/// [`SetGuildCommands`] unchanged commands are left alone, keeping their
+/// permissions.
+///
+/// In a [dry run] the existing commands are only fetched, and the
+/// [`SyncReport`] describes the changes that would be made.
+///
+/// # Examples
+///
+/// Synchronize the global commands and print what changed:
+///
+/// ```rust,no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use twilight_http::Client;
+/// use twilight_model::{
+///     application::command::{Command, CommandType},
+///     id::ApplicationId,
+/// };
+///
+/// let client = Client::new("my token".to_owned());
+/// client.set_application_id(ApplicationId(1));
+///
+/// let commands = [Command {
+///     application_id: None,
+///     default_permission: None,
+///     description: "Ping the bot".to_owned(),
+///     description_localizations: None,
+///     guild_id: None,
+///     id: None,
+///     kind: CommandType::ChatInput,
+///     name: "ping".to_owned(),
+///     name_localizations: None,
+///     options: Vec::new(),
+/// }];
+///
+/// let report = client.sync_global_commands(&commands)?.exec().await?;
+///
+/// for command in report.created() {
+///     println!("created {}", command.name);
+/// }
+/// # Ok(()) }
+/// ```
+///
+/// [`SetGlobalCommands`]: super::SetGlobalCommands
+/// [`SetGuildCommands`]: super::SetGuildCommands
+/// [dry run]: Self::dry_run
+#[must_use = "requests must be configured and executed"]
+pub struct SyncCommands<'a> {
+    application_id: ApplicationId,
+    commands: &'a [Command],
+    dry_run: bool,
+    guild_id: Option<GuildId>,
+    http: &'a Client,
+}
+
+impl<'a> SyncCommands<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        application_id: ApplicationId,
+        guild_id: Option<GuildId>,
+        commands: &'a [Command],
+    ) -> Result<Self, InteractionError> {
+        for command in commands {
+            validate_command(command)?;
+        }
+
+        Ok(Self {
+            application_id,
+            commands,
+            dry_run: false,
+            guild_id,
+            http,
+        })
+    }
+
+    /// Set whether to only compute the changes without making them.
+    ///
+    /// Defaults to false.
+    pub const fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+
+        self
+    }
+
+    fn get(&self) -> ResponseFuture<ListBody<Command>> {
+        let route = match self.guild_id {
+            Some(guild_id) => Route::GetGuildCommands {
+                application_id: self.application_id.0,
+                guild_id: guild_id.0,
+                with_localizations: true,
+            },
+            None => Route::GetGlobalCommands {
+                application_id: self.application_id.0,
+                with_localizations: true,
+            },
+        };
+
+        self.http.request(Request::from_route(&route))
+    }
+
+    fn create(&self, command: &Command) -> ResponseFuture<Command> {
+        let route = match self.guild_id {
+            Some(guild_id) => Route::CreateGuildCommand {
+                application_id: self.application_id.0,
+                guild_id: guild_id.0,
+            },
+            None => Route::CreateGlobalCommand {
+                application_id: self.application_id.0,
+            },
+        };
+
+        let fields = SyncCommandFields::create(command);
+
+        match Request::builder(&route)
+            .json(&fields)
+            .map(RequestBuilder::build)
+        {
+            Ok(request) => self.http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+
+    fn update(&self, command_id: CommandId, command: &Command) -> ResponseFuture<Command> {
+        let route = match self.guild_id {
+            Some(guild_id) => Route::UpdateGuildCommand {
+                application_id: self.application_id.0,
+                command_id: command_id.0,
+                guild_id: guild_id.0,
+            },
+            None => Route::UpdateGlobalCommand {
+                application_id: self.application_id.0,
+                command_id: command_id.0,
+            },
+        };
+
+        let fields = SyncCommandFields::update(command);
+
+        match Request::builder(&route)
+            .json(&fields)
+            .map(RequestBuilder::build)
+        {
+            Ok(request) => self.http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+
+    fn delete(&self, command_id: CommandId) -> ResponseFuture<EmptyBody> {
+        let route = match self.guild_id {
+            Some(guild_id) => Route::DeleteGuildCommand {
+                application_id: self.application_id.0,
+                command_id: command_id.0,
+                guild_id: guild_id.0,
+            },
+            None => Route::DeleteGlobalCommand {
+                application_id: self.application_id.0,
+                command_id: command_id.0,
+            },
+        };
+
+        self.http.request(Request::from_route(&route))
+    }
+
+    /// Execute the synchronization, returning a future resolving to a report
+    /// of the changes.
+    ///
+    /// Requests are made one after another.
+    pub fn exec(self) -> SyncCommandsFuture<'a> {
+        Box::pin(async move {
+            let existing = self
+                .get()
+                .await
+                .map_err(SyncCommandsError::request)?
+                .models()
+                .await
+                .map_err(SyncCommandsError::deserializing)?;
+
+            let mut report = SyncReport::default();
+
+            for change in diff(&existing, self.commands)? {
+                match change {
+                    Change::Create(command) if self.dry_run => report.created.push(command.clone()),
+                    Change::Create(command) => {
+                        let created = self
+                            .create(command)
+                            .await
+                            .map_err(SyncCommandsError::request)?
+                            .model()
+                            .await
+                            .map_err(SyncCommandsError::deserializing)?;
+
+                        report.created.push(created);
+                    }
+                    Change::Delete(command) => {
+                        if let (Some(id), false) = (command.id, self.dry_run) {
+                            self.delete(id).await.map_err(SyncCommandsError::request)?;
+                        }
+
+                        report.deleted.push(command.clone());
+                    }
+                    Change::Unchanged(command) => report.unchanged.push(command.clone()),
+                    Change::Update(id, command) if self.dry_run => {
+                        let mut command = command.clone();
+                        command.id = Some(id);
+
+                        report.updated.push(command);
+                    }
+                    Change::Update(id, command) => {
+                        let updated = self
+                            .update(id, command)
+                            .await
+                            .map_err(SyncCommandsError::request)?
+                            .model()
+                            .await
+                            .map_err(SyncCommandsError::deserializing)?;
+
+                        report.updated.push(updated);
+                    }
+                }
+            }
+
+            Ok(report)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{diff, Change, SyncCommands, SyncCommandsError, SyncCommandsErrorType, SyncReport};
+    use crate::{
+        request::application::InteractionErrorType,
+        request::Method,
+        routing::Route,
+        transport::{MockResponse, MockTransport, RecordedRequest},
+        Client,
+    };
+    use static_assertions::assert_impl_all;
+    use std::{collections::BTreeMap, error::Error, fmt::Debug};
+    use twilight_model::{
+        application::{
+            command::{
+                BaseCommandOptionData, ChoiceCommandOptionData, Command, CommandOption,
+                CommandOptionChoice, CommandType,
+            },
+            Locale,
+        },
+        id::{ApplicationId, CommandId, GuildId},
+    };
+
+    assert_impl_all!(SyncCommands<'_>: Send, Sync);
+    assert_impl_all!(SyncCommandsError: Error, Send, Sync);
+    assert_impl_all!(SyncCommandsErrorType: Debug, Send, Sync);
+    assert_impl_all!(SyncReport: Clone, Debug, Default, Eq, PartialEq, Send, Sync);
+
+    fn client(transport: &MockTransport) -> Client {
+        let client = Client::builder()
+            .token("token".to_owned())
+            .transport(Box::new(transport.clone()))
+            .build();
+        client.set_application_id(ApplicationId(1));
+
+        client
+    }
+
+    fn command(name: &str, description: &str, options: Vec<CommandOption>) -> Command {
+        Command {
+            application_id: None,
+            default_permission: None,
+            description: description.to_owned(),
+            description_localizations: None,
+            guild_id: None,
+            id: None,
+            kind: CommandType::ChatInput,
+            name: name.to_owned(),
+            name_localizations: None,
+            options,
+        }
+    }
+
+    fn existing(id: u64, mut command: Command) -> Command {
+        command.application_id = Some(ApplicationId(1));
+        command.default_permission = Some(true);
+        command.id = Some(CommandId(id));
+
+        command
+    }
+
+    fn choice(value: &str) -> CommandOption {
+        CommandOption::String(ChoiceCommandOptionData {
+            autocomplete: false,
+            choices: vec![CommandOptionChoice::String {
+                name: value.to_owned(),
+                name_localizations: None,
+                value: value.to_owned(),
+            }],
+            description: "kind of thing".to_owned(),
+            description_localizations: None,
+            name: "kind".to_owned(),
+            name_localizations: None,
+            required: false,
+        })
+    }
+
+    #[test]
+    fn test_diff() -> Result<(), Box<dyn Error>> {
+        let current = vec![
+            existing(1, command("ping", "Ping the bot", Vec::new())),
+            existing(2, command("info", "Show info", vec![choice("user")])),
+            existing(3, command("old", "Removed command", Vec::new())),
+        ];
+        let desired = vec![
+            command("ping", "Ping the bot", Vec::new()),
+            command("info", "Show info", vec![choice("guild")]),
+            command("new", "Added command", Vec::new()),
+        ];
+
+        assert_eq!(
+            vec![
+                Change::Delete(&current[2]),
+                Change::Unchanged(&current[0]),
+                Change::Update(CommandId(2), &desired[1]),
+                Change::Create(&desired[2]),
+            ],
+            diff(&current, &desired)?
+        );
+
+        let mut restricted = desired[0].clone();
+        restricted.default_permission = Some(false);
+        assert_eq!(
+            vec![Change::Update(CommandId(1), &restricted)],
+            diff(&current[..1], std::slice::from_ref(&restricted))?
+        );
+
+        let mut user = desired[0].clone();
+        user.description = String::new();
+        user.kind = CommandType::User;
+        assert_eq!(
+            vec![Change::Delete(&current[0]), Change::Create(&user)],
+            diff(&current[..1], std::slice::from_ref(&user))?
+        );
+
+        let mut localized = desired[0].clone();
+        localized.name_localizations = Some(BTreeMap::new());
+        assert_eq!(
+            vec![Change::Unchanged(&current[0])],
+            diff(&current[..1], std::slice::from_ref(&localized))?
+        );
+
+        localized
+            .name_localizations
+            .get_or_insert_with(BTreeMap::new)
+            .insert(Locale::German, "pingen".to_owned());
+        assert_eq!(
+            vec![Change::Update(CommandId(1), &localized)],
+            diff(&current[..1], std::slice::from_ref(&localized))?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_diff_duplicate() {
+        let desired = vec![
+            command("ping", "Ping the bot", Vec::new()),
+            command("ping", "Ping the bot again", Vec::new()),
+        ];
+
+        assert!(matches!(
+            diff(&[], &desired).unwrap_err().kind(),
+            SyncCommandsErrorType::DuplicateCommand { name, .. } if name == "ping"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_sync() -> Result<(), Box<dyn Error>> {
+        let current = vec![
+            existing(1, command("ping", "Ping the bot", Vec::new())),
+            existing(
+                2,
+                command(
+                    "info",
+                    "Show info",
+                    vec![CommandOption::Boolean(BaseCommandOptionData {
+                        description: "Show more".to_owned(),
+                        description_localizations: None,
+                        name: "verbose".to_owned(),
+                        name_localizations: None,
+                        required: false,
+                    })],
+                ),
+            ),
+            existing(3, command("old", "Removed command", Vec::new())),
+        ];
+        let desired = vec![
+            command("ping", "Ping the bot", Vec::new()),
+            command("info", "Show information", Vec::new()),
+            command("new", "Added command", Vec::new()),
+        ];
+
+        let transport = MockTransport::new();
+        transport
+            .on_route(
+                &Route::GetGuildCommands {
+                    application_id: 1,
+                    guild_id: 10,
+                    with_localizations: true,
+                },
+                MockResponse::new(200).json(&current)?,
+            )
+            .on_route(
+                &Route::DeleteGuildCommand {
+                    application_id: 1,
+                    command_id: 3,
+                    guild_id: 10,
+                },
+                MockResponse::new(204),
+            )
+            .on_route(
+                &Route::UpdateGuildCommand {
+                    application_id: 1,
+                    command_id: 2,
+                    guild_id: 10,
+                },
+                MockResponse::new(200).json(&existing(2, desired[1].clone()))?,
+            )
+            .on_route(
+                &Route::CreateGuildCommand {
+                    application_id: 1,
+                    guild_id: 10,
+                },
+                MockResponse::new(201).json(&existing(4, desired[2].clone()))?,
+            );
+
+        let client = client(&transport);
+
+        let report = client
+            .sync_guild_commands(GuildId(10), &desired)?
+            .dry_run(true)
+            .exec()
+            .await?;
+        assert!(report.has_changes());
+        assert_eq!(1, transport.requests().len());
+        assert_eq!(None, report.created()[0].id);
+        assert_eq!(Some(CommandId(2)), report.updated()[0].id);
+
+        let report = client
+            .sync_guild_commands(GuildId(10), &desired)?
+            .exec()
+            .await?;
+        assert_eq!(vec![existing(4, desired[2].clone())], report.created());
+        assert_eq!(&current[2..], report.deleted());
+        assert_eq!(&current[..1], report.unchanged());
+        assert_eq!(vec![existing(2, desired[1].clone())], report.updated());
+
+        let requests = transport.requests();
+        let methods = requests[1..]
+            .iter()
+            .map(RecordedRequest::method)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![Method::Get, Method::Delete, Method::Patch, Method::Post],
+            methods
+        );
+
+        let update: serde_json::Value = serde_json::from_slice(requests[3].body())?;
+        assert_eq!(
+            serde_json::json!({
+                "default_permission": true,
+                "description": "Show information",
+                "description_localizations": null,
+                "name": "info",
+                "name_localizations": null,
+                "options": [],
+            }),
+            update
+        );
+
+        let create: serde_json::Value = serde_json::from_slice(requests[4].body())?;
+        assert_eq!(1, create["type"]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_sync_resets_fields() -> Result<(), Box<dyn Error>> {
+        let mut localizations = BTreeMap::new();
+        localizations.insert(Locale::German, "pingen".to_owned());
+
+        let mut current = existing(1, command("ping", "Ping the bot", Vec::new()));
+        current.default_permission = Some(false);
+        current.description_localizations = Some(localizations.clone());
+        current.name_localizations = Some(localizations);
+        let desired = vec![command("ping", "Ping the bot", Vec::new())];
+
+        let update = Route::UpdateGlobalCommand {
+            application_id: 1,
+            command_id: 1,
+        };
+        let transport = MockTransport::new();
+        transport
+            .on_route(
+                &Route::GetGlobalCommands {
+                    application_id: 1,
+                    with_localizations: true,
+                },
+                MockResponse::new(200).json(&[&current])?,
+            )
+            .on_route(
+                &update,
+                MockResponse::new(200).json(&existing(1, desired[0].clone()))?,
+            );
+
+        let report = client(&transport)
+            .sync_global_commands(&desired)?
+            .exec()
+            .await?;
+        assert_eq!(1, report.updated().len());
+
+        // Apply the update the way Discord does, replacing the fields that
+        // were sent.
+        let mut updated = serde_json::to_value(&current)?;
+        let body: serde_json::Value = serde_json::from_slice(transport.requests()[1].body())?;
+
+        for (key, value) in body.as_object().into_iter().flatten() {
+            updated[key] = value.clone();
+        }
+
+        let transport = MockTransport::new();
+        transport.on_route(
+            &Route::GetGlobalCommands {
+                application_id: 1,
+                with_localizations: true,
+            },
+            MockResponse::new(200).json(&[updated])?,
+        );
+
+        let report = client(&transport)
+            .sync_global_commands(&desired)?
+            .exec()
+            .await?;
+        assert!(!report.has_changes());
+        assert_eq!(1, transport.requests().len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate() {
+        let transport = MockTransport::new();
+        let client = client(&transport);
+
+        let invalid = [command("ping", "", Vec::new())];
+        assert!(matches!(
+            client
+                .sync_global_commands(&invalid)
+                .err()
+                .map(|error| error.into_parts().0),
+            Some(InteractionErrorType::CommandDescriptionValidationFailed)
+        ));
+
+        let invalid = [command(&"a".repeat(33), "Ping the bot", Vec::new())];
+        assert!(matches!(
+            client
+                .sync_global_commands(&invalid)
+                .err()
+                .map(|error| error.into_parts().0),
+            Some(InteractionErrorType::CommandNameValidationFailed)
+        ));
+
+        let mut user = command("Profile", "", Vec::new());
+        user.kind = CommandType::User;
+        assert!(client.sync_global_commands(&[user]).is_ok());
+    }
+}
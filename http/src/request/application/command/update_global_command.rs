@@ -1,3 +1,6 @@
+use super::{
+    super::InteractionError, validate_description_localizations, validate_name_localizations,
+};
 use crate::{
     client::Client,
     error::Error,
@@ -6,8 +9,12 @@ use crate::{
     routing::Route,
 };
 use serde::Serialize;
+use std::collections::BTreeMap;
 use twilight_model::{
-    application::command::{Command, CommandOption},
+    application::{
+        command::{Command, CommandOption},
+        Locale,
+    },
     id::{ApplicationId, CommandId},
 };
 
@@ -16,8 +23,12 @@ struct UpdateGlobalCommandFields<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     description: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    description_localizations: Option<&'a BTreeMap<Locale, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     name: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    name_localizations: Option<&'a BTreeMap<Locale, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     options: Option<&'a [CommandOption]>,
 }
 
@@ -46,7 +57,9 @@ impl<'a> UpdateGlobalCommand<'a> {
             command_id,
             fields: UpdateGlobalCommandFields {
                 description: None,
+                description_localizations: None,
                 name: None,
+                name_localizations: None,
                 options: None,
             },
             http,
@@ -67,6 +80,42 @@ impl<'a> UpdateGlobalCommand<'a> {
         self
     }
 
+    /// Edit the localization dictionary for the command name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InteractionErrorType::CommandNameLocalizationValidationFailed`]
+    /// if a localized name is not between 1 and 32 characters in length.
+    ///
+    /// [`InteractionErrorType::CommandNameLocalizationValidationFailed`]: crate::request::application::InteractionErrorType::CommandNameLocalizationValidationFailed
+    pub fn name_localizations(
+        mut self,
+        localizations: &'a BTreeMap<Locale, String>,
+    ) -> Result<Self, InteractionError> {
+        validate_name_localizations(localizations)?;
+        self.fields.name_localizations = Some(localizations);
+
+        Ok(self)
+    }
+
+    /// Edit the localization dictionary for the command description.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InteractionErrorType::CommandDescriptionLocalizationValidationFailed`]
+    /// if a localized description is not between 1 and 100 characters in length.
+    ///
+    /// [`InteractionErrorType::CommandDescriptionLocalizationValidationFailed`]: crate::request::application::InteractionErrorType::CommandDescriptionLocalizationValidationFailed
+    pub fn description_localizations(
+        mut self,
+        localizations: &'a BTreeMap<Locale, String>,
+    ) -> Result<Self, InteractionError> {
+        validate_description_localizations(localizations)?;
+        self.fields.description_localizations = Some(localizations);
+
+        Ok(self)
+    }
+
     /// Edit the command options of the command.
     pub const fn command_options(mut self, options: &'a [CommandOption]) -> Self {
         self.fields.options = Some(options);
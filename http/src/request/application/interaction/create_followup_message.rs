@@ -1,6 +1,7 @@
 use crate::{
     client::Client,
     error::Error as HttpError,
+    ratelimiting::Priority,
     request::{
         validate_inner::{self, ComponentValidationError, ComponentValidationErrorType},
         Form, Request,
@@ -343,11 +344,11 @@ impl<'a> CreateFollowupMessage<'a> {
     // `self` needs to be consumed and the client returned due to parameters
     // being consumed in request construction.
     fn request(&self) -> Result<Request, HttpError> {
-        let mut request = Request::builder(&Route::ExecuteWebhook {
-            token: self.token,
-            wait: None,
-            webhook_id: self.application_id.0,
-        });
+        let mut request = Request::builder(&Route::CreateFollowupMessage {
+            application_id: self.application_id.0,
+            interaction_token: self.token,
+        })
+        .priority(Priority::Interactive);
 
         if !self.files.is_empty() || self.fields.payload_json.is_some() {
             let mut form = Form::new();
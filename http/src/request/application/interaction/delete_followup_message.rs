@@ -1,5 +1,6 @@
 use crate::{
     client::Client,
+    ratelimiting::Priority,
     request::Request,
     response::{marker::EmptyBody, ResponseFuture},
     routing::Route,
@@ -47,11 +48,13 @@ impl<'a> DeleteFollowupMessage<'a> {
     }
 
     fn request(self) -> Request {
-        Request::from_route(&Route::DeleteWebhookMessage {
+        Request::builder(&Route::DeleteFollowupMessage {
+            application_id: self.application_id.0,
+            interaction_token: self.token,
             message_id: self.message_id.0,
-            token: self.token,
-            webhook_id: self.application_id.0,
         })
+        .priority(Priority::Interactive)
+        .build()
     }
 
     /// Execute the request, returning a future resolving to a [`Response`].
@@ -75,12 +78,13 @@ mod tests {
         let builder = DeleteFollowupMessage::new(&client, ApplicationId(1), "token", MessageId(2));
         let actual = builder.request();
 
-        let expected = Request::from_route(&Route::DeleteWebhookMessage {
+        let expected = Request::from_route(&Route::DeleteFollowupMessage {
+            application_id: 1,
+            interaction_token: "token",
             message_id: 2,
-            token: "token",
-            webhook_id: 1,
         });
 
         assert_eq!(expected.path, actual.path);
+        assert_eq!(expected.ratelimit_path, actual.ratelimit_path);
     }
 }
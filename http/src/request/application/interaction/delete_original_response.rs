@@ -1,5 +1,6 @@
 use crate::{
     client::Client,
+    ratelimiting::Priority,
     request::Request,
     response::{marker::EmptyBody, ResponseFuture},
     routing::Route,
@@ -51,10 +52,12 @@ impl<'a> DeleteOriginalResponse<'a> {
     ///
     /// [`Response`]: crate::response::Response
     pub fn exec(self) -> ResponseFuture<EmptyBody> {
-        let request = Request::from_route(&Route::DeleteInteractionOriginal {
+        let request = Request::builder(&Route::DeleteInteractionOriginal {
             application_id: self.application_id.0,
             interaction_token: self.token,
-        });
+        })
+        .priority(Priority::Interactive)
+        .build();
 
         self.http.request(request)
     }
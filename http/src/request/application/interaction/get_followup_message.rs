@@ -1,4 +1,7 @@
-use crate::{client::Client, request::Request, response::ResponseFuture, routing::Route};
+use crate::{
+    client::Client, ratelimiting::Priority, request::Request, response::ResponseFuture,
+    routing::Route,
+};
 use twilight_model::{
     channel::Message,
     id::{ApplicationId, MessageId},
@@ -49,11 +52,13 @@ impl<'a> GetFollowupMessage<'a> {
     }
 
     fn request(&self) -> Request {
-        Request::from_route(&Route::GetFollowupMessage {
+        Request::builder(&Route::GetFollowupMessage {
             application_id: self.application_id.0,
             interaction_token: self.interaction_token,
             message_id: self.message_id.0,
         })
+        .priority(Priority::Interactive)
+        .build()
     }
 
     /// Execute the request, returning a future resolving to a [`Response`].
@@ -1,4 +1,7 @@
-use crate::{client::Client, request::Request, response::ResponseFuture, routing::Route};
+use crate::{
+    client::Client, ratelimiting::Priority, request::Request, response::ResponseFuture,
+    routing::Route,
+};
 use twilight_model::{channel::Message, id::ApplicationId};
 
 /// Get the original message, by its token.
@@ -46,10 +49,12 @@ impl<'a> GetOriginalResponse<'a> {
     ///
     /// [`Response`]: crate::response::Response
     pub fn exec(self) -> ResponseFuture<Message> {
-        let request = Request::from_route(&Route::GetInteractionOriginal {
+        let request = Request::builder(&Route::GetInteractionOriginal {
             application_id: self.application_id.0,
             interaction_token: self.token,
-        });
+        })
+        .priority(Priority::Interactive)
+        .build();
 
         self.http.request(request)
     }
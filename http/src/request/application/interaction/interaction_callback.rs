@@ -1,13 +1,18 @@
 use crate::{
     client::Client,
-    error::Error,
-    request::Request,
+    error::{Error, ErrorType},
+    ratelimiting::Priority,
+    request::{validate_inner, Request},
     response::{marker::EmptyBody, ResponseFuture},
     routing::Route,
 };
 use twilight_model::{application::callback::InteractionResponse, id::InteractionId};
 
 /// Respond to an interaction, by ID and token.
+///
+/// [`InteractionResponse::Modal`] responses are validated before being sent,
+/// resolving to an error of type [`ErrorType::BuildingRequest`] if the modal
+/// or its components are invalid.
 #[must_use = "requests must be configured and executed"]
 pub struct InteractionCallback<'a> {
     interaction_id: InteractionId,
@@ -34,10 +39,18 @@ impl<'a> InteractionCallback<'a> {
     // `self` needs to be consumed and the client returned due to parameters
     // being consumed in request construction.
     fn request(&self) -> Result<Request, Error> {
+        if let InteractionResponse::Modal(modal) = self.response {
+            validate_inner::modal(modal).map_err(|source| Error {
+                kind: ErrorType::BuildingRequest,
+                source: Some(Box::new(source)),
+            })?;
+        }
+
         let request = Request::builder(&Route::InteractionCallback {
             interaction_id: self.interaction_id.0,
             interaction_token: self.interaction_token,
         })
+        .priority(Priority::Interactive)
         .json(self.response)?
         .build();
 
@@ -54,3 +67,85 @@ impl<'a> InteractionCallback<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::InteractionCallback;
+    use crate::{client::Client, error::ErrorType};
+    use static_assertions::assert_impl_all;
+    use std::error::Error;
+    use twilight_model::{
+        application::{
+            callback::{AutocompleteCallbackData, InteractionResponse, ModalCallbackData},
+            command::CommandOptionChoice,
+            component::{
+                text_input::{TextInput, TextInputStyle},
+                ActionRow, Component,
+            },
+        },
+        id::InteractionId,
+    };
+
+    assert_impl_all!(InteractionCallback<'_>: Send, Sync);
+
+    fn modal(title: &str) -> InteractionResponse {
+        InteractionResponse::Modal(ModalCallbackData {
+            components: vec![Component::ActionRow(ActionRow {
+                components: vec![Component::TextInput(TextInput {
+                    custom_id: "reason".to_owned(),
+                    label: "Reason".to_owned(),
+                    max_length: None,
+                    min_length: None,
+                    placeholder: None,
+                    required: None,
+                    style: TextInputStyle::Short,
+                    value: None,
+                })],
+            })],
+            custom_id: "modal".to_owned(),
+            title: title.to_owned(),
+        })
+    }
+
+    #[test]
+    fn test_request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new("token".to_owned());
+
+        let response =
+            InteractionResponse::ApplicationCommandAutocompleteResult(AutocompleteCallbackData {
+                choices: vec![CommandOptionChoice::Int {
+                    name: "one".to_owned(),
+                    name_localizations: None,
+                    value: 1,
+                }],
+            });
+        let request = client
+            .interaction_callback(InteractionId(1), "token", &response)
+            .request()?;
+        assert_eq!(
+            Some(br#"{"type":8,"data":{"choices":[{"name":"one","value":1}]}}"#.as_ref()),
+            request.body()
+        );
+
+        let response = modal("Ban");
+        let request = client
+            .interaction_callback(InteractionId(1), "token", &response)
+            .request()?;
+        assert!(request.body().is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_modal() {
+        let client = Client::new("token".to_owned());
+        let response = modal(&"a".repeat(46));
+
+        let error = client
+            .interaction_callback(InteractionId(1), "token", &response)
+            .request()
+            .unwrap_err();
+
+        assert!(matches!(error.kind(), ErrorType::BuildingRequest));
+    }
+}
@@ -3,6 +3,7 @@
 use crate::{
     client::Client,
     error::Error as HttpError,
+    ratelimiting::Priority,
     request::{
         self,
         validate_inner::{self, ComponentValidationError, ComponentValidationErrorType},
@@ -395,11 +396,12 @@ impl<'a> UpdateFollowupMessage<'a> {
     // `self` needs to be consumed and the client returned due to parameters
     // being consumed in request construction.
     fn request(&mut self) -> Result<Request, HttpError> {
-        let mut request = Request::builder(&Route::UpdateWebhookMessage {
+        let mut request = Request::builder(&Route::UpdateFollowupMessage {
+            application_id: self.application_id.0,
+            interaction_token: self.token,
             message_id: self.message_id.0,
-            token: self.token,
-            webhook_id: self.application_id.0,
-        });
+        })
+        .priority(Priority::Interactive);
 
         if !self.files.is_empty() || self.fields.payload_json.is_some() {
             let mut form = Form::new();
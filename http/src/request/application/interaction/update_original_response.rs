@@ -3,6 +3,7 @@
 use crate::{
     client::Client,
     error::Error as HttpError,
+    ratelimiting::Priority,
     request::{
         self,
         validate_inner::{self, ComponentValidationError, ComponentValidationErrorType},
@@ -399,7 +400,8 @@ impl<'a> UpdateOriginalResponse<'a> {
         let mut request = Request::builder(&Route::UpdateInteractionOriginal {
             application_id: self.application_id.0,
             interaction_token: self.token,
-        });
+        })
+        .priority(Priority::Interactive);
 
         if !self.files.is_empty() || self.fields.payload_json.is_some() {
             let mut form = Form::new();
@@ -93,6 +93,7 @@ use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
 };
+use twilight_model::application::Locale;
 
 /// The error created if the creation of interaction fails.
 #[derive(Debug)]
@@ -109,6 +110,31 @@ pub enum InteractionErrorType {
     CommandNameValidationFailed,
     /// Command description validation failed.
     CommandDescriptionValidationFailed,
+    /// Localized command name validation failed.
+    CommandNameLocalizationValidationFailed {
+        /// Locale of the name that failed validation.
+        locale: Locale,
+    },
+    /// Localized command description validation failed.
+    CommandDescriptionLocalizationValidationFailed {
+        /// Locale of the description that failed validation.
+        locale: Locale,
+    },
+    /// Localized command option name validation failed.
+    CommandOptionNameLocalizationValidationFailed {
+        /// Locale of the name that failed validation.
+        locale: Locale,
+    },
+    /// Localized command option description validation failed.
+    CommandOptionDescriptionLocalizationValidationFailed {
+        /// Locale of the description that failed validation.
+        locale: Locale,
+    },
+    /// Localized command option choice name validation failed.
+    CommandOptionChoiceNameLocalizationValidationFailed {
+        /// Locale of the name that failed validation.
+        locale: Locale,
+    },
     /// Required command options have to be passed before optional ones.
     CommandOptionsRequiredFirst {
         /// Index of the option that failed validation.
@@ -164,6 +190,40 @@ impl Display for InteractionError {
             InteractionErrorType::CommandDescriptionValidationFailed => {
                 f.write_str("command description must be between 1 and 100 characters")
             }
+            InteractionErrorType::CommandNameLocalizationValidationFailed { locale } => {
+                f.write_str("command name for locale ")?;
+                Display::fmt(&locale, f)?;
+
+                f.write_str(" must be between 1 and 32 characters")
+            }
+            InteractionErrorType::CommandDescriptionLocalizationValidationFailed { locale } => {
+                f.write_str("command description for locale ")?;
+                Display::fmt(&locale, f)?;
+
+                f.write_str(" must be between 1 and 100 characters")
+            }
+            InteractionErrorType::CommandOptionNameLocalizationValidationFailed { locale } => {
+                f.write_str("command option name for locale ")?;
+                Display::fmt(&locale, f)?;
+
+                f.write_str(" must be between 1 and 32 characters")
+            }
+            InteractionErrorType::CommandOptionDescriptionLocalizationValidationFailed {
+                locale,
+            } => {
+                f.write_str("command option description for locale ")?;
+                Display::fmt(&locale, f)?;
+
+                f.write_str(" must be between 1 and 100 characters")
+            }
+            InteractionErrorType::CommandOptionChoiceNameLocalizationValidationFailed {
+                locale,
+            } => {
+                f.write_str("command option choice name for locale ")?;
+                Display::fmt(&locale, f)?;
+
+                f.write_str(" must be between 1 and 100 characters")
+            }
             InteractionErrorType::CommandOptionsRequiredFirst { .. } => {
                 f.write_str("optional command options must be added after required")
             }
@@ -13,7 +13,7 @@ mod private {
     use crate::request::{
         channel::{
             invite::{CreateInvite, DeleteInvite},
-            message::{DeleteMessage, DeleteMessages},
+            message::{DeleteMessage, DeleteMessages, PurgeMessages},
             thread::UpdateThread,
             webhook::{
                 CreateWebhook, DeleteWebhook, DeleteWebhookMessage, UpdateWebhook,
@@ -28,6 +28,8 @@ mod private {
             integration::DeleteGuildIntegration,
             member::{AddRoleToMember, RemoveMember, RemoveRoleFromMember, UpdateGuildMember},
             role::{CreateRole, DeleteRole, UpdateRole},
+            scheduled_event::{CreateGuildScheduledEvent, UpdateGuildScheduledEvent},
+            sticker::{CreateGuildSticker, DeleteGuildSticker, UpdateGuildSticker},
             CreateGuildChannel, CreateGuildPrune, UpdateGuild,
         },
     };
@@ -38,6 +40,7 @@ mod private {
     impl<'a> Sealed for DeleteInvite<'a> {}
     impl<'a> Sealed for DeleteMessage<'a> {}
     impl<'a> Sealed for DeleteMessages<'a> {}
+    impl<'a> Sealed for PurgeMessages<'a> {}
     impl<'a> Sealed for UpdateChannel<'a> {}
     impl<'a> Sealed for CreateWebhook<'a> {}
     impl Sealed for DeleteWebhookMessage<'_> {}
@@ -63,6 +66,11 @@ mod private {
     impl<'a> Sealed for CreateRole<'a> {}
     impl<'a> Sealed for DeleteRole<'a> {}
     impl<'a> Sealed for UpdateRole<'a> {}
+    impl<'a> Sealed for CreateGuildScheduledEvent<'a> {}
+    impl<'a> Sealed for UpdateGuildScheduledEvent<'a> {}
+    impl<'a> Sealed for CreateGuildSticker<'a> {}
+    impl<'a> Sealed for DeleteGuildSticker<'a> {}
+    impl<'a> Sealed for UpdateGuildSticker<'a> {}
     impl<'a> Sealed for UpdateGuild<'a> {}
     impl<'a> Sealed for UpdateThread<'a> {}
     impl Sealed for UpdateWebhookMessage<'_> {}
@@ -144,7 +152,7 @@ mod test {
     use crate::request::{
         channel::{
             invite::{CreateInvite, DeleteInvite},
-            message::{DeleteMessage, DeleteMessages},
+            message::{DeleteMessage, DeleteMessages, PurgeMessages},
             webhook::{CreateWebhook, DeleteWebhook, UpdateWebhook},
             CreatePin, DeleteChannel, DeleteChannelPermissionConfigured, DeletePin, UpdateChannel,
             UpdateChannelPermissionConfigured,
@@ -155,6 +163,8 @@ mod test {
             integration::DeleteGuildIntegration,
             member::{AddRoleToMember, RemoveMember, RemoveRoleFromMember, UpdateGuildMember},
             role::{CreateRole, DeleteRole, UpdateRole},
+            scheduled_event::{CreateGuildScheduledEvent, UpdateGuildScheduledEvent},
+            sticker::{CreateGuildSticker, DeleteGuildSticker, UpdateGuildSticker},
             CreateGuildChannel, CreateGuildPrune, UpdateGuild,
         },
     };
@@ -166,6 +176,7 @@ mod test {
     assert_impl_all!(DeleteInvite<'_>: AuditLogReason<'static>);
     assert_impl_all!(DeleteMessage<'_>: AuditLogReason<'static>);
     assert_impl_all!(DeleteMessages<'_>: AuditLogReason<'static>);
+    assert_impl_all!(PurgeMessages<'_>: AuditLogReason<'static>);
     assert_impl_all!(UpdateChannel<'_>: AuditLogReason<'static>);
     assert_impl_all!(CreateWebhook<'_>: AuditLogReason<'static>);
     assert_impl_all!(DeleteWebhook<'_>: AuditLogReason<'static>);
@@ -190,5 +201,10 @@ mod test {
     assert_impl_all!(CreateRole<'_>: AuditLogReason<'static>);
     assert_impl_all!(DeleteRole<'_>: AuditLogReason<'static>);
     assert_impl_all!(UpdateRole<'_>: AuditLogReason<'static>);
+    assert_impl_all!(CreateGuildScheduledEvent<'_>: AuditLogReason<'static>);
+    assert_impl_all!(UpdateGuildScheduledEvent<'_>: AuditLogReason<'static>);
+    assert_impl_all!(CreateGuildSticker<'_>: AuditLogReason<'static>);
+    assert_impl_all!(DeleteGuildSticker<'_>: AuditLogReason<'static>);
+    assert_impl_all!(UpdateGuildSticker<'_>: AuditLogReason<'static>);
     assert_impl_all!(UpdateGuild<'_>: AuditLogReason<'static>);
 }
@@ -1,6 +1,8 @@
 use super::{Form, Method};
 use crate::{
+    client::RetryPolicy,
     error::Error,
+    ratelimiting::Priority,
     routing::{Path, Route},
 };
 use hyper::header::{HeaderMap, HeaderName, HeaderValue};
@@ -71,7 +73,9 @@ impl RequestBuilder {
             headers: None,
             method,
             path: path_and_query,
+            priority: Priority::Normal,
             ratelimit_path,
+            retry_policy: None,
             use_authorization_token: true,
         })
     }
@@ -123,6 +127,39 @@ impl RequestBuilder {
         Ok(self.body(bytes))
     }
 
+    /// Set the ratelimiting priority of the request.
+    ///
+    /// Defaults to [`Priority::Normal`], or [`Priority::Interactive`] for
+    /// requests created by the interaction request builders. Bulk requests
+    /// may use [`Priority::Background`] to let other requests to the same
+    /// bucket go first.
+    ///
+    /// Requests created through the client's request builders may instead
+    /// use [`ResponseFuture::priority`].
+    ///
+    /// [`ResponseFuture::priority`]: crate::response::ResponseFuture::priority
+    #[must_use = "request has not been fully built"]
+    pub const fn priority(mut self, priority: Priority) -> Self {
+        self.0.priority = priority;
+
+        self
+    }
+
+    /// Set the retry policy of the request, overriding the client's
+    /// [retry policy].
+    ///
+    /// Requests created through the client's request builders may instead
+    /// use [`ResponseFuture::retry_policy`].
+    ///
+    /// [`ResponseFuture::retry_policy`]: crate::response::ResponseFuture::retry_policy
+    /// [retry policy]: crate::client::ClientBuilder::retry_policy
+    #[must_use = "request has not been fully built"]
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.0.retry_policy = Some(retry_policy);
+
+        self
+    }
+
     /// Whether to use the client's authorization token in the request, if one
     /// is set.
     ///
@@ -141,7 +178,9 @@ pub struct Request {
     pub(crate) headers: Option<HeaderMap<HeaderValue>>,
     pub(crate) method: Method,
     pub(crate) path: String,
+    pub(crate) priority: Priority,
     pub(crate) ratelimit_path: Path,
+    pub(crate) retry_policy: Option<RetryPolicy>,
     pub(crate) use_authorization_token: bool,
 }
 
@@ -195,7 +234,9 @@ impl Request {
             headers: None,
             method: route.method(),
             path: route.display().to_string(),
+            priority: Priority::Normal,
             ratelimit_path: route.path(),
+            retry_policy: None,
             use_authorization_token: true,
         }
     }
@@ -225,11 +266,21 @@ impl Request {
         &self.path
     }
 
+    /// Priority of the request when ratelimiting.
+    pub const fn priority(&self) -> Priority {
+        self.priority
+    }
+
     /// Path used for ratelimiting.
     pub const fn ratelimit_path(&self) -> &Path {
         &self.ratelimit_path
     }
 
+    /// Retry policy of the request, if it overrides the client's.
+    pub const fn retry_policy(&self) -> Option<&RetryPolicy> {
+        self.retry_policy.as_ref()
+    }
+
     /// Whether to use the client's authorization token in the request.
     pub const fn use_authorization_token(&self) -> bool {
         self.use_authorization_token
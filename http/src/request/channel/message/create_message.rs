@@ -3,7 +3,7 @@ use crate::{
     error::Error as HttpError,
     request::{
         self,
-        multipart::Form,
+        multipart::{FileUpload, Form},
         validate_inner::{
             self, ComponentValidationError, ComponentValidationErrorType, EmbedValidationError,
         },
@@ -161,6 +161,7 @@ pub(crate) struct CreateMessageFields<'a> {
 pub struct CreateMessage<'a> {
     channel_id: ChannelId,
     pub(crate) fields: CreateMessageFields<'a>,
+    file_uploads: Vec<FileUpload>,
     files: &'a [(&'a str, &'a [u8])],
     http: &'a Client,
 }
@@ -179,6 +180,7 @@ impl<'a> CreateMessage<'a> {
                 allowed_mentions: None,
                 tts: None,
             },
+            file_uploads: Vec::new(),
             files: &[],
             http,
         }
@@ -290,6 +292,20 @@ impl<'a> CreateMessage<'a> {
         self
     }
 
+    /// Attach files whose contents are streamed into the request instead of
+    /// being buffered in memory.
+    ///
+    /// The files are attached after those set via [`files`]. Calling this
+    /// method will clear any previous calls.
+    ///
+    /// [`files`]: Self::files
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn file_uploads(mut self, file_uploads: Vec<FileUpload>) -> Self {
+        self.file_uploads = file_uploads;
+
+        self
+    }
+
     /// Attach multiple files to the message.
     ///
     /// Calling this method will clear any previous calls.
@@ -360,13 +376,21 @@ impl<'a> CreateMessage<'a> {
             channel_id: self.channel_id.0,
         });
 
-        if !self.files.is_empty() || self.fields.payload_json.is_some() {
+        if !self.files.is_empty()
+            || !self.file_uploads.is_empty()
+            || self.fields.payload_json.is_some()
+        {
             let mut form = Form::new();
 
             for (index, (name, file)) in self.files.iter().enumerate() {
                 form.file(format!("{}", index).as_bytes(), name.as_bytes(), file);
             }
 
+            for (index, upload) in self.file_uploads.into_iter().enumerate() {
+                let index = self.files.len() + index;
+                form.file_upload(format!("{}", index).as_bytes(), upload);
+            }
+
             if let Some(payload_json) = &self.fields.payload_json {
                 form.payload_json(payload_json);
             } else {
@@ -1,7 +1,7 @@
 use super::GetChannelMessagesConfigured;
 use crate::{
     client::Client,
-    request::{validate_inner, Request},
+    request::{validate_inner, Paginator, Request},
     response::{marker::ListBody, ResponseFuture},
     routing::Route,
 };
@@ -185,4 +185,23 @@ impl<'a> GetChannelMessages<'a> {
 
         self.http.request(request)
     }
+
+    /// Stream the messages of the channel across pages, starting with the
+    /// most recent message and moving backward in time.
+    ///
+    /// Pages contain the number of messages set via [`limit`], or 100 if it
+    /// isn't set.
+    ///
+    /// [`limit`]: Self::limit
+    pub fn paginate(self) -> Paginator<'a, Message> {
+        GetChannelMessagesConfigured::new(
+            self.http,
+            self.channel_id,
+            None,
+            None,
+            None,
+            self.fields.limit,
+        )
+        .paginate()
+    }
 }
@@ -1,6 +1,9 @@
 use crate::{
     client::Client,
-    request::{validate_inner, Request},
+    request::{
+        paginate::{self, PageFuture, Pager, Paginator},
+        validate_inner, Request,
+    },
     response::{marker::ListBody, ResponseFuture},
     routing::Route,
 };
@@ -139,4 +142,52 @@ impl<'a> GetChannelMessagesConfigured<'a> {
 
         self.http.request(request)
     }
+
+    /// Stream the messages across pages.
+    ///
+    /// Pages contain the number of messages set via [`limit`], or 100 if it
+    /// isn't set. When retrieving messages after a message, pages move
+    /// forward in time; otherwise they move backward. Messages around a
+    /// message are retrieved as a single page.
+    ///
+    /// [`limit`]: Self::limit
+    pub fn paginate(self) -> Paginator<'a, Message> {
+        Paginator::new(self)
+    }
+}
+
+impl<'a> Pager<'a> for GetChannelMessagesConfigured<'a> {
+    type Item = Message;
+
+    fn page_size(&self) -> u64 {
+        self.fields.limit.unwrap_or(100)
+    }
+
+    fn page(&self, limit: u64) -> PageFuture<'a, Message> {
+        let request = Request::from_route(&Route::GetMessages {
+            after: self.after.map(|x| x.0),
+            around: self.around.map(|x| x.0),
+            before: self.before.map(|x| x.0),
+            channel_id: self.channel_id.0,
+            limit: Some(limit),
+        });
+
+        paginate::list_page(self.http.request(request), limit)
+    }
+
+    fn advance(&mut self, items: &[Message]) -> bool {
+        let ids = items.iter().map(|message| message.id);
+
+        if self.around.is_some() {
+            false
+        } else if self.after.is_some() {
+            self.after = ids.max();
+
+            true
+        } else {
+            self.before = ids.min();
+
+            true
+        }
+    }
 }
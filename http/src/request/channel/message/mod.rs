@@ -2,6 +2,7 @@ pub mod create_message;
 pub mod crosspost_message;
 pub mod get_channel_messages;
 pub mod get_channel_messages_configured;
+pub mod purge_messages;
 pub mod update_message;
 
 mod delete_message;
@@ -13,6 +14,6 @@ pub use self::{
     delete_message::DeleteMessage, delete_messages::DeleteMessages,
     get_channel_messages::GetChannelMessages,
     get_channel_messages_configured::GetChannelMessagesConfigured, get_message::GetMessage,
-    update_message::UpdateMessage,
+    purge_messages::PurgeMessages, update_message::UpdateMessage,
 };
 pub use super::super::validate_inner::EmbedValidationError;
@@ -0,0 +1,650 @@
+//! Delete the messages of a channel matching a filter.
+
+use crate::{
+    client::Client,
+    error::Error as HttpError,
+    ratelimiting::Priority,
+    request::{self, AuditLogReason, AuditLogReasonError, Request},
+    response::{
+        marker::{EmptyBody, ListBody},
+        DeserializeBodyError,
+    },
+    routing::Route,
+};
+use futures_core::Stream;
+use serde::Serialize;
+use std::{
+    collections::VecDeque,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::{SystemTime, UNIX_EPOCH},
+};
+use twilight_model::{
+    channel::Message,
+    id::{ChannelId, MessageId, UserId},
+};
+
+/// Milliseconds since the Unix epoch of the first second of 2015, which
+/// message IDs are relative to.
+const DISCORD_EPOCH: u64 = 1_420_070_400_000;
+
+/// Maximum age of a message that can be bulk deleted in milliseconds.
+///
+/// Discord refuses to bulk delete messages older than 14 days, so a margin of
+/// 10 minutes is subtracted to account for the time spent waiting on
+/// ratelimits between requesting a page and deleting its messages.
+const BULK_DELETE_MAX_AGE: u64 = (14 * 24 * 60 - 10) * 60 * 1000;
+
+/// Maximum number of messages in a bulk delete and in a page of messages.
+const BATCH_SIZE: usize = 100;
+
+/// Whether a message is recent enough to be bulk deleted, given the current
+/// time in milliseconds since the Unix epoch.
+const fn is_bulk_deletable(message_id: MessageId, now: u64) -> bool {
+    let created_at = (message_id.0 >> 22) + DISCORD_EPOCH;
+
+    now.saturating_sub(created_at) < BULK_DELETE_MAX_AGE
+}
+
+/// Predicate over a message deciding whether it is deleted.
+type PurgeFilter<'a> = Box<dyn Fn(&Message) -> bool + Send + Sync + 'a>;
+
+/// Future resolving to the next step of a purge.
+type StepFuture<'a> = Pin<
+    Box<
+        dyn Future<Output = (PurgeState<'a>, Option<Result<PurgeProgress, PurgeError>>)>
+            + Send
+            + 'a,
+    >,
+>;
+
+/// The error returned when purging messages failed.
+#[derive(Debug)]
+pub struct PurgeError {
+    kind: PurgeErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl PurgeError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &PurgeErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (PurgeErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+
+    fn deserializing(source: DeserializeBodyError) -> Self {
+        Self {
+            kind: PurgeErrorType::Deserializing,
+            source: Some(Box::new(source)),
+        }
+    }
+
+    fn request(source: HttpError) -> Self {
+        Self {
+            kind: PurgeErrorType::Request,
+            source: Some(Box::new(source)),
+        }
+    }
+}
+
+impl Display for PurgeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            PurgeErrorType::Deserializing => f.write_str("failed to deserialize messages"),
+            PurgeErrorType::Request => f.write_str("failed to request messages or delete them"),
+        }
+    }
+}
+
+impl Error for PurgeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`PurgeError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum PurgeErrorType {
+    /// Deserializing a page of messages failed.
+    Deserializing,
+    /// Requesting a page of messages or deleting messages failed.
+    ///
+    /// The source is an [`Error`].
+    ///
+    /// [`Error`]: crate::Error
+    Request,
+}
+
+/// Progress of a [`Purge`], reported after every deletion.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PurgeProgress {
+    bulk: bool,
+    deleted: Vec<MessageId>,
+    scanned: u64,
+    total: u64,
+}
+
+impl PurgeProgress {
+    /// IDs of the messages deleted by this step.
+    pub fn deleted(&self) -> &[MessageId] {
+        &self.deleted
+    }
+
+    /// Whether the messages were deleted in bulk, rather than one by one due
+    /// to being too old.
+    pub const fn is_bulk(&self) -> bool {
+        self.bulk
+    }
+
+    /// Number of messages of the channel's history looked at so far.
+    pub const fn scanned(&self) -> u64 {
+        self.scanned
+    }
+
+    /// Number of messages deleted so far, including by this step.
+    pub const fn total(&self) -> u64 {
+        self.total
+    }
+}
+
+#[derive(Serialize)]
+struct DeleteMessagesFields<'a> {
+    messages: &'a [MessageId],
+}
+
+/// Delete the messages of a channel matching a filter.
+///
+/// The channel's history is paged through from the most recent message
+/// backward. Matching messages younger than 14 days are deleted in bulk in
+/// batches of up to 100, while older messages, which Discord refuses to bulk
+/// delete, are deleted one by one.
+///
+/// Requests are sent with the [`Priority::Background`] ratelimiting priority
+/// so that they don't hold back other requests to the channel.
+///
+/// # Examples
+///
+/// Delete the last 250 messages of a user in a channel, printing the
+/// progress:
+///
+/// ```rust,no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use futures_util::StreamExt;
+/// use twilight_http::Client;
+/// use twilight_model::id::{ChannelId, UserId};
+///
+/// let client = Client::new("my token".to_owned());
+///
+/// let mut purge = client
+///     .purge_messages(ChannelId(1))
+///     .author(UserId(2))
+///     .count(250)
+///     .exec();
+///
+/// while let Some(progress) = purge.next().await {
+///     println!("deleted {} messages", progress?.total());
+/// }
+/// # Ok(()) }
+/// ```
+#[must_use = "requests must be configured and executed"]
+pub struct PurgeMessages<'a> {
+    after: Option<MessageId>,
+    author: Option<UserId>,
+    before: Option<MessageId>,
+    channel_id: ChannelId,
+    count: Option<u64>,
+    filter: Option<PurgeFilter<'a>>,
+    http: &'a Client,
+    reason: Option<&'a str>,
+}
+
+impl<'a> PurgeMessages<'a> {
+    pub(crate) const fn new(http: &'a Client, channel_id: ChannelId) -> Self {
+        Self {
+            after: None,
+            author: None,
+            before: None,
+            channel_id,
+            count: None,
+            filter: None,
+            http,
+            reason: None,
+        }
+    }
+
+    /// Only delete messages sent after a message.
+    pub const fn after(mut self, message_id: MessageId) -> Self {
+        self.after = Some(message_id);
+
+        self
+    }
+
+    /// Only delete messages sent by a user.
+    pub const fn author(mut self, user_id: UserId) -> Self {
+        self.author = Some(user_id);
+
+        self
+    }
+
+    /// Only delete messages sent before a message.
+    pub const fn before(mut self, message_id: MessageId) -> Self {
+        self.before = Some(message_id);
+
+        self
+    }
+
+    /// Set the maximum number of messages to delete.
+    pub const fn count(mut self, count: u64) -> Self {
+        self.count = Some(count);
+
+        self
+    }
+
+    /// Only delete messages for which a predicate returns `true`.
+    pub fn filter(mut self, predicate: impl Fn(&Message) -> bool + Send + Sync + 'a) -> Self {
+        self.filter = Some(Box::new(predicate));
+
+        self
+    }
+
+    /// Execute the purge, returning a stream reporting its progress.
+    ///
+    /// The stream ends once no more messages match, or after an error.
+    pub fn exec(self) -> Purge<'a> {
+        Purge {
+            future: None,
+            state: Some(PurgeState {
+                after: self.after,
+                author: self.author,
+                before: self.before,
+                bulk: VecDeque::new(),
+                channel_id: self.channel_id,
+                exhausted: self.count == Some(0),
+                filter: self.filter,
+                http: self.http,
+                reason: self.reason,
+                remaining: self.count,
+                scanned: 0,
+                single: VecDeque::new(),
+                total: 0,
+            }),
+        }
+    }
+}
+
+impl<'a> AuditLogReason<'a> for PurgeMessages<'a> {
+    fn reason(mut self, reason: &'a str) -> Result<Self, AuditLogReasonError> {
+        self.reason.replace(AuditLogReasonError::validate(reason)?);
+
+        Ok(self)
+    }
+}
+
+/// Stream deleting the messages of a purge, yielding its progress after every
+/// deletion.
+///
+/// Created via [`PurgeMessages::exec`].
+#[must_use = "streams do nothing unless polled"]
+pub struct Purge<'a> {
+    future: Option<StepFuture<'a>>,
+    state: Option<PurgeState<'a>>,
+}
+
+impl Stream for Purge<'_> {
+    type Item = Result<PurgeProgress, PurgeError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        if this.future.is_none() {
+            match this.state.take() {
+                Some(state) => this.future = Some(Box::pin(state.step())),
+                None => return Poll::Ready(None),
+            }
+        }
+
+        let (state, item) = match this.future.as_mut().map(|future| future.as_mut().poll(cx)) {
+            Some(Poll::Ready(output)) => output,
+            Some(Poll::Pending) => return Poll::Pending,
+            None => return Poll::Ready(None),
+        };
+
+        this.future = None;
+
+        // The purge ends after an error.
+        if let Some(Ok(_)) = item {
+            this.state = Some(state);
+        }
+
+        Poll::Ready(item)
+    }
+}
+
+struct PurgeState<'a> {
+    after: Option<MessageId>,
+    author: Option<UserId>,
+    /// Cursor of the next page of messages.
+    before: Option<MessageId>,
+    /// Matching messages that can be bulk deleted.
+    bulk: VecDeque<MessageId>,
+    channel_id: ChannelId,
+    /// Whether no more pages of messages need to be requested.
+    exhausted: bool,
+    filter: Option<PurgeFilter<'a>>,
+    http: &'a Client,
+    reason: Option<&'a str>,
+    remaining: Option<u64>,
+    scanned: u64,
+    /// Matching messages that are too old to be bulk deleted.
+    single: VecDeque<MessageId>,
+    total: u64,
+}
+
+impl PurgeState<'_> {
+    /// Perform the next deletion, requesting pages of messages until there
+    /// is something to delete.
+    async fn step(mut self) -> (Self, Option<Result<PurgeProgress, PurgeError>>) {
+        loop {
+            // Older messages are only found once all bulk deletable messages
+            // have been found, in which case the batch is complete.
+            let flush = self.exhausted || !self.single.is_empty();
+
+            if self.bulk.len() >= BATCH_SIZE || (flush && self.bulk.len() >= 2) {
+                let len = self.bulk.len().min(BATCH_SIZE);
+                let ids = self.bulk.drain(..len).collect::<Vec<_>>();
+                let result = self.delete_bulk(&ids).await;
+
+                return self.progress(result, ids, true);
+            }
+
+            if flush {
+                if let Some(id) = self.bulk.pop_front() {
+                    self.single.push_front(id);
+                }
+            }
+
+            if let Some(id) = self.single.pop_front() {
+                let result = self.delete_single(id).await;
+
+                return self.progress(result, vec![id], false);
+            }
+
+            if self.exhausted {
+                return (self, None);
+            }
+
+            if let Err(source) = self.next_page().await {
+                return (self, Some(Err(source)));
+            }
+        }
+    }
+
+    fn progress(
+        mut self,
+        result: Result<(), PurgeError>,
+        deleted: Vec<MessageId>,
+        bulk: bool,
+    ) -> (Self, Option<Result<PurgeProgress, PurgeError>>) {
+        if let Err(source) = result {
+            return (self, Some(Err(source)));
+        }
+
+        self.total += deleted.len() as u64;
+
+        let progress = PurgeProgress {
+            bulk,
+            deleted,
+            scanned: self.scanned,
+            total: self.total,
+        };
+
+        (self, Some(Ok(progress)))
+    }
+
+    async fn next_page(&mut self) -> Result<(), PurgeError> {
+        let request = Request::builder(&Route::GetMessages {
+            after: None,
+            around: None,
+            before: self.before.map(|id| id.0),
+            channel_id: self.channel_id.0,
+            limit: Some(BATCH_SIZE as u64),
+        })
+        .priority(Priority::Background)
+        .build();
+
+        let messages = self
+            .http
+            .request::<ListBody<Message>>(request)
+            .await
+            .map_err(PurgeError::request)?
+            .models()
+            .await
+            .map_err(PurgeError::deserializing)?;
+
+        self.exhausted = messages.len() < BATCH_SIZE;
+        self.before = messages.iter().map(|message| message.id).min();
+
+        // Milliseconds since the Unix epoch won't overflow a `u64` for
+        // millions of years.
+        #[allow(clippy::cast_possible_truncation)]
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_millis() as u64);
+
+        for message in &messages {
+            if self.after.map_or(false, |after| message.id <= after) || self.remaining == Some(0) {
+                self.exhausted = true;
+
+                break;
+            }
+
+            self.scanned += 1;
+
+            if !self.matches(message) {
+                continue;
+            }
+
+            if let Some(remaining) = self.remaining.as_mut() {
+                *remaining -= 1;
+            }
+
+            if is_bulk_deletable(message.id, now) {
+                self.bulk.push_back(message.id);
+            } else {
+                self.single.push_back(message.id);
+            }
+        }
+
+        if self.remaining == Some(0) {
+            self.exhausted = true;
+        }
+
+        Ok(())
+    }
+
+    fn matches(&self, message: &Message) -> bool {
+        self.author
+            .map_or(true, |author| message.author.id == author)
+            && self.filter.as_ref().map_or(true, |filter| filter(message))
+    }
+
+    async fn delete_bulk(&self, ids: &[MessageId]) -> Result<(), PurgeError> {
+        let request = Request::builder(&Route::DeleteMessages {
+            channel_id: self.channel_id.0,
+        })
+        .json(&DeleteMessagesFields { messages: ids })
+        .map_err(PurgeError::request)?;
+
+        self.send(request).await
+    }
+
+    async fn delete_single(&self, id: MessageId) -> Result<(), PurgeError> {
+        let request = Request::builder(&Route::DeleteMessage {
+            channel_id: self.channel_id.0,
+            message_id: id.0,
+        });
+
+        self.send(request).await
+    }
+
+    async fn send(&self, mut request: request::RequestBuilder) -> Result<(), PurgeError> {
+        if let Some(reason) = self.reason {
+            request = request.headers(request::audit_header(reason).map_err(PurgeError::request)?);
+        }
+
+        let request = request.priority(Priority::Background).build();
+
+        self.http
+            .request::<EmptyBody>(request)
+            .await
+            .map_err(PurgeError::request)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_bulk_deletable, Purge, PurgeError, PurgeErrorType, DISCORD_EPOCH};
+    use crate::{
+        routing::Route,
+        transport::{MockResponse, MockTransport},
+        Client,
+    };
+    use futures_core::Stream;
+    use futures_util::StreamExt;
+    use static_assertions::assert_impl_all;
+    use std::{
+        error::Error,
+        fmt::Debug,
+        time::{SystemTime, UNIX_EPOCH},
+    };
+    use twilight_model::id::{ChannelId, MessageId, UserId};
+
+    assert_impl_all!(Purge<'_>: Send, Stream);
+    assert_impl_all!(PurgeErrorType: Debug, Send, Sync);
+    assert_impl_all!(PurgeError: Error, Send, Sync);
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn now() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64
+    }
+
+    /// ID of a message created a number of days ago.
+    fn id_days_ago(days: u64, increment: u64) -> u64 {
+        ((now() - days * 24 * 60 * 60 * 1000 - DISCORD_EPOCH) << 22) + increment
+    }
+
+    fn messages(messages: &[(u64, u64)]) -> Vec<u8> {
+        let messages = messages
+            .iter()
+            .map(|(id, author)| {
+                format!(
+                    r#"{{"attachments":[],"author":{{"avatar":null,"discriminator":"0001","id":"{}","username":"user"}},"channel_id":"1","content":"","edited_timestamp":null,"embeds":[],"id":"{}","mention_everyone":false,"mention_roles":[],"mentions":[],"pinned":false,"timestamp":"2021-01-01T00:00:00.000000+00:00","tts":false,"type":0}}"#,
+                    author, id
+                )
+            })
+            .collect::<Vec<_>>();
+
+        format!("[{}]", messages.join(",")).into_bytes()
+    }
+
+    #[test]
+    fn test_is_bulk_deletable() {
+        let now = now();
+
+        assert!(is_bulk_deletable(MessageId(id_days_ago(1, 0)), now));
+        assert!(is_bulk_deletable(MessageId(id_days_ago(13, 0)), now));
+        assert!(!is_bulk_deletable(MessageId(id_days_ago(14, 0)), now));
+        assert!(!is_bulk_deletable(MessageId(1), now));
+    }
+
+    #[tokio::test]
+    async fn test_purge() -> Result<(), Box<dyn Error>> {
+        let recent = [id_days_ago(1, 3), id_days_ago(1, 2), id_days_ago(1, 1)];
+        let old = [id_days_ago(20, 2), id_days_ago(20, 1)];
+
+        let transport = MockTransport::new();
+        transport
+            .on_route(
+                &Route::GetMessages {
+                    after: None,
+                    around: None,
+                    before: None,
+                    channel_id: 1,
+                    limit: Some(100),
+                },
+                MockResponse::new(200).body(messages(&[
+                    (recent[0], 2),
+                    (recent[1], 3),
+                    (recent[2], 2),
+                    (old[0], 2),
+                    (old[1], 2),
+                ])),
+            )
+            .on_route(
+                &Route::DeleteMessages { channel_id: 1 },
+                MockResponse::new(204),
+            )
+            .on_route(
+                &Route::DeleteMessage {
+                    channel_id: 1,
+                    message_id: old[0],
+                },
+                MockResponse::new(204),
+            );
+
+        let client = Client::builder()
+            .token("token".to_owned())
+            .transport(Box::new(transport.clone()))
+            .build();
+
+        let progress = client
+            .purge_messages(ChannelId(1))
+            .author(UserId(2))
+            .count(3)
+            .exec()
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()?;
+
+        assert_eq!(2, progress.len());
+        assert!(progress[0].is_bulk());
+        assert_eq!(
+            &[MessageId(recent[0]), MessageId(recent[2])],
+            progress[0].deleted()
+        );
+        assert!(!progress[1].is_bulk());
+        assert_eq!(&[MessageId(old[0])], progress[1].deleted());
+        assert_eq!(3, progress[1].total());
+        assert_eq!(4, progress[1].scanned());
+
+        let requests = transport.requests();
+        assert_eq!(3, requests.len());
+
+        Ok(())
+    }
+}
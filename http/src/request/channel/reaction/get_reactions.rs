@@ -1,7 +1,10 @@
 use super::RequestReactionType;
 use crate::{
     client::Client,
-    request::{validate_inner, Request},
+    request::{
+        paginate::{self, PageFuture, Pager, Paginator},
+        validate_inner, Request,
+    },
     response::{marker::ListBody, ResponseFuture},
     routing::Route,
 };
@@ -141,4 +144,41 @@ impl<'a> GetReactions<'a> {
 
         self.http.request(request)
     }
+
+    /// Stream the users that reacted across pages.
+    ///
+    /// Pages contain the number of users set via [`limit`], or 100 if it isn't
+    /// set, starting after the user set via [`after`].
+    ///
+    /// [`after`]: Self::after
+    /// [`limit`]: Self::limit
+    pub fn paginate(self) -> Paginator<'a, User> {
+        Paginator::new(self)
+    }
+}
+
+impl<'a> Pager<'a> for GetReactions<'a> {
+    type Item = User;
+
+    fn page_size(&self) -> u64 {
+        self.fields.limit.unwrap_or(100)
+    }
+
+    fn page(&self, limit: u64) -> PageFuture<'a, User> {
+        let request = Request::from_route(&Route::GetReactionUsers {
+            after: self.fields.after.map(|x| x.0),
+            channel_id: self.channel_id.0,
+            emoji: self.emoji,
+            limit: Some(limit),
+            message_id: self.message_id.0,
+        });
+
+        paginate::list_page(self.http.request(request), limit)
+    }
+
+    fn advance(&mut self, items: &[User]) -> bool {
+        self.fields.after = items.iter().map(|user| user.id).max();
+
+        true
+    }
 }
@@ -1,5 +1,16 @@
-use crate::{client::Client, request::Request, response::ResponseFuture, routing::Route};
-use twilight_model::{channel::thread::ThreadsListing, id::ChannelId};
+use crate::{
+    client::Client,
+    request::{
+        paginate::{PageFuture, Pager, Paginator},
+        Request,
+    },
+    response::ResponseFuture,
+    routing::Route,
+};
+use twilight_model::{
+    channel::{thread::ThreadsListing, Channel},
+    id::ChannelId,
+};
 
 /// Returns archived private threads in the channel that the current user has
 /// joined.
@@ -49,4 +60,40 @@ impl<'a> GetJoinedPrivateArchivedThreads<'a> {
 
         self.http.request(request)
     }
+
+    /// Stream the archived threads across pages, in descending order of
+    /// their ID.
+    ///
+    /// Pages contain the number of threads set via [`limit`], or 100 if it
+    /// isn't set, starting before the thread set via [`before`].
+    ///
+    /// [`before`]: Self::before
+    /// [`limit`]: Self::limit
+    pub fn paginate(self) -> Paginator<'a, Channel> {
+        Paginator::new(self)
+    }
+}
+
+impl<'a> Pager<'a> for GetJoinedPrivateArchivedThreads<'a> {
+    type Item = Channel;
+
+    fn page_size(&self) -> u64 {
+        self.limit.unwrap_or(100)
+    }
+
+    fn page(&self, limit: u64) -> PageFuture<'a, Channel> {
+        let request = Request::from_route(&Route::GetJoinedPrivateArchivedThreads {
+            before: self.before.map(|id| id.0),
+            channel_id: self.channel_id.0,
+            limit: Some(limit),
+        });
+
+        super::listing_page(self.http.request(request))
+    }
+
+    fn advance(&mut self, items: &[Channel]) -> bool {
+        self.before = items.iter().map(Channel::id).min();
+
+        true
+    }
 }
@@ -1,5 +1,16 @@
-use crate::{client::Client, request::Request, response::ResponseFuture, routing::Route};
-use twilight_model::{channel::thread::ThreadsListing, id::ChannelId};
+use crate::{
+    client::Client,
+    request::{
+        paginate::{PageFuture, Pager, Paginator},
+        Request,
+    },
+    response::ResponseFuture,
+    routing::Route,
+};
+use twilight_model::{
+    channel::{thread::ThreadsListing, Channel},
+    id::ChannelId,
+};
 
 /// Returns archived private threads in the channel.
 ///
@@ -51,4 +62,57 @@ impl<'a> GetPrivateArchivedThreads<'a> {
 
         self.http.request(request)
     }
+
+    /// Stream the archived threads across pages, in descending order of
+    /// their archive timestamp.
+    ///
+    /// Pages contain the number of threads set via [`limit`], or 100 if it
+    /// isn't set, starting before the timestamp set via [`before`].
+    ///
+    /// [`before`]: Self::before
+    /// [`limit`]: Self::limit
+    pub fn paginate(self) -> Paginator<'a, Channel> {
+        Paginator::new(PrivateArchivedThreadsPager {
+            before: self.before.map(ToOwned::to_owned),
+            channel_id: self.channel_id,
+            http: self.http,
+            limit: self.limit,
+        })
+    }
+}
+
+/// Pager owning the timestamp to request threads before, which moves with
+/// every page.
+struct PrivateArchivedThreadsPager<'a> {
+    before: Option<String>,
+    channel_id: ChannelId,
+    http: &'a Client,
+    limit: Option<u64>,
+}
+
+impl<'a> Pager<'a> for PrivateArchivedThreadsPager<'a> {
+    type Item = Channel;
+
+    fn page_size(&self) -> u64 {
+        self.limit.unwrap_or(100)
+    }
+
+    fn page(&self, limit: u64) -> PageFuture<'a, Channel> {
+        let request = Request::from_route(&Route::GetPrivateArchivedThreads {
+            before: self.before.as_deref(),
+            channel_id: self.channel_id.0,
+            limit: Some(limit),
+        });
+
+        super::listing_page(self.http.request(request))
+    }
+
+    fn advance(&mut self, items: &[Channel]) -> bool {
+        self.before = items
+            .last()
+            .and_then(super::archive_timestamp)
+            .map(ToOwned::to_owned);
+
+        self.before.is_some()
+    }
 }
@@ -20,11 +20,15 @@ pub use self::{
     update_thread::UpdateThread,
 };
 
+use crate::{
+    request::paginate::{Page, PageFuture, PaginateError},
+    response::ResponseFuture,
+};
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
 };
-use twilight_model::channel::ChannelType;
+use twilight_model::channel::{thread::ThreadsListing, Channel, ChannelType, GuildChannel};
 
 /// Returned when the thread can not be updated as configured.
 #[derive(Debug)]
@@ -96,3 +100,31 @@ pub enum ThreadValidationErrorType {
         kind: ChannelType,
     },
 }
+
+/// Request a page of archived threads.
+fn listing_page<'a>(future: ResponseFuture<ThreadsListing>) -> PageFuture<'a, Channel> {
+    Box::pin(async move {
+        let response = future.await.map_err(PaginateError::request)?;
+        let listing = response
+            .model()
+            .await
+            .map_err(PaginateError::deserializing)?;
+
+        Ok(Page::with_more(
+            listing.threads,
+            listing.has_more.unwrap_or(false),
+        ))
+    })
+}
+
+/// Timestamp at which a thread was archived, if the channel is a thread.
+fn archive_timestamp(channel: &Channel) -> Option<&str> {
+    let metadata = match channel {
+        Channel::Guild(GuildChannel::NewsThread(thread)) => &thread.thread_metadata,
+        Channel::Guild(GuildChannel::PrivateThread(thread)) => &thread.thread_metadata,
+        Channel::Guild(GuildChannel::PublicThread(thread)) => &thread.thread_metadata,
+        _ => return None,
+    };
+
+    Some(&metadata.archive_timestamp)
+}
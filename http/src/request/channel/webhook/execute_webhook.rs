@@ -4,7 +4,7 @@ use crate::{
     error::Error as HttpError,
     request::{
         validate_inner::{self, ComponentValidationError, ComponentValidationErrorType},
-        Form, Request,
+        FileUpload, Form, Request,
     },
     response::{marker::EmptyBody, ResponseFuture},
     routing::Route,
@@ -13,6 +13,7 @@ use serde::Serialize;
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
+    mem,
 };
 use twilight_model::{
     application::component::Component,
@@ -117,7 +118,8 @@ pub(crate) struct ExecuteWebhookFields<'a> {
 
 /// Execute a webhook, sending a message to its channel.
 ///
-/// You can only specify one of [`content`], [`embeds`], or [`files`].
+/// You can only specify one of [`content`], [`embeds`], or [`files`] and
+/// [`file_uploads`].
 ///
 /// # Examples
 ///
@@ -140,10 +142,12 @@ pub(crate) struct ExecuteWebhookFields<'a> {
 ///
 /// [`content`]: Self::content
 /// [`embeds`]: Self::embeds
+/// [`file_uploads`]: Self::file_uploads
 /// [`files`]: Self::files
 #[must_use = "requests must be configured and executed"]
 pub struct ExecuteWebhook<'a> {
     pub(crate) fields: ExecuteWebhookFields<'a>,
+    file_uploads: Vec<FileUpload>,
     files: &'a [(&'a str, &'a [u8])],
     pub(super) http: &'a Client,
     token: &'a str,
@@ -164,6 +168,7 @@ impl<'a> ExecuteWebhook<'a> {
                 username: None,
                 allowed_mentions: None,
             },
+            file_uploads: Vec::new(),
             files: &[],
             http,
             token,
@@ -233,6 +238,20 @@ impl<'a> ExecuteWebhook<'a> {
         self
     }
 
+    /// Attach files whose contents are streamed into the request instead of
+    /// being buffered in memory.
+    ///
+    /// The files are attached after those set via [`files`]. Calling this
+    /// method will clear any previous calls.
+    ///
+    /// [`files`]: Self::files
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn file_uploads(mut self, file_uploads: Vec<FileUpload>) -> Self {
+        self.file_uploads = file_uploads;
+
+        self
+    }
+
     /// Attach multiple files to the webhook.
     pub const fn files(mut self, files: &'a [(&'a str, &'a [u8])]) -> Self {
         self.files = files;
@@ -334,7 +353,7 @@ impl<'a> ExecuteWebhook<'a> {
 
     // `self` needs to be consumed and the client returned due to parameters
     // being consumed in request construction.
-    pub(super) fn request(&self, wait: bool) -> Result<Request, HttpError> {
+    pub(super) fn request(&mut self, wait: bool) -> Result<Request, HttpError> {
         let mut request = Request::builder(&Route::ExecuteWebhook {
             token: self.token,
             wait: Some(wait),
@@ -345,13 +364,21 @@ impl<'a> ExecuteWebhook<'a> {
         // webhook token.
         request = request.use_authorization_token(false);
 
-        if !self.files.is_empty() || self.fields.payload_json.is_some() {
+        if !self.files.is_empty()
+            || !self.file_uploads.is_empty()
+            || self.fields.payload_json.is_some()
+        {
             let mut form = Form::new();
 
             for (index, (name, file)) in self.files.iter().enumerate() {
                 form.file(format!("{}", index).as_bytes(), name.as_bytes(), file);
             }
 
+            for (index, upload) in mem::take(&mut self.file_uploads).into_iter().enumerate() {
+                let index = self.files.len() + index;
+                form.file_upload(format!("{}", index).as_bytes(), upload);
+            }
+
             if let Some(payload_json) = &self.fields.payload_json {
                 form.payload_json(payload_json);
             } else {
@@ -371,7 +398,7 @@ impl<'a> ExecuteWebhook<'a> {
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
-    pub fn exec(self) -> ResponseFuture<EmptyBody> {
+    pub fn exec(mut self) -> ResponseFuture<EmptyBody> {
         match self.request(false) {
             Ok(request) => self.http.request(request),
             Err(source) => ResponseFuture::error(source),
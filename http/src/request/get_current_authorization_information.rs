@@ -0,0 +1,26 @@
+use crate::{client::Client, request::Request, response::ResponseFuture, routing::Route};
+use twilight_model::oauth::CurrentAuthorizationInformation;
+
+/// Get information about the authorization of the client's `OAuth2` access
+/// token.
+///
+/// The client must be authorized with a `Bearer` token.
+#[must_use = "requests must be configured and executed"]
+pub struct GetCurrentAuthorizationInformation<'a> {
+    http: &'a Client,
+}
+
+impl<'a> GetCurrentAuthorizationInformation<'a> {
+    pub(crate) const fn new(http: &'a Client) -> Self {
+        Self { http }
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<CurrentAuthorizationInformation> {
+        let request = Request::from_route(&Route::GetCurrentAuthorizationInformation);
+
+        self.http.request(request)
+    }
+}
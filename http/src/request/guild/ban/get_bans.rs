@@ -1,10 +1,74 @@
 use crate::{
     client::Client,
-    request::Request,
+    request::{
+        paginate::{self, PageFuture, Pager, Paginator},
+        validate_inner, Request,
+    },
     response::{marker::ListBody, ResponseFuture},
     routing::Route,
 };
-use twilight_model::{guild::Ban, id::GuildId};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    guild::Ban,
+    id::{GuildId, UserId},
+};
+
+/// The error created when the bans can not be fetched as configured.
+#[derive(Debug)]
+pub struct GetBansError {
+    kind: GetBansErrorType,
+}
+
+impl GetBansError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &GetBansErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (GetBansErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, None)
+    }
+}
+
+impl Display for GetBansError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            GetBansErrorType::LimitInvalid { .. } => f.write_str("the limit is invalid"),
+        }
+    }
+}
+
+impl Error for GetBansError {}
+
+/// Type of [`GetBansError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum GetBansErrorType {
+    /// The limit is either 0 or more than 1000.
+    LimitInvalid {
+        /// Provided limit.
+        limit: u64,
+    },
+}
+
+struct GetBansFields {
+    after: Option<UserId>,
+    before: Option<UserId>,
+    limit: Option<u64>,
+}
 
 /// Retrieve the bans for a guild.
 ///
@@ -27,23 +91,113 @@ use twilight_model::{guild::Ban, id::GuildId};
 /// ```
 #[must_use = "requests must be configured and executed"]
 pub struct GetBans<'a> {
+    fields: GetBansFields,
     guild_id: GuildId,
     http: &'a Client,
 }
 
 impl<'a> GetBans<'a> {
     pub(crate) const fn new(http: &'a Client, guild_id: GuildId) -> Self {
-        Self { guild_id, http }
+        Self {
+            fields: GetBansFields {
+                after: None,
+                before: None,
+                limit: None,
+            },
+            guild_id,
+            http,
+        }
+    }
+
+    /// Set the user ID to get bans after.
+    pub const fn after(mut self, after: UserId) -> Self {
+        self.fields.after = Some(after);
+
+        self
+    }
+
+    /// Set the user ID to get bans before.
+    pub const fn before(mut self, before: UserId) -> Self {
+        self.fields.before = Some(before);
+
+        self
+    }
+
+    /// Set the maximum number of bans to retrieve.
+    ///
+    /// The limit must be greater than 0 and at most 1000. Discord defaults
+    /// the limit to 1000.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`GetBansErrorType::LimitInvalid`] error type if the limit is
+    /// 0 or greater than 1000.
+    pub const fn limit(mut self, limit: u64) -> Result<Self, GetBansError> {
+        if !validate_inner::get_guild_bans_limit(limit) {
+            return Err(GetBansError {
+                kind: GetBansErrorType::LimitInvalid { limit },
+            });
+        }
+
+        self.fields.limit = Some(limit);
+
+        Ok(self)
     }
 
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
     pub fn exec(self) -> ResponseFuture<ListBody<Ban>> {
-        let request = Request::from_route(&Route::GetBans {
+        let request = Request::from_route(&Route::GetBansWithParameters {
+            after: self.fields.after.map(|x| x.0),
+            before: self.fields.before.map(|x| x.0),
             guild_id: self.guild_id.0,
+            limit: self.fields.limit,
         });
 
         self.http.request(request)
     }
+
+    /// Stream the bans of the guild across pages.
+    ///
+    /// Pages contain the number of bans set via [`limit`], or 1000 if it isn't
+    /// set. If only [`before`] is set the bans are streamed backwards,
+    /// otherwise they are streamed forwards starting after the user set via
+    /// [`after`].
+    ///
+    /// [`after`]: Self::after
+    /// [`before`]: Self::before
+    /// [`limit`]: Self::limit
+    pub fn paginate(self) -> Paginator<'a, Ban> {
+        Paginator::new(self)
+    }
+}
+
+impl<'a> Pager<'a> for GetBans<'a> {
+    type Item = Ban;
+
+    fn page_size(&self) -> u64 {
+        self.fields.limit.unwrap_or(1000)
+    }
+
+    fn page(&self, limit: u64) -> PageFuture<'a, Ban> {
+        let request = Request::from_route(&Route::GetBansWithParameters {
+            after: self.fields.after.map(|x| x.0),
+            before: self.fields.before.map(|x| x.0),
+            guild_id: self.guild_id.0,
+            limit: Some(limit),
+        });
+
+        paginate::list_page(self.http.request(request), limit)
+    }
+
+    fn advance(&mut self, items: &[Ban]) -> bool {
+        if self.fields.after.is_none() && self.fields.before.is_some() {
+            self.fields.before = items.iter().map(|ban| ban.user.id).min();
+        } else {
+            self.fields.after = items.iter().map(|ban| ban.user.id).max();
+        }
+
+        true
+    }
 }
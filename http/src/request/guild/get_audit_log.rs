@@ -1,6 +1,9 @@
 use crate::{
     client::Client,
-    request::{validate_inner, Request},
+    request::{
+        paginate::{Page, PageFuture, Pager, PaginateError, Paginator},
+        validate_inner, Request,
+    },
     response::ResponseFuture,
     routing::Route,
 };
@@ -9,7 +12,7 @@ use std::{
     fmt::{Display, Formatter, Result as FmtResult},
 };
 use twilight_model::{
-    guild::audit_log::{AuditLog, AuditLogEventType},
+    guild::audit_log::{AuditLog, AuditLogEntry, AuditLogEventType},
     id::{GuildId, UserId},
 };
 
@@ -174,4 +177,54 @@ impl<'a> GetAuditLog<'a> {
 
         self.http.request(request)
     }
+
+    /// Stream the entries of the audit log across pages, starting with the
+    /// most recent entry, or the entry before the one set via [`before`], and
+    /// moving backward in time.
+    ///
+    /// Pages contain the number of entries set via [`limit`], or 100 if it
+    /// isn't set. Only the entries of the audit log are yielded; the users,
+    /// webhooks, and integrations referenced by them are not.
+    ///
+    /// [`before`]: Self::before
+    /// [`limit`]: Self::limit
+    pub fn paginate(self) -> Paginator<'a, AuditLogEntry> {
+        Paginator::new(self)
+    }
+}
+
+impl<'a> Pager<'a> for GetAuditLog<'a> {
+    type Item = AuditLogEntry;
+
+    fn page_size(&self) -> u64 {
+        self.fields.limit.unwrap_or(100)
+    }
+
+    fn page(&self, limit: u64) -> PageFuture<'a, AuditLogEntry> {
+        let request = Request::from_route(&Route::GetAuditLogs {
+            action_type: self.fields.action_type.map(|x| x as u64),
+            before: self.fields.before,
+            guild_id: self.guild_id.0,
+            limit: Some(limit),
+            user_id: self.fields.user_id.map(|x| x.0),
+        });
+
+        let future = self.http.request::<AuditLog>(request);
+
+        Box::pin(async move {
+            let response = future.await.map_err(PaginateError::request)?;
+            let audit_log = response
+                .model()
+                .await
+                .map_err(PaginateError::deserializing)?;
+
+            Ok(Page::new(audit_log.entries, limit))
+        })
+    }
+
+    fn advance(&mut self, items: &[AuditLogEntry]) -> bool {
+        self.fields.before = items.iter().map(|entry| entry.id.0).min();
+
+        true
+    }
 }
@@ -1,6 +1,9 @@
 use crate::{
     client::Client,
-    request::{validate_inner, Request},
+    request::{
+        paginate::{Page, PageFuture, Pager, PaginateError, Paginator},
+        validate_inner, Request,
+    },
     response::{marker::MemberListBody, ResponseFuture},
     routing::Route,
 };
@@ -8,7 +11,10 @@ use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
 };
-use twilight_model::id::{GuildId, UserId};
+use twilight_model::{
+    guild::Member,
+    id::{GuildId, UserId},
+};
 
 /// The error created when the members can not be fetched as configured.
 #[derive(Debug)]
@@ -161,4 +167,51 @@ impl<'a> GetGuildMembers<'a> {
 
         future
     }
+
+    /// Stream the members of the guild across pages.
+    ///
+    /// Pages contain the number of members set via [`limit`], or 1000 if it
+    /// isn't set, starting after the user set via [`after`].
+    ///
+    /// [`after`]: Self::after
+    /// [`limit`]: Self::limit
+    pub fn paginate(self) -> Paginator<'a, Member> {
+        Paginator::new(self)
+    }
+}
+
+impl<'a> Pager<'a> for GetGuildMembers<'a> {
+    type Item = Member;
+
+    fn page_size(&self) -> u64 {
+        self.fields.limit.unwrap_or(1000)
+    }
+
+    fn page(&self, limit: u64) -> PageFuture<'a, Member> {
+        let request = Request::from_route(&Route::GetGuildMembers {
+            after: self.fields.after.map(|x| x.0),
+            guild_id: self.guild_id.0,
+            limit: Some(limit),
+            presences: self.fields.presences,
+        });
+
+        let mut future = self.http.request::<MemberListBody>(request);
+        future.set_guild_id(self.guild_id);
+
+        Box::pin(async move {
+            let response = future.await.map_err(PaginateError::request)?;
+            let members = response
+                .models()
+                .await
+                .map_err(PaginateError::deserializing)?;
+
+            Ok(Page::new(members, limit))
+        })
+    }
+
+    fn advance(&mut self, items: &[Member]) -> bool {
+        self.fields.after = items.iter().map(|member| member.user.id).max();
+
+        true
+    }
 }
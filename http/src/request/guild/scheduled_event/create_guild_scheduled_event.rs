@@ -0,0 +1,322 @@
+use super::{
+    EntityMetadataFields, ScheduledEventValidationError, ScheduledEventValidationErrorType,
+};
+use crate::{
+    client::Client,
+    request::{self, validate_inner, AuditLogReason, AuditLogReasonError, Request},
+    response::ResponseFuture,
+    routing::Route,
+};
+use serde::Serialize;
+use twilight_model::{
+    id::{ChannelId, GuildId},
+    scheduled_event::{EntityType, GuildScheduledEvent, PrivacyLevel, RecurrenceRule},
+};
+
+#[derive(Serialize)]
+struct CreateGuildScheduledEventFields<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    channel_id: Option<ChannelId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    entity_metadata: Option<EntityMetadataFields<'a>>,
+    entity_type: EntityType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    image: Option<&'a str>,
+    name: &'a str,
+    privacy_level: PrivacyLevel,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recurrence_rule: Option<&'a RecurrenceRule>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scheduled_end_time: Option<&'a str>,
+    scheduled_start_time: &'a str,
+}
+
+/// Create a scheduled event in a guild.
+///
+/// Stage instance and voice events must be given a [channel], while external
+/// events must be given a [location] and an [end time].
+///
+/// Requires the [`MANAGE_EVENTS`] permission.
+///
+/// # Examples
+///
+/// Create an event taking place in a voice channel:
+///
+/// ```rust,no_run
+/// use twilight_http::Client;
+/// use twilight_model::{
+///     id::{ChannelId, GuildId},
+///     scheduled_event::EntityType,
+/// };
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new("my token".to_owned());
+///
+/// let event = client
+///     .create_guild_scheduled_event(
+///         GuildId(1),
+///         EntityType::Voice,
+///         "movie night",
+///         "2021-01-01T20:00:00+00:00",
+///     )?
+///     .channel_id(ChannelId(2))
+///     .exec()
+///     .await?
+///     .model()
+///     .await?;
+///
+/// println!("created event {}", event.id);
+/// # Ok(()) }
+/// ```
+///
+/// [`MANAGE_EVENTS`]: twilight_model::guild::Permissions::MANAGE_EVENTS
+/// [channel]: Self::channel_id
+/// [end time]: Self::scheduled_end_time
+/// [location]: Self::location
+#[must_use = "requests must be configured and executed"]
+pub struct CreateGuildScheduledEvent<'a> {
+    fields: CreateGuildScheduledEventFields<'a>,
+    guild_id: GuildId,
+    http: &'a Client,
+    reason: Option<&'a str>,
+}
+
+impl<'a> CreateGuildScheduledEvent<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        guild_id: GuildId,
+        entity_type: EntityType,
+        name: &'a str,
+        scheduled_start_time: &'a str,
+    ) -> Result<Self, ScheduledEventValidationError> {
+        if !validate_inner::scheduled_event_name(name) {
+            return Err(ScheduledEventValidationError {
+                kind: ScheduledEventValidationErrorType::NameInvalid,
+            });
+        }
+
+        Ok(Self {
+            fields: CreateGuildScheduledEventFields {
+                channel_id: None,
+                description: None,
+                entity_metadata: None,
+                entity_type,
+                image: None,
+                name,
+                privacy_level: PrivacyLevel::GuildOnly,
+                recurrence_rule: None,
+                scheduled_end_time: None,
+                scheduled_start_time,
+            },
+            guild_id,
+            http,
+            reason: None,
+        })
+    }
+
+    /// Set the stage or voice channel the event takes place in.
+    pub const fn channel_id(mut self, channel_id: ChannelId) -> Self {
+        self.fields.channel_id = Some(channel_id);
+
+        self
+    }
+
+    /// Set the description of the event.
+    ///
+    /// The description must be between 1 and 1000 characters in length.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ScheduledEventValidationErrorType::DescriptionInvalid`]
+    /// error type if the description is invalid.
+    pub fn description(
+        mut self,
+        description: &'a str,
+    ) -> Result<Self, ScheduledEventValidationError> {
+        if !validate_inner::scheduled_event_description(description) {
+            return Err(ScheduledEventValidationError {
+                kind: ScheduledEventValidationErrorType::DescriptionInvalid,
+            });
+        }
+
+        self.fields.description = Some(description);
+
+        Ok(self)
+    }
+
+    /// Set the cover image of the event.
+    ///
+    /// The image must be a Data URI, in the form of
+    /// `data:image/{type};base64,{data}` where `{type}` is the image MIME
+    /// type and `{data}` is the base64-encoded image.
+    pub const fn image(mut self, image: &'a str) -> Self {
+        self.fields.image = Some(image);
+
+        self
+    }
+
+    /// Set the location of an external event.
+    ///
+    /// The location must be between 1 and 100 characters in length.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ScheduledEventValidationErrorType::LocationInvalid`] error
+    /// type if the location is invalid.
+    pub fn location(mut self, location: &'a str) -> Result<Self, ScheduledEventValidationError> {
+        if !validate_inner::scheduled_event_location(location) {
+            return Err(ScheduledEventValidationError {
+                kind: ScheduledEventValidationErrorType::LocationInvalid,
+            });
+        }
+
+        self.fields.entity_metadata = Some(EntityMetadataFields { location });
+
+        Ok(self)
+    }
+
+    /// Set who can see the event.
+    ///
+    /// Defaults to [`PrivacyLevel::GuildOnly`].
+    pub const fn privacy_level(mut self, privacy_level: PrivacyLevel) -> Self {
+        self.fields.privacy_level = privacy_level;
+
+        self
+    }
+
+    /// Set the rules by which the event repeats.
+    pub const fn recurrence_rule(mut self, recurrence_rule: &'a RecurrenceRule) -> Self {
+        self.fields.recurrence_rule = Some(recurrence_rule);
+
+        self
+    }
+
+    /// Set the ISO 8601 timestamp of when the event ends.
+    pub const fn scheduled_end_time(mut self, scheduled_end_time: &'a str) -> Self {
+        self.fields.scheduled_end_time = Some(scheduled_end_time);
+
+        self
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<GuildScheduledEvent> {
+        let mut request = Request::builder(&Route::CreateGuildScheduledEvent {
+            guild_id: self.guild_id.0,
+        });
+
+        request = match request.json(&self.fields) {
+            Ok(request) => request,
+            Err(source) => return ResponseFuture::error(source),
+        };
+
+        if let Some(reason) = self.reason.as_ref() {
+            let header = match request::audit_header(reason) {
+                Ok(header) => header,
+                Err(source) => return ResponseFuture::error(source),
+            };
+
+            request = request.headers(header);
+        }
+
+        self.http.request(request.build())
+    }
+}
+
+impl<'a> AuditLogReason<'a> for CreateGuildScheduledEvent<'a> {
+    fn reason(mut self, reason: &'a str) -> Result<Self, AuditLogReasonError> {
+        self.reason.replace(AuditLogReasonError::validate(reason)?);
+
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::ScheduledEventValidationErrorType;
+    use crate::client::Client;
+    use serde_json::json;
+    use twilight_model::{
+        id::{ChannelId, GuildId},
+        scheduled_event::{EntityType, RecurrenceFrequency, RecurrenceRule, RecurrenceWeekday},
+    };
+
+    #[test]
+    fn test_validation() {
+        let client = Client::new("token".to_owned());
+        let start = "2021-01-01T00:00:00+00:00";
+
+        assert!(matches!(
+            client
+                .create_guild_scheduled_event(GuildId(1), EntityType::Voice, "", start)
+                .err()
+                .map(|e| e.into_parts().0),
+            Some(ScheduledEventValidationErrorType::NameInvalid)
+        ));
+
+        let long = "a".repeat(101);
+        assert!(matches!(
+            client
+                .create_guild_scheduled_event(GuildId(1), EntityType::External, "event", start)
+                .and_then(|request| request.location(&long))
+                .err()
+                .map(|e| e.into_parts().0),
+            Some(ScheduledEventValidationErrorType::LocationInvalid)
+        ));
+    }
+
+    #[test]
+    fn test_recurrence_rule() {
+        let client = Client::new("token".to_owned());
+        let rule = RecurrenceRule {
+            by_month: None,
+            by_month_day: None,
+            by_n_weekday: None,
+            by_weekday: Some(vec![RecurrenceWeekday::Friday]),
+            by_year_day: None,
+            count: None,
+            end: None,
+            frequency: RecurrenceFrequency::Weekly,
+            interval: 1,
+            start: "2021-01-01T20:00:00+00:00".to_owned(),
+        };
+
+        let builder = client
+            .create_guild_scheduled_event(
+                GuildId(1),
+                EntityType::Voice,
+                "movie night",
+                "2021-01-01T20:00:00+00:00",
+            )
+            .unwrap()
+            .channel_id(ChannelId(2))
+            .recurrence_rule(&rule);
+
+        assert_eq!(
+            serde_json::to_value(&builder.fields).unwrap(),
+            json!({
+                "channel_id": "2",
+                "entity_type": 2,
+                "name": "movie night",
+                "privacy_level": 2,
+                "recurrence_rule": {
+                    "by_month": null,
+                    "by_month_day": null,
+                    "by_n_weekday": null,
+                    "by_weekday": [4],
+                    "by_year_day": null,
+                    "count": null,
+                    "end": null,
+                    "frequency": 2,
+                    "interval": 1,
+                    "start": "2021-01-01T20:00:00+00:00",
+                },
+                "scheduled_start_time": "2021-01-01T20:00:00+00:00",
+            })
+        );
+    }
+}
@@ -0,0 +1,62 @@
+use crate::{
+    client::Client,
+    request::Request,
+    response::{marker::EmptyBody, ResponseFuture},
+    routing::Route,
+};
+use twilight_model::id::{GuildId, ScheduledEventId};
+
+/// Delete a scheduled event in a guild.
+///
+/// Requires the [`MANAGE_EVENTS`] permission.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use twilight_http::Client;
+/// use twilight_model::id::{GuildId, ScheduledEventId};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new("my token".to_owned());
+///
+/// client
+///     .delete_guild_scheduled_event(GuildId(1), ScheduledEventId(2))
+///     .exec()
+///     .await?;
+/// # Ok(()) }
+/// ```
+///
+/// [`MANAGE_EVENTS`]: twilight_model::guild::Permissions::MANAGE_EVENTS
+#[must_use = "requests must be configured and executed"]
+pub struct DeleteGuildScheduledEvent<'a> {
+    guild_id: GuildId,
+    http: &'a Client,
+    scheduled_event_id: ScheduledEventId,
+}
+
+impl<'a> DeleteGuildScheduledEvent<'a> {
+    pub(crate) const fn new(
+        http: &'a Client,
+        guild_id: GuildId,
+        scheduled_event_id: ScheduledEventId,
+    ) -> Self {
+        Self {
+            guild_id,
+            http,
+            scheduled_event_id,
+        }
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<EmptyBody> {
+        let request = Request::from_route(&Route::DeleteGuildScheduledEvent {
+            guild_id: self.guild_id.0,
+            scheduled_event_id: self.scheduled_event_id.0,
+        });
+
+        self.http.request(request)
+    }
+}
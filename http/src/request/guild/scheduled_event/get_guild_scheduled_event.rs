@@ -0,0 +1,71 @@
+use crate::{client::Client, request::Request, response::ResponseFuture, routing::Route};
+use twilight_model::{
+    id::{GuildId, ScheduledEventId},
+    scheduled_event::GuildScheduledEvent,
+};
+
+/// Get a scheduled event in a guild.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use twilight_http::Client;
+/// use twilight_model::id::{GuildId, ScheduledEventId};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new("my token".to_owned());
+///
+/// let event = client
+///     .guild_scheduled_event(GuildId(1), ScheduledEventId(2))
+///     .with_user_count(true)
+///     .exec()
+///     .await?
+///     .model()
+///     .await?;
+///
+/// println!("{:?} users are interested", event.user_count);
+/// # Ok(()) }
+/// ```
+#[must_use = "requests must be configured and executed"]
+pub struct GetGuildScheduledEvent<'a> {
+    guild_id: GuildId,
+    http: &'a Client,
+    scheduled_event_id: ScheduledEventId,
+    with_user_count: bool,
+}
+
+impl<'a> GetGuildScheduledEvent<'a> {
+    pub(crate) const fn new(
+        http: &'a Client,
+        guild_id: GuildId,
+        scheduled_event_id: ScheduledEventId,
+    ) -> Self {
+        Self {
+            guild_id,
+            http,
+            scheduled_event_id,
+            with_user_count: false,
+        }
+    }
+
+    /// Set whether to include the number of users subscribed to the event.
+    pub const fn with_user_count(mut self, with_user_count: bool) -> Self {
+        self.with_user_count = with_user_count;
+
+        self
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<GuildScheduledEvent> {
+        let request = Request::from_route(&Route::GetGuildScheduledEvent {
+            guild_id: self.guild_id.0,
+            scheduled_event_id: self.scheduled_event_id.0,
+            with_user_count: self.with_user_count,
+        });
+
+        self.http.request(request)
+    }
+}
@@ -0,0 +1,235 @@
+use crate::{
+    client::Client,
+    request::{
+        paginate::{self, PageFuture, Pager, Paginator},
+        validate_inner, Request,
+    },
+    response::{marker::ListBody, ResponseFuture},
+    routing::Route,
+};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    id::{GuildId, ScheduledEventId, UserId},
+    scheduled_event::GuildScheduledEventUser,
+};
+
+/// The error created when the users can not be fetched as configured.
+#[derive(Debug)]
+pub struct GetGuildScheduledEventUsersError {
+    kind: GetGuildScheduledEventUsersErrorType,
+}
+
+impl GetGuildScheduledEventUsersError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &GetGuildScheduledEventUsersErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        GetGuildScheduledEventUsersErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, None)
+    }
+}
+
+impl Display for GetGuildScheduledEventUsersError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            GetGuildScheduledEventUsersErrorType::LimitInvalid { .. } => {
+                f.write_str("the limit is invalid")
+            }
+        }
+    }
+}
+
+impl Error for GetGuildScheduledEventUsersError {}
+
+/// Type of [`GetGuildScheduledEventUsersError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum GetGuildScheduledEventUsersErrorType {
+    /// The limit is either 0 or more than 100.
+    LimitInvalid {
+        /// Provided limit.
+        limit: u64,
+    },
+}
+
+struct GetGuildScheduledEventUsersFields {
+    after: Option<UserId>,
+    before: Option<UserId>,
+    limit: Option<u64>,
+    with_member: bool,
+}
+
+/// Get the users subscribed to a scheduled event in a guild.
+///
+/// # Examples
+///
+/// Stream every user subscribed to an event, including their guild member
+/// data:
+///
+/// ```rust,no_run
+/// use futures_util::StreamExt;
+/// use twilight_http::Client;
+/// use twilight_model::id::{GuildId, ScheduledEventId};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new("my token".to_owned());
+///
+/// let mut users = client
+///     .guild_scheduled_event_users(GuildId(1), ScheduledEventId(2))
+///     .with_member(true)
+///     .paginate();
+///
+/// while let Some(user) = users.next().await {
+///     println!("{}", user?.user.name);
+/// }
+/// # Ok(()) }
+/// ```
+#[must_use = "requests must be configured and executed"]
+pub struct GetGuildScheduledEventUsers<'a> {
+    fields: GetGuildScheduledEventUsersFields,
+    guild_id: GuildId,
+    http: &'a Client,
+    scheduled_event_id: ScheduledEventId,
+}
+
+impl<'a> GetGuildScheduledEventUsers<'a> {
+    pub(crate) const fn new(
+        http: &'a Client,
+        guild_id: GuildId,
+        scheduled_event_id: ScheduledEventId,
+    ) -> Self {
+        Self {
+            fields: GetGuildScheduledEventUsersFields {
+                after: None,
+                before: None,
+                limit: None,
+                with_member: false,
+            },
+            guild_id,
+            http,
+            scheduled_event_id,
+        }
+    }
+
+    /// Set the user ID to get users after.
+    pub const fn after(mut self, after: UserId) -> Self {
+        self.fields.after = Some(after);
+
+        self
+    }
+
+    /// Set the user ID to get users before.
+    pub const fn before(mut self, before: UserId) -> Self {
+        self.fields.before = Some(before);
+
+        self
+    }
+
+    /// Set the maximum number of users to retrieve.
+    ///
+    /// The limit must be greater than 0 and at most 100. Discord defaults the
+    /// limit to 100.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`GetGuildScheduledEventUsersErrorType::LimitInvalid`] error
+    /// type if the limit is 0 or greater than 100.
+    pub const fn limit(mut self, limit: u64) -> Result<Self, GetGuildScheduledEventUsersError> {
+        if !validate_inner::get_guild_scheduled_event_users_limit(limit) {
+            return Err(GetGuildScheduledEventUsersError {
+                kind: GetGuildScheduledEventUsersErrorType::LimitInvalid { limit },
+            });
+        }
+
+        self.fields.limit = Some(limit);
+
+        Ok(self)
+    }
+
+    /// Set whether to include the guild member data of the users.
+    pub const fn with_member(mut self, with_member: bool) -> Self {
+        self.fields.with_member = with_member;
+
+        self
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<ListBody<GuildScheduledEventUser>> {
+        let request = Request::from_route(&self.route(self.fields.limit));
+
+        self.http.request(request)
+    }
+
+    /// Stream the users subscribed to the event across pages.
+    ///
+    /// Pages contain the number of users set via [`limit`], or 100 if it
+    /// isn't set. If only [`before`] is set the users are streamed backwards,
+    /// otherwise they are streamed forwards starting after the user set via
+    /// [`after`].
+    ///
+    /// [`after`]: Self::after
+    /// [`before`]: Self::before
+    /// [`limit`]: Self::limit
+    pub fn paginate(self) -> Paginator<'a, GuildScheduledEventUser> {
+        Paginator::new(self)
+    }
+
+    fn route(&self, limit: Option<u64>) -> Route<'static> {
+        Route::GetGuildScheduledEventUsers {
+            after: self.fields.after.map(|x| x.0),
+            before: self.fields.before.map(|x| x.0),
+            guild_id: self.guild_id.0,
+            limit,
+            scheduled_event_id: self.scheduled_event_id.0,
+            with_member: self.fields.with_member,
+        }
+    }
+}
+
+impl<'a> Pager<'a> for GetGuildScheduledEventUsers<'a> {
+    type Item = GuildScheduledEventUser;
+
+    fn page_size(&self) -> u64 {
+        self.fields.limit.unwrap_or(100)
+    }
+
+    fn page(&self, limit: u64) -> PageFuture<'a, GuildScheduledEventUser> {
+        let request = Request::from_route(&self.route(Some(limit)));
+
+        paginate::list_page(self.http.request(request), limit)
+    }
+
+    fn advance(&mut self, items: &[GuildScheduledEventUser]) -> bool {
+        let ids = items.iter().map(|item| item.user.id);
+
+        if self.fields.after.is_none() && self.fields.before.is_some() {
+            self.fields.before = ids.min();
+        } else {
+            self.fields.after = ids.max();
+        }
+
+        true
+    }
+}
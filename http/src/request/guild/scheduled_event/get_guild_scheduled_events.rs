@@ -0,0 +1,65 @@
+use crate::{
+    client::Client,
+    request::Request,
+    response::{marker::ListBody, ResponseFuture},
+    routing::Route,
+};
+use twilight_model::{id::GuildId, scheduled_event::GuildScheduledEvent};
+
+/// Get the scheduled events of a guild.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use twilight_http::Client;
+/// use twilight_model::id::GuildId;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new("my token".to_owned());
+///
+/// let events = client
+///     .guild_scheduled_events(GuildId(1))
+///     .exec()
+///     .await?
+///     .models()
+///     .await?;
+///
+/// println!("{}", events.len());
+/// # Ok(()) }
+/// ```
+#[must_use = "requests must be configured and executed"]
+pub struct GetGuildScheduledEvents<'a> {
+    guild_id: GuildId,
+    http: &'a Client,
+    with_user_count: bool,
+}
+
+impl<'a> GetGuildScheduledEvents<'a> {
+    pub(crate) const fn new(http: &'a Client, guild_id: GuildId) -> Self {
+        Self {
+            guild_id,
+            http,
+            with_user_count: false,
+        }
+    }
+
+    /// Set whether to include the number of users subscribed to each event.
+    pub const fn with_user_count(mut self, with_user_count: bool) -> Self {
+        self.with_user_count = with_user_count;
+
+        self
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<ListBody<GuildScheduledEvent>> {
+        let request = Request::from_route(&Route::GetGuildScheduledEvents {
+            guild_id: self.guild_id.0,
+            with_user_count: self.with_user_count,
+        });
+
+        self.http.request(request)
+    }
+}
@@ -0,0 +1,104 @@
+pub mod create_guild_scheduled_event;
+pub mod get_guild_scheduled_event_users;
+pub mod update_guild_scheduled_event;
+
+mod delete_guild_scheduled_event;
+mod get_guild_scheduled_event;
+mod get_guild_scheduled_events;
+
+pub use self::{
+    create_guild_scheduled_event::CreateGuildScheduledEvent,
+    delete_guild_scheduled_event::DeleteGuildScheduledEvent,
+    get_guild_scheduled_event::GetGuildScheduledEvent,
+    get_guild_scheduled_event_users::GetGuildScheduledEventUsers,
+    get_guild_scheduled_events::GetGuildScheduledEvents,
+    update_guild_scheduled_event::UpdateGuildScheduledEvent,
+};
+
+use serde::Serialize;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Returned when a scheduled event can not be created or updated as
+/// configured.
+#[derive(Debug)]
+pub struct ScheduledEventValidationError {
+    kind: ScheduledEventValidationErrorType,
+}
+
+impl ScheduledEventValidationError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ScheduledEventValidationErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        ScheduledEventValidationErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, None)
+    }
+}
+
+impl Display for ScheduledEventValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            ScheduledEventValidationErrorType::DescriptionInvalid => {
+                f.write_str("the length of the description is invalid")
+            }
+            ScheduledEventValidationErrorType::LocationInvalid => {
+                f.write_str("the length of the location is invalid")
+            }
+            ScheduledEventValidationErrorType::NameInvalid => {
+                f.write_str("the length of the name is invalid")
+            }
+        }
+    }
+}
+
+impl Error for ScheduledEventValidationError {}
+
+/// Type of [`ScheduledEventValidationError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ScheduledEventValidationErrorType {
+    /// The length of the description is either fewer than 1 character or
+    /// more than 1000 characters.
+    DescriptionInvalid,
+    /// The length of the location is either fewer than 1 character or more
+    /// than 100 characters.
+    LocationInvalid,
+    /// The length of the name is either fewer than 1 character or more than
+    /// 100 characters.
+    NameInvalid,
+}
+
+/// Entity metadata of a scheduled event as sent to Discord.
+#[derive(Serialize)]
+struct EntityMetadataFields<'a> {
+    location: &'a str,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ScheduledEventValidationError, ScheduledEventValidationErrorType};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+
+    assert_impl_all!(ScheduledEventValidationErrorType: Debug, Send, Sync);
+    assert_impl_all!(ScheduledEventValidationError: Error, Send, Sync);
+}
@@ -0,0 +1,332 @@
+use super::{
+    EntityMetadataFields, ScheduledEventValidationError, ScheduledEventValidationErrorType,
+};
+use crate::{
+    client::Client,
+    request::{self, validate_inner, AuditLogReason, AuditLogReasonError, NullableField, Request},
+    response::ResponseFuture,
+    routing::Route,
+};
+use serde::Serialize;
+use twilight_model::{
+    id::{ChannelId, GuildId, ScheduledEventId},
+    scheduled_event::{EntityType, GuildScheduledEvent, PrivacyLevel, RecurrenceRule, Status},
+};
+
+#[derive(Serialize)]
+struct UpdateGuildScheduledEventFields<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    channel_id: Option<NullableField<ChannelId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    entity_metadata: Option<EntityMetadataFields<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    entity_type: Option<EntityType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    image: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    privacy_level: Option<PrivacyLevel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recurrence_rule: Option<NullableField<&'a RecurrenceRule>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scheduled_end_time: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scheduled_start_time: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<Status>,
+}
+
+/// Update a scheduled event in a guild.
+///
+/// Events are started, completed, and cancelled by updating their [status].
+/// When changing an event to be external its [channel] must be set to `None`.
+///
+/// Requires the [`MANAGE_EVENTS`] permission.
+///
+/// # Examples
+///
+/// Start an event:
+///
+/// ```rust,no_run
+/// use twilight_http::Client;
+/// use twilight_model::{
+///     id::{GuildId, ScheduledEventId},
+///     scheduled_event::Status,
+/// };
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new("my token".to_owned());
+///
+/// client
+///     .update_guild_scheduled_event(GuildId(1), ScheduledEventId(2))
+///     .status(Status::Active)
+///     .exec()
+///     .await?;
+/// # Ok(()) }
+/// ```
+///
+/// [`MANAGE_EVENTS`]: twilight_model::guild::Permissions::MANAGE_EVENTS
+/// [channel]: Self::channel_id
+/// [status]: Self::status
+#[must_use = "requests must be configured and executed"]
+pub struct UpdateGuildScheduledEvent<'a> {
+    fields: UpdateGuildScheduledEventFields<'a>,
+    guild_id: GuildId,
+    http: &'a Client,
+    reason: Option<&'a str>,
+    scheduled_event_id: ScheduledEventId,
+}
+
+impl<'a> UpdateGuildScheduledEvent<'a> {
+    pub(crate) const fn new(
+        http: &'a Client,
+        guild_id: GuildId,
+        scheduled_event_id: ScheduledEventId,
+    ) -> Self {
+        Self {
+            fields: UpdateGuildScheduledEventFields {
+                channel_id: None,
+                description: None,
+                entity_metadata: None,
+                entity_type: None,
+                image: None,
+                name: None,
+                privacy_level: None,
+                recurrence_rule: None,
+                scheduled_end_time: None,
+                scheduled_start_time: None,
+                status: None,
+            },
+            guild_id,
+            http,
+            reason: None,
+            scheduled_event_id,
+        }
+    }
+
+    /// Set the stage or voice channel the event takes place in.
+    ///
+    /// Set to `None` when changing the event to be external.
+    pub const fn channel_id(mut self, channel_id: Option<ChannelId>) -> Self {
+        self.fields.channel_id = Some(NullableField(channel_id));
+
+        self
+    }
+
+    /// Set the description of the event.
+    ///
+    /// The description must be between 1 and 1000 characters in length.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ScheduledEventValidationErrorType::DescriptionInvalid`]
+    /// error type if the description is invalid.
+    pub fn description(
+        mut self,
+        description: &'a str,
+    ) -> Result<Self, ScheduledEventValidationError> {
+        if !validate_inner::scheduled_event_description(description) {
+            return Err(ScheduledEventValidationError {
+                kind: ScheduledEventValidationErrorType::DescriptionInvalid,
+            });
+        }
+
+        self.fields.description = Some(description);
+
+        Ok(self)
+    }
+
+    /// Set the type of location the event takes place in.
+    pub const fn entity_type(mut self, entity_type: EntityType) -> Self {
+        self.fields.entity_type = Some(entity_type);
+
+        self
+    }
+
+    /// Set the cover image of the event.
+    ///
+    /// The image must be a Data URI, in the form of
+    /// `data:image/{type};base64,{data}` where `{type}` is the image MIME
+    /// type and `{data}` is the base64-encoded image.
+    pub const fn image(mut self, image: &'a str) -> Self {
+        self.fields.image = Some(image);
+
+        self
+    }
+
+    /// Set the location of an external event.
+    ///
+    /// The location must be between 1 and 100 characters in length.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ScheduledEventValidationErrorType::LocationInvalid`] error
+    /// type if the location is invalid.
+    pub fn location(mut self, location: &'a str) -> Result<Self, ScheduledEventValidationError> {
+        if !validate_inner::scheduled_event_location(location) {
+            return Err(ScheduledEventValidationError {
+                kind: ScheduledEventValidationErrorType::LocationInvalid,
+            });
+        }
+
+        self.fields.entity_metadata = Some(EntityMetadataFields { location });
+
+        Ok(self)
+    }
+
+    /// Set the name of the event.
+    ///
+    /// The name must be between 1 and 100 characters in length.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ScheduledEventValidationErrorType::NameInvalid`] error
+    /// type if the name is invalid.
+    pub fn name(mut self, name: &'a str) -> Result<Self, ScheduledEventValidationError> {
+        if !validate_inner::scheduled_event_name(name) {
+            return Err(ScheduledEventValidationError {
+                kind: ScheduledEventValidationErrorType::NameInvalid,
+            });
+        }
+
+        self.fields.name = Some(name);
+
+        Ok(self)
+    }
+
+    /// Set who can see the event.
+    pub const fn privacy_level(mut self, privacy_level: PrivacyLevel) -> Self {
+        self.fields.privacy_level = Some(privacy_level);
+
+        self
+    }
+
+    /// Set the rules by which the event repeats.
+    ///
+    /// Set to `None` to stop the event from repeating.
+    pub const fn recurrence_rule(mut self, recurrence_rule: Option<&'a RecurrenceRule>) -> Self {
+        self.fields.recurrence_rule = Some(NullableField(recurrence_rule));
+
+        self
+    }
+
+    /// Set the ISO 8601 timestamp of when the event ends.
+    pub const fn scheduled_end_time(mut self, scheduled_end_time: &'a str) -> Self {
+        self.fields.scheduled_end_time = Some(scheduled_end_time);
+
+        self
+    }
+
+    /// Set the ISO 8601 timestamp of when the event starts.
+    pub const fn scheduled_start_time(mut self, scheduled_start_time: &'a str) -> Self {
+        self.fields.scheduled_start_time = Some(scheduled_start_time);
+
+        self
+    }
+
+    /// Set the status of the event.
+    ///
+    /// Scheduled events may be changed to active or cancelled, and active
+    /// events may be changed to completed.
+    pub const fn status(mut self, status: Status) -> Self {
+        self.fields.status = Some(status);
+
+        self
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<GuildScheduledEvent> {
+        let mut request = Request::builder(&Route::UpdateGuildScheduledEvent {
+            guild_id: self.guild_id.0,
+            scheduled_event_id: self.scheduled_event_id.0,
+        });
+
+        request = match request.json(&self.fields) {
+            Ok(request) => request,
+            Err(source) => return ResponseFuture::error(source),
+        };
+
+        if let Some(reason) = self.reason.as_ref() {
+            let header = match request::audit_header(reason) {
+                Ok(header) => header,
+                Err(source) => return ResponseFuture::error(source),
+            };
+
+            request = request.headers(header);
+        }
+
+        self.http.request(request.build())
+    }
+}
+
+impl<'a> AuditLogReason<'a> for UpdateGuildScheduledEvent<'a> {
+    fn reason(mut self, reason: &'a str) -> Result<Self, AuditLogReasonError> {
+        self.reason.replace(AuditLogReasonError::validate(reason)?);
+
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::Client;
+    use serde_json::json;
+    use twilight_model::{
+        id::{GuildId, ScheduledEventId},
+        scheduled_event::{RecurrenceFrequency, RecurrenceRule},
+    };
+
+    #[test]
+    fn test_recurrence_rule() {
+        let client = Client::new("token".to_owned());
+        let rule = RecurrenceRule {
+            by_month: None,
+            by_month_day: None,
+            by_n_weekday: None,
+            by_weekday: None,
+            by_year_day: None,
+            count: Some(3),
+            end: None,
+            frequency: RecurrenceFrequency::Daily,
+            interval: 2,
+            start: "2021-01-01T20:00:00+00:00".to_owned(),
+        };
+
+        let builder = client
+            .update_guild_scheduled_event(GuildId(1), ScheduledEventId(2))
+            .recurrence_rule(Some(&rule));
+
+        assert_eq!(
+            serde_json::to_value(&builder.fields).unwrap(),
+            json!({
+                "recurrence_rule": {
+                    "by_month": null,
+                    "by_month_day": null,
+                    "by_n_weekday": null,
+                    "by_weekday": null,
+                    "by_year_day": null,
+                    "count": 3,
+                    "end": null,
+                    "frequency": 3,
+                    "interval": 2,
+                    "start": "2021-01-01T20:00:00+00:00",
+                },
+            })
+        );
+
+        let builder = client
+            .update_guild_scheduled_event(GuildId(1), ScheduledEventId(2))
+            .recurrence_rule(None);
+
+        assert_eq!(
+            serde_json::to_value(&builder.fields).unwrap(),
+            json!({ "recurrence_rule": null })
+        );
+    }
+}
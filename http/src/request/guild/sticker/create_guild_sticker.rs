@@ -0,0 +1,283 @@
+use super::{StickerValidationError, StickerValidationErrorType};
+use crate::{
+    client::Client,
+    request::{self, validate_inner, AuditLogReason, AuditLogReasonError, Form, Request},
+    response::ResponseFuture,
+    routing::Route,
+};
+use twilight_model::{channel::message::sticker::Sticker, id::GuildId};
+
+/// Format of a sticker file, detected from its contents.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum StickerFormat {
+    /// Lottie animation, which is a JSON object.
+    Lottie,
+    /// PNG or APNG image, which share their signature.
+    Png,
+}
+
+impl StickerFormat {
+    const PNG_SIGNATURE: &'static [u8] = b"\x89PNG\r\n\x1a\n";
+
+    fn detect(file: &[u8]) -> Option<Self> {
+        if file.starts_with(Self::PNG_SIGNATURE) {
+            return Some(Self::Png);
+        }
+
+        match file.iter().find(|byte| !byte.is_ascii_whitespace()) {
+            Some(b'{') => Some(Self::Lottie),
+            _ => None,
+        }
+    }
+
+    const fn content_type(self) -> &'static [u8] {
+        match self {
+            Self::Lottie => b"application/json",
+            Self::Png => b"image/png",
+        }
+    }
+
+    const fn filename(self) -> &'static [u8] {
+        match self {
+            Self::Lottie => b"sticker.json",
+            Self::Png => b"sticker.png",
+        }
+    }
+}
+
+struct CreateGuildStickerFields<'a> {
+    description: &'a str,
+    file: &'a [u8],
+    name: &'a str,
+    tags: &'a str,
+}
+
+/// Create a sticker in a guild.
+///
+/// The file must be a PNG, APNG, or Lottie JSON file of at most 512 KiB. Its
+/// format is detected from its contents and sent along with it.
+/// Requires the [`MANAGE_EMOJIS`] permission.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use twilight_http::Client;
+/// use twilight_model::id::GuildId;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new("my token".to_owned());
+///
+/// let file = std::fs::read("sticker.png")?;
+/// let sticker = client
+///     .create_guild_sticker(GuildId(1), "sticker", "a new sticker", "smile", &file)?
+///     .exec()
+///     .await?
+///     .model()
+///     .await?;
+///
+/// println!("{:#?}", sticker);
+/// # Ok(()) }
+/// ```
+///
+/// [`MANAGE_EMOJIS`]: twilight_model::guild::Permissions::MANAGE_EMOJIS
+#[must_use = "requests must be configured and executed"]
+pub struct CreateGuildSticker<'a> {
+    fields: CreateGuildStickerFields<'a>,
+    guild_id: GuildId,
+    http: &'a Client,
+    reason: Option<&'a str>,
+}
+
+impl<'a> CreateGuildSticker<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        guild_id: GuildId,
+        name: &'a str,
+        description: &'a str,
+        tags: &'a str,
+        file: &'a [u8],
+    ) -> Result<Self, StickerValidationError> {
+        if !validate_inner::sticker_name(name) {
+            return Err(StickerValidationError {
+                kind: StickerValidationErrorType::NameInvalid,
+            });
+        }
+
+        if !validate_inner::sticker_description(description) {
+            return Err(StickerValidationError {
+                kind: StickerValidationErrorType::DescriptionInvalid,
+            });
+        }
+
+        if !validate_inner::sticker_tags(tags) {
+            return Err(StickerValidationError {
+                kind: StickerValidationErrorType::TagsInvalid,
+            });
+        }
+
+        if file.is_empty() {
+            return Err(StickerValidationError {
+                kind: StickerValidationErrorType::FileEmpty,
+            });
+        }
+
+        if !validate_inner::sticker_file_size(file.len()) {
+            return Err(StickerValidationError {
+                kind: StickerValidationErrorType::FileTooLarge { size: file.len() },
+            });
+        }
+
+        Ok(Self {
+            fields: CreateGuildStickerFields {
+                description,
+                file,
+                name,
+                tags,
+            },
+            guild_id,
+            http,
+            reason: None,
+        })
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<Sticker> {
+        let mut request = Request::builder(&Route::CreateGuildSticker {
+            guild_id: self.guild_id.0,
+        });
+
+        request = request.form(self.form());
+
+        if let Some(reason) = self.reason.as_ref() {
+            let header = match request::audit_header(reason) {
+                Ok(header) => header,
+                Err(source) => return ResponseFuture::error(source),
+            };
+
+            request = request.headers(header);
+        }
+
+        self.http.request(request.build())
+    }
+
+    fn form(&self) -> Form {
+        let mut form = Form::new();
+        form.part(b"name", self.fields.name.as_bytes())
+            .part(b"description", self.fields.description.as_bytes())
+            .part(b"tags", self.fields.tags.as_bytes());
+
+        // Let Discord reject files of unknown formats itself.
+        match StickerFormat::detect(self.fields.file) {
+            Some(format) => form.file_with_content_type(
+                b"file",
+                format.filename(),
+                format.content_type(),
+                self.fields.file,
+            ),
+            None => form.file(b"file", b"sticker", self.fields.file),
+        };
+
+        form
+    }
+}
+
+impl<'a> AuditLogReason<'a> for CreateGuildSticker<'a> {
+    fn reason(mut self, reason: &'a str) -> Result<Self, AuditLogReasonError> {
+        self.reason.replace(AuditLogReasonError::validate(reason)?);
+
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CreateGuildSticker, StickerFormat};
+    use crate::{request::guild::sticker::StickerValidationErrorType, Client};
+    use twilight_model::id::GuildId;
+
+    const PNG: &[u8] = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+
+    fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+        haystack
+            .windows(needle.len())
+            .any(|window| window == needle)
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(Some(StickerFormat::Png), StickerFormat::detect(PNG));
+        assert_eq!(
+            Some(StickerFormat::Lottie),
+            StickerFormat::detect(b"\n  {\"v\":\"5.5.2\"}")
+        );
+        assert_eq!(None, StickerFormat::detect(b"GIF89a"));
+        assert_eq!(None, StickerFormat::detect(&[]));
+    }
+
+    #[test]
+    fn test_form_file() {
+        let client = Client::new("token".to_owned());
+
+        let png = CreateGuildSticker::new(&client, GuildId(1), "name", "", "tag", PNG)
+            .unwrap()
+            .form()
+            .build()
+            .unwrap();
+        assert!(contains(
+            &png,
+            b"filename=\"sticker.png\"\r\nContent-Type: image/png\r\n\r\n\x89PNG"
+        ));
+
+        let lottie = CreateGuildSticker::new(&client, GuildId(1), "name", "", "tag", b"{}")
+            .unwrap()
+            .form()
+            .build()
+            .unwrap();
+        assert!(contains(
+            &lottie,
+            b"filename=\"sticker.json\"\r\nContent-Type: application/json\r\n\r\n{}"
+        ));
+    }
+
+    #[test]
+    fn test_validation() {
+        let client = Client::new("token".to_owned());
+
+        assert!(CreateGuildSticker::new(&client, GuildId(1), "name", "", "tag", PNG).is_ok());
+        assert!(matches!(
+            CreateGuildSticker::new(&client, GuildId(1), "name", "", "tag", &[])
+                .err()
+                .map(|error| error.into_parts().0),
+            Some(StickerValidationErrorType::FileEmpty)
+        ));
+        assert!(matches!(
+            CreateGuildSticker::new(&client, GuildId(1), "n", "", "tag", PNG)
+                .err()
+                .map(|error| error.into_parts().0),
+            Some(StickerValidationErrorType::NameInvalid)
+        ));
+        assert!(matches!(
+            CreateGuildSticker::new(&client, GuildId(1), "name", "d", "tag", PNG)
+                .err()
+                .map(|error| error.into_parts().0),
+            Some(StickerValidationErrorType::DescriptionInvalid)
+        ));
+        assert!(matches!(
+            CreateGuildSticker::new(&client, GuildId(1), "name", "", "t", PNG)
+                .err()
+                .map(|error| error.into_parts().0),
+            Some(StickerValidationErrorType::TagsInvalid)
+        ));
+
+        let file = vec![0; 512 * 1024 + 1];
+        assert!(matches!(
+            CreateGuildSticker::new(&client, GuildId(1), "name", "", "tag", &file)
+                .err()
+                .map(|error| error.into_parts().0),
+            Some(StickerValidationErrorType::FileTooLarge { size }) if size == file.len()
+        ));
+    }
+}
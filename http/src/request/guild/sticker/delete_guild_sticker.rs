@@ -0,0 +1,77 @@
+use crate::{
+    client::Client,
+    request::{self, AuditLogReason, AuditLogReasonError, Request},
+    response::{marker::EmptyBody, ResponseFuture},
+    routing::Route,
+};
+use twilight_model::{channel::message::sticker::StickerId, id::GuildId};
+
+/// Delete a sticker of a guild.
+///
+/// Requires the [`MANAGE_EMOJIS`] permission.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use twilight_http::Client;
+/// use twilight_model::{channel::message::sticker::StickerId, id::GuildId};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new("my token".to_owned());
+///
+/// let guild_id = GuildId(1);
+/// let sticker_id = StickerId(2);
+///
+/// client.delete_guild_sticker(guild_id, sticker_id).exec().await?;
+/// # Ok(()) }
+/// ```
+///
+/// [`MANAGE_EMOJIS`]: twilight_model::guild::Permissions::MANAGE_EMOJIS
+#[must_use = "requests must be configured and executed"]
+pub struct DeleteGuildSticker<'a> {
+    guild_id: GuildId,
+    http: &'a Client,
+    reason: Option<&'a str>,
+    sticker_id: StickerId,
+}
+
+impl<'a> DeleteGuildSticker<'a> {
+    pub(crate) const fn new(http: &'a Client, guild_id: GuildId, sticker_id: StickerId) -> Self {
+        Self {
+            guild_id,
+            http,
+            reason: None,
+            sticker_id,
+        }
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<EmptyBody> {
+        let mut request = Request::builder(&Route::DeleteGuildSticker {
+            guild_id: self.guild_id.0,
+            sticker_id: self.sticker_id.0,
+        });
+
+        if let Some(reason) = self.reason.as_ref() {
+            let header = match request::audit_header(reason) {
+                Ok(header) => header,
+                Err(source) => return ResponseFuture::error(source),
+            };
+
+            request = request.headers(header);
+        }
+
+        self.http.request(request.build())
+    }
+}
+
+impl<'a> AuditLogReason<'a> for DeleteGuildSticker<'a> {
+    fn reason(mut self, reason: &'a str) -> Result<Self, AuditLogReasonError> {
+        self.reason.replace(AuditLogReasonError::validate(reason)?);
+
+        Ok(self)
+    }
+}
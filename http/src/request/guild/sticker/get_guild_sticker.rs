@@ -0,0 +1,60 @@
+use crate::{client::Client, request::Request, response::ResponseFuture, routing::Route};
+use twilight_model::{
+    channel::message::sticker::{Sticker, StickerId},
+    id::GuildId,
+};
+
+/// Get a sticker of a guild by the guild's ID and sticker's ID.
+///
+/// # Examples
+///
+/// Get sticker `2` from guild `1`:
+///
+/// ```rust,no_run
+/// use twilight_http::Client;
+/// use twilight_model::{channel::message::sticker::StickerId, id::GuildId};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new("my token".to_owned());
+///
+/// let guild_id = GuildId(1);
+/// let sticker_id = StickerId(2);
+/// let sticker = client
+///     .guild_sticker(guild_id, sticker_id)
+///     .exec()
+///     .await?
+///     .model()
+///     .await?;
+///
+/// println!("{:#?}", sticker);
+/// # Ok(()) }
+/// ```
+#[must_use = "requests must be configured and executed"]
+pub struct GetGuildSticker<'a> {
+    guild_id: GuildId,
+    http: &'a Client,
+    sticker_id: StickerId,
+}
+
+impl<'a> GetGuildSticker<'a> {
+    pub(crate) const fn new(http: &'a Client, guild_id: GuildId, sticker_id: StickerId) -> Self {
+        Self {
+            guild_id,
+            http,
+            sticker_id,
+        }
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<Sticker> {
+        let request = Request::from_route(&Route::GetGuildSticker {
+            guild_id: self.guild_id.0,
+            sticker_id: self.sticker_id.0,
+        });
+
+        self.http.request(request)
+    }
+}
@@ -0,0 +1,106 @@
+pub mod create_guild_sticker;
+pub mod update_guild_sticker;
+
+mod delete_guild_sticker;
+mod get_guild_sticker;
+mod get_guild_stickers;
+
+pub use self::{
+    create_guild_sticker::CreateGuildSticker, delete_guild_sticker::DeleteGuildSticker,
+    get_guild_sticker::GetGuildSticker, get_guild_stickers::GetGuildStickers,
+    update_guild_sticker::UpdateGuildSticker,
+};
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Returned when a sticker can not be created or updated as configured.
+#[derive(Debug)]
+pub struct StickerValidationError {
+    kind: StickerValidationErrorType,
+}
+
+impl StickerValidationError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &StickerValidationErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        StickerValidationErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, None)
+    }
+}
+
+impl Display for StickerValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            StickerValidationErrorType::DescriptionInvalid => {
+                f.write_str("the length of the description is invalid")
+            }
+            StickerValidationErrorType::FileEmpty => f.write_str("the file is empty"),
+            StickerValidationErrorType::FileTooLarge { size } => {
+                f.write_str("the file is ")?;
+                Display::fmt(size, f)?;
+
+                f.write_str(" bytes, but must be at most 512 KiB")
+            }
+            StickerValidationErrorType::NameInvalid => {
+                f.write_str("the length of the name is invalid")
+            }
+            StickerValidationErrorType::TagsInvalid => {
+                f.write_str("the length of the tags is invalid")
+            }
+        }
+    }
+}
+
+impl Error for StickerValidationError {}
+
+/// Type of [`StickerValidationError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum StickerValidationErrorType {
+    /// The length of the description is either 1 character or more than 100
+    /// characters.
+    DescriptionInvalid,
+    /// The file is empty.
+    FileEmpty,
+    /// The file is larger than 512 KiB.
+    FileTooLarge {
+        /// Size of the provided file in bytes.
+        size: usize,
+    },
+    /// The length of the name is either fewer than 2 characters or more than
+    /// 30 characters.
+    NameInvalid,
+    /// The length of the tags is either fewer than 2 characters or more than
+    /// 200 characters.
+    TagsInvalid,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{StickerValidationError, StickerValidationErrorType};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+
+    assert_impl_all!(StickerValidationErrorType: Debug, Send, Sync);
+    assert_impl_all!(StickerValidationError: Error, Send, Sync);
+}
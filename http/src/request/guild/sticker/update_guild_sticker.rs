@@ -0,0 +1,169 @@
+use super::{StickerValidationError, StickerValidationErrorType};
+use crate::{
+    client::Client,
+    request::{self, validate_inner, AuditLogReason, AuditLogReasonError, Request},
+    response::ResponseFuture,
+    routing::Route,
+};
+use serde::Serialize;
+use twilight_model::{
+    channel::message::sticker::{Sticker, StickerId},
+    id::GuildId,
+};
+
+#[derive(Serialize)]
+struct UpdateGuildStickerFields<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tags: Option<&'a str>,
+}
+
+/// Update a sticker of a guild.
+///
+/// Requires the [`MANAGE_EMOJIS`] permission.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use twilight_http::Client;
+/// use twilight_model::{channel::message::sticker::StickerId, id::GuildId};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new("my token".to_owned());
+///
+/// let sticker = client
+///     .update_guild_sticker(GuildId(1), StickerId(2))
+///     .name("renamed")?
+///     .exec()
+///     .await?
+///     .model()
+///     .await?;
+///
+/// println!("{:#?}", sticker);
+/// # Ok(()) }
+/// ```
+///
+/// [`MANAGE_EMOJIS`]: twilight_model::guild::Permissions::MANAGE_EMOJIS
+#[must_use = "requests must be configured and executed"]
+pub struct UpdateGuildSticker<'a> {
+    fields: UpdateGuildStickerFields<'a>,
+    guild_id: GuildId,
+    http: &'a Client,
+    reason: Option<&'a str>,
+    sticker_id: StickerId,
+}
+
+impl<'a> UpdateGuildSticker<'a> {
+    pub(crate) const fn new(http: &'a Client, guild_id: GuildId, sticker_id: StickerId) -> Self {
+        Self {
+            fields: UpdateGuildStickerFields {
+                description: None,
+                name: None,
+                tags: None,
+            },
+            guild_id,
+            http,
+            reason: None,
+            sticker_id,
+        }
+    }
+
+    /// Set the sticker's description.
+    ///
+    /// The description must be empty or between 2 and 100 characters in
+    /// length.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StickerValidationErrorType::DescriptionInvalid`] error
+    /// type if the description is invalid.
+    pub fn description(mut self, description: &'a str) -> Result<Self, StickerValidationError> {
+        if !validate_inner::sticker_description(description) {
+            return Err(StickerValidationError {
+                kind: StickerValidationErrorType::DescriptionInvalid,
+            });
+        }
+
+        self.fields.description = Some(description);
+
+        Ok(self)
+    }
+
+    /// Set the sticker's name.
+    ///
+    /// The name must be between 2 and 30 characters in length.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StickerValidationErrorType::NameInvalid`] error type if
+    /// the name is invalid.
+    pub fn name(mut self, name: &'a str) -> Result<Self, StickerValidationError> {
+        if !validate_inner::sticker_name(name) {
+            return Err(StickerValidationError {
+                kind: StickerValidationErrorType::NameInvalid,
+            });
+        }
+
+        self.fields.name = Some(name);
+
+        Ok(self)
+    }
+
+    /// Set the sticker's autocomplete and suggestion tags.
+    ///
+    /// The tags must be between 2 and 200 characters in length.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StickerValidationErrorType::TagsInvalid`] error type if
+    /// the tags are invalid.
+    pub fn tags(mut self, tags: &'a str) -> Result<Self, StickerValidationError> {
+        if !validate_inner::sticker_tags(tags) {
+            return Err(StickerValidationError {
+                kind: StickerValidationErrorType::TagsInvalid,
+            });
+        }
+
+        self.fields.tags = Some(tags);
+
+        Ok(self)
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<Sticker> {
+        let mut request = Request::builder(&Route::UpdateGuildSticker {
+            guild_id: self.guild_id.0,
+            sticker_id: self.sticker_id.0,
+        });
+
+        request = match request.json(&self.fields) {
+            Ok(request) => request,
+            Err(source) => return ResponseFuture::error(source),
+        };
+
+        if let Some(reason) = self.reason.as_ref() {
+            let header = match request::audit_header(reason) {
+                Ok(header) => header,
+                Err(source) => return ResponseFuture::error(source),
+            };
+
+            request = request.headers(header);
+        }
+
+        self.http.request(request.build())
+    }
+}
+
+impl<'a> AuditLogReason<'a> for UpdateGuildSticker<'a> {
+    fn reason(mut self, reason: &'a str) -> Result<Self, AuditLogReasonError> {
+        self.reason.replace(AuditLogReasonError::validate(reason)?);
+
+        Ok(self)
+    }
+}
@@ -1,12 +1,15 @@
 pub mod application;
 pub mod channel;
 pub mod guild;
+pub mod paginate;
 pub mod prelude;
+pub mod sticker;
 pub mod template;
 pub mod user;
 
 mod audit_reason;
 mod base;
+mod get_current_authorization_information;
 mod get_gateway;
 mod get_gateway_authed;
 mod get_user_application;
@@ -23,13 +26,17 @@ mod validate_inner;
 pub use self::{
     audit_reason::{AuditLogReason, AuditLogReasonError},
     base::{Request, RequestBuilder},
+    get_current_authorization_information::GetCurrentAuthorizationInformation,
     get_gateway::GetGateway,
     get_gateway_authed::GetGatewayAuthed,
     get_user_application::GetUserApplicationInfo,
     get_voice_regions::GetVoiceRegions,
-    multipart::Form,
+    multipart::{FileUpload, Form},
+    paginate::Paginator,
 };
 
+pub(crate) use self::multipart::StreamedForm;
+
 use crate::error::{Error, ErrorType};
 use hyper::{
     header::{HeaderName, HeaderValue},
@@ -1,9 +1,301 @@
+use futures_core::Stream;
+use hyper::body::Bytes;
 use rand::{distributions::Alphanumeric, Rng};
+use std::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    future::Future,
+    io::{Error as IoError, ErrorKind as IoErrorKind},
+    mem,
+    path::{Path, PathBuf},
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+use tokio::{
+    fs::{self, File},
+    io::{AsyncRead, ReadBuf},
+};
+
+/// Size of the chunks file uploads are read in.
+const CHUNK_SIZE: usize = 8 * 1024;
+
+/// Reader of the contents of a [`FileUpload`].
+type Reader = Pin<Box<dyn AsyncRead + Send>>;
+
+/// Future resolving to a reader of the contents of a [`FileUpload`].
+type OpenFuture = Pin<Box<dyn Future<Output = Result<Reader, IoError>> + Send>>;
+
+/// File whose contents are streamed into the body of a multipart request
+/// instead of being buffered in memory.
+///
+/// Uploads read from a path are opened again if the request is retried.
+/// Uploads read from a reader can only be sent once; retries of the request
+/// fail.
+///
+/// # Examples
+///
+/// Send a message with a large file attached:
+///
+/// ```rust,no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use twilight_http::{request::FileUpload, Client};
+/// use twilight_model::id::ChannelId;
+///
+/// let client = Client::new("my token".to_owned());
+///
+/// let upload = FileUpload::from_path("recording.mp4").await?;
+///
+/// client
+///     .create_message(ChannelId(1))
+///     .file_uploads(vec![upload])
+///     .exec()
+///     .await?;
+/// # Ok(()) }
+/// ```
+pub struct FileUpload {
+    filename: String,
+    len: u64,
+    source: FileSource,
+}
+
+enum FileSource {
+    Path(PathBuf),
+    Reader(Mutex<Option<Reader>>),
+}
+
+impl FileUpload {
+    /// Create an upload reading the file at a path.
+    ///
+    /// The filename of the upload is the final component of the path, and
+    /// its length is the current size of the file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the metadata of the file could not be read.
+    pub async fn from_path(path: impl AsRef<Path>) -> Result<Self, IoError> {
+        let path = path.as_ref();
+        let metadata = fs::metadata(path).await?;
+
+        let filename = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .ok_or_else(|| IoError::new(IoErrorKind::InvalidInput, "path has no file name"))?;
+
+        Ok(Self {
+            filename,
+            len: metadata.len(),
+            source: FileSource::Path(path.to_owned()),
+        })
+    }
+
+    /// Create an upload reading from an asynchronous reader.
+    ///
+    /// The reader must provide at least `len` bytes, as the length of the
+    /// request body is computed before it is sent. Further bytes are not read.
+    pub fn from_reader(
+        filename: String,
+        reader: impl AsyncRead + Send + 'static,
+        len: u64,
+    ) -> Self {
+        Self {
+            filename,
+            len,
+            source: FileSource::Reader(Mutex::new(Some(Box::pin(reader)))),
+        }
+    }
+
+    /// Name of the file.
+    pub fn filename(&self) -> &str {
+        &self.filename
+    }
+
+    /// Number of bytes of the file.
+    pub const fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Whether the file is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Open the contents of the upload for reading.
+    fn open(&self) -> OpenFuture {
+        match &self.source {
+            FileSource::Path(path) => {
+                let path = path.clone();
+
+                Box::pin(async move {
+                    let file = File::open(path).await?;
+
+                    Ok(Box::pin(file) as Reader)
+                })
+            }
+            FileSource::Reader(reader) => {
+                let reader = reader.lock().expect("file upload poisoned").take();
+
+                Box::pin(async move {
+                    reader.ok_or_else(|| {
+                        IoError::new(IoErrorKind::Other, "file upload reader already consumed")
+                    })
+                })
+            }
+        }
+    }
+}
+
+impl Debug for FileUpload {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let mut debug = f.debug_struct("FileUpload");
+        debug
+            .field("filename", &self.filename)
+            .field("len", &self.len);
+
+        if let FileSource::Path(path) = &self.source {
+            debug.field("path", path);
+        }
+
+        debug.finish()
+    }
+}
+
+/// Segment of the body of a multipart form.
+#[derive(Clone, Debug)]
+enum FormSegment {
+    Bytes(Bytes),
+    Upload(Arc<FileUpload>),
+}
+
+/// Body of a multipart form containing [`FileUpload`]s, which can be streamed
+/// into a request any number of times.
+#[derive(Clone, Debug)]
+pub(crate) struct StreamedForm {
+    len: u64,
+    segments: Arc<[FormSegment]>,
+}
+
+impl StreamedForm {
+    /// Total length of the body.
+    pub const fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Stream the body.
+    pub fn stream(&self) -> FormStream {
+        FormStream {
+            index: 0,
+            segments: Arc::clone(&self.segments),
+            state: FormStreamState::Next,
+        }
+    }
+}
+
+/// Stream yielding the chunks of a [`StreamedForm`].
+pub(crate) struct FormStream {
+    index: usize,
+    segments: Arc<[FormSegment]>,
+    state: FormStreamState,
+}
+
+enum FormStreamState {
+    Next,
+    Opening { future: OpenFuture, remaining: u64 },
+    Reading { reader: Reader, remaining: u64 },
+}
+
+impl Stream for FormStream {
+    type Item = Result<Bytes, IoError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            match &mut this.state {
+                FormStreamState::Next => {
+                    let segment = match this.segments.get(this.index) {
+                        Some(segment) => segment,
+                        None => return Poll::Ready(None),
+                    };
+                    this.index += 1;
+
+                    match segment {
+                        FormSegment::Bytes(bytes) => return Poll::Ready(Some(Ok(bytes.clone()))),
+                        FormSegment::Upload(upload) => {
+                            this.state = FormStreamState::Opening {
+                                future: upload.open(),
+                                remaining: upload.len,
+                            };
+                        }
+                    }
+                }
+                FormStreamState::Opening { future, remaining } => {
+                    let reader = match future.as_mut().poll(cx) {
+                        Poll::Ready(Ok(reader)) => reader,
+                        Poll::Ready(Err(source)) => {
+                            this.state = FormStreamState::Next;
+                            this.index = this.segments.len();
+
+                            return Poll::Ready(Some(Err(source)));
+                        }
+                        Poll::Pending => return Poll::Pending,
+                    };
+
+                    this.state = FormStreamState::Reading {
+                        reader,
+                        remaining: *remaining,
+                    };
+                }
+                FormStreamState::Reading { reader, remaining } => {
+                    if *remaining == 0 {
+                        this.state = FormStreamState::Next;
+
+                        continue;
+                    }
+
+                    #[allow(clippy::cast_possible_truncation)]
+                    let size = (*remaining).min(CHUNK_SIZE as u64) as usize;
+                    let mut chunk = vec![0; size];
+                    let mut buf = ReadBuf::new(&mut chunk);
+
+                    match reader.as_mut().poll_read(cx, &mut buf) {
+                        Poll::Ready(Ok(())) => {}
+                        Poll::Ready(Err(source)) => {
+                            this.state = FormStreamState::Next;
+                            this.index = this.segments.len();
+
+                            return Poll::Ready(Some(Err(source)));
+                        }
+                        Poll::Pending => return Poll::Pending,
+                    }
+
+                    let read = buf.filled().len();
+
+                    if read == 0 {
+                        this.state = FormStreamState::Next;
+                        this.index = this.segments.len();
+
+                        return Poll::Ready(Some(Err(IoError::new(
+                            IoErrorKind::UnexpectedEof,
+                            "file upload ended before its length",
+                        ))));
+                    }
+
+                    *remaining -= read as u64;
+                    chunk.truncate(read);
+
+                    return Poll::Ready(Some(Ok(Bytes::from(chunk))));
+                }
+            }
+        }
+    }
+}
 
 #[derive(Debug)]
 pub struct Form {
     boundary: [u8; 15],
     buffer: Vec<u8>,
+    segments: Vec<FormSegment>,
 }
 
 impl Form {
@@ -11,12 +303,30 @@ impl Form {
         Self::default()
     }
 
-    pub fn build(mut self) -> Vec<u8> {
-        self.buffer.extend(b"\r\n");
-        self.boundary();
-        self.buffer.extend(b"--");
+    /// Build the form into a buffer.
+    ///
+    /// Returns `None` if the form contains [`FileUpload`]s, as their contents
+    /// are only streamed into the body when the request is sent.
+    pub fn build(self) -> Option<Vec<u8>> {
+        if self.is_streamed() {
+            return None;
+        }
+
+        Some(self.into_buffer())
+    }
+
+    /// Build the form into a buffer, skipping the contents of any
+    /// [`FileUpload`]s.
+    pub(crate) fn into_buffer(self) -> Vec<u8> {
+        let mut buffer = Vec::new();
+
+        for segment in self.finish().segments.iter() {
+            if let FormSegment::Bytes(bytes) = segment {
+                buffer.extend(bytes);
+            }
+        }
 
-        self.buffer
+        buffer
     }
 
     pub fn content_type(&self) -> Vec<u8> {
@@ -38,6 +348,38 @@ impl Form {
         self
     }
 
+    /// Add a file along with the content type of its data.
+    pub fn file_with_content_type(
+        &mut self,
+        name: &[u8],
+        filename: &[u8],
+        content_type: &[u8],
+        data: &[u8],
+    ) -> &mut Self {
+        self.start();
+        self.name(name);
+        self.filename(filename);
+        self.buffer.extend(b"\r\nContent-Type: ");
+        self.buffer.extend(content_type);
+        self.data(data);
+
+        self
+    }
+
+    /// Add a file whose contents are streamed into the body when the request
+    /// is sent.
+    pub fn file_upload(&mut self, name: &[u8], upload: FileUpload) -> &mut Self {
+        self.start();
+        self.name(name);
+        self.filename(upload.filename.as_bytes());
+        self.buffer.extend(b"\r\n\r\n");
+
+        self.flush();
+        self.segments.push(FormSegment::Upload(Arc::new(upload)));
+
+        self
+    }
+
     pub fn part(&mut self, name: &[u8], data: &[u8]) -> &mut Self {
         self.start();
         self.name(name);
@@ -55,6 +397,43 @@ impl Form {
         self
     }
 
+    /// Whether the form contains [`FileUpload`]s.
+    pub(crate) fn is_streamed(&self) -> bool {
+        self.segments
+            .iter()
+            .any(|segment| matches!(segment, FormSegment::Upload(_)))
+    }
+
+    /// Finish the form into a body that can be streamed.
+    pub(crate) fn finish(mut self) -> StreamedForm {
+        self.buffer.extend(b"\r\n");
+        self.boundary();
+        self.buffer.extend(b"--");
+        self.flush();
+
+        let len = self
+            .segments
+            .iter()
+            .map(|segment| match segment {
+                FormSegment::Bytes(bytes) => bytes.len() as u64,
+                FormSegment::Upload(upload) => upload.len,
+            })
+            .sum();
+
+        StreamedForm {
+            len,
+            segments: self.segments.into(),
+        }
+    }
+
+    /// Move the buffered bytes into a segment.
+    fn flush(&mut self) {
+        if !self.buffer.is_empty() {
+            let buffer = mem::take(&mut self.buffer);
+            self.segments.push(FormSegment::Bytes(Bytes::from(buffer)));
+        }
+    }
+
     fn start(&mut self) {
         self.buffer.extend(b"\r\n");
         self.boundary();
@@ -96,6 +475,142 @@ impl Default for Form {
         Self {
             boundary,
             buffer: Vec::new(),
+            segments: Vec::new(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{FileUpload, Form};
+    use crate::{
+        routing::Route,
+        transport::{MockResponse, MockTransport},
+        Client,
+    };
+    use futures_util::StreamExt;
+    use hyper::header::CONTENT_LENGTH;
+    use static_assertions::assert_impl_all;
+    use std::{
+        env,
+        error::Error,
+        ffi::OsStr,
+        fmt::Debug,
+        fs,
+        io::{Cursor, ErrorKind},
+        process,
+    };
+    use twilight_model::id::ChannelId;
+
+    assert_impl_all!(FileUpload: Debug, Send, Sync);
+    assert_impl_all!(Form: Debug, Send, Sync);
+
+    async fn collect(form: Form) -> Result<Vec<u8>, std::io::Error> {
+        let streamed = form.finish();
+        let mut stream = streamed.stream();
+        let mut body = Vec::new();
+
+        while let Some(chunk) = stream.next().await {
+            body.extend(chunk?);
+        }
+
+        assert_eq!(streamed.len(), body.len() as u64);
+
+        Ok(body)
+    }
+
+    #[tokio::test]
+    async fn test_streamed_matches_buffered() -> Result<(), Box<dyn Error>> {
+        let data = vec![7; 20_000];
+
+        let mut buffered = Form::new();
+        buffered.file(b"0", b"a.bin", &data).payload_json(b"{}");
+
+        let mut streamed = Form {
+            boundary: buffered.boundary,
+            ..Form::new()
+        };
+        streamed
+            .file_upload(
+                b"0",
+                FileUpload::from_reader(
+                    "a.bin".to_owned(),
+                    Cursor::new(data.clone()),
+                    data.len() as u64,
+                ),
+            )
+            .payload_json(b"{}");
+        assert!(streamed.is_streamed());
+        assert!(!buffered.is_streamed());
+
+        let body = collect(streamed).await?;
+        assert_eq!(Some(body), buffered.build());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_create_message_streams_uploads() -> Result<(), Box<dyn Error>> {
+        let path = env::temp_dir().join(format!("twilight-upload-{}.txt", process::id()));
+        fs::write(&path, b"streamed from disk")?;
+
+        let transport = MockTransport::new();
+        transport.on_route(
+            &Route::CreateMessage { channel_id: 1 },
+            MockResponse::new(500),
+        );
+        let client = Client::builder()
+            .token("token".to_owned())
+            .transport(Box::new(transport.clone()))
+            .build();
+
+        let upload = FileUpload::from_path(&path).await?;
+        let _ = client
+            .create_message(ChannelId(1))
+            .files(&[("a.txt", b"in memory")])
+            .file_uploads(vec![upload])
+            .exec()
+            .await;
+        fs::remove_file(&path)?;
+
+        let request = &transport.requests()[0];
+        assert_eq!(
+            request.body().len().to_string(),
+            request.headers()[CONTENT_LENGTH]
+        );
+
+        let parts = request.form().expect("body is a multipart form");
+        assert_eq!(3, parts.len());
+        assert_eq!("1", parts[1].name());
+        assert_eq!(
+            path.file_name().and_then(OsStr::to_str),
+            parts[1].filename()
+        );
+        assert_eq!(b"streamed from disk", parts[1].data());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_with_uploads() {
+        let mut form = Form::new();
+        form.file_upload(
+            b"0",
+            FileUpload::from_reader("a".to_owned(), Cursor::new(b"a"), 1),
+        );
+
+        assert!(form.build().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_reader_too_short() {
+        let mut form = Form::new();
+        form.file_upload(
+            b"0",
+            FileUpload::from_reader("a".to_owned(), Cursor::new(b"ab"), 3),
+        );
+
+        let error = collect(form).await.unwrap_err();
+        assert_eq!(ErrorKind::UnexpectedEof, error.kind());
+    }
+}
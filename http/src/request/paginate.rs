@@ -0,0 +1,468 @@
+//! Streams yielding the items of paginated endpoints across pages.
+
+use crate::{
+    error::Error as HttpError,
+    response::{marker::ListBody, DeserializeBodyError, ResponseFuture},
+};
+use futures_core::Stream;
+use serde::de::DeserializeOwned;
+use std::{
+    collections::VecDeque,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Future resolving to a page of items.
+pub(crate) type PageFuture<'a, T> =
+    Pin<Box<dyn Future<Output = Result<Page<T>, PaginateError>> + Send + 'a>>;
+
+/// Page of items returned by a paginated endpoint.
+pub(crate) struct Page<T> {
+    items: Vec<T>,
+    /// Whether more pages may be available after this one.
+    more: bool,
+}
+
+impl<T> Page<T> {
+    /// Create a page, which has more pages after it if it is full.
+    pub fn new(items: Vec<T>, limit: u64) -> Self {
+        let more = items.len() as u64 >= limit;
+
+        Self { items, more }
+    }
+
+    /// Create a page, with whether there are more pages after it determined
+    /// by the API.
+    pub const fn with_more(items: Vec<T>, more: bool) -> Self {
+        Self { items, more }
+    }
+}
+
+/// Request of a paginated endpoint that can request its pages one after
+/// another.
+pub(crate) trait Pager<'a>: Send + 'a {
+    /// Type of the items in a page.
+    type Item;
+
+    /// Maximum number of items in a page.
+    fn page_size(&self) -> u64;
+
+    /// Request the next page, containing at most `limit` items.
+    fn page(&self, limit: u64) -> PageFuture<'a, Self::Item>;
+
+    /// Move the cursor past a received page, returning whether another page
+    /// may be requested.
+    fn advance(&mut self, items: &[Self::Item]) -> bool;
+}
+
+/// Request a page from a list endpoint.
+pub(crate) fn list_page<'a, T: DeserializeOwned + Send + Unpin + 'a>(
+    future: ResponseFuture<ListBody<T>>,
+    limit: u64,
+) -> PageFuture<'a, T> {
+    Box::pin(async move {
+        let response = future.await.map_err(PaginateError::request)?;
+        let items = response
+            .models()
+            .await
+            .map_err(PaginateError::deserializing)?;
+
+        Ok(Page::new(items, limit))
+    })
+}
+
+/// The error returned when a page could not be retrieved.
+#[derive(Debug)]
+pub struct PaginateError {
+    kind: PaginateErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl PaginateError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &PaginateErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (PaginateErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+
+    pub(crate) fn deserializing(source: DeserializeBodyError) -> Self {
+        Self {
+            kind: PaginateErrorType::Deserializing,
+            source: Some(Box::new(source)),
+        }
+    }
+
+    pub(crate) fn request(source: HttpError) -> Self {
+        Self {
+            kind: PaginateErrorType::Request,
+            source: Some(Box::new(source)),
+        }
+    }
+}
+
+impl Display for PaginateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            PaginateErrorType::Deserializing => f.write_str("failed to deserialize a page"),
+            PaginateErrorType::Request => f.write_str("failed to request a page"),
+        }
+    }
+}
+
+impl Error for PaginateError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`PaginateError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum PaginateErrorType {
+    /// Deserializing the items of a page failed.
+    Deserializing,
+    /// Requesting a page failed.
+    ///
+    /// The source is an [`Error`].
+    ///
+    /// [`Error`]: crate::Error
+    Request,
+}
+
+/// Stream yielding the items of a paginated endpoint across pages.
+///
+/// Pages are requested one at a time once all items of the previous page have
+/// been yielded, so every page passes through the client's ratelimiter. The
+/// stream ends once the endpoint has no more items, after an error, or once
+/// the [total] number of items has been yielded.
+///
+/// Created via the `paginate` method of paginated requests, such as
+/// [`GetGuildMembers::paginate`].
+///
+/// # Examples
+///
+/// Count the members of a guild:
+///
+/// ```rust,no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use futures_util::StreamExt;
+/// use twilight_http::Client;
+/// use twilight_model::id::GuildId;
+///
+/// let client = Client::new("my token".to_owned());
+///
+/// let mut members = client.guild_members(GuildId(1)).paginate();
+/// let mut count = 0;
+///
+/// while let Some(member) = members.next().await {
+///     member?;
+///     count += 1;
+/// }
+///
+/// println!("guild has {} members", count);
+/// # Ok(()) }
+/// ```
+///
+/// [`GetGuildMembers::paginate`]: super::guild::member::GetGuildMembers::paginate
+/// [total]: Self::total
+#[must_use = "streams do nothing unless polled"]
+pub struct Paginator<'a, T> {
+    buffer: VecDeque<T>,
+    done: bool,
+    future: Option<PageFuture<'a, T>>,
+    pager: Box<dyn Pager<'a, Item = T>>,
+    remaining: Option<u64>,
+}
+
+impl<'a, T> Paginator<'a, T> {
+    pub(crate) fn new(pager: impl Pager<'a, Item = T>) -> Self {
+        Self {
+            buffer: VecDeque::new(),
+            done: false,
+            future: None,
+            pager: Box::new(pager),
+            remaining: None,
+        }
+    }
+
+    /// Set the maximum number of items to yield in total.
+    ///
+    /// The size of the last page is reduced so that no more items than
+    /// necessary are requested.
+    pub fn total(mut self, total: u64) -> Self {
+        self.remaining = Some(total);
+
+        self
+    }
+}
+
+impl<'a, T: Unpin + 'a> Stream for Paginator<'a, T> {
+    type Item = Result<T, PaginateError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            if this.remaining == Some(0) {
+                return Poll::Ready(None);
+            }
+
+            if let Some(item) = this.buffer.pop_front() {
+                if let Some(remaining) = this.remaining.as_mut() {
+                    *remaining -= 1;
+                }
+
+                return Poll::Ready(Some(Ok(item)));
+            }
+
+            if this.done {
+                return Poll::Ready(None);
+            }
+
+            let pager = &this.pager;
+            let limit = this.remaining.map_or(pager.page_size(), |remaining| {
+                remaining.min(pager.page_size())
+            });
+            let future = this.future.get_or_insert_with(|| pager.page(limit));
+
+            let page = match future.as_mut().poll(cx) {
+                Poll::Ready(Ok(page)) => page,
+                Poll::Ready(Err(source)) => {
+                    this.done = true;
+                    this.future = None;
+
+                    return Poll::Ready(Some(Err(source)));
+                }
+                Poll::Pending => return Poll::Pending,
+            };
+
+            this.future = None;
+            this.done = page.items.is_empty() || !page.more || !this.pager.advance(&page.items);
+            this.buffer.extend(page.items);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PaginateError, PaginateErrorType, Paginator};
+    use crate::{
+        routing::Route,
+        transport::{MockResponse, MockTransport},
+        Client,
+    };
+    use futures_core::Stream;
+    use futures_util::StreamExt;
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+    use twilight_model::{
+        guild::Member,
+        id::{GuildId, UserId},
+    };
+
+    assert_impl_all!(PaginateErrorType: Debug, Send, Sync);
+    assert_impl_all!(PaginateError: Error, Send, Sync);
+    assert_impl_all!(Paginator<'_, Member>: Send, Stream);
+
+    fn bans(ids: &[u64]) -> Vec<u8> {
+        let bans = ids
+            .iter()
+            .map(|id| {
+                format!(
+                    r#"{{"reason":null,"user":{{"avatar":null,"discriminator":"0001","id":"{}","username":"user"}}}}"#,
+                    id
+                )
+            })
+            .collect::<Vec<_>>();
+
+        format!("[{}]", bans.join(",")).into_bytes()
+    }
+
+    fn members(ids: &[u64]) -> Vec<u8> {
+        let members = ids
+            .iter()
+            .map(|id| {
+                format!(
+                    r#"{{"deaf":false,"joined_at":null,"mute":false,"nick":null,"roles":[],"user":{{"avatar":null,"discriminator":"0001","id":"{}","username":"user"}}}}"#,
+                    id
+                )
+            })
+            .collect::<Vec<_>>();
+
+        format!("[{}]", members.join(",")).into_bytes()
+    }
+
+    #[tokio::test]
+    async fn test_paginate_until_partial_page() -> Result<(), Box<dyn Error>> {
+        let transport = MockTransport::new();
+        transport
+            .on_route(
+                &Route::GetBansWithParameters {
+                    after: None,
+                    before: None,
+                    guild_id: 1,
+                    limit: Some(2),
+                },
+                MockResponse::new(200).body(bans(&[2, 1])),
+            )
+            .on_route(
+                &Route::GetBansWithParameters {
+                    after: Some(2),
+                    before: None,
+                    guild_id: 1,
+                    limit: Some(2),
+                },
+                MockResponse::new(200).body(bans(&[3])),
+            );
+
+        let client = Client::builder()
+            .token("token".to_owned())
+            .transport(Box::new(transport.clone()))
+            .build();
+
+        let mut ids = Vec::new();
+        let mut bans = client.bans(GuildId(1)).limit(2)?.paginate();
+
+        while let Some(ban) = bans.next().await {
+            ids.push(ban?.user.id);
+        }
+
+        assert_eq!(vec![UserId(2), UserId(1), UserId(3)], ids);
+        assert_eq!(2, transport.requests().len());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_paginate_total() -> Result<(), Box<dyn Error>> {
+        let transport = MockTransport::new();
+        transport.on_route(
+            &Route::GetBansWithParameters {
+                after: None,
+                before: None,
+                guild_id: 1,
+                limit: Some(1),
+            },
+            MockResponse::new(200).body(bans(&[1])),
+        );
+
+        let client = Client::builder()
+            .token("token".to_owned())
+            .transport(Box::new(transport.clone()))
+            .build();
+
+        let bans = client.bans(GuildId(1)).paginate().total(1);
+        assert_eq!(1, bans.count().await);
+        assert_eq!(1, transport.requests().len());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_paginate_members() -> Result<(), Box<dyn Error>> {
+        let transport = MockTransport::new();
+        transport
+            .on_route(
+                &Route::GetGuildMembers {
+                    after: None,
+                    guild_id: 1,
+                    limit: Some(2),
+                    presences: None,
+                },
+                MockResponse::new(200).body(members(&[1, 2])),
+            )
+            .on_route(
+                &Route::GetGuildMembers {
+                    after: Some(2),
+                    guild_id: 1,
+                    limit: Some(2),
+                    presences: None,
+                },
+                MockResponse::new(200).body(members(&[3, 4])),
+            )
+            .on_route(
+                &Route::GetGuildMembers {
+                    after: Some(4),
+                    guild_id: 1,
+                    limit: Some(2),
+                    presences: None,
+                },
+                MockResponse::new(200).body(members(&[])),
+            );
+
+        let client = Client::builder()
+            .token("token".to_owned())
+            .transport(Box::new(transport.clone()))
+            .build();
+
+        let mut ids = Vec::new();
+        let mut members = client.guild_members(GuildId(1)).limit(2)?.paginate();
+
+        while let Some(member) = members.next().await {
+            let member = member?;
+            assert_eq!(GuildId(1), member.guild_id);
+            ids.push(member.user.id);
+        }
+
+        // Full pages are followed by another request, which ends the stream
+        // once it comes back empty.
+        assert_eq!(vec![UserId(1), UserId(2), UserId(3), UserId(4)], ids);
+        assert_eq!(3, transport.requests().len());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_paginate_members_total() -> Result<(), Box<dyn Error>> {
+        let transport = MockTransport::new();
+        transport
+            .on_route(
+                &Route::GetGuildMembers {
+                    after: None,
+                    guild_id: 1,
+                    limit: Some(1000),
+                    presences: None,
+                },
+                MockResponse::new(200).body(members(&[1, 2])),
+            )
+            .on_route(
+                &Route::GetGuildMembers {
+                    after: None,
+                    guild_id: 1,
+                    limit: Some(3),
+                    presences: None,
+                },
+                MockResponse::new(200).body(members(&[1, 2, 3])),
+            );
+
+        let client = Client::builder()
+            .token("token".to_owned())
+            .transport(Box::new(transport.clone()))
+            .build();
+
+        // The page size is capped to the number of remaining members.
+        let members = client.guild_members(GuildId(1)).paginate().total(3);
+        assert_eq!(3, members.count().await);
+        assert_eq!(1, transport.requests().len());
+
+        Ok(())
+    }
+}
@@ -4,7 +4,11 @@ pub use super::{
     get_gateway::GetGateway,
     get_gateway_authed::GetGatewayAuthed,
     get_voice_regions::GetVoiceRegions,
-    guild::{ban::*, emoji::*, integration::*, member::*, role::*, user::*, *},
+    guild::{
+        ban::*, emoji::*, integration::*, member::*, role::*, scheduled_event::*, sticker::*,
+        user::*, *,
+    },
+    sticker::*,
     template::{
         create_guild_from_template::CreateGuildFromTemplateError,
         create_template::CreateTemplateError, *,
@@ -0,0 +1,67 @@
+use crate::{client::Client, request::Request, response::ResponseFuture, routing::Route};
+use serde::{Deserialize, Serialize};
+use twilight_model::channel::message::sticker::StickerPack;
+
+/// List of sticker packs returned by [`GetNitroStickerPacks`].
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[non_exhaustive]
+pub struct StickerPackListing {
+    /// Sticker packs available to Nitro subscribers.
+    pub sticker_packs: Vec<StickerPack>,
+}
+
+/// Get the sticker packs available to Nitro subscribers.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use twilight_http::Client;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new("my token".to_owned());
+///
+/// let packs = client.nitro_sticker_packs().exec().await?.model().await?;
+///
+/// println!("{}", packs.sticker_packs.len());
+/// # Ok(()) }
+/// ```
+#[must_use = "requests must be configured and executed"]
+pub struct GetNitroStickerPacks<'a> {
+    http: &'a Client,
+}
+
+impl<'a> GetNitroStickerPacks<'a> {
+    pub(crate) const fn new(http: &'a Client) -> Self {
+        Self { http }
+    }
+
+    /// Execute the request, returning a future resolving to a [`Response`].
+    ///
+    /// [`Response`]: crate::response::Response
+    pub fn exec(self) -> ResponseFuture<StickerPackListing> {
+        let request = Request::from_route(&Route::GetNitroStickerPacks);
+
+        self.http.request(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StickerPackListing;
+    use serde::{Deserialize, Serialize};
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::fmt::Debug;
+
+    assert_fields!(StickerPackListing: sticker_packs);
+    assert_impl_all!(
+        StickerPackListing: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+}
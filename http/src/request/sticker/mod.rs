@@ -0,0 +1,5 @@
+pub mod get_nitro_sticker_packs;
+
+mod get_sticker;
+
+pub use self::{get_nitro_sticker_packs::GetNitroStickerPacks, get_sticker::GetSticker};
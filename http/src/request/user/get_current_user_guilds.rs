@@ -1,6 +1,9 @@
 use crate::{
     client::Client,
-    request::{validate_inner, Request},
+    request::{
+        paginate::{self, PageFuture, Pager, Paginator},
+        validate_inner, Request,
+    },
     response::{marker::ListBody, ResponseFuture},
     routing::Route,
 };
@@ -157,4 +160,48 @@ impl<'a> GetCurrentUserGuilds<'a> {
 
         self.http.request(request)
     }
+
+    /// Stream the guilds across pages.
+    ///
+    /// Pages contain the number of guilds set via [`limit`], or 200 if it
+    /// isn't set. If only [`before`] is set then pages move backward from it;
+    /// otherwise they move forward, starting after the guild set via
+    /// [`after`].
+    ///
+    /// [`after`]: Self::after
+    /// [`before`]: Self::before
+    /// [`limit`]: Self::limit
+    pub fn paginate(self) -> Paginator<'a, CurrentUserGuild> {
+        Paginator::new(self)
+    }
+}
+
+impl<'a> Pager<'a> for GetCurrentUserGuilds<'a> {
+    type Item = CurrentUserGuild;
+
+    fn page_size(&self) -> u64 {
+        self.fields.limit.unwrap_or(200)
+    }
+
+    fn page(&self, limit: u64) -> PageFuture<'a, CurrentUserGuild> {
+        let request = Request::from_route(&Route::GetGuilds {
+            after: self.fields.after.map(|x| x.0),
+            before: self.fields.before.map(|x| x.0),
+            limit: Some(limit),
+        });
+
+        paginate::list_page(self.http.request(request), limit)
+    }
+
+    fn advance(&mut self, items: &[CurrentUserGuild]) -> bool {
+        let ids = items.iter().map(|guild| guild.id);
+
+        if self.fields.before.is_some() && self.fields.after.is_none() {
+            self.fields.before = ids.min();
+        } else {
+            self.fields.after = ids.max();
+        }
+
+        true
+    }
 }
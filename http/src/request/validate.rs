@@ -9,7 +9,12 @@ use std::{
     fmt::{Display, Formatter, Result as FmtResult},
 };
 use twilight_model::{
-    application::component::{select_menu::SelectMenuOption, Component, ComponentType},
+    application::{
+        callback::ModalCallbackData,
+        component::{
+            select_menu::SelectMenuOption, text_input::TextInput, Component, ComponentType,
+        },
+    },
     channel::{embed::Embed, ChannelType},
 };
 
@@ -63,6 +68,15 @@ impl ComponentValidationError {
     /// [1]: https://discord.com/developers/docs/interactions/message-components#component-object-component-structure
     pub const COMPONENT_LABEL_LENGTH: usize = 80;
 
+    /// Maximum length of a [`ModalCallbackData::title`] in codepoints.
+    ///
+    /// This is defined in Discord's documentation, per
+    /// [Discord Docs/Modal][1].
+    ///
+    /// [`ModalCallbackData::title`]: twilight_model::application::callback::ModalCallbackData::title
+    /// [1]: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object-modal
+    pub const MODAL_TITLE_LENGTH: usize = 45;
+
     /// Maximum number of [`SelectMenuOption`]s that can be chosen in a
     /// [`SelectMenu`].
     ///
@@ -141,6 +155,60 @@ impl ComponentValidationError {
     /// [1]: https://discord.com/developers/docs/interactions/message-components#select-menu-object-select-menu-structure
     pub const SELECT_PLACEHOLDER_LENGTH: usize = 100;
 
+    /// Maximum length of a [`TextInput::label`] in codepoints.
+    ///
+    /// This is defined in Discord's documentation, per
+    /// [Discord Docs/Text Input][1].
+    ///
+    /// [`TextInput::label`]: twilight_model::application::component::TextInput::label
+    /// [1]: https://discord.com/developers/docs/interactions/message-components#text-inputs-text-input-structure
+    pub const TEXT_INPUT_LABEL_LENGTH: usize = 45;
+
+    /// Maximum value of a [`TextInput::max_length`].
+    ///
+    /// This is defined in Discord's documentation, per
+    /// [Discord Docs/Text Input][1].
+    ///
+    /// [`TextInput::max_length`]: twilight_model::application::component::TextInput::max_length
+    /// [1]: https://discord.com/developers/docs/interactions/message-components#text-inputs-text-input-structure
+    pub const TEXT_INPUT_MAX_LENGTH_LIMIT: usize = 4000;
+
+    /// Minimum value of a [`TextInput::max_length`].
+    ///
+    /// This is defined in Discord's documentation, per
+    /// [Discord Docs/Text Input][1].
+    ///
+    /// [`TextInput::max_length`]: twilight_model::application::component::TextInput::max_length
+    /// [1]: https://discord.com/developers/docs/interactions/message-components#text-inputs-text-input-structure
+    pub const TEXT_INPUT_MAX_LENGTH_REQUIREMENT: usize = 1;
+
+    /// Maximum value of a [`TextInput::min_length`].
+    ///
+    /// This is defined in Discord's documentation, per
+    /// [Discord Docs/Text Input][1].
+    ///
+    /// [`TextInput::min_length`]: twilight_model::application::component::TextInput::min_length
+    /// [1]: https://discord.com/developers/docs/interactions/message-components#text-inputs-text-input-structure
+    pub const TEXT_INPUT_MIN_LENGTH_LIMIT: usize = 4000;
+
+    /// Maximum length of a [`TextInput::placeholder`] in codepoints.
+    ///
+    /// This is defined in Discord's documentation, per
+    /// [Discord Docs/Text Input][1].
+    ///
+    /// [`TextInput::placeholder`]: twilight_model::application::component::TextInput::placeholder
+    /// [1]: https://discord.com/developers/docs/interactions/message-components#text-inputs-text-input-structure
+    pub const TEXT_INPUT_PLACEHOLDER_LENGTH: usize = 100;
+
+    /// Maximum length of a [`TextInput::value`] in codepoints.
+    ///
+    /// This is defined in Discord's documentation, per
+    /// [Discord Docs/Text Input][1].
+    ///
+    /// [`TextInput::value`]: twilight_model::application::component::TextInput::value
+    /// [1]: https://discord.com/developers/docs/interactions/message-components#text-inputs-text-input-structure
+    pub const TEXT_INPUT_VALUE_LENGTH: usize = 4000;
+
     /// Consume the error, returning the owned error type and the source error.
     #[must_use = "consuming the error into its parts has no effect if left unused"]
     pub fn into_parts(
@@ -154,6 +222,7 @@ impl ComponentValidationError {
 }
 
 impl Display for ComponentValidationError {
+    #[allow(clippy::too_many_lines)]
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match &self.kind {
             ComponentValidationErrorType::ActionRowComponentCount { count } => {
@@ -195,6 +264,13 @@ impl Display for ComponentValidationError {
 
                 f.write_str("' component was provided, but can not be a root component")
             }
+            ComponentValidationErrorType::ModalTitleLength { chars } => {
+                f.write_str("a modal's title is ")?;
+                Display::fmt(&chars, f)?;
+                f.write_str(" characters long, but the max is ")?;
+
+                Display::fmt(&Self::MODAL_TITLE_LENGTH, f)
+            }
             ComponentValidationErrorType::SelectMaximumValuesCount { count } => {
                 f.write_str("maximum number of values that can be chosen is ")?;
                 Display::fmt(count, f)?;
@@ -246,6 +322,43 @@ impl Display for ComponentValidationError {
 
                 Display::fmt(&Self::SELECT_OPTION_COUNT, f)
             }
+            ComponentValidationErrorType::TextInputLabelLength { chars } => {
+                f.write_str("a text input's label is ")?;
+                Display::fmt(&chars, f)?;
+                f.write_str(" characters long, but the max is ")?;
+
+                Display::fmt(&Self::TEXT_INPUT_LABEL_LENGTH, f)
+            }
+            ComponentValidationErrorType::TextInputMaxLength { len } => {
+                f.write_str("a text input's maximum length is ")?;
+                Display::fmt(&len, f)?;
+                f.write_str(", but it must be at least ")?;
+                Display::fmt(&Self::TEXT_INPUT_MAX_LENGTH_REQUIREMENT, f)?;
+                f.write_str(" and at most ")?;
+
+                Display::fmt(&Self::TEXT_INPUT_MAX_LENGTH_LIMIT, f)
+            }
+            ComponentValidationErrorType::TextInputMinLength { len } => {
+                f.write_str("a text input's minimum length is ")?;
+                Display::fmt(&len, f)?;
+                f.write_str(", but the max is ")?;
+
+                Display::fmt(&Self::TEXT_INPUT_MIN_LENGTH_LIMIT, f)
+            }
+            ComponentValidationErrorType::TextInputPlaceholderLength { chars } => {
+                f.write_str("a text input's placeholder is ")?;
+                Display::fmt(&chars, f)?;
+                f.write_str(" characters long, but the max is ")?;
+
+                Display::fmt(&Self::TEXT_INPUT_PLACEHOLDER_LENGTH, f)
+            }
+            ComponentValidationErrorType::TextInputValueLength { chars } => {
+                f.write_str("a text input's value is ")?;
+                Display::fmt(&chars, f)?;
+                f.write_str(" characters long, but the max is ")?;
+
+                Display::fmt(&Self::TEXT_INPUT_VALUE_LENGTH, f)
+            }
         }
     }
 }
@@ -298,6 +411,13 @@ pub enum ComponentValidationErrorType {
         /// Type of provided component.
         kind: ComponentType,
     },
+    /// Title of a modal is larger than [the maximum][`MODAL_TITLE_LENGTH`].
+    ///
+    /// [`MODAL_TITLE_LENGTH`]: ComponentValidationError::MODAL_TITLE_LENGTH
+    ModalTitleLength {
+        /// Number of codepoints that were provided.
+        chars: usize,
+    },
     /// Maximum number of items that can be chosen is smaller than
     /// [the minimum][`SELECT_MAXIMUM_VALUES_REQUIREMENT`] or larger than
     /// [the maximum][`SELECT_MAXIMUM_VALUES_LIMIT`].
@@ -350,6 +470,49 @@ pub enum ComponentValidationErrorType {
         /// Number of codepoints that were provided.
         chars: usize,
     },
+    /// Label of a text input is larger than
+    /// [the maximum][`TEXT_INPUT_LABEL_LENGTH`].
+    ///
+    /// [`TEXT_INPUT_LABEL_LENGTH`]: ComponentValidationError::TEXT_INPUT_LABEL_LENGTH
+    TextInputLabelLength {
+        /// Number of codepoints that were provided.
+        chars: usize,
+    },
+    /// Maximum length of a text input is smaller than
+    /// [the minimum][`TEXT_INPUT_MAX_LENGTH_REQUIREMENT`], larger than
+    /// [the maximum][`TEXT_INPUT_MAX_LENGTH_LIMIT`], or smaller than the
+    /// minimum length of the text input.
+    ///
+    /// [`TEXT_INPUT_MAX_LENGTH_LIMIT`]: ComponentValidationError::TEXT_INPUT_MAX_LENGTH_LIMIT
+    /// [`TEXT_INPUT_MAX_LENGTH_REQUIREMENT`]: ComponentValidationError::TEXT_INPUT_MAX_LENGTH_REQUIREMENT
+    TextInputMaxLength {
+        /// Provided maximum length.
+        len: usize,
+    },
+    /// Minimum length of a text input is larger than
+    /// [the maximum][`TEXT_INPUT_MIN_LENGTH_LIMIT`].
+    ///
+    /// [`TEXT_INPUT_MIN_LENGTH_LIMIT`]: ComponentValidationError::TEXT_INPUT_MIN_LENGTH_LIMIT
+    TextInputMinLength {
+        /// Provided minimum length.
+        len: usize,
+    },
+    /// Placeholder of a text input is larger than
+    /// [the maximum][`TEXT_INPUT_PLACEHOLDER_LENGTH`].
+    ///
+    /// [`TEXT_INPUT_PLACEHOLDER_LENGTH`]: ComponentValidationError::TEXT_INPUT_PLACEHOLDER_LENGTH
+    TextInputPlaceholderLength {
+        /// Number of codepoints that were provided.
+        chars: usize,
+    },
+    /// Pre-filled value of a text input is larger than
+    /// [the maximum][`TEXT_INPUT_VALUE_LENGTH`].
+    ///
+    /// [`TEXT_INPUT_VALUE_LENGTH`]: ComponentValidationError::TEXT_INPUT_VALUE_LENGTH
+    TextInputValueLength {
+        /// Number of codepoints that were provided.
+        chars: usize,
+    },
 }
 
 /// An embed is not valid.
@@ -593,8 +756,9 @@ pub fn components(components: &[Component]) -> Result<(), ComponentValidationErr
 /// type if the provided list of components is too many for an [`ActionRow`].
 ///
 /// Returns a [`ComponentValidationErrorType::InvalidChildComponent`] if the
-/// provided nested component is an [`ActionRow`]. Action rows can not
-/// contain another action row.
+/// provided nested component is an [`ActionRow`] or a [`TextInput`]. Action
+/// rows can not contain another action row, and text inputs may only be sent
+/// in modals.
 ///
 /// [`ActionRow`]: twilight_model::application::component::ActionRow
 pub fn component(component: &Component) -> Result<(), ComponentValidationError> {
@@ -622,8 +786,9 @@ pub fn component(component: &Component) -> Result<(), ComponentValidationError>
 /// # Errors
 ///
 /// Returns a [`ComponentValidationErrorType::InvalidChildComponent`] if a
-/// provided nested component is a [`Component::ActionRow`]. Action rows can not
-/// contain another action row.
+/// provided nested component is a [`Component::ActionRow`] or a
+/// [`Component::TextInput`]. Action rows can not contain another action row,
+/// and text inputs may only be sent in modals.
 ///
 /// Returns a [`ComponentValidationErrorType::OptionDescriptionLength`] error
 /// type if a provided select option description is too long.
@@ -687,6 +852,13 @@ fn component_inner(component: &Component) -> Result<(), ComponentValidationError
                 }
             }
         }
+        Component::TextInput(_) => {
+            return Err(ComponentValidationError {
+                kind: ComponentValidationErrorType::InvalidChildComponent {
+                    kind: ComponentType::TextInput,
+                },
+            })
+        }
     }
 
     Ok(())
@@ -906,6 +1078,156 @@ fn component_select_placeholder(placeholder: &str) -> Result<(), ComponentValida
     Ok(())
 }
 
+/// Validate the contents of a [`TextInput`].
+///
+/// # Errors
+///
+/// Returns a [`ComponentValidationErrorType::ComponentCustomIdLength`] if the
+/// provided custom ID is too long.
+///
+/// Returns a [`ComponentValidationErrorType::TextInputLabelLength`] if the
+/// provided label is too long.
+///
+/// Returns a [`ComponentValidationErrorType::TextInputMaxLength`] if the
+/// provided maximum length is smaller than the minimum or larger than the
+/// maximum, or if it's smaller than the provided minimum length.
+///
+/// Returns a [`ComponentValidationErrorType::TextInputMinLength`] if the
+/// provided minimum length is larger than the maximum.
+///
+/// Returns a [`ComponentValidationErrorType::TextInputPlaceholderLength`] if
+/// the provided placeholder is too long.
+///
+/// Returns a [`ComponentValidationErrorType::TextInputValueLength`] if the
+/// provided pre-filled value is too long.
+pub fn text_input(text_input: &TextInput) -> Result<(), ComponentValidationError> {
+    component_custom_id(&text_input.custom_id)?;
+
+    let chars = text_input.label.chars().count();
+
+    if chars > ComponentValidationError::TEXT_INPUT_LABEL_LENGTH {
+        return Err(ComponentValidationError {
+            kind: ComponentValidationErrorType::TextInputLabelLength { chars },
+        });
+    }
+
+    if let Some(max_length) = text_input.max_length {
+        let len = usize::from(max_length);
+
+        if !(ComponentValidationError::TEXT_INPUT_MAX_LENGTH_REQUIREMENT
+            ..=ComponentValidationError::TEXT_INPUT_MAX_LENGTH_LIMIT)
+            .contains(&len)
+        {
+            return Err(ComponentValidationError {
+                kind: ComponentValidationErrorType::TextInputMaxLength { len },
+            });
+        }
+    }
+
+    if let Some(min_length) = text_input.min_length {
+        let len = usize::from(min_length);
+
+        if len > ComponentValidationError::TEXT_INPUT_MIN_LENGTH_LIMIT {
+            return Err(ComponentValidationError {
+                kind: ComponentValidationErrorType::TextInputMinLength { len },
+            });
+        }
+    }
+
+    if let (Some(max_length), Some(min_length)) = (text_input.max_length, text_input.min_length) {
+        if min_length > max_length {
+            return Err(ComponentValidationError {
+                kind: ComponentValidationErrorType::TextInputMaxLength {
+                    len: usize::from(max_length),
+                },
+            });
+        }
+    }
+
+    if let Some(placeholder) = text_input.placeholder.as_ref() {
+        let chars = placeholder.chars().count();
+
+        if chars > ComponentValidationError::TEXT_INPUT_PLACEHOLDER_LENGTH {
+            return Err(ComponentValidationError {
+                kind: ComponentValidationErrorType::TextInputPlaceholderLength { chars },
+            });
+        }
+    }
+
+    if let Some(value) = text_input.value.as_ref() {
+        let chars = value.chars().count();
+
+        if chars > ComponentValidationError::TEXT_INPUT_VALUE_LENGTH {
+            return Err(ComponentValidationError {
+                kind: ComponentValidationErrorType::TextInputValueLength { chars },
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Validate a modal sent as an interaction response.
+///
+/// # Errors
+///
+/// Returns a [`ComponentValidationErrorType::ComponentCustomIdLength`] if the
+/// provided custom ID is too long.
+///
+/// Returns a [`ComponentValidationErrorType::ModalTitleLength`] if the
+/// provided title is too long.
+///
+/// Returns a [`ComponentValidationErrorType::ComponentCount`] if there are
+/// too many components in the modal.
+///
+/// Returns a [`ComponentValidationErrorType::InvalidRootComponent`] if a
+/// component of the modal isn't an action row.
+///
+/// Refer to the errors sections of [`component`] and [`text_input`] for a list
+/// of errors that may be returned as a result of validating the components
+/// within the action rows of the modal.
+pub fn modal(modal: &ModalCallbackData) -> Result<(), ComponentValidationError> {
+    component_custom_id(&modal.custom_id)?;
+
+    let chars = modal.title.chars().count();
+
+    if chars > ComponentValidationError::MODAL_TITLE_LENGTH {
+        return Err(ComponentValidationError {
+            kind: ComponentValidationErrorType::ModalTitleLength { chars },
+        });
+    }
+
+    let count = modal.components.len();
+
+    if count > ComponentValidationError::COMPONENT_COUNT {
+        return Err(ComponentValidationError {
+            kind: ComponentValidationErrorType::ComponentCount { count },
+        });
+    }
+
+    for component in &modal.components {
+        match component {
+            Component::ActionRow(action_row) => {
+                component_action_row_components(&action_row.components)?;
+
+                for inner in &action_row.components {
+                    match inner {
+                        Component::TextInput(text_input) => self::text_input(text_input)?,
+                        other => component_inner(other)?,
+                    }
+                }
+            }
+            other => {
+                return Err(ComponentValidationError {
+                    kind: ComponentValidationErrorType::InvalidRootComponent { kind: other.kind() },
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
 pub fn content_limit(value: impl AsRef<str>) -> bool {
     _content_limit(value.as_ref())
 }
@@ -1022,11 +1344,21 @@ pub const fn get_current_user_guilds_limit(value: u64) -> bool {
     value >= 1 && value <= 200
 }
 
+pub const fn get_guild_bans_limit(value: u64) -> bool {
+    // <https://discord.com/developers/docs/resources/guild#get-guild-bans-query-string-params>
+    value >= 1 && value <= 1000
+}
+
 pub const fn get_guild_members_limit(value: u64) -> bool {
     // <https://discordapp.com/developers/docs/resources/guild#list-guild-members-query-string-params>
     value >= 1 && value <= 1000
 }
 
+pub const fn get_guild_scheduled_event_users_limit(value: u64) -> bool {
+    // <https://discord.com/developers/docs/resources/guild-scheduled-event#get-guild-scheduled-event-users-query-string-params>
+    value >= 1 && value <= 100
+}
+
 pub const fn search_guild_members_limit(value: u64) -> bool {
     value > 0 && value <= 1000
 }
@@ -1107,6 +1439,39 @@ fn _template_description(value: &str) -> bool {
     (0..=120).contains(&len)
 }
 
+pub fn scheduled_event_description(value: impl AsRef<str>) -> bool {
+    _scheduled_event_description(value.as_ref())
+}
+
+fn _scheduled_event_description(value: &str) -> bool {
+    let len = value.chars().count();
+
+    // <https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object-guild-scheduled-event-structure>
+    (1..=1000).contains(&len)
+}
+
+pub fn scheduled_event_location(value: impl AsRef<str>) -> bool {
+    _scheduled_event_location(value.as_ref())
+}
+
+fn _scheduled_event_location(value: &str) -> bool {
+    let len = value.chars().count();
+
+    // <https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object-guild-scheduled-event-entity-metadata>
+    (1..=100).contains(&len)
+}
+
+pub fn scheduled_event_name(value: impl AsRef<str>) -> bool {
+    _scheduled_event_name(value.as_ref())
+}
+
+fn _scheduled_event_name(value: &str) -> bool {
+    let len = value.chars().count();
+
+    // <https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object-guild-scheduled-event-structure>
+    (1..=100).contains(&len)
+}
+
 pub fn stage_topic(value: impl AsRef<str>) -> bool {
     _stage_topic(value.as_ref())
 }
@@ -1118,6 +1483,44 @@ fn _stage_topic(value: &str) -> bool {
     (0..=120).contains(&len)
 }
 
+pub fn sticker_description(value: impl AsRef<str>) -> bool {
+    _sticker_description(value.as_ref())
+}
+
+fn _sticker_description(value: &str) -> bool {
+    let len = value.chars().count();
+
+    // <https://discord.com/developers/docs/resources/sticker#create-guild-sticker-form-params>
+    len == 0 || (2..=100).contains(&len)
+}
+
+pub const fn sticker_file_size(len: usize) -> bool {
+    // <https://discord.com/developers/docs/resources/sticker#create-guild-sticker-form-params>
+    len <= 512 * 1024
+}
+
+pub fn sticker_name(value: impl AsRef<str>) -> bool {
+    _sticker_name(value.as_ref())
+}
+
+fn _sticker_name(value: &str) -> bool {
+    let len = value.chars().count();
+
+    // <https://discord.com/developers/docs/resources/sticker#create-guild-sticker-form-params>
+    (2..=30).contains(&len)
+}
+
+pub fn sticker_tags(value: impl AsRef<str>) -> bool {
+    _sticker_tags(value.as_ref())
+}
+
+fn _sticker_tags(value: &str) -> bool {
+    let len = value.chars().count();
+
+    // <https://discord.com/developers/docs/resources/sticker#create-guild-sticker-form-params>
+    (2..=200).contains(&len)
+}
+
 pub fn command_name(value: impl AsRef<str>) -> bool {
     _command_name(value.as_ref())
 }
@@ -1140,6 +1543,17 @@ fn _command_description(value: &str) -> bool {
     (1..=100).contains(&len)
 }
 
+pub fn command_option_choice_name(value: impl AsRef<str>) -> bool {
+    _command_option_choice_name(value.as_ref())
+}
+
+fn _command_option_choice_name(value: &str) -> bool {
+    let len = value.chars().count();
+
+    // https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-option-choice-structure
+    (1..=100).contains(&len)
+}
+
 pub const fn command_permissions(len: usize) -> bool {
     // https://discord.com/developers/docs/interactions/application-commands#edit-application-command-permissions
     len <= 10
@@ -1168,7 +1582,10 @@ mod tests {
     use super::*;
     use static_assertions::{assert_fields, assert_impl_all, const_assert_eq};
     use std::fmt::Debug;
-    use twilight_model::channel::embed::{EmbedAuthor, EmbedField, EmbedFooter};
+    use twilight_model::{
+        application::component::{text_input::TextInputStyle, ActionRow},
+        channel::embed::{EmbedAuthor, EmbedField, EmbedFooter},
+    };
 
     assert_fields!(ComponentValidationErrorType::ActionRowComponentCount: count);
     assert_fields!(ComponentValidationErrorType::ComponentCount: count);
@@ -1176,12 +1593,18 @@ mod tests {
     assert_fields!(ComponentValidationErrorType::ComponentLabelLength: chars);
     assert_fields!(ComponentValidationErrorType::InvalidChildComponent: kind);
     assert_fields!(ComponentValidationErrorType::InvalidRootComponent: kind);
+    assert_fields!(ComponentValidationErrorType::ModalTitleLength: chars);
     assert_fields!(ComponentValidationErrorType::SelectMaximumValuesCount: count);
     assert_fields!(ComponentValidationErrorType::SelectMinimumValuesCount: count);
     assert_fields!(ComponentValidationErrorType::SelectOptionDescriptionLength: chars);
     assert_fields!(ComponentValidationErrorType::SelectOptionLabelLength: chars);
     assert_fields!(ComponentValidationErrorType::SelectOptionValueLength: chars);
     assert_fields!(ComponentValidationErrorType::SelectPlaceholderLength: chars);
+    assert_fields!(ComponentValidationErrorType::TextInputLabelLength: chars);
+    assert_fields!(ComponentValidationErrorType::TextInputMaxLength: len);
+    assert_fields!(ComponentValidationErrorType::TextInputMinLength: len);
+    assert_fields!(ComponentValidationErrorType::TextInputPlaceholderLength: chars);
+    assert_fields!(ComponentValidationErrorType::TextInputValueLength: chars);
     assert_impl_all!(ComponentValidationErrorType: Debug, Send, Sync);
     assert_impl_all!(ComponentValidationError: Debug, Send, Sync);
     assert_impl_all!(EmbedValidationErrorType: Debug, Send, Sync);
@@ -1190,6 +1613,7 @@ mod tests {
     const_assert_eq!(5, ComponentValidationError::COMPONENT_COUNT);
     const_assert_eq!(100, ComponentValidationError::COMPONENT_CUSTOM_ID_LENGTH);
     const_assert_eq!(80, ComponentValidationError::COMPONENT_LABEL_LENGTH);
+    const_assert_eq!(45, ComponentValidationError::MODAL_TITLE_LENGTH);
     const_assert_eq!(25, ComponentValidationError::SELECT_MAXIMUM_VALUES_LIMIT);
     const_assert_eq!(
         1,
@@ -1204,6 +1628,15 @@ mod tests {
     const_assert_eq!(100, ComponentValidationError::SELECT_OPTION_LABEL_LENGTH);
     const_assert_eq!(100, ComponentValidationError::SELECT_OPTION_VALUE_LENGTH);
     const_assert_eq!(100, ComponentValidationError::SELECT_PLACEHOLDER_LENGTH);
+    const_assert_eq!(45, ComponentValidationError::TEXT_INPUT_LABEL_LENGTH);
+    const_assert_eq!(4000, ComponentValidationError::TEXT_INPUT_MAX_LENGTH_LIMIT);
+    const_assert_eq!(
+        1,
+        ComponentValidationError::TEXT_INPUT_MAX_LENGTH_REQUIREMENT
+    );
+    const_assert_eq!(4000, ComponentValidationError::TEXT_INPUT_MIN_LENGTH_LIMIT);
+    const_assert_eq!(100, ComponentValidationError::TEXT_INPUT_PLACEHOLDER_LENGTH);
+    const_assert_eq!(4000, ComponentValidationError::TEXT_INPUT_VALUE_LENGTH);
     const_assert_eq!(256, EmbedValidationError::AUTHOR_NAME_LENGTH);
     const_assert_eq!(4096, EmbedValidationError::DESCRIPTION_LENGTH);
     const_assert_eq!(6000, EmbedValidationError::EMBED_TOTAL_LENGTH);
@@ -1474,6 +1907,24 @@ mod tests {
         assert!(!get_current_user_guilds_limit(201));
     }
 
+    #[test]
+    fn test_get_guild_bans_limit() {
+        assert!(get_guild_bans_limit(1));
+        assert!(get_guild_bans_limit(1000));
+
+        assert!(!get_guild_bans_limit(0));
+        assert!(!get_guild_bans_limit(1001));
+    }
+
+    #[test]
+    fn test_get_guild_scheduled_event_users_limit() {
+        assert!(get_guild_scheduled_event_users_limit(1));
+        assert!(get_guild_scheduled_event_users_limit(100));
+
+        assert!(!get_guild_scheduled_event_users_limit(0));
+        assert!(!get_guild_scheduled_event_users_limit(101));
+    }
+
     #[test]
     fn test_get_guild_members_limit() {
         assert!(get_guild_members_limit(1));
@@ -1535,6 +1986,161 @@ mod tests {
         assert!(!nickname("a".repeat(33)));
     }
 
+    #[test]
+    fn test_scheduled_event_description() {
+        assert!(scheduled_event_description("a"));
+        assert!(scheduled_event_description("a".repeat(1000)));
+
+        assert!(!scheduled_event_description(""));
+        assert!(!scheduled_event_description("a".repeat(1001)));
+    }
+
+    #[test]
+    fn test_scheduled_event_location() {
+        assert!(scheduled_event_location("a"));
+        assert!(scheduled_event_location("a".repeat(100)));
+
+        assert!(!scheduled_event_location(""));
+        assert!(!scheduled_event_location("a".repeat(101)));
+    }
+
+    #[test]
+    fn test_scheduled_event_name() {
+        assert!(scheduled_event_name("a"));
+        assert!(scheduled_event_name("a".repeat(100)));
+
+        assert!(!scheduled_event_name(""));
+        assert!(!scheduled_event_name("a".repeat(101)));
+    }
+
+    #[test]
+    fn test_sticker_description() {
+        assert!(sticker_description(""));
+        assert!(sticker_description("aa"));
+        assert!(sticker_description("a".repeat(100)));
+
+        assert!(!sticker_description("a"));
+        assert!(!sticker_description("a".repeat(101)));
+    }
+
+    #[test]
+    fn test_sticker_file_size() {
+        assert!(sticker_file_size(0));
+        assert!(sticker_file_size(512 * 1024));
+
+        assert!(!sticker_file_size(512 * 1024 + 1));
+    }
+
+    #[test]
+    fn test_sticker_name() {
+        assert!(sticker_name("aa"));
+        assert!(sticker_name("a".repeat(30)));
+
+        assert!(!sticker_name("a"));
+        assert!(!sticker_name("a".repeat(31)));
+    }
+
+    #[test]
+    fn test_sticker_tags() {
+        assert!(sticker_tags("aa"));
+        assert!(sticker_tags("a".repeat(200)));
+
+        assert!(!sticker_tags("a"));
+        assert!(!sticker_tags("a".repeat(201)));
+    }
+
+    #[test]
+    fn test_text_input() {
+        let text_input = TextInput {
+            custom_id: "reason".to_owned(),
+            label: "Reason".to_owned(),
+            max_length: Some(4000),
+            min_length: Some(4000),
+            placeholder: Some("a".repeat(100)),
+            required: None,
+            style: TextInputStyle::Paragraph,
+            value: Some("a".repeat(4000)),
+        };
+        assert!(super::text_input(&text_input).is_ok());
+
+        let invalid = [
+            TextInput {
+                label: "a".repeat(46),
+                ..text_input.clone()
+            },
+            TextInput {
+                max_length: Some(0),
+                ..text_input.clone()
+            },
+            TextInput {
+                max_length: Some(4001),
+                ..text_input.clone()
+            },
+            TextInput {
+                min_length: Some(4001),
+                ..text_input.clone()
+            },
+            TextInput {
+                max_length: Some(10),
+                min_length: Some(11),
+                ..text_input.clone()
+            },
+            TextInput {
+                placeholder: Some("a".repeat(101)),
+                ..text_input.clone()
+            },
+            TextInput {
+                value: Some("a".repeat(4001)),
+                ..text_input.clone()
+            },
+        ];
+
+        for text_input in &invalid {
+            assert!(super::text_input(text_input).is_err());
+        }
+
+        assert!(matches!(
+            super::text_input(&TextInput {
+                max_length: Some(10),
+                min_length: Some(11),
+                ..text_input.clone()
+            })
+            .unwrap_err()
+            .into_parts()
+            .0,
+            ComponentValidationErrorType::TextInputMaxLength { len: 10 }
+        ));
+
+        // Text inputs may only be sent in modals.
+        let row = Component::ActionRow(ActionRow {
+            components: vec![Component::TextInput(text_input.clone())],
+        });
+        assert!(matches!(
+            super::component(&row).unwrap_err().into_parts().0,
+            ComponentValidationErrorType::InvalidChildComponent {
+                kind: ComponentType::TextInput
+            }
+        ));
+
+        let modal = ModalCallbackData {
+            components: vec![Component::ActionRow(ActionRow {
+                components: vec![Component::TextInput(text_input)],
+            })],
+            custom_id: "modal".to_owned(),
+            title: "a".repeat(45),
+        };
+        assert!(super::modal(&modal).is_ok());
+
+        let invalid = ModalCallbackData {
+            title: "a".repeat(46),
+            ..modal
+        };
+        assert!(matches!(
+            super::modal(&invalid).unwrap_err().into_parts().0,
+            ComponentValidationErrorType::ModalTitleLength { chars: 46 }
+        ));
+    }
+
     #[test]
     fn test_username() {
         assert!(username("aa"));
@@ -1,13 +1,20 @@
 use super::{Response, StatusCode};
 use crate::{
     api_error::ApiError,
+    client::{Client, PreparedRequest, RetryPolicy, RetryReason},
     error::{Error, ErrorType},
-    ratelimiting::RatelimitHeaders,
+    middleware::{MiddlewareRequest, MiddlewareResponse},
+    oauth::AuthorizationFuture,
+    ratelimiting::{Priority, RatelimitHeaders, TicketReceiver, TicketSender},
+    transport::TransportFuture,
+};
+use hyper::{
+    header::{HeaderValue, AUTHORIZATION},
+    Body, Response as HyperResponse, StatusCode as HyperStatusCode,
 };
-use hyper::{client::ResponseFuture as HyperResponseFuture, StatusCode as HyperStatusCode};
 use std::{
     convert::TryFrom,
-    future::Future,
+    future::{self, Future},
     marker::PhantomData,
     mem,
     pin::Pin,
@@ -16,12 +23,9 @@ use std::{
         Arc,
     },
     task::{Context, Poll},
-    time::Duration,
-};
-use tokio::{
-    sync::oneshot::{Receiver, Sender},
-    time::{self, Timeout},
+    time::{Duration, Instant},
 };
+use tokio::time::{self, Sleep, Timeout};
 use twilight_model::id::GuildId;
 
 pub enum InvalidToken {
@@ -37,6 +41,181 @@ enum InnerPoll<T> {
     Ready(Output<T>),
 }
 
+/// Request that may be sent again if an attempt fails.
+pub(crate) struct Resend {
+    /// Number of attempts that have been started.
+    attempts: u16,
+    client: Client,
+    policy: RetryPolicy,
+    request: PreparedRequest,
+}
+
+impl Resend {
+    pub(crate) const fn new(client: Client, policy: RetryPolicy, request: PreparedRequest) -> Self {
+        Self {
+            attempts: 0,
+            client,
+            policy,
+            request,
+        }
+    }
+
+    /// Start a new attempt of the request, queueing it in the client's
+    /// ratelimiter if it has one.
+    fn start(mut self, guild_id: Option<GuildId>) -> ResponseFutureStage {
+        self.attempts += 1;
+
+        let invalid_token = self.client.invalid_token();
+        let request_timeout = self.client.request_timeout();
+        let client = self.client.clone();
+        let middleware = client.middleware();
+
+        let intercept = if middleware.is_empty() {
+            None
+        } else {
+            let mut request = MiddlewareRequest::new(self.attempts, self.request.clone());
+
+            for (layers, layer) in middleware.iter().enumerate() {
+                if let Some(response) = layer.request(&mut request) {
+                    return ResponseFutureStage::Intercepting(Intercepting {
+                        future: Box::pin(future::ready(Ok(response))),
+                        guild_id,
+                        intercept: Intercept { layers, request },
+                        invalid_token,
+                        resend: self,
+                        tx: None,
+                    });
+                }
+            }
+
+            Some(Intercept {
+                layers: middleware.len(),
+                request,
+            })
+        };
+
+        // The request is only handed to the transport once the ratelimiter
+        // grants it a ticket, as transports may start sending it right away.
+        if let Some(ratelimiter) = self.client.ratelimiter() {
+            let rx = ratelimiter
+                .ticket_with_priority(self.request.ratelimit_path.clone(), self.request.priority);
+
+            return ResponseFutureStage::RatelimitQueue(RatelimitQueue {
+                guild_id,
+                intercept,
+                invalid_token,
+                request_timeout,
+                resend: self,
+                wait_for_sender: rx,
+            });
+        }
+
+        match self.send(intercept.as_ref()) {
+            Ok(response_future) => ResponseFutureStage::InFlight(InFlight {
+                future: Box::pin(time::timeout(request_timeout, response_future)),
+                guild_id,
+                intercept,
+                invalid_token,
+                resend: self,
+                sent_at: Instant::now(),
+                tx: None,
+            }),
+            Err(source) => ResponseFutureStage::Failed(Failed { source }),
+        }
+    }
+
+    /// Send the request, as modified by middleware if any, through the
+    /// client's transport.
+    fn send(&self, intercept: Option<&Intercept>) -> Result<TransportFuture, Error> {
+        match intercept {
+            Some(intercept) => self.client.send_prepared(&intercept.request.prepared),
+            None => self.client.send_prepared(&self.request),
+        }
+    }
+
+    /// Wait before starting another attempt of the request if the retry
+    /// policy allows it.
+    fn backoff(self, guild_id: Option<GuildId>, reason: RetryReason) -> Result<Backoff, Self> {
+        let delay = match self
+            .policy
+            .delay(self.attempts, self.request.method, reason)
+        {
+            Some(delay) => delay,
+            None => return Err(self),
+        };
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            attempts = self.attempts,
+            milliseconds = %delay.as_millis(),
+            path = ?self.request.ratelimit_path,
+            ?reason,
+            "retrying request",
+        );
+
+        Ok(Backoff {
+            guild_id,
+            resend: self,
+            sleep: Box::pin(time::sleep(delay)),
+        })
+    }
+}
+
+/// Request waiting for the client's token provider to provide its
+/// authorization.
+struct Authorizing {
+    future: AuthorizationFuture,
+    guild_id: Option<GuildId>,
+    resend: Resend,
+}
+
+impl Authorizing {
+    fn poll<T>(mut self, cx: &mut Context<'_>) -> InnerPoll<T> {
+        let authorization = match self.future.as_mut().poll(cx) {
+            Poll::Ready(Ok(authorization)) => authorization,
+            Poll::Ready(Err(source)) => {
+                return InnerPoll::Ready(Err(Error {
+                    kind: ErrorType::Authorizing,
+                    source: Some(source),
+                }))
+            }
+            Poll::Pending => return InnerPoll::Pending(ResponseFutureStage::Authorizing(self)),
+        };
+
+        let value = match HeaderValue::from_str(&authorization) {
+            Ok(value) => value,
+            Err(source) => {
+                return InnerPoll::Ready(Err(Error {
+                    kind: ErrorType::CreatingHeader {
+                        name: AUTHORIZATION.to_string(),
+                    },
+                    source: Some(Box::new(source)),
+                }))
+            }
+        };
+
+        self.resend.request.headers.insert(AUTHORIZATION, value);
+
+        InnerPoll::Advance(self.resend.start(self.guild_id))
+    }
+}
+
+struct Backoff {
+    guild_id: Option<GuildId>,
+    resend: Resend,
+    sleep: Pin<Box<Sleep>>,
+}
+
+impl Backoff {
+    fn poll<T>(mut self, cx: &mut Context<'_>) -> InnerPoll<T> {
+        if Pin::new(&mut self.sleep).poll(cx).is_pending() {
+            return InnerPoll::Pending(ResponseFutureStage::Backoff(self));
+        }
+
+        InnerPoll::Advance(self.resend.start(self.guild_id))
+    }
+}
+
 struct Chunking {
     future: Pin<Box<dyn Future<Output = Result<Vec<u8>, Error>> + Send + Sync + 'static>>,
     status: HyperStatusCode,
@@ -96,117 +275,222 @@ impl Failed {
 }
 
 struct InFlight {
-    future: Pin<Box<Timeout<HyperResponseFuture>>>,
+    future: Pin<Box<Timeout<TransportFuture>>>,
     guild_id: Option<GuildId>,
+    intercept: Option<Intercept>,
     invalid_token: InvalidToken,
-    tx: Option<Sender<Option<RatelimitHeaders>>>,
+    resend: Resend,
+    sent_at: Instant,
+    tx: Option<TicketSender>,
 }
 
 impl InFlight {
     fn poll<T>(mut self, cx: &mut Context<'_>) -> InnerPoll<T> {
-        let resp = match Pin::new(&mut self.future).poll(cx) {
-            Poll::Ready(Ok(Ok(resp))) => resp,
-            Poll::Ready(Ok(Err(source))) => {
-                return InnerPoll::Ready(Err(Error {
-                    kind: ErrorType::RequestError,
-                    source: Some(Box::new(source)),
-                }))
-            }
-            Poll::Ready(Err(source)) => {
-                return InnerPoll::Ready(Err(Error {
-                    kind: ErrorType::RequestTimedOut,
-                    source: Some(Box::new(source)),
-                }))
-            }
-            Poll::Pending => {
-                return InnerPoll::Pending(ResponseFutureStage::InFlight(Self {
-                    future: self.future,
+        let error = match Pin::new(&mut self.future).poll(cx) {
+            Poll::Ready(Ok(Ok(resp))) => {
+                let intercept = match self.intercept {
+                    Some(intercept) => intercept,
+                    None => {
+                        return handle_response(
+                            resp,
+                            self.guild_id,
+                            self.invalid_token,
+                            self.resend,
+                            self.tx,
+                        )
+                    }
+                };
+
+                let elapsed = self.sent_at.elapsed();
+                let status = resp.status().as_u16();
+                let mut headers = resp.headers().clone();
+                // The body is decompressed while reading it.
+                headers.remove(hyper::header::CONTENT_ENCODING);
+
+                let fut = async move {
+                    let body = Response::<()>::new(resp)
+                        .bytes()
+                        .await
+                        .map_err(|source| Error {
+                            kind: ErrorType::ChunkingResponse,
+                            source: Some(Box::new(source)),
+                        })?;
+
+                    Ok(MiddlewareResponse::received(status, headers, body, elapsed))
+                };
+
+                return InnerPoll::Advance(ResponseFutureStage::Intercepting(Intercepting {
+                    future: Box::pin(fut),
                     guild_id: self.guild_id,
+                    intercept,
                     invalid_token: self.invalid_token,
+                    resend: self.resend,
                     tx: self.tx,
-                }))
+                }));
             }
+            Poll::Ready(Ok(Err(source))) => Error {
+                kind: ErrorType::RequestError,
+                source: Some(source),
+            },
+            Poll::Ready(Err(source)) => Error {
+                kind: ErrorType::RequestTimedOut,
+                source: Some(Box::new(source)),
+            },
+            Poll::Pending => return InnerPoll::Pending(ResponseFutureStage::InFlight(self)),
         };
 
-        // If the API sent back an Unauthorized response, then the client's
-        // configured token is permanently invalid and future requests must be
-        // ignored to avoid API bans.
-        if resp.status() == HyperStatusCode::UNAUTHORIZED {
-            if let InvalidToken::Remember(state) = self.invalid_token {
-                state.store(true, Ordering::Relaxed);
-            }
+        match self.resend.backoff(self.guild_id, RetryReason::Transport) {
+            Ok(backoff) => InnerPoll::Advance(ResponseFutureStage::Backoff(backoff)),
+            Err(_) => InnerPoll::Ready(Err(error)),
         }
+    }
+}
 
-        if let Some(tx) = self.tx {
-            match RatelimitHeaders::try_from(resp.headers()) {
-                Ok(v) => {
-                    let _res = tx.send(Some(v));
-                }
-                #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
-                Err(source) => {
-                    #[cfg(feature = "tracing")]
-                    tracing::warn!("header parsing failed: {:?}; {:?}", source, resp);
+/// Handle the response to an attempt of a request, after it has passed
+/// through the client's middleware.
+fn handle_response<T>(
+    resp: HyperResponse<Body>,
+    guild_id: Option<GuildId>,
+    invalid_token: InvalidToken,
+    resend: Resend,
+    tx: Option<TicketSender>,
+) -> InnerPoll<T> {
+    // If the API sent back an Unauthorized response, then the client's
+    // configured token is permanently invalid and future requests must be
+    // ignored to avoid API bans.
+    if resp.status() == HyperStatusCode::UNAUTHORIZED {
+        if let InvalidToken::Remember(state) = invalid_token {
+            state.store(true, Ordering::Relaxed);
+        }
+    }
 
-                    let _res = tx.send(None);
-                }
+    if let Some(tx) = tx {
+        match RatelimitHeaders::try_from(resp.headers()) {
+            Ok(v) => {
+                let _res = tx.send(Some(v));
+            }
+            #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+            Err(source) => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!("header parsing failed: {:?}; {:?}", source, resp);
+
+                let _res = tx.send(None);
             }
         }
+    }
 
-        let status = resp.status();
+    let status = resp.status();
 
-        if status.is_success() {
-            #[cfg(feature = "decompression")]
-            let mut resp = resp;
-            // Inaccurate since end-users can only access the decompressed body.
-            #[cfg(feature = "decompression")]
-            resp.headers_mut().remove(hyper::header::CONTENT_LENGTH);
+    if status.is_success() {
+        #[cfg(feature = "decompression")]
+        let mut resp = resp;
+        // Inaccurate since end-users can only access the decompressed body.
+        #[cfg(feature = "decompression")]
+        resp.headers_mut().remove(hyper::header::CONTENT_LENGTH);
 
-            let mut response = Response::new(resp);
+        let mut response = Response::new(resp);
 
-            if let Some(guild_id) = self.guild_id {
-                response.set_guild_id(guild_id);
-            }
+        if let Some(guild_id) = guild_id {
+            response.set_guild_id(guild_id);
+        }
+
+        return InnerPoll::Ready(Ok(response));
+    }
 
-            return InnerPoll::Ready(Ok(response));
+    if let Some(reason) = RetryReason::from_response(status, resp.headers()) {
+        if let Ok(backoff) = resend.backoff(guild_id, reason) {
+            return InnerPoll::Advance(ResponseFutureStage::Backoff(backoff));
         }
+    }
 
-        match status {
-            HyperStatusCode::TOO_MANY_REQUESTS => {
-                #[cfg(feature = "tracing")]
-                tracing::warn!("429 response: {:?}", resp)
-            }
-            HyperStatusCode::SERVICE_UNAVAILABLE => {
-                return InnerPoll::Ready(Err(Error {
-                    kind: ErrorType::ServiceUnavailable { response: resp },
-                    source: None,
-                }));
-            }
-            _ => {}
+    match status {
+        HyperStatusCode::TOO_MANY_REQUESTS => {
+            #[cfg(feature = "tracing")]
+            tracing::warn!("429 response: {:?}", resp)
         }
+        HyperStatusCode::SERVICE_UNAVAILABLE => {
+            return InnerPoll::Ready(Err(Error {
+                kind: ErrorType::ServiceUnavailable { response: resp },
+                source: None,
+            }));
+        }
+        _ => {}
+    }
 
-        let fut = async {
-            Response::<()>::new(resp)
-                .bytes()
-                .await
-                .map_err(|source| Error {
-                    kind: ErrorType::ChunkingResponse,
+    let fut = async {
+        Response::<()>::new(resp)
+            .bytes()
+            .await
+            .map_err(|source| Error {
+                kind: ErrorType::ChunkingResponse,
+                source: Some(Box::new(source)),
+            })
+    };
+
+    InnerPoll::Advance(ResponseFutureStage::Chunking(Chunking {
+        future: Box::pin(fut),
+        status,
+    }))
+}
+
+/// Request attempt passing through the client's middleware.
+struct Intercept {
+    /// Number of middleware the response is passed to.
+    layers: usize,
+    request: MiddlewareRequest,
+}
+
+struct Intercepting {
+    future:
+        Pin<Box<dyn Future<Output = Result<MiddlewareResponse, Error>> + Send + Sync + 'static>>,
+    guild_id: Option<GuildId>,
+    intercept: Intercept,
+    invalid_token: InvalidToken,
+    resend: Resend,
+    tx: Option<TicketSender>,
+}
+
+impl Intercepting {
+    fn poll<T>(mut self, cx: &mut Context<'_>) -> InnerPoll<T> {
+        let mut response = match Pin::new(&mut self.future).poll(cx) {
+            Poll::Ready(Ok(response)) => response,
+            Poll::Ready(Err(source)) => return InnerPoll::Ready(Err(source)),
+            Poll::Pending => return InnerPoll::Pending(ResponseFutureStage::Intercepting(self)),
+        };
+
+        let Intercept { layers, request } = &self.intercept;
+
+        for layer in self.resend.client.middleware()[..*layers].iter().rev() {
+            layer.response(request, &mut response);
+        }
+
+        let resp = match response.into_hyper() {
+            Ok(resp) => resp,
+            Err(source) => {
+                return InnerPoll::Ready(Err(Error {
+                    kind: ErrorType::BuildingRequest,
                     source: Some(Box::new(source)),
-                })
+                }))
+            }
         };
 
-        InnerPoll::Advance(ResponseFutureStage::Chunking(Chunking {
-            future: Box::pin(fut),
-            status,
-        }))
+        handle_response(
+            resp,
+            self.guild_id,
+            self.invalid_token,
+            self.resend,
+            self.tx,
+        )
     }
 }
 
 struct RatelimitQueue {
     guild_id: Option<GuildId>,
+    intercept: Option<Intercept>,
     invalid_token: InvalidToken,
     request_timeout: Duration,
-    response_future: HyperResponseFuture,
-    wait_for_sender: Receiver<Sender<Option<RatelimitHeaders>>>,
+    resend: Resend,
+    wait_for_sender: TicketReceiver,
 }
 
 impl RatelimitQueue {
@@ -220,35 +504,64 @@ impl RatelimitQueue {
                 }))
             }
             Poll::Pending => {
-                return InnerPoll::Pending(ResponseFutureStage::RatelimitQueue(Self {
-                    guild_id: self.guild_id,
-                    invalid_token: self.invalid_token,
-                    request_timeout: self.request_timeout,
-                    response_future: self.response_future,
-                    wait_for_sender: self.wait_for_sender,
-                }))
+                return InnerPoll::Pending(ResponseFutureStage::RatelimitQueue(self));
+            }
+        };
+
+        let response_future = match self.resend.send(self.intercept.as_ref()) {
+            Ok(response_future) => response_future,
+            Err(source) => {
+                // Give the ticket back without headers, as the request was
+                // never sent.
+                let _sent = tx.send(None);
+
+                return InnerPoll::Ready(Err(source));
             }
         };
 
         InnerPoll::Advance(ResponseFutureStage::InFlight(InFlight {
-            future: Box::pin(time::timeout(self.request_timeout, self.response_future)),
+            future: Box::pin(time::timeout(self.request_timeout, response_future)),
             guild_id: self.guild_id,
+            intercept: self.intercept,
             invalid_token: self.invalid_token,
+            resend: self.resend,
+            sent_at: Instant::now(),
             tx: Some(tx),
         }))
     }
 }
 
+/// Request that hasn't been started yet, so that it can still be configured
+/// before it's queued in the ratelimiter.
+struct Starting {
+    guild_id: Option<GuildId>,
+    resend: Resend,
+}
+
+impl Starting {
+    fn poll<T>(self, _: &mut Context<'_>) -> InnerPoll<T> {
+        InnerPoll::Advance(self.resend.start(self.guild_id))
+    }
+}
+
 enum ResponseFutureStage {
+    Authorizing(Authorizing),
+    Backoff(Backoff),
     Chunking(Chunking),
     Completed,
     Failed(Failed),
     InFlight(InFlight),
+    Intercepting(Intercepting),
     RatelimitQueue(RatelimitQueue),
+    Starting(Starting),
 }
 
 /// Future that will resolve to a [`Response`].
 ///
+/// Every request builder's `exec` method returns this future, which can
+/// override how the individual request is sent before it's awaited, such as
+/// its [retry policy][`retry_policy`] or ratelimiting [priority][`priority`].
+///
 /// # Errors
 ///
 ///
@@ -281,6 +594,8 @@ enum ResponseFutureStage {
 /// [`ErrorType::Response`]: crate::error::ErrorType::Response
 /// [`ErrorType::ServiceUnavailable`]: crate::error::ErrorType::ServiceUnavailable
 /// [`Response`]: super::Response
+/// [`priority`]: Self::priority
+/// [`retry_policy`]: Self::retry_policy
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct ResponseFuture<T> {
     phantom: PhantomData<T>,
@@ -288,46 +603,123 @@ pub struct ResponseFuture<T> {
 }
 
 impl<T> ResponseFuture<T> {
-    pub(crate) fn new(
-        invalid_token: InvalidToken,
-        future: Timeout<HyperResponseFuture>,
-        ratelimit_tx: Option<Sender<Option<RatelimitHeaders>>>,
-    ) -> Self {
+    pub(crate) const fn new(resend: Resend) -> Self {
         Self {
             phantom: PhantomData,
-            stage: ResponseFutureStage::InFlight(InFlight {
-                future: Box::pin(future),
+            stage: ResponseFutureStage::Starting(Starting {
                 guild_id: None,
-                invalid_token,
-                tx: ratelimit_tx,
+                resend,
             }),
         }
     }
 
-    pub(crate) const fn error(source: Error) -> Self {
+    /// Create a future that retrieves the authorization of the request
+    /// before starting it.
+    pub(crate) fn authorizing(resend: Resend, future: AuthorizationFuture) -> Self {
         Self {
             phantom: PhantomData,
-            stage: ResponseFutureStage::Failed(Failed { source }),
+            stage: ResponseFutureStage::Authorizing(Authorizing {
+                future,
+                guild_id: None,
+                resend,
+            }),
         }
     }
 
-    pub(crate) fn ratelimit(
-        guild_id: Option<GuildId>,
-        invalid_token: InvalidToken,
-        rx: Receiver<Sender<Option<RatelimitHeaders>>>,
-        request_timeout: Duration,
-        response_future: HyperResponseFuture,
-    ) -> Self {
+    pub(crate) const fn error(source: Error) -> Self {
         Self {
             phantom: PhantomData,
-            stage: ResponseFutureStage::RatelimitQueue(RatelimitQueue {
-                guild_id,
-                invalid_token,
-                response_future,
-                wait_for_sender: rx,
-                request_timeout,
-            }),
+            stage: ResponseFutureStage::Failed(Failed { source }),
+        }
+    }
+
+    /// Set the ratelimiting priority of the request.
+    ///
+    /// This is the per-request override for requests created through the
+    /// client's request builders, which default to [`Priority::Normal`], or
+    /// [`Priority::Interactive`] for interaction responses. Bulk requests may
+    /// use [`Priority::Background`] to let other requests to the same bucket
+    /// go first.
+    ///
+    /// This has no effect if the request has already failed to be created.
+    ///
+    /// # Examples
+    ///
+    /// Add a role to a member in the background:
+    ///
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use twilight_http::{ratelimiting::Priority, Client};
+    /// use twilight_model::id::{GuildId, RoleId, UserId};
+    ///
+    /// let client = Client::new("my token".to_owned());
+    /// client
+    ///     .add_guild_member_role(GuildId(1), UserId(2), RoleId(3))
+    ///     .exec()
+    ///     .priority(Priority::Background)
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    pub fn priority(mut self, priority: Priority) -> Self {
+        match &mut self.stage {
+            ResponseFutureStage::Authorizing(stage) => stage.resend.request.priority = priority,
+            ResponseFutureStage::Backoff(stage) => stage.resend.request.priority = priority,
+            ResponseFutureStage::InFlight(stage) => stage.resend.request.priority = priority,
+            ResponseFutureStage::Intercepting(stage) => stage.resend.request.priority = priority,
+            ResponseFutureStage::RatelimitQueue(stage) => stage.resend.request.priority = priority,
+            ResponseFutureStage::Starting(stage) => stage.resend.request.priority = priority,
+            _ => {}
+        }
+
+        self
+    }
+
+    /// Set the retry policy of the request, overriding the client's
+    /// [retry policy].
+    ///
+    /// This is the per-request override for requests created through the
+    /// client's request builders, such as [`Client::user`] or
+    /// [`Client::create_message`].
+    ///
+    /// This has no effect if the request has already failed to be created.
+    ///
+    /// # Examples
+    ///
+    /// Retry getting a user up to 5 times:
+    ///
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use twilight_http::{client::RetryPolicy, Client};
+    /// use twilight_model::id::UserId;
+    ///
+    /// let client = Client::new("my token".to_owned());
+    /// let user = client
+    ///     .user(UserId(1))
+    ///     .exec()
+    ///     .retry_policy(RetryPolicy::new().max_attempts(5))
+    ///     .await?
+    ///     .model()
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`Client::create_message`]: crate::Client::create_message
+    /// [`Client::user`]: crate::Client::user
+    /// [retry policy]: crate::client::ClientBuilder::retry_policy
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        match &mut self.stage {
+            ResponseFutureStage::Authorizing(stage) => stage.resend.policy = retry_policy,
+            ResponseFutureStage::Backoff(stage) => stage.resend.policy = retry_policy,
+            ResponseFutureStage::InFlight(stage) => stage.resend.policy = retry_policy,
+            ResponseFutureStage::Intercepting(stage) => stage.resend.policy = retry_policy,
+            ResponseFutureStage::RatelimitQueue(stage) => stage.resend.policy = retry_policy,
+            ResponseFutureStage::Starting(stage) => stage.resend.policy = retry_policy,
+            _ => {}
         }
+
+        self
     }
 
     /// Set the ID of the relevant guild.
@@ -335,12 +727,24 @@ impl<T> ResponseFuture<T> {
     /// Necessary for [`MemberBody`] and [`MemberListBody`] deserialization.
     pub(crate) fn set_guild_id(&mut self, guild_id: GuildId) {
         match &mut self.stage {
+            ResponseFutureStage::Authorizing(ref mut stage) => {
+                stage.guild_id.replace(guild_id);
+            }
             ResponseFutureStage::InFlight(ref mut stage) => {
                 stage.guild_id.replace(guild_id);
             }
             ResponseFutureStage::RatelimitQueue(ref mut stage) => {
                 stage.guild_id.replace(guild_id);
             }
+            ResponseFutureStage::Backoff(ref mut stage) => {
+                stage.guild_id.replace(guild_id);
+            }
+            ResponseFutureStage::Intercepting(ref mut stage) => {
+                stage.guild_id.replace(guild_id);
+            }
+            ResponseFutureStage::Starting(ref mut stage) => {
+                stage.guild_id.replace(guild_id);
+            }
             _ => {}
         }
     }
@@ -354,11 +758,15 @@ impl<T: Unpin> Future for ResponseFuture<T> {
             let stage = mem::replace(&mut self.stage, ResponseFutureStage::Completed);
 
             let result = match stage {
+                ResponseFutureStage::Authorizing(authorizing) => authorizing.poll(cx),
+                ResponseFutureStage::Backoff(backoff) => backoff.poll(cx),
                 ResponseFutureStage::Chunking(chunking) => chunking.poll(cx),
                 ResponseFutureStage::Completed => panic!("future already completed"),
                 ResponseFutureStage::Failed(failed) => failed.poll(cx),
                 ResponseFutureStage::InFlight(in_flight) => in_flight.poll(cx),
+                ResponseFutureStage::Intercepting(intercepting) => intercepting.poll(cx),
                 ResponseFutureStage::RatelimitQueue(queue) => queue.poll(cx),
+                ResponseFutureStage::Starting(starting) => starting.poll(cx),
             };
 
             match result {
@@ -379,3 +787,176 @@ impl<T: Unpin> Future for ResponseFuture<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ratelimiting::{
+            IsGloballyLockedFuture, Priority, Ratelimiter, TicketReceiver, TicketSender,
+            TimeUntilAvailableFuture,
+        },
+        request::Method,
+        routing::Path,
+        transport::{MockResponse, MockTransport, Transport, TransportFuture},
+        Client,
+    };
+    use hyper::{Body, Request as HyperRequest};
+    use std::{
+        error::Error,
+        future,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc, Mutex,
+        },
+        time::Duration,
+    };
+    use tokio::sync::oneshot::{self, Sender};
+    use twilight_model::id::{GuildId, RoleId, UserId};
+
+    /// Ratelimiter granting every ticket, recording the priorities they were
+    /// requested with.
+    #[derive(Clone, Debug, Default)]
+    struct PriorityRatelimiter(Arc<Mutex<Vec<Priority>>>);
+
+    impl Ratelimiter for PriorityRatelimiter {
+        fn is_globally_locked(&self) -> IsGloballyLockedFuture<'_> {
+            Box::pin(future::ready(false))
+        }
+
+        fn ticket(&self, path: Path) -> TicketReceiver {
+            self.ticket_with_priority(path, Priority::Normal)
+        }
+
+        fn ticket_with_priority(&self, _: Path, priority: Priority) -> TicketReceiver {
+            self.0.lock().expect("priorities poisoned").push(priority);
+
+            let (tx, rx) = oneshot::channel();
+            let (headers_tx, _) = oneshot::channel();
+            let _sent = tx.send(headers_tx);
+
+            rx
+        }
+
+        fn time_until_available<'a>(&'a self, _: &'a Path) -> TimeUntilAvailableFuture<'a> {
+            Box::pin(future::ready(None))
+        }
+    }
+
+    /// Ratelimiter holding a ticket until the test grants it.
+    #[derive(Clone, Debug, Default)]
+    struct HeldRatelimiter(Arc<Mutex<Option<Sender<TicketSender>>>>);
+
+    impl HeldRatelimiter {
+        fn grant(&self) {
+            let tx = self.0.lock().expect("ticket poisoned").take();
+            let (headers_tx, _) = oneshot::channel();
+            let _sent = tx.expect("ticket requested").send(headers_tx);
+        }
+    }
+
+    impl Ratelimiter for HeldRatelimiter {
+        fn is_globally_locked(&self) -> IsGloballyLockedFuture<'_> {
+            Box::pin(future::ready(false))
+        }
+
+        fn ticket(&self, path: Path) -> TicketReceiver {
+            self.ticket_with_priority(path, Priority::Normal)
+        }
+
+        fn ticket_with_priority(&self, _: Path, _: Priority) -> TicketReceiver {
+            let (tx, rx) = oneshot::channel();
+            self.0.lock().expect("ticket poisoned").replace(tx);
+
+            rx
+        }
+
+        fn time_until_available<'a>(&'a self, _: &'a Path) -> TimeUntilAvailableFuture<'a> {
+            Box::pin(future::ready(None))
+        }
+    }
+
+    /// Transport counting how many times it was asked to send a request.
+    #[derive(Debug)]
+    struct CountingTransport {
+        inner: MockTransport,
+        sends: Arc<AtomicUsize>,
+    }
+
+    impl Transport for CountingTransport {
+        fn send(&self, request: HyperRequest<Body>) -> TransportFuture {
+            self.sends.fetch_add(1, Ordering::SeqCst);
+
+            self.inner.send(request)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_transport_called_after_ticket() -> Result<(), Box<dyn Error>> {
+        let ratelimiter = HeldRatelimiter::default();
+        let sends = Arc::new(AtomicUsize::new(0));
+        let inner = MockTransport::new();
+        inner.on_path(
+            Method::Put,
+            Path::GuildsIdMembersIdRolesId(1),
+            MockResponse::new(204),
+        );
+        let client = Client::builder()
+            .ratelimiter(Some(Box::new(ratelimiter.clone())))
+            .token("token".to_owned())
+            .transport(Box::new(CountingTransport {
+                inner,
+                sends: Arc::clone(&sends),
+            }))
+            .build();
+
+        let handle = tokio::spawn(async move {
+            client
+                .add_guild_member_role(GuildId(1), UserId(2), RoleId(3))
+                .exec()
+                .await
+                .map(|response| response.status())
+        });
+
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert_eq!(0, sends.load(Ordering::SeqCst));
+
+        ratelimiter.grant();
+        assert!(handle.await??.is_success());
+        assert_eq!(1, sends.load(Ordering::SeqCst));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_priority() -> Result<(), Box<dyn Error>> {
+        let ratelimiter = PriorityRatelimiter::default();
+        let transport = MockTransport::new();
+        transport.on_path(
+            Method::Put,
+            Path::GuildsIdMembersIdRolesId(1),
+            MockResponse::new(204),
+        );
+        let client = Client::builder()
+            .ratelimiter(Some(Box::new(ratelimiter.clone())))
+            .token("token".to_owned())
+            .transport(Box::new(transport))
+            .build();
+
+        client
+            .add_guild_member_role(GuildId(1), UserId(2), RoleId(3))
+            .exec()
+            .priority(Priority::Background)
+            .await?;
+        client
+            .add_guild_member_role(GuildId(1), UserId(2), RoleId(3))
+            .exec()
+            .await?;
+
+        assert_eq!(
+            vec![Priority::Background, Priority::Normal],
+            *ratelimiter.0.lock().expect("priorities poisoned"),
+        );
+
+        Ok(())
+    }
+}
@@ -149,6 +149,11 @@ pub enum Path {
     GuildsIdRegions(u64),
     GuildsIdRoles(u64),
     GuildsIdRolesId(u64),
+    GuildsIdScheduledEvents(u64),
+    GuildsIdScheduledEventsId(u64),
+    GuildsIdScheduledEventsIdUsers(u64),
+    GuildsIdStickers(u64),
+    GuildsIdStickersId(u64),
     GuildsIdTemplates(u64),
     GuildsIdTemplatesCode(u64),
     GuildsIdThreads(u64),
@@ -160,8 +165,18 @@ pub enum Path {
     /// Operating on an interaction's callback.
     InteractionCallback(u64),
     StageInstances,
+    /// Operating on the sticker packs available to Nitro subscribers.
+    StickerPacks,
+    /// Operating on a sticker.
+    StickersId,
     UsersId,
     OauthApplicationsMe,
+    /// Operating on the current OAuth2 authorization.
+    OauthMe,
+    /// Operating on OAuth2 access tokens.
+    OauthToken,
+    /// Operating on the revocation of OAuth2 tokens.
+    OauthTokenRevoke,
     UsersIdConnections,
     UsersIdChannels,
     /// Operating on the state of a guild that the user is in.
@@ -174,6 +189,114 @@ pub enum Path {
     WebhooksIdTokenMessagesId(u64),
     /// Operating on a webhook.
     WebhooksId(u64),
+    /// Operating on the followup messages of an interaction through its
+    /// token.
+    WebhooksIdInteractionToken(u64),
+    /// Operating on the original response or a followup message of an
+    /// interaction through its token.
+    WebhooksIdInteractionTokenMessagesId(u64),
+}
+
+impl Path {
+    /// Major parameter of the path, if it has one.
+    ///
+    /// Discord scopes ratelimit buckets by the major parameter of a path,
+    /// which is the ID of the channel, guild, webhook, application or
+    /// interaction being operated on. Paths with the same bucket hash but
+    /// different major parameters are ratelimited separately.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use twilight_http::routing::Path;
+    ///
+    /// assert_eq!(Some(123), Path::ChannelsIdMessages(123).major_parameter());
+    /// assert_eq!(None, Path::VoiceRegions.major_parameter());
+    /// ```
+    #[allow(clippy::too_many_lines)]
+    pub const fn major_parameter(&self) -> Option<u64> {
+        match self {
+            Self::ApplicationCommand(id)
+            | Self::ApplicationCommandId(id)
+            | Self::ApplicationGuildCommand(id)
+            | Self::ApplicationGuildCommandId(id)
+            | Self::ChannelsId(id)
+            | Self::ChannelsIdInvites(id)
+            | Self::ChannelsIdMessages(id)
+            | Self::ChannelsIdMessagesBulkDelete(id)
+            | Self::ChannelsIdMessagesIdCrosspost(id)
+            | Self::ChannelsIdMessagesIdReactions(id)
+            | Self::ChannelsIdMessagesIdReactionsUserIdType(id)
+            | Self::ChannelsIdMessagesIdThreads(id)
+            | Self::ChannelsIdPermissionsOverwriteId(id)
+            | Self::ChannelsIdPins(id)
+            | Self::ChannelsIdPinsMessageId(id)
+            | Self::ChannelsIdRecipients(id)
+            | Self::ChannelsIdThreadMembers(id)
+            | Self::ChannelsIdThreads(id)
+            | Self::ChannelsIdTyping(id)
+            | Self::ChannelsIdWebhooks(id)
+            | Self::ChannelsIdFollowers(id)
+            | Self::GuildsId(id)
+            | Self::GuildsIdBans(id)
+            | Self::GuildsIdBansId(id)
+            | Self::GuildsIdAuditLogs(id)
+            | Self::GuildsIdBansUserId(id)
+            | Self::GuildsIdChannels(id)
+            | Self::GuildsIdWidget(id)
+            | Self::GuildsIdEmojis(id)
+            | Self::GuildsIdEmojisId(id)
+            | Self::GuildsIdIntegrations(id)
+            | Self::GuildsIdIntegrationsId(id)
+            | Self::GuildsIdIntegrationsIdSync(id)
+            | Self::GuildsIdInvites(id)
+            | Self::GuildsIdMembers(id)
+            | Self::GuildsIdMembersId(id)
+            | Self::GuildsIdMembersIdRolesId(id)
+            | Self::GuildsIdMembersMeNick(id)
+            | Self::GuildsIdMembersSearch(id)
+            | Self::GuildsIdPreview(id)
+            | Self::GuildsIdPrune(id)
+            | Self::GuildsIdRegions(id)
+            | Self::GuildsIdRoles(id)
+            | Self::GuildsIdRolesId(id)
+            | Self::GuildsIdScheduledEvents(id)
+            | Self::GuildsIdScheduledEventsId(id)
+            | Self::GuildsIdScheduledEventsIdUsers(id)
+            | Self::GuildsIdStickers(id)
+            | Self::GuildsIdStickersId(id)
+            | Self::GuildsIdTemplates(id)
+            | Self::GuildsIdTemplatesCode(id)
+            | Self::GuildsIdThreads(id)
+            | Self::GuildsIdVanityUrl(id)
+            | Self::GuildsIdVoiceStates(id)
+            | Self::GuildsIdWelcomeScreen(id)
+            | Self::GuildsIdWebhooks(id)
+            | Self::InteractionCallback(id)
+            | Self::WebhooksIdTokenMessagesId(id)
+            | Self::WebhooksId(id)
+            | Self::WebhooksIdInteractionToken(id)
+            | Self::WebhooksIdInteractionTokenMessagesId(id)
+            | Self::ChannelsIdMessagesId(_, id) => Some(*id),
+            Self::Gateway
+            | Self::GatewayBot
+            | Self::Guilds
+            | Self::InvitesCode
+            | Self::StageInstances
+            | Self::StickerPacks
+            | Self::StickersId
+            | Self::UsersId
+            | Self::OauthApplicationsMe
+            | Self::OauthMe
+            | Self::OauthToken
+            | Self::OauthTokenRevoke
+            | Self::UsersIdConnections
+            | Self::UsersIdChannels
+            | Self::UsersIdGuilds
+            | Self::UsersIdGuildsId
+            | Self::VoiceRegions => None,
+        }
+    }
 }
 
 impl FromStr for Path {
@@ -291,6 +414,13 @@ impl FromStr for Path {
             ["guilds", id, "regions"] => GuildsIdRegions(parse_id(id)?),
             ["guilds", id, "roles"] => GuildsIdRoles(parse_id(id)?),
             ["guilds", id, "roles", _] => GuildsIdRolesId(parse_id(id)?),
+            ["guilds", id, "scheduled-events"] => GuildsIdScheduledEvents(parse_id(id)?),
+            ["guilds", id, "scheduled-events", _] => GuildsIdScheduledEventsId(parse_id(id)?),
+            ["guilds", id, "scheduled-events", _, "users"] => {
+                GuildsIdScheduledEventsIdUsers(parse_id(id)?)
+            }
+            ["guilds", id, "stickers"] => GuildsIdStickers(parse_id(id)?),
+            ["guilds", id, "stickers", _] => GuildsIdStickersId(parse_id(id)?),
             ["guilds", id, "templates"] => GuildsIdTemplates(parse_id(id)?),
             ["guilds", id, "templates", _] => GuildsIdTemplatesCode(parse_id(id)?),
             ["guilds", id, "threads", _] => GuildsIdThreads(parse_id(id)?),
@@ -301,7 +431,12 @@ impl FromStr for Path {
             ["invites", _] => InvitesCode,
             ["interactions", id, _, "callback"] => InteractionCallback(parse_id(id)?),
             ["stage-instances", _] => StageInstances,
+            ["sticker-packs"] => StickerPacks,
+            ["stickers", _] => StickersId,
             ["oauth2", "applications", "@me"] => OauthApplicationsMe,
+            ["oauth2", "@me"] => OauthMe,
+            ["oauth2", "token"] => OauthToken,
+            ["oauth2", "token", "revoke"] => OauthTokenRevoke,
             ["users", _] => UsersId,
             ["users", _, "connections"] => UsersIdConnections,
             ["users", _, "channels"] => UsersIdChannels,
@@ -362,6 +497,20 @@ mod tests {
         assert_eq!(Path::ChannelsId(123), Path::from_str("/channels/123")?);
         assert_eq!(Path::WebhooksId(123), Path::from_str("/webhooks/123")?);
         assert_eq!(Path::InvitesCode, Path::from_str("/invites/abc")?);
+        assert_eq!(
+            Path::GuildsIdStickersId(123),
+            Path::from_str("/guilds/123/stickers/456")?
+        );
+        assert_eq!(Path::StickersId, Path::from_str("/stickers/456")?);
+        assert_eq!(Path::OauthMe, Path::from_str("/oauth2/@me")?);
+        assert_eq!(
+            Path::OauthTokenRevoke,
+            Path::from_str("/oauth2/token/revoke")?
+        );
+        assert_eq!(
+            Path::GuildsIdScheduledEventsIdUsers(123),
+            Path::from_str("/guilds/123/scheduled-events/456/users")?
+        );
 
         Ok(())
     }
@@ -45,11 +45,20 @@ pub enum Route<'a> {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to create a followup message for an interaction.
+    CreateFollowupMessage {
+        /// ID of the application.
+        application_id: u64,
+        /// Token of the interaction.
+        interaction_token: &'a str,
+    },
     /// Route information to create a global command.
     CreateGlobalCommand {
         /// The ID of the owner application.
         application_id: u64,
     },
+    /// Route information to exchange a grant for an OAuth2 access token.
+    CreateAccessToken,
     /// Route information to create a guild.
     CreateGuild,
     /// Route information to create a guild command.
@@ -84,6 +93,16 @@ pub enum Route<'a> {
         /// pruned.
         include_roles: &'a [RoleId],
     },
+    /// Route information to create a scheduled event in a guild.
+    CreateGuildScheduledEvent {
+        /// ID of the guild.
+        guild_id: u64,
+    },
+    /// Route information to create a sticker in a guild.
+    CreateGuildSticker {
+        /// ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to create an invite to a channel.
     CreateInvite {
         /// The ID of the channel.
@@ -165,6 +184,15 @@ pub enum Route<'a> {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to delete a followup message for an interaction.
+    DeleteFollowupMessage {
+        /// ID of the application.
+        application_id: u64,
+        /// Token of the interaction.
+        interaction_token: &'a str,
+        /// ID of the followup message.
+        message_id: u64,
+    },
     /// Route information to delete a global command.
     DeleteGlobalCommand {
         /// The ID of the owner application.
@@ -193,6 +221,20 @@ pub enum Route<'a> {
         /// The ID of the integration.
         integration_id: u64,
     },
+    /// Route information to delete a scheduled event in a guild.
+    DeleteGuildScheduledEvent {
+        /// ID of the guild.
+        guild_id: u64,
+        /// ID of the scheduled event.
+        scheduled_event_id: u64,
+    },
+    /// Route information to delete a guild sticker.
+    DeleteGuildSticker {
+        /// ID of the guild.
+        guild_id: u64,
+        /// ID of the sticker.
+        sticker_id: u64,
+    },
     /// Route information to delete an invite.
     DeleteInvite {
         /// The unique invite code.
@@ -338,6 +380,17 @@ pub enum Route<'a> {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to get a guild's bans with parameters.
+    GetBansWithParameters {
+        /// The user ID after which to retrieve bans.
+        after: Option<u64>,
+        /// The user ID before which to retrieve bans.
+        before: Option<u64>,
+        /// The ID of the guild.
+        guild_id: u64,
+        /// The maximum number of bans to retrieve.
+        limit: Option<u64>,
+    },
     /// Route information to get a channel.
     GetChannel {
         /// The ID of the channel.
@@ -367,6 +420,9 @@ pub enum Route<'a> {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to get information about the current OAuth2
+    /// authorization.
+    GetCurrentAuthorizationInformation,
     /// Route information to get info about application the current bot user belongs to
     GetCurrentUserApplicationInfo,
     /// Route information to get the current user.
@@ -407,6 +463,9 @@ pub enum Route<'a> {
     GetGlobalCommands {
         /// The ID of the owner application.
         application_id: u64,
+        /// Whether to include the full localization dictionaries of the
+        /// commands.
+        with_localizations: bool,
     },
     /// Route information to get a guild.
     GetGuild {
@@ -438,6 +497,9 @@ pub enum Route<'a> {
         application_id: u64,
         /// The ID of the guild.
         guild_id: u64,
+        /// Whether to include the full localization dictionaries of the
+        /// commands.
+        with_localizations: bool,
     },
     /// Route information to get a guild's widget.
     GetGuildWidget {
@@ -489,6 +551,49 @@ pub enum Route<'a> {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to get a scheduled event in a guild.
+    GetGuildScheduledEvent {
+        /// ID of the guild.
+        guild_id: u64,
+        /// ID of the scheduled event.
+        scheduled_event_id: u64,
+        /// Whether to include the number of subscribed users.
+        with_user_count: bool,
+    },
+    /// Route information to get the users subscribed to a scheduled event.
+    GetGuildScheduledEventUsers {
+        /// User ID after which to retrieve users.
+        after: Option<u64>,
+        /// User ID before which to retrieve users.
+        before: Option<u64>,
+        /// ID of the guild.
+        guild_id: u64,
+        /// Maximum number of users to retrieve.
+        limit: Option<u64>,
+        /// ID of the scheduled event.
+        scheduled_event_id: u64,
+        /// Whether to include the guild member data of the users.
+        with_member: bool,
+    },
+    /// Route information to get a guild's scheduled events.
+    GetGuildScheduledEvents {
+        /// ID of the guild.
+        guild_id: u64,
+        /// Whether to include the number of subscribed users.
+        with_user_count: bool,
+    },
+    /// Route information to get a guild sticker.
+    GetGuildSticker {
+        /// ID of the guild.
+        guild_id: u64,
+        /// ID of the sticker.
+        sticker_id: u64,
+    },
+    /// Route information to get a guild's stickers.
+    GetGuildStickers {
+        /// ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to get a guild's vanity URL.
     GetGuildVanityUrl {
         /// The ID of the guild.
@@ -568,6 +673,9 @@ pub enum Route<'a> {
         /// The maximum number of messages to get.
         limit: Option<u64>,
     },
+    /// Route information to get the sticker packs available to Nitro
+    /// subscribers.
+    GetNitroStickerPacks,
     /// Route information to get a channel's pins.
     GetPins {
         /// The ID of the channel.
@@ -619,6 +727,11 @@ pub enum Route<'a> {
         /// ID of the stage channel.
         channel_id: u64,
     },
+    /// Route information to get a sticker.
+    GetSticker {
+        /// ID of the sticker.
+        sticker_id: u64,
+    },
     /// Route information to get a template.
     GetTemplate {
         /// The template code.
@@ -714,6 +827,8 @@ pub enum Route<'a> {
         /// ID of the member.
         user_id: u64,
     },
+    /// Route information to revoke an OAuth2 access or refresh token.
+    RevokeAccessToken,
     /// Route information to search for members in a guild.
     SearchGuildMembers {
         /// ID of the guild to search in.
@@ -791,6 +906,15 @@ pub enum Route<'a> {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to update a followup message for an interaction.
+    UpdateFollowupMessage {
+        /// ID of the application.
+        application_id: u64,
+        /// Token of the interaction.
+        interaction_token: &'a str,
+        /// ID of the followup message.
+        message_id: u64,
+    },
     /// Route information to update a global command.
     UpdateGlobalCommand {
         /// The ID of the owner application.
@@ -817,6 +941,20 @@ pub enum Route<'a> {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to update a scheduled event in a guild.
+    UpdateGuildScheduledEvent {
+        /// ID of the guild.
+        guild_id: u64,
+        /// ID of the scheduled event.
+        scheduled_event_id: u64,
+    },
+    /// Route information to update a guild sticker.
+    UpdateGuildSticker {
+        /// ID of the guild.
+        guild_id: u64,
+        /// ID of the sticker.
+        sticker_id: u64,
+    },
     /// Route information to update a guild's widget.
     UpdateGuildWidget {
         /// The ID of the guild.
@@ -977,10 +1115,13 @@ impl<'a> Route<'a> {
             Self::DeleteBan { .. }
             | Self::DeleteChannel { .. }
             | Self::DeleteEmoji { .. }
+            | Self::DeleteFollowupMessage { .. }
             | Self::DeleteGlobalCommand { .. }
             | Self::DeleteGuild { .. }
             | Self::DeleteGuildCommand { .. }
             | Self::DeleteGuildIntegration { .. }
+            | Self::DeleteGuildScheduledEvent { .. }
+            | Self::DeleteGuildSticker { .. }
             | Self::DeleteInteractionOriginal { .. }
             | Self::DeleteInvite { .. }
             | Self::DeleteMessageReactions { .. }
@@ -1004,12 +1145,14 @@ impl<'a> Route<'a> {
             | Self::GetAuditLogs { .. }
             | Self::GetBan { .. }
             | Self::GetBans { .. }
+            | Self::GetBansWithParameters { .. }
             | Self::GetGatewayBot
             | Self::GetChannel { .. }
             | Self::GetChannelInvites { .. }
             | Self::GetChannelWebhooks { .. }
             | Self::GetChannels { .. }
             | Self::GetCommandPermissions { .. }
+            | Self::GetCurrentAuthorizationInformation
             | Self::GetCurrentUserApplicationInfo
             | Self::GetCurrentUser
             | Self::GetEmoji { .. }
@@ -1028,6 +1171,11 @@ impl<'a> Route<'a> {
             | Self::GetGuildPreview { .. }
             | Self::GetGuildPruneCount { .. }
             | Self::GetGuildRoles { .. }
+            | Self::GetGuildScheduledEvent { .. }
+            | Self::GetGuildScheduledEventUsers { .. }
+            | Self::GetGuildScheduledEvents { .. }
+            | Self::GetGuildSticker { .. }
+            | Self::GetGuildStickers { .. }
             | Self::GetGuildVanityUrl { .. }
             | Self::GetGuildVoiceRegions { .. }
             | Self::GetGuildWelcomeScreen { .. }
@@ -1040,12 +1188,14 @@ impl<'a> Route<'a> {
             | Self::GetMember { .. }
             | Self::GetMessage { .. }
             | Self::GetMessages { .. }
+            | Self::GetNitroStickerPacks
             | Self::GetPins { .. }
             | Self::GetJoinedPrivateArchivedThreads { .. }
             | Self::GetPrivateArchivedThreads { .. }
             | Self::GetPublicArchivedThreads { .. }
             | Self::GetReactionUsers { .. }
             | Self::GetStageInstance { .. }
+            | Self::GetSticker { .. }
             | Self::GetTemplate { .. }
             | Self::GetTemplates { .. }
             | Self::GetThreadMembers { .. }
@@ -1060,10 +1210,13 @@ impl<'a> Route<'a> {
             | Self::UpdateCurrentUser
             | Self::UpdateCurrentUserVoiceState { .. }
             | Self::UpdateEmoji { .. }
+            | Self::UpdateFollowupMessage { .. }
             | Self::UpdateGlobalCommand { .. }
             | Self::UpdateGuild { .. }
             | Self::UpdateGuildChannels { .. }
             | Self::UpdateGuildCommand { .. }
+            | Self::UpdateGuildScheduledEvent { .. }
+            | Self::UpdateGuildSticker { .. }
             | Self::UpdateGuildWidget { .. }
             | Self::UpdateGuildIntegration { .. }
             | Self::UpdateGuildWelcomeScreen { .. }
@@ -1079,13 +1232,17 @@ impl<'a> Route<'a> {
             | Self::UpdateWebhookMessage { .. }
             | Self::UpdateWebhook { .. } => Method::Patch,
             Self::CreateChannel { .. }
+            | Self::CreateFollowupMessage { .. }
             | Self::CreateGlobalCommand { .. }
             | Self::CreateGuildCommand { .. }
+            | Self::CreateAccessToken
             | Self::CreateEmoji { .. }
             | Self::CreateGuild
             | Self::CreateGuildFromTemplate { .. }
             | Self::CreateGuildIntegration { .. }
             | Self::CreateGuildPrune { .. }
+            | Self::CreateGuildScheduledEvent { .. }
+            | Self::CreateGuildSticker { .. }
             | Self::CreateInvite { .. }
             | Self::CreateMessage { .. }
             | Self::CreatePrivateChannel
@@ -1101,6 +1258,7 @@ impl<'a> Route<'a> {
             | Self::ExecuteWebhook { .. }
             | Self::FollowNewsChannel { .. }
             | Self::InteractionCallback { .. }
+            | Self::RevokeAccessToken
             | Self::SyncGuildIntegration { .. } => Method::Post,
             Self::AddGuildMember { .. }
             | Self::AddMemberRole { .. }
@@ -1129,15 +1287,18 @@ impl<'a> Route<'a> {
     /// ```
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// use twilight_http::{ratelimiting::Ratelimiter, routing::Route};
+    /// use twilight_http::{
+    ///     ratelimiting::{InMemoryRatelimiter, Ratelimiter},
+    ///     routing::Route,
+    /// };
     ///
-    /// let ratelimiter = Ratelimiter::new();
+    /// let ratelimiter = InMemoryRatelimiter::new();
     /// let route = Route::CreateMessage {
     ///     channel_id: 123,
     ///  };
     ///
     /// // Take a ticket from the ratelimiter.
-    /// let rx = ratelimiter.get(route.path()).await;
+    /// let rx = ratelimiter.ticket(route.path());
     ///
     /// // Wait to be told that a request can be made...
     /// let _tx = rx.await;
@@ -1172,7 +1333,7 @@ impl<'a> Route<'a> {
                 Path::GuildsIdEmojis(*guild_id)
             }
             Self::CreateGlobalCommand { application_id }
-            | Self::GetGlobalCommands { application_id }
+            | Self::GetGlobalCommands { application_id, .. }
             | Self::SetGlobalCommands { application_id } => {
                 Path::ApplicationCommand(*application_id)
             }
@@ -1238,11 +1399,16 @@ impl<'a> Route<'a> {
             | Self::UpdateGuildIntegration { guild_id, .. } => {
                 Path::GuildsIdIntegrationsId(*guild_id)
             }
-            Self::DeleteInteractionOriginal { application_id, .. }
+            Self::CreateFollowupMessage { application_id, .. } => {
+                Path::WebhooksIdInteractionToken(*application_id)
+            }
+            Self::DeleteFollowupMessage { application_id, .. }
+            | Self::DeleteInteractionOriginal { application_id, .. }
             | Self::GetFollowupMessage { application_id, .. }
             | Self::GetInteractionOriginal { application_id, .. }
+            | Self::UpdateFollowupMessage { application_id, .. }
             | Self::UpdateInteractionOriginal { application_id, .. } => {
-                Path::WebhooksIdTokenMessagesId(*application_id)
+                Path::WebhooksIdInteractionTokenMessagesId(*application_id)
             }
             Self::DeleteInvite { .. }
             | Self::GetInvite { .. }
@@ -1284,7 +1450,9 @@ impl<'a> Route<'a> {
             Self::GetActiveThreads { guild_id, .. } => Path::GuildsIdThreads(*guild_id),
             Self::GetAuditLogs { guild_id, .. } => Path::GuildsIdAuditLogs(*guild_id),
             Self::GetBan { guild_id, .. } => Path::GuildsIdBansId(*guild_id),
-            Self::GetBans { guild_id } => Path::GuildsIdBans(*guild_id),
+            Self::GetBans { guild_id } | Self::GetBansWithParameters { guild_id, .. } => {
+                Path::GuildsIdBans(*guild_id)
+            }
             Self::GetGatewayBot => Path::GatewayBot,
             Self::GetChannel { channel_id } | Self::UpdateChannel { channel_id } => {
                 Path::ChannelsId(*channel_id)
@@ -1296,6 +1464,8 @@ impl<'a> Route<'a> {
             | Self::UpdateCommandPermissions { application_id, .. } => {
                 Path::ApplicationGuildCommandId(*application_id)
             }
+            Self::CreateAccessToken => Path::OauthToken,
+            Self::GetCurrentAuthorizationInformation => Path::OauthMe,
             Self::GetCurrentUserApplicationInfo => Path::OauthApplicationsMe,
             Self::GetCurrentUser | Self::GetUser { .. } | Self::UpdateCurrentUser => Path::UsersId,
             Self::GetEmoji { guild_id, .. } | Self::UpdateEmoji { guild_id, .. } => {
@@ -1312,6 +1482,24 @@ impl<'a> Route<'a> {
             Self::GetGuildInvites { guild_id } => Path::GuildsIdInvites(*guild_id),
             Self::GetGuildMembers { guild_id, .. } => Path::GuildsIdMembers(*guild_id),
             Self::GetGuildPreview { guild_id } => Path::GuildsIdPreview(*guild_id),
+            Self::CreateGuildScheduledEvent { guild_id }
+            | Self::GetGuildScheduledEvents { guild_id, .. } => {
+                Path::GuildsIdScheduledEvents(*guild_id)
+            }
+            Self::DeleteGuildScheduledEvent { guild_id, .. }
+            | Self::GetGuildScheduledEvent { guild_id, .. }
+            | Self::UpdateGuildScheduledEvent { guild_id, .. } => {
+                Path::GuildsIdScheduledEventsId(*guild_id)
+            }
+            Self::GetGuildScheduledEventUsers { guild_id, .. } => {
+                Path::GuildsIdScheduledEventsIdUsers(*guild_id)
+            }
+            Self::CreateGuildSticker { guild_id } | Self::GetGuildStickers { guild_id } => {
+                Path::GuildsIdStickers(*guild_id)
+            }
+            Self::DeleteGuildSticker { guild_id, .. }
+            | Self::GetGuildSticker { guild_id, .. }
+            | Self::UpdateGuildSticker { guild_id, .. } => Path::GuildsIdStickersId(*guild_id),
             Self::GetGuildVanityUrl { guild_id } => Path::GuildsIdVanityUrl(*guild_id),
             Self::GetGuildVoiceRegions { guild_id } => Path::GuildsIdRegions(*guild_id),
             Self::GetGuildWelcomeScreen { guild_id }
@@ -1321,15 +1509,18 @@ impl<'a> Route<'a> {
             Self::GetMessage { channel_id, .. } => {
                 Path::ChannelsIdMessagesId(Method::Get, *channel_id)
             }
+            Self::GetNitroStickerPacks => Path::StickerPacks,
             Self::GetPins { channel_id } | Self::PinMessage { channel_id, .. } => {
                 Path::ChannelsIdPins(*channel_id)
             }
+            Self::GetSticker { .. } => Path::StickersId,
             Self::GetUserConnections => Path::UsersIdConnections,
             Self::GetVoiceRegions => Path::VoiceRegions,
             Self::InteractionCallback { interaction_id, .. } => {
                 Path::InteractionCallback(*interaction_id)
             }
             Self::LeaveGuild { .. } => Path::UsersIdGuildsId,
+            Self::RevokeAccessToken => Path::OauthTokenRevoke,
             Self::SearchGuildMembers { guild_id, .. } => Path::GuildsIdMembersSearch(*guild_id),
             Self::SyncGuildIntegration { guild_id, .. } => {
                 Path::GuildsIdIntegrationsIdSync(*guild_id)
@@ -127,33 +127,69 @@ impl Display for RouteDisplay<'_> {
 
                 f.write_str("/emojis")
             }
+            Route::CreateFollowupMessage {
+                application_id,
+                interaction_token,
+            } => {
+                f.write_str("webhooks/")?;
+                Display::fmt(application_id, f)?;
+                f.write_str("/")?;
+
+                f.write_str(interaction_token)
+            }
             Route::CreateGlobalCommand { application_id }
-            | Route::GetGlobalCommands { application_id }
             | Route::SetGlobalCommands { application_id } => {
                 f.write_str("applications/")?;
                 Display::fmt(application_id, f)?;
 
                 f.write_str("/commands")
             }
+            Route::GetGlobalCommands {
+                application_id,
+                with_localizations,
+            } => {
+                f.write_str("applications/")?;
+                Display::fmt(application_id, f)?;
+                f.write_str("/commands")?;
+
+                if *with_localizations {
+                    f.write_str("?with_localizations=true")?;
+                }
+
+                Ok(())
+            }
             Route::CreateGuild => f.write_str("guilds"),
             Route::CreateGuildCommand {
                 application_id,
                 guild_id,
             }
-            | Route::GetGuildCommands {
+            | Route::SetGuildCommands {
                 application_id,
                 guild_id,
+            } => {
+                f.write_str("applications/")?;
+                Display::fmt(application_id, f)?;
+                f.write_str("/guilds/")?;
+                Display::fmt(guild_id, f)?;
+
+                f.write_str("/commands")
             }
-            | Route::SetGuildCommands {
+            Route::GetGuildCommands {
                 application_id,
                 guild_id,
+                with_localizations,
             } => {
                 f.write_str("applications/")?;
                 Display::fmt(application_id, f)?;
                 f.write_str("/guilds/")?;
                 Display::fmt(guild_id, f)?;
+                f.write_str("/commands")?;
 
-                f.write_str("/commands")
+                if *with_localizations {
+                    f.write_str("?with_localizations=true")?;
+                }
+
+                Ok(())
             }
             Route::CreateGuildFromTemplate { template_code }
             | Route::GetTemplate { template_code } => {
@@ -168,6 +204,94 @@ impl Display for RouteDisplay<'_> {
 
                 f.write_str("/integrations")
             }
+            Route::CreateGuildScheduledEvent { guild_id } => {
+                f.write_str("guilds/")?;
+                Display::fmt(guild_id, f)?;
+
+                f.write_str("/scheduled-events")
+            }
+            Route::GetGuildScheduledEvents {
+                guild_id,
+                with_user_count,
+            } => {
+                f.write_str("guilds/")?;
+                Display::fmt(guild_id, f)?;
+                f.write_str("/scheduled-events")?;
+
+                if *with_user_count {
+                    f.write_str("?with_user_count=true")?;
+                }
+
+                Ok(())
+            }
+            Route::DeleteGuildScheduledEvent {
+                guild_id,
+                scheduled_event_id,
+            }
+            | Route::UpdateGuildScheduledEvent {
+                guild_id,
+                scheduled_event_id,
+            } => {
+                f.write_str("guilds/")?;
+                Display::fmt(guild_id, f)?;
+                f.write_str("/scheduled-events/")?;
+
+                Display::fmt(scheduled_event_id, f)
+            }
+            Route::GetGuildScheduledEvent {
+                guild_id,
+                scheduled_event_id,
+                with_user_count,
+            } => {
+                f.write_str("guilds/")?;
+                Display::fmt(guild_id, f)?;
+                f.write_str("/scheduled-events/")?;
+                Display::fmt(scheduled_event_id, f)?;
+
+                if *with_user_count {
+                    f.write_str("?with_user_count=true")?;
+                }
+
+                Ok(())
+            }
+            Route::GetGuildScheduledEventUsers {
+                after,
+                before,
+                guild_id,
+                limit,
+                scheduled_event_id,
+                with_member,
+            } => {
+                f.write_str("guilds/")?;
+                Display::fmt(guild_id, f)?;
+                f.write_str("/scheduled-events/")?;
+                Display::fmt(scheduled_event_id, f)?;
+                f.write_str("/users?with_member=")?;
+                Display::fmt(with_member, f)?;
+
+                if let Some(after) = after {
+                    f.write_str("&after=")?;
+                    Display::fmt(after, f)?;
+                }
+
+                if let Some(before) = before {
+                    f.write_str("&before=")?;
+                    Display::fmt(before, f)?;
+                }
+
+                if let Some(limit) = limit {
+                    f.write_str("&limit=")?;
+                    Display::fmt(limit, f)?;
+                }
+
+                Ok(())
+            }
+            Route::CreateGuildSticker { guild_id } | Route::GetGuildStickers { guild_id } => {
+                f.write_str("guilds/")?;
+                Display::fmt(guild_id, f)?;
+
+                f.write_str("/stickers")
+            }
             Route::CreateGuildPrune {
                 compute_prune_count,
                 days,
@@ -485,6 +609,24 @@ impl Display for RouteDisplay<'_> {
 
                 Display::fmt(role_id, f)
             }
+            Route::DeleteGuildSticker {
+                guild_id,
+                sticker_id,
+            }
+            | Route::GetGuildSticker {
+                guild_id,
+                sticker_id,
+            }
+            | Route::UpdateGuildSticker {
+                guild_id,
+                sticker_id,
+            } => {
+                f.write_str("guilds/")?;
+                Display::fmt(guild_id, f)?;
+                f.write_str("/stickers/")?;
+
+                Display::fmt(sticker_id, f)
+            }
             Route::DeleteStageInstance { channel_id }
             | Route::GetStageInstance { channel_id }
             | Route::UpdateStageInstance { channel_id } => {
@@ -515,11 +657,21 @@ impl Display for RouteDisplay<'_> {
                 token,
                 webhook_id,
             }
+            | Route::DeleteFollowupMessage {
+                application_id: webhook_id,
+                interaction_token: token,
+                message_id,
+            }
             | Route::GetFollowupMessage {
                 application_id: webhook_id,
                 interaction_token: token,
                 message_id,
             }
+            | Route::UpdateFollowupMessage {
+                application_id: webhook_id,
+                interaction_token: token,
+                message_id,
+            }
             | Route::GetWebhookMessage {
                 message_id,
                 token,
@@ -619,6 +771,33 @@ impl Display for RouteDisplay<'_> {
 
                 f.write_str("/bans")
             }
+            Route::GetBansWithParameters {
+                after,
+                before,
+                guild_id,
+                limit,
+            } => {
+                f.write_str("guilds/")?;
+                Display::fmt(guild_id, f)?;
+                f.write_str("/bans?")?;
+
+                if let Some(after) = after {
+                    f.write_str("after=")?;
+                    Display::fmt(after, f)?;
+                }
+
+                if let Some(before) = before {
+                    f.write_str("&before=")?;
+                    Display::fmt(before, f)?;
+                }
+
+                if let Some(limit) = limit {
+                    f.write_str("&limit=")?;
+                    Display::fmt(limit, f)?;
+                }
+
+                Ok(())
+            }
             Route::GetGatewayBot => f.write_str("gateway/bot"),
             Route::GetCommandPermissions {
                 application_id,
@@ -639,7 +818,10 @@ impl Display for RouteDisplay<'_> {
 
                 f.write_str("/permissions")
             }
+            Route::CreateAccessToken => f.write_str("oauth2/token"),
+            Route::GetCurrentAuthorizationInformation => f.write_str("oauth2/@me"),
             Route::GetCurrentUserApplicationInfo => f.write_str("/oauth2/applications/@me"),
+            Route::RevokeAccessToken => f.write_str("oauth2/token/revoke"),
             Route::GetCurrentUser | Route::UpdateCurrentUser => f.write_str("users/@me"),
             Route::GetGateway => f.write_str("gateway"),
             Route::GetGuild {
@@ -964,6 +1146,12 @@ impl Display for RouteDisplay<'_> {
 
                 Display::fmt(user_id, f)
             }
+            Route::GetNitroStickerPacks => f.write_str("sticker-packs"),
+            Route::GetSticker { sticker_id } => {
+                f.write_str("stickers/")?;
+
+                Display::fmt(sticker_id, f)
+            }
             Route::GetVoiceRegions => f.write_str("voice/regions"),
             Route::InteractionCallback {
                 interaction_id,
@@ -1076,6 +1264,58 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_get_guild_scheduled_event_users() {
+        let route = Route::GetGuildScheduledEventUsers {
+            after: Some(3),
+            before: None,
+            guild_id: 1,
+            limit: Some(50),
+            scheduled_event_id: 2,
+            with_member: true,
+        };
+
+        assert_eq!(
+            "guilds/1/scheduled-events/2/users?with_member=true&after=3&limit=50",
+            route.display().to_string()
+        );
+    }
+
+    #[test]
+    fn test_get_commands_with_localizations() {
+        let route = Route::GetGlobalCommands {
+            application_id: 1,
+            with_localizations: false,
+        };
+        assert_eq!("applications/1/commands", route.display().to_string());
+
+        let route = Route::GetGuildCommands {
+            application_id: 1,
+            guild_id: 2,
+            with_localizations: true,
+        };
+        assert_eq!(
+            "applications/1/guilds/2/commands?with_localizations=true",
+            route.display().to_string()
+        );
+    }
+
+    #[test]
+    fn test_followup_messages() {
+        let route = Route::CreateFollowupMessage {
+            application_id: 1,
+            interaction_token: "token",
+        };
+        assert_eq!("webhooks/1/token", route.display().to_string());
+
+        let route = Route::UpdateFollowupMessage {
+            application_id: 1,
+            interaction_token: "token",
+            message_id: 2,
+        };
+        assert_eq!("webhooks/1/token/messages/2", route.display().to_string());
+    }
+
     #[test]
     fn test_update_global_command() {
         let route = Route::UpdateGlobalCommand {
@@ -0,0 +1,823 @@
+//! Transports recording HTTP traffic into a [`Fixture`] and replaying it,
+//! for deterministic tests that don't connect to Discord.
+//!
+//! A [`RecordingTransport`] wraps another transport, such as the default
+//! [`HyperTransport`], and records every request and its response. The
+//! recording can then be saved to a file and later loaded into a
+//! [`ReplayTransport`], which responds to requests with the recorded
+//! responses.
+//!
+//! Recorded requests are keyed by their method, their [`Route`], and a hash
+//! of their body. The `Authorization` header is never saved, and the tokens
+//! of webhooks and interactions in routes are redacted.
+//!
+//! # Examples
+//!
+//! Record the traffic of a client once:
+//!
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use twilight_http::{
+//!     transport::{HyperTransport, RecordingTransport},
+//!     Client,
+//! };
+//! use twilight_model::id::ChannelId;
+//!
+//! let transport = RecordingTransport::new(Box::new(HyperTransport::new()));
+//! let client = Client::builder()
+//!     .token("my token".to_owned())
+//!     .transport(Box::new(transport.clone()))
+//!     .build();
+//!
+//! client.channel(ChannelId(1)).exec().await?;
+//!
+//! transport.fixture().save("tests/fixtures/channel.json")?;
+//! # Ok(()) }
+//! ```
+//!
+//! And replay it in tests afterwards:
+//!
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use twilight_http::{transport::ReplayTransport, Client};
+//! use twilight_model::id::ChannelId;
+//!
+//! let transport = ReplayTransport::load("tests/fixtures/channel.json")?;
+//! let client = Client::builder()
+//!     .token("my token".to_owned())
+//!     .transport(Box::new(transport))
+//!     .build();
+//!
+//! let channel = client.channel(ChannelId(1)).exec().await?.model().await?;
+//! # Ok(()) }
+//! ```
+//!
+//! [`HyperTransport`]: super::HyperTransport
+//! [`Route`]: crate::routing::Route
+
+use super::{mock::RecordedRequest, Transport, TransportFuture};
+use crate::request::Method;
+use hyper::{
+    body,
+    header::{HeaderMap, HeaderName, HeaderValue, ACCEPT_ENCODING, AUTHORIZATION},
+    Body, Request as HyperRequest, Response as HyperResponse, StatusCode,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    fs,
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+/// Error working with a [`Fixture`] or replaying it.
+#[derive(Debug)]
+pub struct FixtureError {
+    kind: FixtureErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl FixtureError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &FixtureErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (FixtureErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for FixtureError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            FixtureErrorType::Deserializing => f.write_str("the fixture is invalid"),
+            FixtureErrorType::Io => f.write_str("reading or writing the fixture file failed"),
+            FixtureErrorType::ReadingBody => f.write_str("reading the response body failed"),
+            FixtureErrorType::Serializing => f.write_str("serializing the fixture failed"),
+            FixtureErrorType::Unmatched {
+                body_hash,
+                method,
+                route,
+            } => {
+                f.write_str("no recorded response matches the request ")?;
+                f.write_str(method.into_hyper().as_str())?;
+                f.write_str(" ")?;
+                f.write_str(route)?;
+                f.write_str(" with body hash ")?;
+
+                f.write_str(body_hash)
+            }
+        }
+    }
+}
+
+impl Error for FixtureError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`FixtureError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum FixtureErrorType {
+    /// Deserializing the fixture failed.
+    Deserializing,
+    /// Reading or writing the fixture file failed.
+    Io,
+    /// Reading the body of a response while recording failed.
+    ReadingBody,
+    /// Serializing the fixture failed.
+    Serializing,
+    /// No unused recorded response matches a request being replayed.
+    Unmatched {
+        /// Hash of the body of the request.
+        body_hash: String,
+        /// Method of the request.
+        method: Method,
+        /// Route of the request, including its query.
+        route: String,
+    },
+}
+
+/// Recorded request in a [`Fixture`].
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct FixtureRequest {
+    /// Body of the request.
+    pub body: Vec<u8>,
+    /// Hash of the body of the request.
+    ///
+    /// The hash of a multipart form is computed from its parts, as the
+    /// boundaries between them are random.
+    pub body_hash: String,
+    /// Headers of the request and their values, without the `Authorization`
+    /// header.
+    pub headers: BTreeMap<String, Vec<String>>,
+    /// Method of the request, such as `GET`.
+    pub method: String,
+    /// Route of the request, including its query.
+    ///
+    /// Webhook and interaction tokens in the route are replaced with
+    /// `{token}`.
+    pub route: String,
+}
+
+/// Recorded response in a [`Fixture`].
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct FixtureResponse {
+    /// Body of the response.
+    pub body: Vec<u8>,
+    /// Headers of the response and their values.
+    pub headers: BTreeMap<String, Vec<String>>,
+    /// Status code of the response.
+    pub status: u16,
+}
+
+/// Request and its response recorded in a [`Fixture`].
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct FixtureEntry {
+    /// Recorded request.
+    pub request: FixtureRequest,
+    /// Recorded response.
+    pub response: FixtureResponse,
+}
+
+/// Recorded HTTP traffic, which may be saved to and loaded from a JSON file.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Fixture {
+    entries: Vec<FixtureEntry>,
+}
+
+impl Fixture {
+    /// Create an empty fixture.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Deserialize a fixture from JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`FixtureErrorType::Deserializing`] error type if the JSON
+    /// is not a valid fixture.
+    pub fn from_slice(json: &[u8]) -> Result<Self, FixtureError> {
+        serde_json::from_slice(json).map_err(|source| FixtureError {
+            kind: FixtureErrorType::Deserializing,
+            source: Some(Box::new(source)),
+        })
+    }
+
+    /// Load a fixture from a JSON file.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`FixtureErrorType::Io`] error type if the file could not be
+    /// read.
+    ///
+    /// Returns a [`FixtureErrorType::Deserializing`] error type if the file
+    /// does not contain a valid fixture.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, FixtureError> {
+        let json = fs::read(path).map_err(|source| FixtureError {
+            kind: FixtureErrorType::Io,
+            source: Some(Box::new(source)),
+        })?;
+
+        Self::from_slice(&json)
+    }
+
+    /// Recorded requests and their responses, in the order they were made.
+    pub fn entries(&self) -> &[FixtureEntry] {
+        &self.entries
+    }
+
+    /// Save the fixture to a file as pretty-printed JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`FixtureErrorType::Serializing`] error type if the fixture
+    /// could not be serialized.
+    ///
+    /// Returns a [`FixtureErrorType::Io`] error type if the file could not be
+    /// written.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), FixtureError> {
+        let json = self.to_vec()?;
+
+        fs::write(path, json).map_err(|source| FixtureError {
+            kind: FixtureErrorType::Io,
+            source: Some(Box::new(source)),
+        })
+    }
+
+    /// Serialize the fixture as pretty-printed JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`FixtureErrorType::Serializing`] error type if the fixture
+    /// could not be serialized.
+    pub fn to_vec(&self) -> Result<Vec<u8>, FixtureError> {
+        serde_json::to_vec_pretty(self).map_err(|source| FixtureError {
+            kind: FixtureErrorType::Serializing,
+            source: Some(Box::new(source)),
+        })
+    }
+}
+
+/// [`Transport`] recording the requests sent through another transport along
+/// with their responses.
+///
+/// The `Accept-Encoding` header is removed from requests so that responses
+/// are recorded uncompressed.
+///
+/// Cloning the transport is cheap and all clones share the same recording,
+/// so a clone can be given to the client while keeping another to save the
+/// [`fixture`].
+///
+/// [`fixture`]: Self::fixture
+#[derive(Clone, Debug)]
+pub struct RecordingTransport {
+    fixture: Arc<Mutex<Fixture>>,
+    inner: Arc<dyn Transport>,
+}
+
+impl RecordingTransport {
+    /// Create a new transport recording the traffic of another transport.
+    pub fn new(inner: Box<dyn Transport>) -> Self {
+        Self {
+            fixture: Arc::default(),
+            inner: Arc::from(inner),
+        }
+    }
+
+    /// Copy of the traffic recorded so far.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mutex of the recorded fixture is poisoned.
+    pub fn fixture(&self) -> Fixture {
+        self.fixture.lock().expect("fixture poisoned").clone()
+    }
+}
+
+impl Transport for RecordingTransport {
+    fn send(&self, request: HyperRequest<Body>) -> TransportFuture {
+        let fixture = Arc::clone(&self.fixture);
+        let inner = Arc::clone(&self.inner);
+
+        Box::pin(async move {
+            let (recorded, mut parts, body) = RecordedRequest::read(request).await?;
+            parts.headers.remove(ACCEPT_ENCODING);
+
+            let response = inner
+                .send(HyperRequest::from_parts(parts, Body::from(body)))
+                .await?;
+            let (parts, body) = response.into_parts();
+            let body = body::to_bytes(body).await.map_err(|source| FixtureError {
+                kind: FixtureErrorType::ReadingBody,
+                source: Some(Box::new(source)),
+            })?;
+
+            let entry = FixtureEntry {
+                request: FixtureRequest {
+                    body: recorded.body().to_vec(),
+                    body_hash: body_hash(&recorded),
+                    headers: header_map(recorded.headers(), true),
+                    method: recorded.method().into_hyper().as_str().to_owned(),
+                    route: redact_route(recorded.path_and_query()),
+                },
+                response: FixtureResponse {
+                    body: body.to_vec(),
+                    headers: header_map(&parts.headers, false),
+                    status: parts.status.as_u16(),
+                },
+            };
+            fixture
+                .lock()
+                .expect("fixture poisoned")
+                .entries
+                .push(entry);
+
+            Ok(HyperResponse::from_parts(parts, Body::from(body)))
+        })
+    }
+}
+
+/// [`Transport`] responding to requests with the responses recorded in a
+/// [`Fixture`].
+///
+/// A request matches a recorded one if their methods, routes with redacted
+/// tokens, and body hashes are the same. Each recorded response is used once, in the order they were
+/// recorded. Requests without a matching unused response fail with a
+/// [`FixtureErrorType::Unmatched`] error.
+#[derive(Clone, Debug)]
+pub struct ReplayTransport {
+    entries: Arc<Mutex<Vec<Option<FixtureEntry>>>>,
+}
+
+impl ReplayTransport {
+    /// Create a new transport replaying a fixture.
+    pub fn new(fixture: Fixture) -> Self {
+        Self {
+            entries: Arc::new(Mutex::new(fixture.entries.into_iter().map(Some).collect())),
+        }
+    }
+
+    /// Create a new transport replaying a fixture loaded from a JSON file.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Fixture::load`].
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, FixtureError> {
+        Fixture::load(path).map(Self::new)
+    }
+
+    /// Number of recorded responses that have not been used yet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mutex of the replayed entries is poisoned.
+    pub fn remaining(&self) -> usize {
+        self.entries
+            .lock()
+            .expect("fixture poisoned")
+            .iter()
+            .filter(|entry| entry.is_some())
+            .count()
+    }
+}
+
+impl Transport for ReplayTransport {
+    fn send(&self, request: HyperRequest<Body>) -> TransportFuture {
+        let entries = Arc::clone(&self.entries);
+
+        Box::pin(async move {
+            let (recorded, ..) = RecordedRequest::read(request).await?;
+            let body_hash = body_hash(&recorded);
+            let method = recorded.method().into_hyper();
+            let route = redact_route(recorded.path_and_query());
+
+            let entry = entries
+                .lock()
+                .expect("fixture poisoned")
+                .iter_mut()
+                .find(|entry| {
+                    entry.as_ref().map_or(false, |entry| {
+                        entry.request.method == method.as_str()
+                            && entry.request.route == route
+                            && entry.request.body_hash == body_hash
+                    })
+                })
+                .and_then(Option::take);
+
+            let response = if let Some(entry) = entry {
+                entry.response
+            } else {
+                #[cfg(feature = "tracing")]
+                tracing::error!(
+                    method = method.as_str(),
+                    route = route.as_str(),
+                    %body_hash,
+                    "no recorded response matches the request",
+                );
+
+                return Err(Box::new(FixtureError {
+                    kind: FixtureErrorType::Unmatched {
+                        body_hash,
+                        method: recorded.method(),
+                        route,
+                    },
+                    source: None,
+                }) as Box<dyn Error + Send + Sync>);
+            };
+
+            let mut hyper_response = HyperResponse::new(Body::from(response.body));
+
+            for (name, values) in &response.headers {
+                let name = if let Ok(name) = HeaderName::from_bytes(name.as_bytes()) {
+                    name
+                } else {
+                    continue;
+                };
+
+                for value in values {
+                    if let Ok(value) = HeaderValue::from_str(value) {
+                        hyper_response.headers_mut().append(&name, value);
+                    }
+                }
+            }
+
+            if let Ok(status) = StatusCode::from_u16(response.status) {
+                *hyper_response.status_mut() = status;
+            }
+
+            Ok(hyper_response)
+        })
+    }
+}
+
+/// Hash of the body of a request, as a hexadecimal string.
+///
+/// The body is hashed with FNV-1a, which unlike the standard library's
+/// hasher is stable across releases. Multipart forms are hashed by their
+/// parts rather than their raw body.
+fn body_hash(request: &RecordedRequest) -> String {
+    const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    fn update(mut hash: u64, bytes: &[u8]) -> u64 {
+        for byte in bytes {
+            hash ^= u64::from(*byte);
+            hash = hash.wrapping_mul(PRIME);
+        }
+
+        hash
+    }
+
+    let hash = match request.form() {
+        Some(parts) => parts.iter().fold(OFFSET, |hash, part| {
+            let hash = update(hash, part.name().as_bytes());
+            let hash = update(hash, part.filename().unwrap_or_default().as_bytes());
+
+            update(hash, part.data())
+        }),
+        None => update(OFFSET, request.body()),
+    };
+
+    format!("{:016x}", hash)
+}
+
+/// Convert headers into a map of their values, optionally scrubbing the
+/// `Authorization` header.
+///
+/// Header values that aren't valid strings are skipped.
+fn header_map(headers: &HeaderMap, scrub: bool) -> BTreeMap<String, Vec<String>> {
+    let mut map = BTreeMap::<_, Vec<_>>::new();
+
+    for (name, value) in headers {
+        if scrub && name == AUTHORIZATION {
+            continue;
+        }
+
+        if let Ok(value) = value.to_str() {
+            map.entry(name.as_str().to_owned())
+                .or_default()
+                .push(value.to_owned());
+        }
+    }
+
+    map
+}
+
+/// Replace the token in a route to a webhook or an interaction with
+/// `{token}`, such as `webhooks/1/{token}/messages/2`.
+fn redact_route(route: &str) -> String {
+    let (path, query) = match route.find('?') {
+        Some(index) => route.split_at(index),
+        None => (route, ""),
+    };
+    let mut segments = path.split('/').collect::<Vec<_>>();
+
+    if segments.len() > 2 && matches!(segments[0], "interactions" | "webhooks") {
+        segments[2] = "{token}";
+    }
+
+    let mut redacted = segments.join("/");
+    redacted.push_str(query);
+
+    redacted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        header_map, redact_route, Fixture, FixtureError, FixtureErrorType, RecordingTransport,
+        ReplayTransport,
+    };
+    use crate::{
+        error::ErrorType,
+        routing::Route,
+        transport::{MockResponse, MockTransport},
+        Client,
+    };
+    use hyper::header::{HeaderMap, HeaderValue, AUTHORIZATION, SET_COOKIE};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+    use twilight_model::{
+        application::callback::InteractionResponse,
+        id::{ChannelId, InteractionId, WebhookId},
+    };
+
+    assert_impl_all!(Fixture: Clone, Debug, Default, Send, Sync);
+    assert_impl_all!(FixtureError: Error, Send, Sync);
+    assert_impl_all!(FixtureErrorType: Debug, Send, Sync);
+    assert_impl_all!(RecordingTransport: Clone, Debug, Send, Sync);
+    assert_impl_all!(ReplayTransport: Clone, Debug, Send, Sync);
+
+    const MESSAGE: &str = r#"{
+        "attachments": [],
+        "author": {"avatar": null, "discriminator": "0001", "id": "2", "username": "bot"},
+        "channel_id": "1",
+        "content": "hello",
+        "edited_timestamp": null,
+        "embeds": [],
+        "id": "3",
+        "mention_everyone": false,
+        "mention_roles": [],
+        "mentions": [],
+        "pinned": false,
+        "timestamp": "2021-01-01T00:00:00.000000+00:00",
+        "tts": false,
+        "type": 0
+    }"#;
+
+    fn client(transport: Box<dyn crate::transport::Transport>) -> Client {
+        Client::builder()
+            .token("secret token".to_owned())
+            .transport(transport)
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_record_and_replay() -> Result<(), Box<dyn Error>> {
+        let mock = MockTransport::new();
+        mock.on_route(
+            &Route::CreateMessage { channel_id: 1 },
+            MockResponse::new(200).body(MESSAGE.as_bytes().to_vec()),
+        );
+        let recording = RecordingTransport::new(Box::new(mock));
+        let recorder = client(Box::new(recording.clone()));
+
+        recorder
+            .create_message(ChannelId(1))
+            .content("hello")?
+            .exec()
+            .await?;
+        recorder
+            .create_message(ChannelId(1))
+            .files(&[("a.txt", b"file")])
+            .exec()
+            .await?;
+
+        let json = recording.fixture().to_vec()?;
+        assert!(!String::from_utf8(json.clone())?.contains("secret token"));
+
+        let fixture = Fixture::from_slice(&json)?;
+        assert_eq!(2, fixture.entries().len());
+        assert_eq!("channels/1/messages", fixture.entries()[0].request.route);
+        assert_eq!(200, fixture.entries()[0].response.status);
+
+        let replay = ReplayTransport::new(fixture);
+        let replayer = client(Box::new(replay.clone()));
+
+        let message = replayer
+            .create_message(ChannelId(1))
+            .content("hello")?
+            .exec()
+            .await?
+            .model()
+            .await?;
+        assert_eq!(ChannelId(1), message.channel_id);
+
+        // Multipart boundaries are random, but forms with the same parts
+        // still match.
+        replayer
+            .create_message(ChannelId(1))
+            .files(&[("a.txt", b"file")])
+            .exec()
+            .await?;
+        assert_eq!(0, replay.remaining());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_replay_unmatched() -> Result<(), Box<dyn Error>> {
+        let mock = MockTransport::new();
+        mock.on_route(
+            &Route::CreateMessage { channel_id: 1 },
+            MockResponse::new(200).body(MESSAGE.as_bytes().to_vec()),
+        );
+        let recording = RecordingTransport::new(Box::new(mock));
+        client(Box::new(recording.clone()))
+            .create_message(ChannelId(1))
+            .content("hello")?
+            .exec()
+            .await?;
+
+        let replay = ReplayTransport::new(recording.fixture());
+        let replayer = client(Box::new(replay.clone()));
+
+        let error = replayer
+            .create_message(ChannelId(1))
+            .content("goodbye")?
+            .exec()
+            .await
+            .unwrap_err();
+        assert!(matches!(error.kind(), ErrorType::RequestError));
+
+        let source = error.into_source().expect("transport error");
+        let fixture_error = source
+            .downcast_ref::<FixtureError>()
+            .expect("fixture error");
+        assert!(matches!(
+            fixture_error.kind(),
+            FixtureErrorType::Unmatched { route, .. } if route == "channels/1/messages"
+        ));
+        assert_eq!(1, replay.remaining());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_header_map() {
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTHORIZATION, HeaderValue::from_static("Bot secret"));
+        headers.append(SET_COOKIE, HeaderValue::from_static("a=1"));
+        headers.append(SET_COOKIE, HeaderValue::from_static("b=2"));
+
+        let map = header_map(&headers, true);
+        assert_eq!(1, map.len());
+        assert_eq!(
+            Some(&Vec::from(["a=1".to_owned(), "b=2".to_owned()])),
+            map.get("set-cookie")
+        );
+        assert!(header_map(&headers, false).contains_key("authorization"));
+    }
+
+    #[test]
+    fn test_redact_route() {
+        assert_eq!("channels/1/messages", redact_route("channels/1/messages"));
+        assert_eq!("webhooks/1", redact_route("webhooks/1"));
+        assert_eq!(
+            "webhooks/1/{token}?wait=true",
+            redact_route("webhooks/1/token?wait=true")
+        );
+        assert_eq!(
+            "webhooks/1/{token}/messages/2",
+            redact_route("webhooks/1/token/messages/2")
+        );
+        assert_eq!(
+            "interactions/1/{token}/callback",
+            redact_route("interactions/1/token/callback")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_tokens_redacted() -> Result<(), Box<dyn Error>> {
+        let mock = MockTransport::new();
+        mock.on_route(
+            &Route::ExecuteWebhook {
+                token: "webhook-token",
+                wait: Some(false),
+                webhook_id: 1,
+            },
+            MockResponse::new(204),
+        );
+        mock.on_route(
+            &Route::InteractionCallback {
+                interaction_id: 2,
+                interaction_token: "interaction-token",
+            },
+            MockResponse::new(204),
+        );
+        let recording = RecordingTransport::new(Box::new(mock));
+        let recorder = client(Box::new(recording.clone()));
+
+        recorder
+            .execute_webhook(WebhookId(1), "webhook-token")
+            .content("hello")
+            .exec()
+            .await?;
+        recorder
+            .interaction_callback(
+                InteractionId(2),
+                "interaction-token",
+                &InteractionResponse::DeferredUpdateMessage,
+            )
+            .exec()
+            .await?;
+
+        let fixture = recording.fixture();
+        let json = String::from_utf8(fixture.to_vec()?)?;
+        assert!(!json.contains("webhook-token"));
+        assert!(!json.contains("interaction-token"));
+        assert_eq!(
+            "webhooks/1/{token}?wait=false",
+            fixture.entries()[0].request.route
+        );
+        assert_eq!(
+            "interactions/2/{token}/callback",
+            fixture.entries()[1].request.route
+        );
+
+        let replay = ReplayTransport::new(fixture);
+        let replayer = client(Box::new(replay.clone()));
+
+        replayer
+            .execute_webhook(WebhookId(1), "webhook-token")
+            .content("hello")
+            .exec()
+            .await?;
+        replayer
+            .interaction_callback(
+                InteractionId(2),
+                "interaction-token",
+                &InteractionResponse::DeferredUpdateMessage,
+            )
+            .exec()
+            .await?;
+        assert_eq!(0, replay.remaining());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_binary_body_and_multiple_header_values() -> Result<(), Box<dyn Error>> {
+        let body = Vec::from([0xff, 0x00, 0xfe]);
+        let mock = MockTransport::new();
+        mock.on_route(
+            &Route::GetChannel { channel_id: 1 },
+            MockResponse::new(200).body(body.clone()),
+        );
+        let recording = RecordingTransport::new(Box::new(mock));
+        client(Box::new(recording.clone()))
+            .channel(ChannelId(1))
+            .exec()
+            .await?;
+
+        // Add a header with multiple values to the recorded response.
+        let mut json = serde_json::to_value(recording.fixture())?;
+        json["entries"][0]["response"]["headers"]["set-cookie"] = serde_json::json!(["a=1", "b=2"]);
+        let fixture = Fixture::from_slice(&serde_json::to_vec(&json)?)?;
+        assert_eq!(body, fixture.entries()[0].response.body);
+
+        let response = client(Box::new(ReplayTransport::new(fixture)))
+            .channel(ChannelId(1))
+            .exec()
+            .await?;
+        let cookies = response
+            .headers()
+            .filter(|(name, _)| *name == "set-cookie")
+            .map(|(_, value)| value.to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(Vec::from([b"a=1".to_vec(), b"b=2".to_vec()]), cookies);
+        assert_eq!(body, response.bytes().await?);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,748 @@
+//! [`Transport`] responding to requests with canned responses, for testing
+//! bots without connecting to Discord.
+//!
+//! [`Transport`]: super::Transport
+
+use super::{Transport, TransportFuture};
+use crate::{
+    error::Error as HttpError,
+    request::Method,
+    routing::{Path, Route},
+    API_VERSION,
+};
+use hyper::{
+    body::{self, Bytes},
+    header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE},
+    http::request::Parts,
+    Body, Method as HyperMethod, Request as HyperRequest, Response as HyperResponse,
+};
+use serde::Serialize;
+use std::{
+    collections::VecDeque,
+    convert::TryFrom,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    str,
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Error returned by a [`MockTransport`] when it can't read or respond to a
+/// request.
+#[derive(Debug)]
+pub struct MockTransportError {
+    kind: MockTransportErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl MockTransportError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &MockTransportErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (MockTransportErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for MockTransportError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            MockTransportErrorType::ReadingBody => f.write_str("reading the request body failed"),
+            MockTransportErrorType::Unmatched { method, url } => {
+                f.write_str("no mock response matches the request ")?;
+                f.write_str(method.into_hyper().as_str())?;
+                f.write_str(" ")?;
+
+                f.write_str(url)
+            }
+            MockTransportErrorType::UnsupportedMethod => {
+                f.write_str("the request method is unsupported")
+            }
+        }
+    }
+}
+
+impl Error for MockTransportError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`MockTransportError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum MockTransportErrorType {
+    /// Reading the body of the request failed.
+    ReadingBody,
+    /// No registered response matches the request.
+    Unmatched {
+        /// Method of the request.
+        method: Method,
+        /// URL of the request.
+        url: String,
+    },
+    /// The method of the request isn't a [`Method`].
+    UnsupportedMethod,
+}
+
+/// Canned response returned by a [`MockTransport`].
+///
+/// # Examples
+///
+/// Respond with a JSON body, including ratelimit headers indicating that no
+/// more requests may be made for 5 seconds:
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::time::Duration;
+/// use twilight_http::transport::MockResponse;
+///
+/// let response = MockResponse::new(200)
+///     .body(br#"{"id":"1","name":"test"}"#.to_vec())
+///     .ratelimit(5, 0, Duration::from_secs(5));
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug)]
+pub struct MockResponse {
+    body: Vec<u8>,
+    headers: HeaderMap,
+    status: u16,
+}
+
+impl MockResponse {
+    /// Create a response with a status code and no body.
+    pub fn new(status: u16) -> Self {
+        Self {
+            body: Vec::new(),
+            headers: HeaderMap::new(),
+            status,
+        }
+    }
+
+    /// Create a 429 response indicating that the request was ratelimited.
+    ///
+    /// The response contains the `Retry-After` header and the body Discord
+    /// sends with ratelimited responses. If `global` is true then the
+    /// `X-RateLimit-Global` header is also set.
+    pub fn ratelimited(retry_after: Duration, global: bool) -> Self {
+        let body = format!(
+            r#"{{"global":{},"message":"You are being rate limited.","retry_after":{}}}"#,
+            global,
+            retry_after.as_secs_f64(),
+        );
+
+        let mut response = Self::new(429).body(body.into_bytes());
+        let retry_after = retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0);
+        response
+            .headers
+            .insert("retry-after", HeaderValue::from(retry_after));
+
+        if global {
+            response
+                .headers
+                .insert("x-ratelimit-global", HeaderValue::from_static("true"));
+        }
+
+        response
+    }
+
+    /// Set the body of the response, marking it as JSON.
+    pub fn body(mut self, body: Vec<u8>) -> Self {
+        self.headers
+            .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        self.body = body;
+
+        self
+    }
+
+    /// Set the Discord bucket hash of the response via the
+    /// `X-RateLimit-Bucket` header.
+    ///
+    /// This has no effect on the client unless [`ratelimit`] is also called.
+    ///
+    /// [`ratelimit`]: Self::ratelimit
+    pub fn bucket(mut self, hash: &str) -> Self {
+        if let Ok(value) = HeaderValue::from_str(hash) {
+            self.headers.insert("x-ratelimit-bucket", value);
+        }
+
+        self
+    }
+
+    /// Add a header to the response.
+    pub fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.insert(name, value);
+
+        self
+    }
+
+    /// Set the body of the response, serialized as JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ErrorType::Json`] error type if the value could not be
+    /// serialized as JSON.
+    ///
+    /// [`ErrorType::Json`]: crate::error::ErrorType::Json
+    pub fn json(self, value: &impl Serialize) -> Result<Self, HttpError> {
+        let body = crate::json::to_vec(value).map_err(HttpError::json)?;
+
+        Ok(self.body(body))
+    }
+
+    /// Set the ratelimit headers of the response.
+    ///
+    /// `reset_after` is the amount of time until the bucket refills.
+    pub fn ratelimit(mut self, limit: u64, remaining: u64, reset_after: Duration) -> Self {
+        let reset = SystemTime::now()
+            .checked_add(reset_after)
+            .and_then(|reset| reset.duration_since(UNIX_EPOCH).ok())
+            .unwrap_or_default();
+
+        self.headers
+            .insert("x-ratelimit-limit", HeaderValue::from(limit));
+        self.headers
+            .insert("x-ratelimit-remaining", HeaderValue::from(remaining));
+
+        for (name, value) in &[
+            ("x-ratelimit-reset", reset),
+            ("x-ratelimit-reset-after", reset_after),
+        ] {
+            let secs = format!("{:.3}", value.as_secs_f64());
+
+            if let Ok(value) = HeaderValue::from_str(&secs) {
+                self.headers.insert(*name, value);
+            }
+        }
+
+        self
+    }
+
+    fn into_hyper(self) -> HyperResponse<Body> {
+        let mut response = HyperResponse::new(Body::from(self.body));
+        *response.headers_mut() = self.headers;
+
+        if let Ok(status) = hyper::StatusCode::from_u16(self.status) {
+            *response.status_mut() = status;
+        }
+
+        response
+    }
+}
+
+/// Part of a multipart form sent in a [`RecordedRequest`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecordedFormPart {
+    data: Vec<u8>,
+    filename: Option<String>,
+    name: String,
+}
+
+impl RecordedFormPart {
+    /// Data of the part.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Filename of the part, if it is a file.
+    pub fn filename(&self) -> Option<&str> {
+        self.filename.as_deref()
+    }
+
+    /// Name of the part.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// Request received by a [`MockTransport`].
+#[derive(Clone, Debug)]
+pub struct RecordedRequest {
+    body: Vec<u8>,
+    headers: HeaderMap,
+    method: Method,
+    path_and_query: String,
+    url: String,
+}
+
+impl RecordedRequest {
+    /// Read a request sent to a transport, returning it along with its parts
+    /// and body so that it may be rebuilt.
+    pub(super) async fn read(
+        request: HyperRequest<Body>,
+    ) -> Result<(Self, Parts, Bytes), MockTransportError> {
+        let (parts, body) = request.into_parts();
+
+        let method = match parts.method {
+            HyperMethod::DELETE => Method::Delete,
+            HyperMethod::GET => Method::Get,
+            HyperMethod::PATCH => Method::Patch,
+            HyperMethod::POST => Method::Post,
+            HyperMethod::PUT => Method::Put,
+            _ => {
+                return Err(MockTransportError {
+                    kind: MockTransportErrorType::UnsupportedMethod,
+                    source: None,
+                })
+            }
+        };
+
+        let body = body::to_bytes(body)
+            .await
+            .map_err(|source| MockTransportError {
+                kind: MockTransportErrorType::ReadingBody,
+                source: Some(Box::new(source)),
+            })?;
+
+        let prefix = format!("/api/v{}/", API_VERSION);
+        let path_and_query = parts.uri.path_and_query().map_or("", |pq| pq.as_str());
+        let request = Self {
+            body: body.to_vec(),
+            headers: parts.headers.clone(),
+            method,
+            path_and_query: path_and_query
+                .strip_prefix(prefix.as_str())
+                .unwrap_or(path_and_query)
+                .to_owned(),
+            url: parts.uri.to_string(),
+        };
+
+        Ok((request, parts, body))
+    }
+
+    /// Body of the request.
+    pub fn body(&self) -> &[u8] {
+        &self.body
+    }
+
+    /// Parts of the multipart form sent as the body of the request.
+    ///
+    /// Returns `None` if the body isn't a multipart form.
+    pub fn form(&self) -> Option<Vec<RecordedFormPart>> {
+        let content_type = self.headers.get(CONTENT_TYPE)?.to_str().ok()?;
+        let boundary = content_type.strip_prefix("multipart/form-data; boundary=")?;
+        let delimiter = format!("\r\n--{}", boundary);
+
+        let body = str::from_utf8(&self.body).ok()?;
+        let mut parts = Vec::new();
+
+        // The body starts with a delimiter and the last one is followed by
+        // "--", so skip the empty first and last segments.
+        for segment in body.split(delimiter.as_str()).skip(1) {
+            if segment == "--" {
+                break;
+            }
+
+            let segment = segment.strip_prefix("\r\n")?;
+            let head_len = segment.find("\r\n\r\n")?;
+            let (head, data) = (&segment[..head_len], &segment[head_len + 4..]);
+
+            let field = |key: &str| {
+                let start = head.find(&format!("; {}=\"", key))? + key.len() + 4;
+                let len = head[start..].find('"')?;
+
+                Some(head[start..start + len].to_owned())
+            };
+
+            parts.push(RecordedFormPart {
+                data: data.as_bytes().to_vec(),
+                filename: field("filename"),
+                name: field("name")?,
+            });
+        }
+
+        Some(parts)
+    }
+
+    /// Headers of the request.
+    pub const fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// Method of the request.
+    pub const fn method(&self) -> Method {
+        self.method
+    }
+
+    /// Ratelimit path parsed from the URL of the request, if it is known.
+    pub fn path(&self) -> Option<Path> {
+        let path = self
+            .path_and_query
+            .split('?')
+            .next()
+            .unwrap_or(&self.path_and_query);
+
+        Path::try_from((self.method, path)).ok()
+    }
+
+    /// Path and query of the request's URL, without the API prefix.
+    ///
+    /// In the URL `https://discord.com/api/v9/channels/123/pins` the path and
+    /// query is `channels/123/pins`, which is the same as the display of its
+    /// [`Route`].
+    pub fn path_and_query(&self) -> &str {
+        &self.path_and_query
+    }
+
+    /// Full URL of the request.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum Matcher {
+    Path(Method, Path),
+    Route(Method, String),
+}
+
+impl Matcher {
+    fn matches(&self, request: &RecordedRequest) -> bool {
+        match self {
+            Self::Path(method, path) => {
+                *method == request.method && request.path().as_ref() == Some(path)
+            }
+            Self::Route(method, route) => {
+                *method == request.method && *route == request.path_and_query
+            }
+        }
+    }
+
+    const fn is_route(&self) -> bool {
+        matches!(self, Self::Route(..))
+    }
+}
+
+#[derive(Debug)]
+struct Mock {
+    matcher: Matcher,
+    responses: VecDeque<MockResponse>,
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    mocks: Vec<Mock>,
+    requests: Vec<RecordedRequest>,
+}
+
+impl MockState {
+    fn add(&mut self, matcher: Matcher, response: MockResponse) {
+        if let Some(mock) = self.mocks.iter_mut().find(|mock| mock.matcher == matcher) {
+            mock.responses.push_back(response);
+        } else {
+            self.mocks.push(Mock {
+                matcher,
+                responses: VecDeque::from(vec![response]),
+            });
+        }
+    }
+
+    /// Record a request and find the response to it.
+    fn respond(&mut self, request: RecordedRequest) -> Option<MockResponse> {
+        // Routes are more specific than paths, so try them first.
+        let position = self
+            .mocks
+            .iter()
+            .position(|mock| mock.matcher.is_route() && mock.matcher.matches(&request))
+            .or_else(|| {
+                self.mocks
+                    .iter()
+                    .position(|mock| mock.matcher.matches(&request))
+            });
+        let mock = position.map(|position| &mut self.mocks[position]);
+
+        let response = mock.and_then(|mock| {
+            if mock.responses.len() > 1 {
+                mock.responses.pop_front()
+            } else {
+                mock.responses.front().cloned()
+            }
+        });
+
+        self.requests.push(request);
+
+        response
+    }
+}
+
+/// [`Transport`] responding to requests with canned responses.
+///
+/// Responses are registered for a [`Route`] or a [`Path`] along with a
+/// method. Multiple responses registered for the same route or path are
+/// returned in order, with the last one being returned for all remaining
+/// requests. Responses registered for routes are preferred over ones
+/// registered for paths. Requests without a matching response fail with a
+/// [`MockTransportErrorType::Unmatched`] error.
+///
+/// Every request is recorded and can be inspected via [`requests`].
+///
+/// Cloning the transport is cheap and all clones share the same responses
+/// and recorded requests, so a clone can be given to the client while
+/// keeping another for the test.
+///
+/// # Examples
+///
+/// Respond to deleting a message with a ratelimit the first time and
+/// successfully afterwards:
+///
+/// ```rust
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::time::Duration;
+/// use twilight_http::{
+///     client::RetryPolicy,
+///     request::Method,
+///     routing::Path,
+///     transport::{MockResponse, MockTransport},
+///     Client,
+/// };
+/// use twilight_model::id::{ChannelId, MessageId};
+///
+/// let transport = MockTransport::new();
+/// transport
+///     .on_path(
+///         Method::Delete,
+///         Path::ChannelsIdMessagesId(Method::Delete, 1),
+///         MockResponse::ratelimited(Duration::from_millis(100), false),
+///     )
+///     .on_path(
+///         Method::Delete,
+///         Path::ChannelsIdMessagesId(Method::Delete, 1),
+///         MockResponse::new(204),
+///     );
+///
+/// let client = Client::builder()
+///     .retry_policy(RetryPolicy::new())
+///     .token("my token".to_owned())
+///     .transport(Box::new(transport.clone()))
+///     .build();
+///
+/// let response = client.delete_message(ChannelId(1), MessageId(2)).exec().await?;
+///
+/// assert_eq!(204, response.status().raw());
+/// assert_eq!(2, transport.requests().len());
+/// # Ok(()) }
+/// ```
+///
+/// [`Transport`]: super::Transport
+/// [`requests`]: Self::requests
+#[derive(Clone, Debug, Default)]
+pub struct MockTransport {
+    state: Arc<Mutex<MockState>>,
+}
+
+impl MockTransport {
+    /// Create a new transport without any responses.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a response to requests to a ratelimit path with a method.
+    ///
+    /// The path of a request is parsed from its URL.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mutex of the transport's state is poisoned.
+    pub fn on_path(&self, method: Method, path: Path, response: MockResponse) -> &Self {
+        self.state
+            .lock()
+            .expect("mock state poisoned")
+            .add(Matcher::Path(method, path), response);
+
+        self
+    }
+
+    /// Register a response to requests to a route.
+    ///
+    /// Requests match if their method and path and query are the same as the
+    /// route's.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mutex of the transport's state is poisoned.
+    pub fn on_route(&self, route: &Route<'_>, response: MockResponse) -> &Self {
+        let matcher = Matcher::Route(route.method(), route.display().to_string());
+
+        self.state
+            .lock()
+            .expect("mock state poisoned")
+            .add(matcher, response);
+
+        self
+    }
+
+    /// Requests received by the transport, in the order they were received.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mutex of the transport's state is poisoned.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.state
+            .lock()
+            .expect("mock state poisoned")
+            .requests
+            .clone()
+    }
+}
+
+impl Transport for MockTransport {
+    fn send(&self, request: HyperRequest<Body>) -> TransportFuture {
+        let state = Arc::clone(&self.state);
+
+        Box::pin(async move {
+            let (request, ..) = RecordedRequest::read(request).await?;
+            let method = request.method;
+            let url = request.url.clone();
+
+            let response = state.lock().expect("mock state poisoned").respond(request);
+
+            match response {
+                Some(response) => Ok(response.into_hyper()),
+                None => Err(Box::new(MockTransportError {
+                    kind: MockTransportErrorType::Unmatched { method, url },
+                    source: None,
+                }) as Box<dyn Error + Send + Sync>),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        MockResponse, MockTransport, MockTransportError, MockTransportErrorType, RecordedFormPart,
+    };
+    use crate::{
+        error::ErrorType,
+        request::Method,
+        routing::{Path, Route},
+        Client,
+    };
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug, time::Duration};
+    use twilight_model::id::{ChannelId, MessageId};
+
+    assert_impl_all!(MockResponse: Clone, Debug, Send, Sync);
+    assert_impl_all!(MockTransportError: Error, Send, Sync);
+    assert_impl_all!(MockTransportErrorType: Debug, Send, Sync);
+
+    fn client(transport: &MockTransport) -> Client {
+        Client::builder()
+            .token("token".to_owned())
+            .transport(Box::new(transport.clone()))
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_route_and_record() -> Result<(), Box<dyn Error>> {
+        let transport = MockTransport::new();
+        transport.on_route(
+            &Route::CreateMessage { channel_id: 1 },
+            MockResponse::new(500),
+        );
+        transport.on_path(
+            Method::Post,
+            Path::ChannelsIdMessages(1),
+            MockResponse::new(204),
+        );
+        let client = client(&transport);
+
+        let error = client
+            .create_message(ChannelId(1))
+            .content("test")?
+            .files(&[("a.txt", b"hello")])
+            .exec()
+            .await
+            .unwrap_err();
+        assert!(matches!(error.kind(), ErrorType::Parsing { .. }));
+
+        let requests = transport.requests();
+        assert_eq!(1, requests.len());
+        assert_eq!("channels/1/messages", requests[0].path_and_query());
+        assert_eq!(Some(Path::ChannelsIdMessages(1)), requests[0].path());
+        assert_eq!(
+            Some("Bot token"),
+            requests[0]
+                .headers()
+                .get("authorization")
+                .and_then(|value| value.to_str().ok()),
+        );
+
+        let form = requests[0].form().expect("body is a form");
+        assert_eq!(2, form.len());
+        assert_eq!(
+            RecordedFormPart {
+                data: b"hello".to_vec(),
+                filename: Some("a.txt".to_owned()),
+                name: "0".to_owned(),
+            },
+            form[0],
+        );
+        assert_eq!("payload_json", form[1].name());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_response_order() -> Result<(), Box<dyn Error>> {
+        let path = Path::ChannelsIdMessagesId(Method::Delete, 1);
+        let transport = MockTransport::new();
+        transport
+            .on_path(
+                Method::Delete,
+                path.clone(),
+                MockResponse::ratelimited(Duration::from_secs(1), true),
+            )
+            .on_path(Method::Delete, path, MockResponse::new(204));
+        let client = client(&transport);
+
+        let error = client
+            .delete_message(ChannelId(1), MessageId(2))
+            .exec()
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            ErrorType::Response { status, .. } if status.raw() == 429
+        ));
+
+        for _ in 0..2 {
+            let response = client
+                .delete_message(ChannelId(1), MessageId(2))
+                .exec()
+                .await?;
+            assert_eq!(204, response.status().raw());
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_unmatched() {
+        let transport = MockTransport::new();
+        let client = client(&transport);
+
+        let error = client
+            .delete_message(ChannelId(1), MessageId(2))
+            .exec()
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error.kind(), ErrorType::RequestError));
+        assert_eq!(1, transport.requests().len());
+    }
+}
@@ -0,0 +1,110 @@
+//! Transports sending the HTTP requests of a [`Client`].
+//!
+//! By default the client sends requests over the network via [`hyper`] using
+//! a [`HyperTransport`]. The [`Transport`] trait allows replacing this, such
+//! as with a [`MockTransport`] to test bots without connecting to Discord, or
+//! with a [`RecordingTransport`] and [`ReplayTransport`] to record real
+//! traffic once and replay it in tests.
+//!
+//! [`Client`]: crate::Client
+
+pub mod fixture;
+pub mod mock;
+
+pub use self::{
+    fixture::{Fixture, RecordingTransport, ReplayTransport},
+    mock::{MockResponse, MockTransport, RecordedRequest},
+};
+
+use hyper::{
+    client::{Client as HyperClient, HttpConnector},
+    Body, Request as HyperRequest, Response as HyperResponse,
+};
+use std::{error::Error, fmt::Debug, future::Future, pin::Pin};
+
+#[cfg(feature = "hyper-rustls")]
+type HttpsConnector<T> = hyper_rustls::HttpsConnector<T>;
+#[cfg(all(feature = "hyper-tls", not(feature = "hyper-rustls")))]
+type HttpsConnector<T> = hyper_tls::HttpsConnector<T>;
+
+/// Future returned by [`Transport::send`].
+pub type TransportFuture = Pin<
+    Box<
+        dyn Future<Output = Result<HyperResponse<Body>, Box<dyn Error + Send + Sync>>>
+            + Send
+            + 'static,
+    >,
+>;
+
+/// Sender of the HTTP requests of a [`Client`].
+///
+/// The request passed to the transport is complete: it includes the full URL,
+/// the headers such as the `Authorization` header, and the body. Ratelimiting,
+/// retries, and [middleware] are handled by the client: [`send`] is only
+/// called once the ratelimiter allows the request to be sent, so the returned
+/// future may start sending it right away.
+///
+/// [`send`]: Self::send
+///
+/// [`Client`]: crate::Client
+/// [middleware]: crate::middleware
+pub trait Transport: Debug + Send + Sync {
+    /// Send a request, resolving to its response.
+    fn send(&self, request: HyperRequest<Body>) -> TransportFuture;
+}
+
+/// [`Transport`] sending requests over the network via a [`hyper`] client.
+///
+/// This is the default transport of the [`Client`].
+///
+/// [`Client`]: crate::Client
+#[derive(Debug)]
+pub struct HyperTransport {
+    http: HyperClient<HttpsConnector<HttpConnector>, Body>,
+}
+
+impl HyperTransport {
+    /// Create a new transport using the TLS implementation selected via the
+    /// crate's features.
+    pub fn new() -> Self {
+        #[cfg(feature = "rustls-native-roots")]
+        let connector = hyper_rustls::HttpsConnector::with_native_roots();
+        #[cfg(all(feature = "rustls-webpki-roots", not(feature = "rustls-native-roots")))]
+        let connector = hyper_rustls::HttpsConnector::with_webpki_roots();
+        #[cfg(all(
+            feature = "hyper-tls",
+            not(feature = "rustls-native-roots"),
+            not(feature = "rustls-webpki-roots")
+        ))]
+        let connector = hyper_tls::HttpsConnector::new();
+
+        let http = hyper::client::Builder::default().build(connector);
+
+        Self { http }
+    }
+}
+
+impl Default for HyperTransport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Transport for HyperTransport {
+    fn send(&self, request: HyperRequest<Body>) -> TransportFuture {
+        let future = self.http.request(request);
+
+        Box::pin(async move { future.await.map_err(From::from) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HyperTransport, MockTransport, Transport};
+    use static_assertions::{assert_impl_all, assert_obj_safe};
+    use std::fmt::Debug;
+
+    assert_obj_safe!(Transport);
+    assert_impl_all!(HyperTransport: Debug, Default, Send, Sync, Transport);
+    assert_impl_all!(MockTransport: Clone, Debug, Default, Send, Sync, Transport);
+}
@@ -0,0 +1,34 @@
+use crate::application::command::CommandOptionChoice;
+use serde::{Deserialize, Serialize};
+
+/// Choices suggested when responding to an [`Interaction`] of type
+/// [`ApplicationCommandAutocomplete`].
+///
+/// [`Interaction`]: crate::application::interaction::Interaction
+/// [`ApplicationCommandAutocomplete`]: crate::application::interaction::Interaction::ApplicationCommandAutocomplete
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct AutocompleteCallbackData {
+    /// List of suggested choices, at most 25.
+    pub choices: Vec<CommandOptionChoice>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AutocompleteCallbackData;
+    use serde::{Deserialize, Serialize};
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_fields!(AutocompleteCallbackData: choices);
+    assert_impl_all!(
+        AutocompleteCallbackData: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+}
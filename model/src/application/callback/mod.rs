@@ -1,15 +1,21 @@
 //! Used when responding to interactions.
 
+mod autocomplete_data;
 mod callback_data;
+mod modal_data;
 mod response_type;
 
-pub use self::{callback_data::CallbackData, response_type::ResponseType};
+pub use self::{
+    autocomplete_data::AutocompleteCallbackData, callback_data::CallbackData,
+    modal_data::ModalCallbackData, response_type::ResponseType,
+};
 
 use serde::{
-    de::{Deserializer, Error as DeError, IgnoredAny, MapAccess, Visitor},
+    de::{DeserializeOwned, Deserializer, Error as DeError, IgnoredAny, MapAccess, Visitor},
     ser::{SerializeStruct, Serializer},
     Deserialize, Serialize,
 };
+use serde_value::Value;
 use std::fmt::{Formatter, Result as FmtResult};
 
 /// Payload used for responding to an interaction.
@@ -31,6 +37,12 @@ pub enum InteractionResponse {
     DeferredUpdateMessage,
     /// Edit the message a component is attached to.
     UpdateMessage(CallbackData),
+    /// Suggest choices for the option the user is filling in.
+    ///
+    /// This is only valid for autocomplete interactions.
+    ApplicationCommandAutocompleteResult(AutocompleteCallbackData),
+    /// Respond with a popup modal for the user to fill in.
+    Modal(ModalCallbackData),
 }
 
 impl InteractionResponse {
@@ -65,6 +77,10 @@ impl InteractionResponse {
             }
             Self::DeferredUpdateMessage => ResponseType::DeferredUpdateMessage,
             Self::UpdateMessage(_) => ResponseType::UpdateMessage,
+            Self::ApplicationCommandAutocompleteResult(_) => {
+                ResponseType::ApplicationCommandAutocompleteResult
+            }
+            Self::Modal(_) => ResponseType::Modal,
         }
     }
 }
@@ -92,7 +108,7 @@ impl<'de> Visitor<'de> for ResponseVisitor {
     }
 
     fn visit_map<V: MapAccess<'de>>(self, mut map: V) -> Result<Self::Value, V::Error> {
-        let mut data: Option<CallbackData> = None;
+        let mut data: Option<Value> = None;
         let mut kind: Option<ResponseType> = None;
 
         let span = tracing::trace_span!("deserializing interaction response");
@@ -141,25 +157,28 @@ impl<'de> Visitor<'de> for ResponseVisitor {
         Ok(match kind {
             ResponseType::Pong => Self::Value::Pong,
             ResponseType::ChannelMessageWithSource => {
-                let data = data.ok_or_else(|| DeError::missing_field("data"))?;
-
-                Self::Value::ChannelMessageWithSource(data)
+                Self::Value::ChannelMessageWithSource(deserialize_data(data)?)
             }
             ResponseType::DeferredChannelMessageWithSource => {
-                let data = data.ok_or_else(|| DeError::missing_field("data"))?;
-
-                Self::Value::DeferredChannelMessageWithSource(data)
+                Self::Value::DeferredChannelMessageWithSource(deserialize_data(data)?)
             }
             ResponseType::DeferredUpdateMessage => Self::Value::DeferredUpdateMessage,
-            ResponseType::UpdateMessage => {
-                let data = data.ok_or_else(|| DeError::missing_field("data"))?;
-
-                Self::Value::UpdateMessage(data)
+            ResponseType::UpdateMessage => Self::Value::UpdateMessage(deserialize_data(data)?),
+            ResponseType::ApplicationCommandAutocompleteResult => {
+                Self::Value::ApplicationCommandAutocompleteResult(deserialize_data(data)?)
             }
+            ResponseType::Modal => Self::Value::Modal(deserialize_data(data)?),
         })
     }
 }
 
+/// Deserialize the data of a response into the type required by its kind.
+fn deserialize_data<T: DeserializeOwned, E: DeError>(data: Option<Value>) -> Result<T, E> {
+    data.ok_or_else(|| DeError::missing_field("data"))?
+        .deserialize_into()
+        .map_err(DeError::custom)
+}
+
 impl Serialize for InteractionResponse {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match self {
@@ -178,6 +197,22 @@ impl Serialize for InteractionResponse {
                 state.serialize_field("type", &self.kind())?;
                 state.serialize_field("data", &data)?;
 
+                state.end()
+            }
+            Self::ApplicationCommandAutocompleteResult(data) => {
+                let mut state = serializer.serialize_struct("InteractionResponse", 2)?;
+
+                state.serialize_field("type", &self.kind())?;
+                state.serialize_field("data", &data)?;
+
+                state.end()
+            }
+            Self::Modal(data) => {
+                let mut state = serializer.serialize_struct("InteractionResponse", 2)?;
+
+                state.serialize_field("type", &self.kind())?;
+                state.serialize_field("data", &data)?;
+
                 state.end()
             }
         }
@@ -186,8 +221,8 @@ impl Serialize for InteractionResponse {
 
 #[cfg(test)]
 mod tests {
-    use super::{CallbackData, InteractionResponse};
-    use crate::channel::message::MessageFlags;
+    use super::{AutocompleteCallbackData, CallbackData, InteractionResponse};
+    use crate::{application::command::CommandOptionChoice, channel::message::MessageFlags};
     use serde::{Deserialize, Serialize};
     use serde_test::Token;
     use static_assertions::assert_impl_all;
@@ -241,4 +276,47 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn test_autocomplete_response() {
+        let value =
+            InteractionResponse::ApplicationCommandAutocompleteResult(AutocompleteCallbackData {
+                choices: Vec::from([CommandOptionChoice::String {
+                    name: "twilight".into(),
+                    name_localizations: None,
+                    value: "twilight".into(),
+                }]),
+            });
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "InteractionResponse",
+                    len: 2,
+                },
+                Token::Str("type"),
+                Token::U8(8),
+                Token::Str("data"),
+                Token::Struct {
+                    name: "AutocompleteCallbackData",
+                    len: 1,
+                },
+                Token::Str("choices"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "CommandOptionChoice",
+                    len: 2,
+                },
+                Token::Str("name"),
+                Token::Str("twilight"),
+                Token::Str("value"),
+                Token::Str("twilight"),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::StructEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
 }
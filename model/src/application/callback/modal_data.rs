@@ -0,0 +1,43 @@
+use crate::application::component::Component;
+use serde::{Deserialize, Serialize};
+
+/// Modal shown to the user when responding to an [`Interaction`].
+///
+/// Modals contain [`ActionRow`]s of [`TextInput`]s, and can't be sent in
+/// response to [`ModalSubmit`] or [`Ping`] interactions.
+///
+/// [`ActionRow`]: crate::application::component::ActionRow
+/// [`Interaction`]: crate::application::interaction::Interaction
+/// [`ModalSubmit`]: crate::application::interaction::Interaction::ModalSubmit
+/// [`Ping`]: crate::application::interaction::Interaction::Ping
+/// [`TextInput`]: crate::application::component::TextInput
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ModalCallbackData {
+    /// List of components of the modal, between 1 and 5 action rows.
+    pub components: Vec<Component>,
+    /// Developer defined identifier of the modal.
+    pub custom_id: String,
+    /// Title shown at the top of the modal.
+    pub title: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ModalCallbackData;
+    use serde::{Deserialize, Serialize};
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_fields!(ModalCallbackData: components, custom_id, title);
+    assert_impl_all!(
+        ModalCallbackData: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+}
@@ -15,6 +15,10 @@ pub enum ResponseType {
     DeferredUpdateMessage = 6,
     /// Edit the message a component is attached to.
     UpdateMessage = 7,
+    /// Respond to an autocomplete interaction with suggested choices.
+    ApplicationCommandAutocompleteResult = 8,
+    /// Respond to an interaction with a popup modal.
+    Modal = 9,
 }
 
 impl ResponseType {
@@ -42,6 +46,8 @@ impl ResponseType {
             Self::DeferredChannelMessageWithSource => "DeferredChannelMessageWithSource",
             Self::DeferredUpdateMessage => "DeferredUpdateMessage",
             Self::UpdateMessage => "UpdateMessage",
+            Self::ApplicationCommandAutocompleteResult => "ApplicationCommandAutocompleteResult",
+            Self::Modal => "Modal",
         }
     }
 }
@@ -72,6 +78,8 @@ mod tests {
     const_assert_eq!(5, ResponseType::DeferredChannelMessageWithSource as u8);
     const_assert_eq!(6, ResponseType::DeferredUpdateMessage as u8);
     const_assert_eq!(7, ResponseType::UpdateMessage as u8);
+    const_assert_eq!(8, ResponseType::ApplicationCommandAutocompleteResult as u8);
+    const_assert_eq!(9, ResponseType::Modal as u8);
 
     #[test]
     fn test_name() {
@@ -89,5 +97,10 @@ mod tests {
             ResponseType::DeferredUpdateMessage.name()
         );
         assert_eq!("UpdateMessage", ResponseType::UpdateMessage.name());
+        assert_eq!(
+            "ApplicationCommandAutocompleteResult",
+            ResponseType::ApplicationCommandAutocompleteResult.name()
+        );
+        assert_eq!("Modal", ResponseType::Modal.name());
     }
 }
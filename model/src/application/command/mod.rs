@@ -13,8 +13,12 @@ pub use self::{
     },
 };
 
-use crate::id::{ApplicationId, CommandId, GuildId};
+use crate::{
+    application::Locale,
+    id::{ApplicationId, CommandId, GuildId},
+};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// Data sent to discord to create a command.
 ///
@@ -31,6 +35,11 @@ pub struct Command {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub guild_id: Option<GuildId>,
     pub name: String,
+    /// Localization dictionary for the name.
+    ///
+    /// Each localized name must be 32 characters or less.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name_localizations: Option<BTreeMap<Locale, String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default_permission: Option<bool>,
     /// Description of the command.
@@ -40,6 +49,11 @@ pub struct Command {
     /// [`User`]: CommandType::User
     /// [`Message`]: CommandType::Message
     pub description: String,
+    /// Localization dictionary for the description.
+    ///
+    /// Each localized description must be 100 characters or less.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_localizations: Option<BTreeMap<Locale, String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<CommandId>,
     #[serde(rename = "type")]
@@ -1,11 +1,14 @@
-use crate::util::is_false;
+use crate::{application::Locale, util::is_false};
 use serde::{
     de::{Deserializer, Error as DeError, IgnoredAny, MapAccess, Visitor},
     ser::Serializer,
     Deserialize, Serialize,
 };
 use serde_repr::{Deserialize_repr, Serialize_repr};
-use std::fmt::{Formatter, Result as FmtResult};
+use std::{
+    collections::BTreeMap,
+    fmt::{Formatter, Result as FmtResult},
+};
 
 /// Option for a [`Command`].
 ///
@@ -66,11 +69,17 @@ impl<'de> Deserialize<'de> for CommandOption {
 
 #[derive(Serialize)]
 struct CommandOptionEnvelope<'ser> {
+    #[serde(skip_serializing_if = "is_false")]
+    autocomplete: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     choices: Option<&'ser [CommandOptionChoice]>,
     description: &'ser str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description_localizations: Option<&'ser BTreeMap<Locale, String>>,
     name: &'ser str,
     #[serde(skip_serializing_if = "Option::is_none")]
+    name_localizations: Option<&'ser BTreeMap<Locale, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     options: Option<&'ser [CommandOption]>,
     #[serde(skip_serializing_if = "is_false")]
     required: bool,
@@ -82,17 +91,23 @@ impl Serialize for CommandOption {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         let envelope = match self {
             Self::SubCommand(data) | Self::SubCommandGroup(data) => CommandOptionEnvelope {
+                autocomplete: false,
                 choices: None,
                 description: data.description.as_ref(),
+                description_localizations: data.description_localizations.as_ref(),
                 name: data.name.as_ref(),
+                name_localizations: data.name_localizations.as_ref(),
                 options: Some(data.options.as_ref()),
                 required: data.required,
                 kind: self.kind(),
             },
             Self::String(data) | Self::Integer(data) => CommandOptionEnvelope {
+                autocomplete: data.autocomplete,
                 choices: Some(data.choices.as_ref()),
                 description: data.description.as_ref(),
+                description_localizations: data.description_localizations.as_ref(),
                 name: data.name.as_ref(),
+                name_localizations: data.name_localizations.as_ref(),
                 options: None,
                 required: data.required,
                 kind: self.kind(),
@@ -102,9 +117,12 @@ impl Serialize for CommandOption {
             | Self::Channel(data)
             | Self::Role(data)
             | Self::Mentionable(data) => CommandOptionEnvelope {
+                autocomplete: false,
                 choices: None,
                 description: data.description.as_ref(),
+                description_localizations: data.description_localizations.as_ref(),
                 name: data.name.as_ref(),
+                name_localizations: data.name_localizations.as_ref(),
                 options: None,
                 required: data.required,
                 kind: self.kind(),
@@ -118,9 +136,12 @@ impl Serialize for CommandOption {
 #[derive(Debug, Deserialize)]
 #[serde(field_identifier, rename_all = "snake_case")]
 enum OptionField {
+    Autocomplete,
     Choices,
     Description,
+    DescriptionLocalizations,
     Name,
+    NameLocalizations,
     Options,
     Required,
     Type,
@@ -137,10 +158,13 @@ impl<'de> Visitor<'de> for OptionVisitor {
 
     #[allow(clippy::too_many_lines)]
     fn visit_map<V: MapAccess<'de>>(self, mut map: V) -> Result<Self::Value, V::Error> {
+        let mut autocomplete: Option<bool> = None;
         let mut choices: Option<Option<Vec<CommandOptionChoice>>> = None;
         let mut description: Option<String> = None;
+        let mut description_localizations: Option<Option<BTreeMap<Locale, String>>> = None;
         let mut kind: Option<CommandOptionType> = None;
         let mut name: Option<String> = None;
+        let mut name_localizations: Option<Option<BTreeMap<Locale, String>>> = None;
         let mut options: Option<Option<Vec<CommandOption>>> = None;
         let mut required: Option<bool> = None;
 
@@ -168,6 +192,13 @@ impl<'de> Visitor<'de> for OptionVisitor {
             };
 
             match key {
+                OptionField::Autocomplete => {
+                    if autocomplete.is_some() {
+                        return Err(DeError::duplicate_field("autocomplete"));
+                    }
+
+                    autocomplete = Some(map.next_value()?);
+                }
                 OptionField::Choices => {
                     if choices.is_some() {
                         return Err(DeError::duplicate_field("choices"));
@@ -182,6 +213,13 @@ impl<'de> Visitor<'de> for OptionVisitor {
 
                     description = Some(map.next_value()?);
                 }
+                OptionField::DescriptionLocalizations => {
+                    if description_localizations.is_some() {
+                        return Err(DeError::duplicate_field("description_localizations"));
+                    }
+
+                    description_localizations = Some(map.next_value()?);
+                }
                 OptionField::Name => {
                     if name.is_some() {
                         return Err(DeError::duplicate_field("name"));
@@ -189,6 +227,13 @@ impl<'de> Visitor<'de> for OptionVisitor {
 
                     name = Some(map.next_value()?);
                 }
+                OptionField::NameLocalizations => {
+                    if name_localizations.is_some() {
+                        return Err(DeError::duplicate_field("name_localizations"));
+                    }
+
+                    name_localizations = Some(map.next_value()?);
+                }
                 OptionField::Options => {
                     if options.is_some() {
                         return Err(DeError::duplicate_field("options"));
@@ -224,6 +269,9 @@ impl<'de> Visitor<'de> for OptionVisitor {
             "common fields of all variants exist"
         );
 
+        let autocomplete = autocomplete.unwrap_or_default();
+        let description_localizations = description_localizations.flatten();
+        let name_localizations = name_localizations.flatten();
         let required = required.unwrap_or_default();
 
         Ok(match kind {
@@ -232,7 +280,9 @@ impl<'de> Visitor<'de> for OptionVisitor {
 
                 CommandOption::SubCommand(OptionsCommandOptionData {
                     description,
+                    description_localizations,
                     name,
+                    name_localizations,
                     options,
                     required,
                 })
@@ -242,46 +292,64 @@ impl<'de> Visitor<'de> for OptionVisitor {
 
                 CommandOption::SubCommandGroup(OptionsCommandOptionData {
                     description,
+                    description_localizations,
                     name,
+                    name_localizations,
                     options,
                     required,
                 })
             }
             CommandOptionType::String => CommandOption::String(ChoiceCommandOptionData {
+                autocomplete,
                 choices: choices.flatten().unwrap_or_default(),
                 description,
+                description_localizations,
                 name,
+                name_localizations,
                 required,
             }),
             CommandOptionType::Integer => CommandOption::Integer(ChoiceCommandOptionData {
+                autocomplete,
                 choices: choices.flatten().unwrap_or_default(),
                 description,
+                description_localizations,
                 name,
+                name_localizations,
                 required,
             }),
             CommandOptionType::Boolean => CommandOption::Boolean(BaseCommandOptionData {
                 description,
+                description_localizations,
                 name,
+                name_localizations,
                 required,
             }),
             CommandOptionType::User => CommandOption::User(BaseCommandOptionData {
                 description,
+                description_localizations,
                 name,
+                name_localizations,
                 required,
             }),
             CommandOptionType::Channel => CommandOption::Channel(BaseCommandOptionData {
                 description,
+                description_localizations,
                 name,
+                name_localizations,
                 required,
             }),
             CommandOptionType::Role => CommandOption::Role(BaseCommandOptionData {
                 description,
+                description_localizations,
                 name,
+                name_localizations,
                 required,
             }),
             CommandOptionType::Mentionable => CommandOption::Mentionable(BaseCommandOptionData {
                 description,
+                description_localizations,
                 name,
+                name_localizations,
                 required,
             }),
         })
@@ -300,8 +368,18 @@ impl<'de> Visitor<'de> for OptionVisitor {
 pub struct BaseCommandOptionData {
     /// Description of the option. It must be 100 characters or less.
     pub description: String,
+    /// Localization dictionary for the description.
+    ///
+    /// Each localized description must be 100 characters or less.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_localizations: Option<BTreeMap<Locale, String>>,
     /// Name of the option. It must be 32 characters or less.
     pub name: String,
+    /// Localization dictionary for the name.
+    ///
+    /// Each localized name must be 32 characters or less.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name_localizations: Option<BTreeMap<Locale, String>>,
     /// Whether the option is required to be completed by a user.
     #[serde(default)]
     pub required: bool,
@@ -316,8 +394,18 @@ pub struct BaseCommandOptionData {
 pub struct OptionsCommandOptionData {
     /// Description of the option. It must be 100 characters or less.
     pub description: String,
+    /// Localization dictionary for the description.
+    ///
+    /// Each localized description must be 100 characters or less.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_localizations: Option<BTreeMap<Locale, String>>,
     /// Name of the option. It must be 32 characters or less.
     pub name: String,
+    /// Localization dictionary for the name.
+    ///
+    /// Each localized name must be 32 characters or less.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name_localizations: Option<BTreeMap<Locale, String>>,
     /// Used for specifying the nested options in a [`SubCommand`] or
     /// [`SubCommandGroup`].
     ///
@@ -336,6 +424,16 @@ pub struct OptionsCommandOptionData {
 /// [`Integer`]: CommandOption::Integer
 #[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct ChoiceCommandOptionData {
+    /// Whether the choices are provided by responding to
+    /// [`ApplicationCommandAutocomplete`] interactions while the user types.
+    ///
+    /// Autocomplete can't be enabled on options with predetermined
+    /// [`choices`].
+    ///
+    /// [`ApplicationCommandAutocomplete`]: crate::application::interaction::Interaction::ApplicationCommandAutocomplete
+    /// [`choices`]: Self::choices
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub autocomplete: bool,
     /// Predetermined choices may be defined for a user to select.
     ///
     /// When completing this option, the user is prompted with a selector of all
@@ -346,8 +444,18 @@ pub struct ChoiceCommandOptionData {
     pub choices: Vec<CommandOptionChoice>,
     /// Description of the option. It must be 100 characters or less.
     pub description: String,
+    /// Localization dictionary for the description.
+    ///
+    /// Each localized description must be 100 characters or less.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_localizations: Option<BTreeMap<Locale, String>>,
     /// Name of the option. It must be 32 characters or less.
     pub name: String,
+    /// Localization dictionary for the name.
+    ///
+    /// Each localized name must be 32 characters or less.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name_localizations: Option<BTreeMap<Locale, String>>,
     /// Whether or not the option is required to be completed by a user.
     #[serde(default)]
     pub required: bool,
@@ -361,8 +469,20 @@ pub struct ChoiceCommandOptionData {
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum CommandOptionChoice {
-    String { name: String, value: String },
-    Int { name: String, value: i64 },
+    String {
+        name: String,
+        /// Localization dictionary for the name.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        name_localizations: Option<BTreeMap<Locale, String>>,
+        value: String,
+    },
+    Int {
+        name: String,
+        /// Localization dictionary for the name.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        name_localizations: Option<BTreeMap<Locale, String>>,
+        value: i64,
+    },
 }
 
 /// Type of a [`CommandOption`].
@@ -405,8 +525,12 @@ mod tests {
         BaseCommandOptionData, ChoiceCommandOptionData, CommandOption, CommandOptionChoice,
         OptionsCommandOptionData,
     };
-    use crate::id::{ApplicationId, CommandId, GuildId};
+    use crate::{
+        application::Locale,
+        id::{ApplicationId, CommandId, GuildId},
+    };
     use serde_test::Token;
+    use std::collections::BTreeMap;
 
     /// Test that when a subcommand or subcommand group's `options` field is
     /// missing during deserialization that the field is defaulted instead of
@@ -415,7 +539,9 @@ mod tests {
     fn test_issue_1150() {
         let value = CommandOption::SubCommand(OptionsCommandOptionData {
             description: "ponyville".to_owned(),
+            description_localizations: None,
             name: "equestria".to_owned(),
+            name_localizations: None,
             options: Vec::new(),
             required: false,
         });
@@ -448,63 +574,90 @@ mod tests {
             guild_id: Some(GuildId(300)),
             kind: CommandType::ChatInput,
             name: "test command".into(),
+            name_localizations: None,
             default_permission: Some(true),
             description: "this command is a test".into(),
+            description_localizations: None,
             id: Some(CommandId(200)),
             options: vec![CommandOption::SubCommandGroup(OptionsCommandOptionData {
                 description: "sub group desc".into(),
+                description_localizations: None,
                 name: "sub group name".into(),
+                name_localizations: None,
                 options: vec![CommandOption::SubCommand(OptionsCommandOptionData {
                     description: "sub command desc".into(),
+                    description_localizations: None,
                     name: "sub command name".into(),
+                    name_localizations: None,
                     options: vec![
                         CommandOption::String(ChoiceCommandOptionData {
+                            autocomplete: true,
                             choices: Vec::new(),
                             description: "string manual desc".into(),
+                            description_localizations: None,
                             name: "string_manual".into(),
+                            name_localizations: None,
                             required: false,
                         }),
                         CommandOption::String(ChoiceCommandOptionData {
+                            autocomplete: false,
                             choices: vec![CommandOptionChoice::String {
                                 name: "choicea".into(),
+                                name_localizations: None,
                                 value: "choice_a".into(),
                             }],
                             description: "string desc".into(),
+                            description_localizations: None,
                             name: "string".into(),
+                            name_localizations: None,
                             required: false,
                         }),
                         CommandOption::Integer(ChoiceCommandOptionData {
+                            autocomplete: false,
                             choices: vec![CommandOptionChoice::Int {
                                 name: "choice2".into(),
+                                name_localizations: None,
                                 value: 2,
                             }],
                             description: "int desc".into(),
+                            description_localizations: None,
                             name: "int".into(),
+                            name_localizations: None,
                             required: false,
                         }),
                         CommandOption::Boolean(BaseCommandOptionData {
                             description: "bool desc".into(),
+                            description_localizations: None,
                             name: "bool".into(),
+                            name_localizations: None,
                             required: false,
                         }),
                         CommandOption::User(BaseCommandOptionData {
                             description: "user desc".into(),
+                            description_localizations: None,
                             name: "user".into(),
+                            name_localizations: None,
                             required: false,
                         }),
                         CommandOption::Channel(BaseCommandOptionData {
                             description: "channel desc".into(),
+                            description_localizations: None,
                             name: "channel".into(),
+                            name_localizations: None,
                             required: false,
                         }),
                         CommandOption::Role(BaseCommandOptionData {
                             description: "role desc".into(),
+                            description_localizations: None,
                             name: "role".into(),
+                            name_localizations: None,
                             required: false,
                         }),
                         CommandOption::Mentionable(BaseCommandOptionData {
                             description: "mentionable desc".into(),
+                            description_localizations: None,
                             name: "mentionable".into(),
+                            name_localizations: None,
                             required: false,
                         }),
                     ],
@@ -570,8 +723,10 @@ mod tests {
                 Token::Seq { len: Some(8) },
                 Token::Struct {
                     name: "CommandOptionEnvelope",
-                    len: 4,
+                    len: 5,
                 },
+                Token::Str("autocomplete"),
+                Token::Bool(true),
                 Token::Str("choices"),
                 Token::Some,
                 Token::Seq { len: Some(0) },
@@ -701,4 +856,62 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn test_localizations() -> Result<(), serde_json::Error> {
+        let value = serde_json::from_str::<Command>(
+            r#"{
+                "description": "Get a tag",
+                "description_localizations": {"de": "Tag abrufen"},
+                "name": "tag",
+                "name_localizations": {"de": "etikett", "fr": "etiquette"},
+                "options": [{
+                    "choices": [{
+                        "name": "short",
+                        "name_localizations": {"de": "kurz"},
+                        "value": "short"
+                    }],
+                    "description": "Length",
+                    "description_localizations": {"de": "Länge"},
+                    "name": "length",
+                    "type": 3
+                }],
+                "type": 1
+            }"#,
+        )?;
+
+        let mut name_localizations = BTreeMap::new();
+        name_localizations.insert(Locale::German, "etikett".to_owned());
+        name_localizations.insert(Locale::French, "etiquette".to_owned());
+        assert_eq!(Some(name_localizations), value.name_localizations);
+
+        let option = match &value.options[0] {
+            CommandOption::String(option) => option,
+            other => panic!("expected string option, got {:?}", other),
+        };
+        assert_eq!(
+            Some("Länge"),
+            option
+                .description_localizations
+                .as_ref()
+                .and_then(|map| map.get(&Locale::German))
+                .map(String::as_str)
+        );
+        assert!(option.name_localizations.is_none());
+
+        match &option.choices[0] {
+            CommandOptionChoice::String {
+                name_localizations: Some(map),
+                ..
+            } => assert_eq!(Some("kurz"), map.get(&Locale::German).map(String::as_str)),
+            other => panic!("expected localized string choice, got {:?}", other),
+        }
+
+        assert_eq!(
+            value,
+            serde_json::from_str(&serde_json::to_string(&value)?)?
+        );
+
+        Ok(())
+    }
 }
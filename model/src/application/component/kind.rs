@@ -25,6 +25,11 @@ pub enum ComponentType {
     ///
     /// [`SelectMenu`]: super::SelectMenu
     SelectMenu = 3,
+
+    /// Component is an [`TextInput`].
+    ///
+    /// [`TextInput`]: super::TextInput
+    TextInput = 4,
 }
 
 impl ComponentType {
@@ -48,6 +53,7 @@ impl ComponentType {
             Self::ActionRow => "ActionRow",
             Self::Button => "Button",
             Self::SelectMenu => "SelectMenu",
+            Self::TextInput => "TextInput",
         }
     }
 }
@@ -83,12 +89,14 @@ mod tests {
     const_assert_eq!(1, ComponentType::ActionRow as u8);
     const_assert_eq!(2, ComponentType::Button as u8);
     const_assert_eq!(3, ComponentType::SelectMenu as u8);
+    const_assert_eq!(4, ComponentType::TextInput as u8);
 
     #[test]
     fn test_variants() {
         serde_test::assert_tokens(&ComponentType::ActionRow, &[Token::U8(1)]);
         serde_test::assert_tokens(&ComponentType::Button, &[Token::U8(2)]);
         serde_test::assert_tokens(&ComponentType::SelectMenu, &[Token::U8(3)]);
+        serde_test::assert_tokens(&ComponentType::TextInput, &[Token::U8(4)]);
     }
 
     #[test]
@@ -96,5 +104,6 @@ mod tests {
         assert_eq!("ActionRow", ComponentType::ActionRow.name());
         assert_eq!("Button", ComponentType::Button.name());
         assert_eq!("SelectMenu", ComponentType::SelectMenu.name());
+        assert_eq!("TextInput", ComponentType::TextInput.name());
     }
 }
@@ -11,11 +11,13 @@
 pub mod action_row;
 pub mod button;
 pub mod select_menu;
+pub mod text_input;
 
 mod kind;
 
 pub use self::{
     action_row::ActionRow, button::Button, kind::ComponentType, select_menu::SelectMenu,
+    text_input::TextInput,
 };
 
 use serde::{Deserialize, Serialize};
@@ -29,6 +31,9 @@ use serde::{Deserialize, Serialize};
 #[serde(untagged)]
 pub enum Component {
     ActionRow(ActionRow),
+    // Text inputs must be attempted before buttons, which would otherwise
+    // accept them.
+    TextInput(TextInput),
     Button(Button),
     SelectMenu(SelectMenu),
 }
@@ -59,6 +64,7 @@ impl Component {
             Self::ActionRow(_) => ComponentType::ActionRow,
             Self::Button(_) => ComponentType::Button,
             Self::SelectMenu(_) => ComponentType::SelectMenu,
+            Self::TextInput(_) => ComponentType::TextInput,
         }
     }
 }
@@ -0,0 +1,251 @@
+use super::ComponentType;
+use serde::{
+    de::{Deserializer, Error as DeError, Unexpected},
+    ser::{SerializeStruct, Serializer},
+    Deserialize, Serialize,
+};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Text field that users fill in within a modal.
+///
+/// Text inputs can only be used in modals sent as an interaction response and
+/// must be placed within an [`ActionRow`].
+///
+/// Refer to [Discord Docs/Message Components] for additional information.
+///
+/// [`ActionRow`]: super::ActionRow
+/// [Discord Docs/Message Components]: https://discord.com/developers/docs/interactions/message-components#text-inputs
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TextInput {
+    /// Developer defined identifier.
+    pub custom_id: String,
+    /// Text appearing over the input field.
+    pub label: String,
+    /// Maximum length of the text.
+    pub max_length: Option<u16>,
+    /// Minimum length of the text.
+    ///
+    /// Defaults to `0`.
+    pub min_length: Option<u16>,
+    /// Placeholder for the text input if it is empty.
+    pub placeholder: Option<String>,
+    /// Whether the user is required to input a text.
+    ///
+    /// Defaults to `true`.
+    pub required: Option<bool>,
+    /// Style variant of the input text.
+    pub style: TextInputStyle,
+    /// Pre-filled value for the input text.
+    pub value: Option<String>,
+}
+
+/// Style of a [`TextInput`].
+///
+/// Refer to [the discord docs] for additional information.
+///
+/// [the discord docs]: https://discord.com/developers/docs/interactions/message-components#text-inputs-text-input-styles
+#[derive(Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, PartialOrd, Serialize_repr)]
+#[repr(u8)]
+pub enum TextInputStyle {
+    /// Intended for short single-line text.
+    Short = 1,
+    /// Intended for much longer inputs.
+    Paragraph = 2,
+}
+
+/// Text input as received, including its type.
+///
+/// Text inputs share most of their fields with [`Button`]s, so the type is
+/// checked to tell them apart when deserializing a [`Component`].
+///
+/// [`Button`]: super::Button
+/// [`Component`]: super::Component
+#[derive(Deserialize)]
+#[serde(rename = "TextInput")]
+struct TextInputEnvelope {
+    custom_id: String,
+    label: String,
+    max_length: Option<u16>,
+    min_length: Option<u16>,
+    placeholder: Option<String>,
+    required: Option<bool>,
+    style: TextInputStyle,
+    #[serde(rename = "type")]
+    kind: ComponentType,
+    value: Option<String>,
+}
+
+impl<'de> Deserialize<'de> for TextInput {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let envelope = TextInputEnvelope::deserialize(deserializer)?;
+
+        if envelope.kind != ComponentType::TextInput {
+            return Err(DeError::invalid_value(
+                Unexpected::Unsigned(envelope.kind as u64),
+                &"text input component type",
+            ));
+        }
+
+        Ok(Self {
+            custom_id: envelope.custom_id,
+            label: envelope.label,
+            max_length: envelope.max_length,
+            min_length: envelope.min_length,
+            placeholder: envelope.placeholder,
+            required: envelope.required,
+            style: envelope.style,
+            value: envelope.value,
+        })
+    }
+}
+
+impl Serialize for TextInput {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Base of 4 to account for the fields that are always present:
+        //
+        // - `custom_id`
+        // - `label`
+        // - `style`
+        // - `type`
+        let field_count = 4
+            + usize::from(self.max_length.is_some())
+            + usize::from(self.min_length.is_some())
+            + usize::from(self.placeholder.is_some())
+            + usize::from(self.required.is_some())
+            + usize::from(self.value.is_some());
+        let mut state = serializer.serialize_struct("TextInput", field_count)?;
+
+        state.serialize_field("custom_id", &self.custom_id)?;
+        state.serialize_field("label", &self.label)?;
+
+        if self.max_length.is_some() {
+            state.serialize_field("max_length", &self.max_length)?;
+        }
+
+        if self.min_length.is_some() {
+            state.serialize_field("min_length", &self.min_length)?;
+        }
+
+        if self.placeholder.is_some() {
+            state.serialize_field("placeholder", &self.placeholder)?;
+        }
+
+        if self.required.is_some() {
+            state.serialize_field("required", &self.required)?;
+        }
+
+        state.serialize_field("style", &self.style)?;
+        state.serialize_field("type", &ComponentType::TextInput)?;
+
+        if self.value.is_some() {
+            state.serialize_field("value", &self.value)?;
+        }
+
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TextInput, TextInputStyle};
+    use crate::application::component::{Component, ComponentType};
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::{assert_fields, assert_impl_all, const_assert_eq};
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_fields!(
+        TextInput: custom_id,
+        label,
+        max_length,
+        min_length,
+        placeholder,
+        required,
+        style,
+        value
+    );
+    assert_impl_all!(
+        TextInput: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+    assert_impl_all!(
+        TextInputStyle: Clone,
+        Copy,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+    const_assert_eq!(1, TextInputStyle::Short as u8);
+    const_assert_eq!(2, TextInputStyle::Paragraph as u8);
+
+    #[test]
+    fn test_text_input() {
+        let value = TextInput {
+            custom_id: "reason".to_owned(),
+            label: "Reason".to_owned(),
+            max_length: Some(100),
+            min_length: None,
+            placeholder: Some("Why?".to_owned()),
+            required: Some(false),
+            style: TextInputStyle::Paragraph,
+            value: None,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "TextInput",
+                    len: 7,
+                },
+                Token::Str("custom_id"),
+                Token::Str("reason"),
+                Token::Str("label"),
+                Token::Str("Reason"),
+                Token::Str("max_length"),
+                Token::Some,
+                Token::U16(100),
+                Token::Str("placeholder"),
+                Token::Some,
+                Token::Str("Why?"),
+                Token::Str("required"),
+                Token::Some,
+                Token::Bool(false),
+                Token::Str("style"),
+                Token::U8(TextInputStyle::Paragraph as u8),
+                Token::Str("type"),
+                Token::U8(ComponentType::TextInput as u8),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    /// Test that text inputs and buttons, which share most of their fields,
+    /// are told apart by their type.
+    #[test]
+    fn test_component_kind() -> Result<(), serde_json::Error> {
+        let text_input = serde_json::from_str::<Component>(
+            r#"{"custom_id":"a","label":"b","style":1,"type":4}"#,
+        )?;
+        assert_eq!(ComponentType::TextInput, text_input.kind());
+
+        let button = serde_json::from_str::<Component>(
+            r#"{"custom_id":"a","label":"b","style":1,"type":2}"#,
+        )?;
+        assert_eq!(ComponentType::Button, button.kind());
+
+        Ok(())
+    }
+}
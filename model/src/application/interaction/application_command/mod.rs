@@ -7,6 +7,7 @@ pub use self::data::{
 
 use super::InteractionType;
 use crate::{
+    application::Locale,
     guild::PartialMember,
     id::{ApplicationId, ChannelId, GuildId, InteractionId},
     user::User,
@@ -28,11 +29,19 @@ pub struct ApplicationCommand {
     pub data: CommandData,
     /// ID of the guild the interaction was triggered from.
     pub guild_id: Option<GuildId>,
+    /// Preferred locale of the guild the interaction was triggered from.
+    ///
+    /// Present when the interaction is triggered from a guild.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_locale: Option<Locale>,
     /// ID of the interaction.
     pub id: InteractionId,
     /// Kind of the interaction.
     #[serde(rename = "type")]
     pub kind: InteractionType,
+    /// Selected language of the user that triggered the interaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<Locale>,
     /// Member that triggered the interaction.
     ///
     /// Present when the command is used in a guild.
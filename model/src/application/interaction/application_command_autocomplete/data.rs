@@ -0,0 +1,224 @@
+use crate::{application::command::CommandOptionType, id::CommandId};
+use serde::{
+    de::{Deserializer, Error as DeError, Visitor},
+    Deserialize, Serialize,
+};
+use std::fmt::{Formatter, Result as FmtResult};
+
+/// Data received when an [`ApplicationCommandAutocomplete`] interaction is
+/// executed.
+///
+/// Refer to [the discord docs] for more information.
+///
+/// [`ApplicationCommandAutocomplete`]: crate::application::interaction::Interaction::ApplicationCommandAutocomplete
+/// [the discord docs]: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-application-command-data-structure
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct AutocompleteData {
+    /// ID of the command.
+    pub id: CommandId,
+    /// Name of the command.
+    pub name: String,
+    /// List of options the user has filled in so far.
+    #[serde(default)]
+    pub options: Vec<AutocompleteDataOption>,
+}
+
+impl AutocompleteData {
+    /// Option the user is currently filling in, searching within
+    /// subcommands.
+    pub fn focused(&self) -> Option<&AutocompleteDataOption> {
+        find_focused(&self.options)
+    }
+}
+
+/// Option the user has filled in, or is filling in, while autocompleting.
+///
+/// Values are partial and unvalidated, so they are provided as they were
+/// typed regardless of the type of the option.
+///
+/// Refer to [the discord docs] for more information.
+///
+/// [the discord docs]: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-application-command-interaction-data-option-structure
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct AutocompleteDataOption {
+    /// Whether this is the option the user is currently filling in.
+    #[serde(default)]
+    pub focused: bool,
+    /// Type of the option.
+    #[serde(rename = "type")]
+    pub kind: CommandOptionType,
+    /// Name of the option.
+    pub name: String,
+    /// Options of the subcommand or subcommand group.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<AutocompleteDataOption>,
+    /// Value the user has input so far.
+    ///
+    /// Present unless the option is a subcommand or subcommand group.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_value",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub value: Option<String>,
+}
+
+fn find_focused(options: &[AutocompleteDataOption]) -> Option<&AutocompleteDataOption> {
+    options.iter().find_map(|option| {
+        if option.focused {
+            Some(option)
+        } else {
+            find_focused(&option.options)
+        }
+    })
+}
+
+/// Deserialize an option value of any type into its textual form.
+fn deserialize_value<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<String>, D::Error> {
+    deserializer.deserialize_option(OptionalValueVisitor)
+}
+
+struct OptionalValueVisitor;
+
+impl<'de> Visitor<'de> for OptionalValueVisitor {
+    type Value = Option<String>;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("an optional boolean, number or string")
+    }
+
+    fn visit_none<E: DeError>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E: DeError>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_any(ValueVisitor).map(Some)
+    }
+}
+
+struct ValueVisitor;
+
+impl Visitor<'_> for ValueVisitor {
+    type Value = String;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("a boolean, number or string")
+    }
+
+    fn visit_bool<E: DeError>(self, v: bool) -> Result<Self::Value, E> {
+        Ok(v.to_string())
+    }
+
+    fn visit_f64<E: DeError>(self, v: f64) -> Result<Self::Value, E> {
+        Ok(v.to_string())
+    }
+
+    fn visit_i64<E: DeError>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(v.to_string())
+    }
+
+    fn visit_u64<E: DeError>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(v.to_string())
+    }
+
+    fn visit_str<E: DeError>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(v.to_owned())
+    }
+
+    fn visit_string<E: DeError>(self, v: String) -> Result<Self::Value, E> {
+        Ok(v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AutocompleteData, AutocompleteDataOption};
+    use crate::{application::command::CommandOptionType, id::CommandId};
+    use serde::{Deserialize, Serialize};
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_fields!(AutocompleteData: id, name, options);
+    assert_fields!(AutocompleteDataOption: focused, kind, name, options, value);
+    assert_impl_all!(
+        AutocompleteData: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+    assert_impl_all!(
+        AutocompleteDataOption: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[test]
+    fn test_autocomplete_data() -> Result<(), serde_json::Error> {
+        let value = serde_json::from_str::<AutocompleteData>(
+            r#"{
+                "id": "1",
+                "name": "tag",
+                "options": [{
+                    "name": "get",
+                    "type": 1,
+                    "options": [
+                        {"name": "limit", "type": 4, "value": 5},
+                        {"focused": true, "name": "name", "type": 3, "value": "twi"}
+                    ]
+                }]
+            }"#,
+        )?;
+
+        let expected = AutocompleteData {
+            id: CommandId(1),
+            name: "tag".to_owned(),
+            options: vec![AutocompleteDataOption {
+                focused: false,
+                kind: CommandOptionType::SubCommand,
+                name: "get".to_owned(),
+                options: vec![
+                    AutocompleteDataOption {
+                        focused: false,
+                        kind: CommandOptionType::Integer,
+                        name: "limit".to_owned(),
+                        options: Vec::new(),
+                        value: Some("5".to_owned()),
+                    },
+                    AutocompleteDataOption {
+                        focused: true,
+                        kind: CommandOptionType::String,
+                        name: "name".to_owned(),
+                        options: Vec::new(),
+                        value: Some("twi".to_owned()),
+                    },
+                ],
+                value: None,
+            }],
+        };
+
+        assert_eq!(expected, value);
+        assert_eq!(
+            Some("twi"),
+            value.focused().and_then(|option| option.value.as_deref())
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+mod data;
+
+pub use self::data::{AutocompleteData, AutocompleteDataOption};
+
+use super::InteractionType;
+use crate::{
+    application::Locale,
+    guild::PartialMember,
+    id::{ApplicationId, ChannelId, GuildId, InteractionId},
+    user::User,
+};
+use serde::Serialize;
+
+/// Data present in an [`Interaction`] of type
+/// [`ApplicationCommandAutocomplete`].
+///
+/// Respond with an [`InteractionResponse::ApplicationCommandAutocompleteResult`]
+/// to suggest choices for the focused option.
+///
+/// [`Interaction`]: super::Interaction
+/// [`ApplicationCommandAutocomplete`]: super::Interaction::ApplicationCommandAutocomplete
+/// [`InteractionResponse::ApplicationCommandAutocompleteResult`]: crate::application::callback::InteractionResponse::ApplicationCommandAutocompleteResult
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename(serialize = "Interaction"))]
+pub struct ApplicationCommandAutocomplete {
+    /// ID of the associated application.
+    pub application_id: ApplicationId,
+    /// The channel the interaction was triggered from.
+    pub channel_id: ChannelId,
+    /// Data of the command being filled in.
+    pub data: AutocompleteData,
+    /// ID of the guild the interaction was triggered from.
+    pub guild_id: Option<GuildId>,
+    /// Preferred locale of the guild the interaction was triggered from.
+    ///
+    /// Present when the interaction is triggered from a guild.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_locale: Option<Locale>,
+    /// ID of the interaction.
+    pub id: InteractionId,
+    /// Kind of the interaction.
+    #[serde(rename = "type")]
+    pub kind: InteractionType,
+    /// Selected language of the user that triggered the interaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<Locale>,
+    /// Member that triggered the interaction.
+    ///
+    /// Present when the command is used in a guild.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member: Option<PartialMember>,
+    /// Token of the interaction.
+    pub token: String,
+    /// User that triggered the interaction.
+    ///
+    /// Present when the command is used in a direct message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ApplicationCommandAutocomplete;
+    use serde::Serialize;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::fmt::Debug;
+
+    assert_fields!(
+        ApplicationCommandAutocomplete: application_id,
+        channel_id,
+        data,
+        guild_id,
+        guild_locale,
+        id,
+        kind,
+        locale,
+        member,
+        token,
+        user
+    );
+    assert_impl_all!(
+        ApplicationCommandAutocomplete: Clone,
+        Debug,
+        Eq,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+}
@@ -19,6 +19,11 @@ pub enum InteractionType {
     ///
     /// [`Component`]: super::super::component::Component
     MessageComponent = 3,
+    /// Interaction requests the choices of an option with autocomplete
+    /// enabled while the user fills it in.
+    ApplicationCommandAutocomplete = 4,
+    /// Interaction submits the text inputs of a modal.
+    ModalSubmit = 5,
 }
 
 impl InteractionType {
@@ -27,6 +32,8 @@ impl InteractionType {
             Self::Ping => "Ping",
             Self::ApplicationCommand => "ApplicationCommand",
             Self::MessageComponent => "MessageComponent",
+            Self::ApplicationCommandAutocomplete => "ApplicationCommandAutocomplete",
+            Self::ModalSubmit => "ModalSubmit",
         }
     }
 }
@@ -52,6 +59,8 @@ impl TryFrom<u8> for InteractionType {
             1 => Ok(Self::Ping),
             2 => Ok(Self::ApplicationCommand),
             3 => Ok(Self::MessageComponent),
+            4 => Ok(Self::ApplicationCommandAutocomplete),
+            5 => Ok(Self::ModalSubmit),
             other => Err(UnknownInteractionTypeError { value: other }),
         }
     }
@@ -81,6 +90,8 @@ mod tests {
     const_assert_eq!(1, InteractionType::Ping as u8);
     const_assert_eq!(2, InteractionType::ApplicationCommand as u8);
     const_assert_eq!(3, InteractionType::MessageComponent as u8);
+    const_assert_eq!(4, InteractionType::ApplicationCommandAutocomplete as u8);
+    const_assert_eq!(5, InteractionType::ModalSubmit as u8);
 
     #[test]
     fn test_kind() {
@@ -90,6 +101,11 @@ mod tests {
             InteractionType::ApplicationCommand.kind()
         );
         assert_eq!("MessageComponent", InteractionType::MessageComponent.kind());
+        assert_eq!(
+            "ApplicationCommandAutocomplete",
+            InteractionType::ApplicationCommandAutocomplete.kind()
+        );
+        assert_eq!("ModalSubmit", InteractionType::ModalSubmit.kind());
     }
 
     #[test]
@@ -103,6 +119,11 @@ mod tests {
             InteractionType::MessageComponent,
             InteractionType::try_from(3)?
         );
+        assert_eq!(
+            InteractionType::ApplicationCommandAutocomplete,
+            InteractionType::try_from(4)?
+        );
+        assert_eq!(InteractionType::ModalSubmit, InteractionType::try_from(5)?);
         assert!(InteractionType::try_from(u8::MAX).is_err());
 
         Ok(())
@@ -4,6 +4,7 @@ pub use self::data::MessageComponentInteractionData;
 
 use super::InteractionType;
 use crate::{
+    application::Locale,
     channel::Message,
     guild::PartialMember,
     id::{ApplicationId, ChannelId, GuildId, InteractionId, UserId},
@@ -25,11 +26,19 @@ pub struct MessageComponentInteraction {
     pub data: MessageComponentInteractionData,
     /// ID of the guild the interaction was triggered from.
     pub guild_id: Option<GuildId>,
+    /// Preferred locale of the guild the interaction was triggered from.
+    ///
+    /// Present when the interaction is triggered from a guild.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_locale: Option<Locale>,
     /// ID of the interaction.
     pub id: InteractionId,
     /// Type of the interaction.
     #[serde(rename = "type")]
     pub kind: InteractionType,
+    /// Selected language of the user that triggered the interaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<Locale>,
     /// Member that triggered the interaction.
     ///
     /// Present when the command is used in a guild.
@@ -92,8 +101,10 @@ mod tests {
         channel_id,
         data,
         guild_id,
+        guild_locale,
         id,
         kind,
+        locale,
         member,
         message,
         token,
@@ -143,8 +154,10 @@ mod tests {
                 values: Vec::from(["bar".to_owned()]),
             },
             guild_id: Some(GuildId(3)),
+            guild_locale: None,
             id: InteractionId(4),
             kind: InteractionType::MessageComponent,
+            locale: None,
             member: Some(PartialMember {
                 deaf: false,
                 joined_at: None,
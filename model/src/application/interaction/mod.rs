@@ -1,17 +1,22 @@
 //! Used when receiving interactions through gateway or webhooks.
 
 pub mod application_command;
+pub mod application_command_autocomplete;
 pub mod message_component;
+pub mod modal;
 
 mod interaction_type;
 mod ping;
 
 pub use self::{
-    application_command::ApplicationCommand, interaction_type::InteractionType,
-    message_component::MessageComponentInteraction, ping::Ping,
+    application_command::ApplicationCommand,
+    application_command_autocomplete::ApplicationCommandAutocomplete,
+    interaction_type::InteractionType, message_component::MessageComponentInteraction,
+    modal::ModalSubmitInteraction, ping::Ping,
 };
 
 use crate::{
+    application::Locale,
     channel::Message,
     guild::PartialMember,
     id::{ApplicationId, ChannelId, GuildId, InteractionId},
@@ -40,6 +45,10 @@ pub enum Interaction {
     ApplicationCommand(Box<ApplicationCommand>),
     /// Message component variant.
     MessageComponent(Box<MessageComponentInteraction>),
+    /// Application command autocomplete variant.
+    ApplicationCommandAutocomplete(Box<ApplicationCommandAutocomplete>),
+    /// Modal submit variant.
+    ModalSubmit(Box<ModalSubmitInteraction>),
 }
 
 impl Interaction {
@@ -48,6 +57,8 @@ impl Interaction {
             Self::Ping(_) => None,
             Self::ApplicationCommand(inner) => inner.guild_id,
             Self::MessageComponent(inner) => inner.guild_id,
+            Self::ApplicationCommandAutocomplete(inner) => inner.guild_id,
+            Self::ModalSubmit(inner) => inner.guild_id,
         }
     }
 }
@@ -65,7 +76,9 @@ enum InteractionField {
     ChannelId,
     Data,
     GuildId,
+    GuildLocale,
     Id,
+    Locale,
     Member,
     Message,
     Token,
@@ -88,7 +101,9 @@ impl<'de> Visitor<'de> for InteractionVisitor {
         let mut channel_id: Option<ChannelId> = None;
         let mut data: Option<Value> = None;
         let mut guild_id: Option<Option<GuildId>> = None;
+        let mut guild_locale: Option<Option<Locale>> = None;
         let mut id: Option<InteractionId> = None;
+        let mut locale: Option<Option<Locale>> = None;
         let mut member: Option<Option<PartialMember>> = None;
         let mut message: Option<Message> = None;
         let mut token: Option<String> = None;
@@ -148,6 +163,13 @@ impl<'de> Visitor<'de> for InteractionVisitor {
 
                     guild_id = Some(map.next_value()?);
                 }
+                InteractionField::GuildLocale => {
+                    if guild_locale.is_some() {
+                        return Err(DeError::duplicate_field("guild_locale"));
+                    }
+
+                    guild_locale = Some(map.next_value()?);
+                }
                 InteractionField::Id => {
                     if id.is_some() {
                         return Err(DeError::duplicate_field("id"));
@@ -155,6 +177,13 @@ impl<'de> Visitor<'de> for InteractionVisitor {
 
                     id = Some(map.next_value()?);
                 }
+                InteractionField::Locale => {
+                    if locale.is_some() {
+                        return Err(DeError::duplicate_field("locale"));
+                    }
+
+                    locale = Some(map.next_value()?);
+                }
                 InteractionField::Member => {
                     if member.is_some() {
                         return Err(DeError::duplicate_field("member"));
@@ -226,6 +255,8 @@ impl<'de> Visitor<'de> for InteractionVisitor {
                     .map_err(|_| DeError::custom("expected CommandData struct"))?;
 
                 let guild_id = guild_id.unwrap_or_default();
+                let guild_locale = guild_locale.unwrap_or_default();
+                let locale = locale.unwrap_or_default();
                 let member = member.unwrap_or_default();
                 let user = user.unwrap_or_default();
 
@@ -236,8 +267,10 @@ impl<'de> Visitor<'de> for InteractionVisitor {
                     channel_id,
                     data,
                     guild_id,
+                    guild_locale,
                     id,
                     kind,
+                    locale,
                     member,
                     token,
                     user,
@@ -254,6 +287,8 @@ impl<'de> Visitor<'de> for InteractionVisitor {
                 let message = message.ok_or_else(|| DeError::missing_field("message"))?;
 
                 let guild_id = guild_id.unwrap_or_default();
+                let guild_locale = guild_locale.unwrap_or_default();
+                let locale = locale.unwrap_or_default();
                 let member = member.unwrap_or_default();
                 let user = user.unwrap_or_default();
 
@@ -262,8 +297,71 @@ impl<'de> Visitor<'de> for InteractionVisitor {
                     channel_id,
                     data,
                     guild_id,
+                    guild_locale,
+                    id,
+                    kind,
+                    locale,
+                    member,
+                    message,
+                    token,
+                    user,
+                }))
+            }
+            InteractionType::ApplicationCommandAutocomplete => {
+                let channel_id = channel_id.ok_or_else(|| DeError::missing_field("channel_id"))?;
+                let data = data
+                    .ok_or_else(|| DeError::missing_field("data"))?
+                    .deserialize_into()
+                    .map_err(|_| DeError::custom("expected AutocompleteData struct"))?;
+
+                let guild_id = guild_id.unwrap_or_default();
+                let guild_locale = guild_locale.unwrap_or_default();
+                let locale = locale.unwrap_or_default();
+                let member = member.unwrap_or_default();
+                let user = user.unwrap_or_default();
+
+                tracing::trace!(%channel_id, "handling application command autocomplete");
+
+                Self::Value::ApplicationCommandAutocomplete(Box::new(
+                    ApplicationCommandAutocomplete {
+                        application_id,
+                        channel_id,
+                        data,
+                        guild_id,
+                        guild_locale,
+                        id,
+                        kind,
+                        locale,
+                        member,
+                        token,
+                        user,
+                    },
+                ))
+            }
+            InteractionType::ModalSubmit => {
+                let channel_id = channel_id.ok_or_else(|| DeError::missing_field("channel_id"))?;
+                let data = data
+                    .ok_or_else(|| DeError::missing_field("data"))?
+                    .deserialize_into()
+                    .map_err(|_| DeError::custom("expected ModalInteractionData struct"))?;
+
+                let guild_id = guild_id.unwrap_or_default();
+                let guild_locale = guild_locale.unwrap_or_default();
+                let locale = locale.unwrap_or_default();
+                let member = member.unwrap_or_default();
+                let user = user.unwrap_or_default();
+
+                tracing::trace!(%channel_id, "handling modal submit");
+
+                Self::Value::ModalSubmit(Box::new(ModalSubmitInteraction {
+                    application_id,
+                    channel_id,
+                    data,
+                    guild_id,
+                    guild_locale,
                     id,
                     kind,
+                    locale,
                     member,
                     message,
                     token,
@@ -281,8 +379,12 @@ mod test {
             application_command::{
                 ApplicationCommand, CommandData, CommandDataOption, CommandInteractionDataResolved,
             },
-            Interaction, InteractionType,
+            modal::{
+                ModalInteractionData, ModalInteractionDataActionRow, ModalInteractionDataComponent,
+            },
+            Interaction, InteractionType, ModalSubmitInteraction,
         },
+        application::{component::ComponentType, Locale},
         guild::{PartialMember, Permissions},
         id::{ApplicationId, ChannelId, CommandId, GuildId, InteractionId, UserId},
         user::User,
@@ -327,8 +429,10 @@ mod test {
                 }),
             },
             guild_id: Some(GuildId(400)),
+            guild_locale: Some(Locale::German),
             id: InteractionId(500),
             kind: InteractionType::ApplicationCommand,
+            locale: Some(Locale::EnglishUk),
             member: Some(PartialMember {
                 deaf: false,
                 joined_at: Some("joined at".into()),
@@ -364,7 +468,7 @@ mod test {
             &[
                 Token::Struct {
                     name: "Interaction",
-                    len: 8,
+                    len: 10,
                 },
                 Token::Str("application_id"),
                 Token::NewtypeStruct {
@@ -434,6 +538,9 @@ mod test {
                 Token::Some,
                 Token::NewtypeStruct { name: "GuildId" },
                 Token::Str("400"),
+                Token::Str("guild_locale"),
+                Token::Some,
+                Token::Str("de"),
                 Token::Str("id"),
                 Token::NewtypeStruct {
                     name: "InteractionId",
@@ -441,6 +548,9 @@ mod test {
                 Token::Str("500"),
                 Token::Str("type"),
                 Token::U8(2),
+                Token::Str("locale"),
+                Token::Some,
+                Token::Str("en-GB"),
                 Token::Str("member"),
                 Token::Some,
                 Token::Struct {
@@ -493,4 +603,93 @@ mod test {
             ],
         );
     }
+
+    #[test]
+    fn test_interaction_modal_submit() -> Result<(), serde_json::Error> {
+        let value = serde_json::from_str::<Interaction>(
+            r#"{
+                "application_id": "1",
+                "channel_id": "2",
+                "data": {
+                    "custom_id": "ban",
+                    "components": [{
+                        "type": 1,
+                        "components": [{"custom_id": "reason", "type": 4, "value": "spam"}]
+                    }]
+                },
+                "id": "3",
+                "locale": "fr",
+                "token": "token",
+                "type": 5,
+                "user": {"avatar": null, "discriminator": "0001", "id": "4", "username": "user"}
+            }"#,
+        )?;
+
+        let interaction = match value {
+            Interaction::ModalSubmit(interaction) => interaction,
+            other => panic!("expected modal submit, got {:?}", other),
+        };
+
+        assert_eq!(
+            ModalSubmitInteraction {
+                application_id: ApplicationId(1),
+                channel_id: ChannelId(2),
+                data: ModalInteractionData {
+                    components: Vec::from([ModalInteractionDataActionRow {
+                        components: Vec::from([ModalInteractionDataComponent {
+                            custom_id: "reason".into(),
+                            kind: ComponentType::TextInput,
+                            value: "spam".into(),
+                        }]),
+                    }]),
+                    custom_id: "ban".into(),
+                },
+                guild_id: None,
+                guild_locale: None,
+                id: InteractionId(3),
+                kind: InteractionType::ModalSubmit,
+                locale: Some(Locale::French),
+                member: None,
+                message: None,
+                token: "token".into(),
+                user: interaction.user.clone(),
+            },
+            *interaction
+        );
+        assert!(interaction.user.is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_interaction_autocomplete() -> Result<(), serde_json::Error> {
+        let value = serde_json::from_str::<Interaction>(
+            r#"{
+                "application_id": "1",
+                "channel_id": "2",
+                "data": {
+                    "id": "3",
+                    "name": "tag",
+                    "options": [{"focused": true, "name": "name", "type": 3, "value": "tw"}]
+                },
+                "guild_id": "4",
+                "id": "5",
+                "token": "token",
+                "type": 4
+            }"#,
+        )?;
+
+        assert_eq!(Some(GuildId(4)), value.guild_id());
+
+        match value {
+            Interaction::ApplicationCommandAutocomplete(interaction) => {
+                let focused = interaction.data.focused().expect("focused option");
+                assert_eq!("name", focused.name);
+                assert_eq!(Some("tw"), focused.value.as_deref());
+            }
+            other => panic!("expected autocomplete, got {:?}", other),
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,125 @@
+use crate::application::component::ComponentType;
+use serde::{Deserialize, Serialize};
+
+/// Data received when a [`ModalSubmit`] interaction is executed.
+///
+/// Refer to [the discord docs] for more information.
+///
+/// [`ModalSubmit`]: crate::application::interaction::Interaction::ModalSubmit
+/// [the discord docs]: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-modal-submit-data-structure
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ModalInteractionData {
+    /// Action rows of the modal, holding the submitted values.
+    pub components: Vec<ModalInteractionDataActionRow>,
+    /// Developer defined identifier of the modal.
+    pub custom_id: String,
+}
+
+impl ModalInteractionData {
+    /// Value submitted for the text input with a custom ID.
+    pub fn value(&self, custom_id: &str) -> Option<&str> {
+        self.components
+            .iter()
+            .flat_map(|row| row.components.iter())
+            .find(|component| component.custom_id == custom_id)
+            .map(|component| component.value.as_str())
+    }
+}
+
+/// Action row of a submitted modal.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ModalInteractionDataActionRow {
+    /// Components within the action row.
+    pub components: Vec<ModalInteractionDataComponent>,
+}
+
+/// Component of a submitted modal, such as a text input.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ModalInteractionDataComponent {
+    /// Developer defined identifier of the component.
+    pub custom_id: String,
+    /// Type of the component.
+    #[serde(rename = "type")]
+    pub kind: ComponentType,
+    /// Value submitted by the user.
+    pub value: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ModalInteractionData, ModalInteractionDataActionRow, ModalInteractionDataComponent,
+    };
+    use crate::application::component::ComponentType;
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_fields!(ModalInteractionData: components, custom_id);
+    assert_fields!(ModalInteractionDataActionRow: components);
+    assert_fields!(ModalInteractionDataComponent: custom_id, kind, value);
+    assert_impl_all!(
+        ModalInteractionData: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[test]
+    fn test_modal_data() {
+        let value = ModalInteractionData {
+            components: Vec::from([ModalInteractionDataActionRow {
+                components: Vec::from([ModalInteractionDataComponent {
+                    custom_id: "reason".to_owned(),
+                    kind: ComponentType::TextInput,
+                    value: "spam".to_owned(),
+                }]),
+            }]),
+            custom_id: "ban".to_owned(),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "ModalInteractionData",
+                    len: 2,
+                },
+                Token::Str("components"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "ModalInteractionDataActionRow",
+                    len: 1,
+                },
+                Token::Str("components"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "ModalInteractionDataComponent",
+                    len: 3,
+                },
+                Token::Str("custom_id"),
+                Token::Str("reason"),
+                Token::Str("type"),
+                Token::U8(ComponentType::TextInput as u8),
+                Token::Str("value"),
+                Token::Str("spam"),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::Str("custom_id"),
+                Token::Str("ban"),
+                Token::StructEnd,
+            ],
+        );
+
+        assert_eq!(Some("spam"), value.value("reason"));
+        assert_eq!(None, value.value("duration"));
+    }
+}
@@ -0,0 +1,95 @@
+mod data;
+
+pub use self::data::{
+    ModalInteractionData, ModalInteractionDataActionRow, ModalInteractionDataComponent,
+};
+
+use super::InteractionType;
+use crate::{
+    application::Locale,
+    channel::Message,
+    guild::PartialMember,
+    id::{ApplicationId, ChannelId, GuildId, InteractionId},
+    user::User,
+};
+use serde::Serialize;
+
+/// Information present in an [`Interaction::ModalSubmit`].
+///
+/// [`Interaction::ModalSubmit`]: super::Interaction::ModalSubmit
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename(serialize = "Interaction"))]
+pub struct ModalSubmitInteraction {
+    /// ID of the associated application.
+    pub application_id: ApplicationId,
+    /// ID of the channel the interaction was triggered from.
+    pub channel_id: ChannelId,
+    /// Values submitted in the modal.
+    pub data: ModalInteractionData,
+    /// ID of the guild the interaction was triggered from.
+    pub guild_id: Option<GuildId>,
+    /// Preferred locale of the guild the interaction was triggered from.
+    ///
+    /// Present when the interaction is triggered from a guild.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_locale: Option<Locale>,
+    /// ID of the interaction.
+    pub id: InteractionId,
+    /// Type of the interaction.
+    #[serde(rename = "type")]
+    pub kind: InteractionType,
+    /// Selected language of the user that triggered the interaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<Locale>,
+    /// Member that triggered the interaction.
+    ///
+    /// Present when the modal is submitted in a guild.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member: Option<PartialMember>,
+    /// Message the modal was opened from.
+    ///
+    /// Present when the modal was sent in response to a message component
+    /// interaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<Message>,
+    /// Token of the interaction.
+    pub token: String,
+    /// User that triggered the interaction.
+    ///
+    /// Present when the modal is submitted in a direct message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ModalSubmitInteraction;
+    use serde::Serialize;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_fields!(
+        ModalSubmitInteraction: application_id,
+        channel_id,
+        data,
+        guild_id,
+        guild_locale,
+        id,
+        kind,
+        locale,
+        member,
+        message,
+        token,
+        user
+    );
+    assert_impl_all!(
+        ModalSubmitInteraction: Clone,
+        Debug,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+}
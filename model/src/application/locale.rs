@@ -0,0 +1,325 @@
+use serde::{
+    de::{Deserializer, Error as DeError, Unexpected, Visitor},
+    Deserialize, Serialize, Serializer,
+};
+use std::{
+    convert::TryFrom,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Language supported by the Discord client.
+///
+/// Used as the key of the localizations of commands, and to describe the
+/// language of users and guilds that interactions originate from.
+///
+/// Refer to [the discord docs] for more information.
+///
+/// [the discord docs]: https://discord.com/developers/docs/reference#locales
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum Locale {
+    /// Indonesian, `id`.
+    Indonesian,
+    /// Danish, `da`.
+    Danish,
+    /// German, `de`.
+    German,
+    /// English as used in the United Kingdom, `en-GB`.
+    EnglishUk,
+    /// English as used in the United States, `en-US`.
+    EnglishUs,
+    /// Spanish, `es-ES`.
+    Spanish,
+    /// French, `fr`.
+    French,
+    /// Croatian, `hr`.
+    Croatian,
+    /// Italian, `it`.
+    Italian,
+    /// Lithuanian, `lt`.
+    Lithuanian,
+    /// Hungarian, `hu`.
+    Hungarian,
+    /// Dutch, `nl`.
+    Dutch,
+    /// Norwegian, `no`.
+    Norwegian,
+    /// Polish, `pl`.
+    Polish,
+    /// Portuguese as used in Brazil, `pt-BR`.
+    PortugueseBrazilian,
+    /// Romanian, `ro`.
+    Romanian,
+    /// Finnish, `fi`.
+    Finnish,
+    /// Swedish, `sv-SE`.
+    Swedish,
+    /// Vietnamese, `vi`.
+    Vietnamese,
+    /// Turkish, `tr`.
+    Turkish,
+    /// Czech, `cs`.
+    Czech,
+    /// Greek, `el`.
+    Greek,
+    /// Bulgarian, `bg`.
+    Bulgarian,
+    /// Russian, `ru`.
+    Russian,
+    /// Ukrainian, `uk`.
+    Ukrainian,
+    /// Hindi, `hi`.
+    Hindi,
+    /// Thai, `th`.
+    Thai,
+    /// Chinese as used in China, `zh-CN`.
+    ChineseChina,
+    /// Japanese, `ja`.
+    Japanese,
+    /// Chinese as used in Taiwan, `zh-TW`.
+    ChineseTaiwan,
+    /// Korean, `ko`.
+    Korean,
+}
+
+impl Locale {
+    /// Code of the locale, such as `en-US`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::application::Locale;
+    ///
+    /// assert_eq!("en-US", Locale::EnglishUs.code());
+    /// assert_eq!("fr", Locale::French.code());
+    /// ```
+    pub const fn code(self) -> &'static str {
+        match self {
+            Self::Indonesian => "id",
+            Self::Danish => "da",
+            Self::German => "de",
+            Self::EnglishUk => "en-GB",
+            Self::EnglishUs => "en-US",
+            Self::Spanish => "es-ES",
+            Self::French => "fr",
+            Self::Croatian => "hr",
+            Self::Italian => "it",
+            Self::Lithuanian => "lt",
+            Self::Hungarian => "hu",
+            Self::Dutch => "nl",
+            Self::Norwegian => "no",
+            Self::Polish => "pl",
+            Self::PortugueseBrazilian => "pt-BR",
+            Self::Romanian => "ro",
+            Self::Finnish => "fi",
+            Self::Swedish => "sv-SE",
+            Self::Vietnamese => "vi",
+            Self::Turkish => "tr",
+            Self::Czech => "cs",
+            Self::Greek => "el",
+            Self::Bulgarian => "bg",
+            Self::Russian => "ru",
+            Self::Ukrainian => "uk",
+            Self::Hindi => "hi",
+            Self::Thai => "th",
+            Self::ChineseChina => "zh-CN",
+            Self::Japanese => "ja",
+            Self::ChineseTaiwan => "zh-TW",
+            Self::Korean => "ko",
+        }
+    }
+}
+
+impl Display for Locale {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.code())
+    }
+}
+
+/// Provided locale code is not known.
+#[derive(Debug)]
+pub struct UnknownLocaleError {
+    code: String,
+}
+
+impl UnknownLocaleError {
+    /// Provided locale code.
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+}
+
+impl Display for UnknownLocaleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("unknown locale: ")?;
+
+        f.write_str(&self.code)
+    }
+}
+
+impl Error for UnknownLocaleError {}
+
+impl TryFrom<&str> for Locale {
+    type Error = UnknownLocaleError;
+
+    fn try_from(code: &str) -> Result<Self, Self::Error> {
+        Ok(match code {
+            "id" => Self::Indonesian,
+            "da" => Self::Danish,
+            "de" => Self::German,
+            "en-GB" => Self::EnglishUk,
+            "en-US" => Self::EnglishUs,
+            "es-ES" => Self::Spanish,
+            "fr" => Self::French,
+            "hr" => Self::Croatian,
+            "it" => Self::Italian,
+            "lt" => Self::Lithuanian,
+            "hu" => Self::Hungarian,
+            "nl" => Self::Dutch,
+            "no" => Self::Norwegian,
+            "pl" => Self::Polish,
+            "pt-BR" => Self::PortugueseBrazilian,
+            "ro" => Self::Romanian,
+            "fi" => Self::Finnish,
+            "sv-SE" => Self::Swedish,
+            "vi" => Self::Vietnamese,
+            "tr" => Self::Turkish,
+            "cs" => Self::Czech,
+            "el" => Self::Greek,
+            "bg" => Self::Bulgarian,
+            "ru" => Self::Russian,
+            "uk" => Self::Ukrainian,
+            "hi" => Self::Hindi,
+            "th" => Self::Thai,
+            "zh-CN" => Self::ChineseChina,
+            "ja" => Self::Japanese,
+            "zh-TW" => Self::ChineseTaiwan,
+            "ko" => Self::Korean,
+            other => {
+                return Err(UnknownLocaleError {
+                    code: other.to_owned(),
+                })
+            }
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for Locale {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(LocaleVisitor)
+    }
+}
+
+struct LocaleVisitor;
+
+impl Visitor<'_> for LocaleVisitor {
+    type Value = Locale;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("a locale code")
+    }
+
+    fn visit_str<E: DeError>(self, v: &str) -> Result<Self::Value, E> {
+        Locale::try_from(v).map_err(|_| E::invalid_value(Unexpected::Str(v), &self))
+    }
+}
+
+impl Serialize for Locale {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Locale, UnknownLocaleError};
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::assert_impl_all;
+    use std::{collections::BTreeMap, convert::TryFrom, error::Error, fmt::Debug, hash::Hash};
+
+    assert_impl_all!(
+        Locale: Clone,
+        Copy,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        Ord,
+        PartialEq,
+        PartialOrd,
+        Send,
+        Serialize,
+        Sync
+    );
+    assert_impl_all!(UnknownLocaleError: Error, Send, Sync);
+
+    const LOCALES: [Locale; 31] = [
+        Locale::Indonesian,
+        Locale::Danish,
+        Locale::German,
+        Locale::EnglishUk,
+        Locale::EnglishUs,
+        Locale::Spanish,
+        Locale::French,
+        Locale::Croatian,
+        Locale::Italian,
+        Locale::Lithuanian,
+        Locale::Hungarian,
+        Locale::Dutch,
+        Locale::Norwegian,
+        Locale::Polish,
+        Locale::PortugueseBrazilian,
+        Locale::Romanian,
+        Locale::Finnish,
+        Locale::Swedish,
+        Locale::Vietnamese,
+        Locale::Turkish,
+        Locale::Czech,
+        Locale::Greek,
+        Locale::Bulgarian,
+        Locale::Russian,
+        Locale::Ukrainian,
+        Locale::Hindi,
+        Locale::Thai,
+        Locale::ChineseChina,
+        Locale::Japanese,
+        Locale::ChineseTaiwan,
+        Locale::Korean,
+    ];
+
+    #[test]
+    fn test_codes() {
+        for locale in &LOCALES {
+            assert_eq!(
+                *locale,
+                Locale::try_from(locale.code()).expect("known code")
+            );
+            serde_test::assert_tokens(locale, &[Token::Str(locale.code())]);
+        }
+
+        assert_eq!(
+            "xx",
+            Locale::try_from("xx").expect_err("unknown code").code()
+        );
+    }
+
+    #[test]
+    fn test_map() -> Result<(), serde_json::Error> {
+        let map = serde_json::from_str::<BTreeMap<Locale, String>>(
+            r#"{"fr":"bonjour","en-GB":"hello"}"#,
+        )?;
+
+        assert_eq!(
+            Some("hello"),
+            map.get(&Locale::EnglishUk).map(String::as_str)
+        );
+        assert_eq!(
+            r#"{"en-GB":"hello","fr":"bonjour"}"#,
+            serde_json::to_string(&map)?
+        );
+
+        Ok(())
+    }
+}
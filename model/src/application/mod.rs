@@ -2,3 +2,7 @@ pub mod callback;
 pub mod command;
 pub mod component;
 pub mod interaction;
+
+mod locale;
+
+pub use self::locale::{Locale, UnknownLocaleError};
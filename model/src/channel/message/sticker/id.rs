@@ -1,6 +1,22 @@
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
+/// Unique ID denoting the banner image of a sticker pack.
+///
+/// # serde
+///
+/// Like all of the IDs in the primary [`crate::id`] crate, these
+/// IDs support deserializing from both integers and strings and serialize into
+/// strings.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct StickerBannerAssetId(#[serde(with = "crate::id::string")] pub u64);
+
+impl Display for StickerBannerAssetId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&self.0, f)
+    }
+}
+
 /// Unique ID denoting a sticker.
 ///
 /// # serde
@@ -33,13 +49,47 @@ impl Display for StickerPackId {
     }
 }
 
+/// Unique ID denoting the SKU of a sticker pack.
+///
+/// # serde
+///
+/// Like all of the IDs in the primary [`crate::id`] crate, these
+/// IDs support deserializing from both integers and strings and serialize into
+/// strings.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct StickerPackSkuId(#[serde(with = "crate::id::string")] pub u64);
+
+impl Display for StickerPackSkuId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&self.0, f)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{StickerId, StickerPackId};
+    use super::{StickerBannerAssetId, StickerId, StickerPackId, StickerPackSkuId};
     use serde_test::Token;
 
     #[test]
     fn test_id_deser() {
+        serde_test::assert_tokens(
+            &StickerBannerAssetId(114_941_315_417_899_012),
+            &[
+                Token::NewtypeStruct {
+                    name: "StickerBannerAssetId",
+                },
+                Token::Str("114941315417899012"),
+            ],
+        );
+        serde_test::assert_de_tokens(
+            &StickerBannerAssetId(114_941_315_417_899_012),
+            &[
+                Token::NewtypeStruct {
+                    name: "StickerBannerAssetId",
+                },
+                Token::U64(114_941_315_417_899_012),
+            ],
+        );
         serde_test::assert_tokens(
             &StickerId(114_941_315_417_899_012),
             &[
@@ -72,5 +122,23 @@ mod tests {
                 Token::U64(114_941_315_417_899_012),
             ],
         );
+        serde_test::assert_tokens(
+            &StickerPackSkuId(114_941_315_417_899_012),
+            &[
+                Token::NewtypeStruct {
+                    name: "StickerPackSkuId",
+                },
+                Token::Str("114941315417899012"),
+            ],
+        );
+        serde_test::assert_de_tokens(
+            &StickerPackSkuId(114_941_315_417_899_012),
+            &[
+                Token::NewtypeStruct {
+                    name: "StickerPackSkuId",
+                },
+                Token::U64(114_941_315_417_899_012),
+            ],
+        );
     }
 }
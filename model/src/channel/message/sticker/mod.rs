@@ -7,11 +7,13 @@
 mod id;
 mod kind;
 mod message;
+mod pack;
 
 pub use self::{
-    id::{StickerId, StickerPackId},
+    id::{StickerBannerAssetId, StickerId, StickerPackId, StickerPackSkuId},
     kind::{StickerFormatType, StickerFormatTypeConversionError},
     message::MessageSticker,
+    pack::StickerPack,
 };
 
 use crate::{id::GuildId, user::User, util::is_false};
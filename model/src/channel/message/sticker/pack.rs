@@ -0,0 +1,144 @@
+use super::{Sticker, StickerBannerAssetId, StickerId, StickerPackId, StickerPackSkuId};
+use serde::{Deserialize, Serialize};
+
+/// Pack of [`Sticker`]s available to Nitro subscribers.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct StickerPack {
+    /// ID of the pack's banner image.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub banner_asset_id: Option<StickerBannerAssetId>,
+    /// ID of the sticker shown as the pack's icon.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cover_sticker_id: Option<StickerId>,
+    /// Description of the pack.
+    pub description: String,
+    /// Unique ID of the pack.
+    pub id: StickerPackId,
+    /// Name of the pack.
+    pub name: String,
+    /// ID of the pack's SKU.
+    pub sku_id: StickerPackSkuId,
+    /// Stickers in the pack.
+    pub stickers: Vec<Sticker>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Sticker, StickerBannerAssetId, StickerId, StickerPack, StickerPackId, StickerPackSkuId,
+    };
+    use crate::channel::message::sticker::StickerFormatType;
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_fields!(
+        StickerPack: banner_asset_id,
+        cover_sticker_id,
+        description,
+        id,
+        name,
+        sku_id,
+        stickers
+    );
+
+    assert_impl_all!(
+        StickerPack: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
+
+    #[test]
+    fn test_full() {
+        let value = StickerPack {
+            banner_asset_id: Some(StickerBannerAssetId(761_773_777_976_819_732)),
+            cover_sticker_id: Some(StickerId(2)),
+            description: "Say hello to Wumpus!".to_owned(),
+            id: StickerPackId(1),
+            name: "Wumpus Beyond".to_owned(),
+            sku_id: StickerPackSkuId(3),
+            stickers: vec![Sticker {
+                available: false,
+                description: "Wumpus waves hello".to_owned(),
+                format_type: StickerFormatType::Lottie,
+                guild_id: None,
+                id: StickerId(2),
+                name: "Wave".to_owned(),
+                pack_id: Some(StickerPackId(1)),
+                sort_value: Some(12),
+                tags: "wumpus, hello, sup, hi, oi, heyo, heya, yo, wave".to_owned(),
+                user: None,
+            }],
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "StickerPack",
+                    len: 7,
+                },
+                Token::Str("banner_asset_id"),
+                Token::Some,
+                Token::NewtypeStruct {
+                    name: "StickerBannerAssetId",
+                },
+                Token::Str("761773777976819732"),
+                Token::Str("cover_sticker_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "StickerId" },
+                Token::Str("2"),
+                Token::Str("description"),
+                Token::Str("Say hello to Wumpus!"),
+                Token::Str("id"),
+                Token::NewtypeStruct {
+                    name: "StickerPackId",
+                },
+                Token::Str("1"),
+                Token::Str("name"),
+                Token::Str("Wumpus Beyond"),
+                Token::Str("sku_id"),
+                Token::NewtypeStruct {
+                    name: "StickerPackSkuId",
+                },
+                Token::Str("3"),
+                Token::Str("stickers"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "Sticker",
+                    len: 7,
+                },
+                Token::Str("description"),
+                Token::Str("Wumpus waves hello"),
+                Token::Str("format_type"),
+                Token::U8(StickerFormatType::Lottie as u8),
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "StickerId" },
+                Token::Str("2"),
+                Token::Str("name"),
+                Token::Str("Wave"),
+                Token::Str("pack_id"),
+                Token::Some,
+                Token::NewtypeStruct {
+                    name: "StickerPackId",
+                },
+                Token::Str("1"),
+                Token::Str("sort_value"),
+                Token::Some,
+                Token::U64(12),
+                Token::Str("tags"),
+                Token::Str("wumpus, hello, sup, hi, oi, heyo, heya, yo, wave"),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+}
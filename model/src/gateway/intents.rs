@@ -189,6 +189,21 @@ bitflags! {
         /// [`TYPING_START`]: super::event::Event::TypingStart
         /// [`GUILD_MESSAGE_TYPING`]: Self::GUILD_MESSAGE_TYPING
         const DIRECT_MESSAGE_TYPING = 1 << 14;
+        /// Guild scheduled events intent.
+        ///
+        /// Event(s) received:
+        ///  - [`GUILD_SCHEDULED_EVENT_CREATE`]
+        ///  - [`GUILD_SCHEDULED_EVENT_UPDATE`]
+        ///  - [`GUILD_SCHEDULED_EVENT_DELETE`]
+        ///  - [`GUILD_SCHEDULED_EVENT_USER_ADD`]
+        ///  - [`GUILD_SCHEDULED_EVENT_USER_REMOVE`]
+        ///
+        /// [`GUILD_SCHEDULED_EVENT_CREATE`]: super::event::Event::GuildScheduledEventCreate
+        /// [`GUILD_SCHEDULED_EVENT_UPDATE`]: super::event::Event::GuildScheduledEventUpdate
+        /// [`GUILD_SCHEDULED_EVENT_DELETE`]: super::event::Event::GuildScheduledEventDelete
+        /// [`GUILD_SCHEDULED_EVENT_USER_ADD`]: super::event::Event::GuildScheduledEventUserAdd
+        /// [`GUILD_SCHEDULED_EVENT_USER_REMOVE`]: super::event::Event::GuildScheduledEventUserRemove
+        const GUILD_SCHEDULED_EVENTS = 1 << 16;
     }
 }
 
@@ -229,5 +244,6 @@ mod tests {
         serde_test::assert_tokens(&Intents::DIRECT_MESSAGES, &[Token::U64(1 << 12)]);
         serde_test::assert_tokens(&Intents::DIRECT_MESSAGE_REACTIONS, &[Token::U64(1 << 13)]);
         serde_test::assert_tokens(&Intents::DIRECT_MESSAGE_TYPING, &[Token::U64(1 << 14)]);
+        serde_test::assert_tokens(&Intents::GUILD_SCHEDULED_EVENTS, &[Token::U64(1 << 16)]);
     }
 }
@@ -0,0 +1,46 @@
+use crate::id::{GuildId, ScheduledEventId, UserId};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct GuildScheduledEventUserAdd {
+    pub guild_id: GuildId,
+    pub guild_scheduled_event_id: ScheduledEventId,
+    pub user_id: UserId,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GuildId, GuildScheduledEventUserAdd, ScheduledEventId, UserId};
+    use serde_test::Token;
+
+    #[test]
+    fn test_guild_scheduled_event_user_add() {
+        let value = GuildScheduledEventUserAdd {
+            guild_id: GuildId(1),
+            guild_scheduled_event_id: ScheduledEventId(2),
+            user_id: UserId(3),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "GuildScheduledEventUserAdd",
+                    len: 3,
+                },
+                Token::Str("guild_id"),
+                Token::NewtypeStruct { name: "GuildId" },
+                Token::Str("1"),
+                Token::Str("guild_scheduled_event_id"),
+                Token::NewtypeStruct {
+                    name: "ScheduledEventId",
+                },
+                Token::Str("2"),
+                Token::Str("user_id"),
+                Token::NewtypeStruct { name: "UserId" },
+                Token::Str("3"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
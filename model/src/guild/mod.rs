@@ -44,6 +44,7 @@ use crate::{
     channel::{GuildChannel, StageInstance},
     gateway::presence::Presence,
     id::{ApplicationId, ChannelId, GuildId, UserId},
+    scheduled_event::GuildScheduledEvent,
     voice::voice_state::VoiceState,
 };
 use serde::{
@@ -70,6 +71,8 @@ pub struct Guild {
     pub emojis: Vec<Emoji>,
     pub explicit_content_filter: ExplicitContentFilter,
     pub features: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub guild_scheduled_events: Vec<GuildScheduledEvent>,
     pub icon: Option<String>,
     pub id: GuildId,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -140,6 +143,7 @@ impl<'de> Deserialize<'de> for Guild {
             Emojis,
             ExplicitContentFilter,
             Features,
+            GuildScheduledEvents,
             Icon,
             Id,
             JoinedAt,
@@ -198,6 +202,7 @@ impl<'de> Deserialize<'de> for Guild {
                 let mut emojis = None;
                 let mut explicit_content_filter = None;
                 let mut features = None;
+                let mut guild_scheduled_events = None::<Vec<GuildScheduledEvent>>;
                 let mut icon = None::<Option<_>>;
                 let mut id = None;
                 let mut joined_at = None::<Option<_>>;
@@ -349,6 +354,13 @@ impl<'de> Deserialize<'de> for Guild {
 
                             features = Some(map.next_value()?);
                         }
+                        Field::GuildScheduledEvents => {
+                            if guild_scheduled_events.is_some() {
+                                return Err(DeError::duplicate_field("guild_scheduled_events"));
+                            }
+
+                            guild_scheduled_events = Some(map.next_value()?);
+                        }
                         Field::Icon => {
                             if icon.is_some() {
                                 return Err(DeError::duplicate_field("icon"));
@@ -608,6 +620,7 @@ impl<'de> Deserialize<'de> for Guild {
                 let description = description.unwrap_or_default();
                 let discovery_splash = discovery_splash.unwrap_or_default();
                 let emojis = emojis.unwrap_or_default();
+                let guild_scheduled_events = guild_scheduled_events.unwrap_or_default();
                 let icon = icon.unwrap_or_default();
                 let large = large.unwrap_or_default();
                 let joined_at = joined_at.unwrap_or_default();
@@ -647,6 +660,7 @@ impl<'de> Deserialize<'de> for Guild {
                     ?emojis,
                     ?explicit_content_filter,
                     ?features,
+                    ?guild_scheduled_events,
                     ?icon,
                     %id,
                     ?large,
@@ -752,6 +766,7 @@ impl<'de> Deserialize<'de> for Guild {
                     emojis,
                     explicit_content_filter,
                     features,
+                    guild_scheduled_events,
                     icon,
                     id,
                     joined_at,
@@ -802,6 +817,7 @@ impl<'de> Deserialize<'de> for Guild {
             "emojis",
             "explicit_content_filter",
             "features",
+            "guild_scheduled_events",
             "icon",
             "id",
             "joined_at",
@@ -865,6 +881,7 @@ mod tests {
             emojis: Vec::new(),
             explicit_content_filter: ExplicitContentFilter::MembersWithoutRole,
             features: vec!["a feature".to_owned()],
+            guild_scheduled_events: Vec::new(),
             icon: Some("icon hash".to_owned()),
             id: GuildId(1),
             joined_at: Some("timestamp".to_owned()),
@@ -40,6 +40,8 @@ bitflags! {
         const MANAGE_EMOJIS = 0x4000_0000;
         const USE_SLASH_COMMANDS = 0x8000_0000;
         const REQUEST_TO_SPEAK = 0x10000_0000;
+        /// Allows for creating, editing, and deleting scheduled events.
+        const MANAGE_EVENTS = 0x0002_0000_0000;
         /// Allows for deleting and archiving threads, and viewing all private threads.
         const MANAGE_THREADS = 0x40000_0000;
         /// Allows for creating public threads.
@@ -107,6 +109,7 @@ mod tests {
         Serialize,
         Sync
     );
+    const_assert_eq!(0x0002_0000_0000, Permissions::MANAGE_EVENTS.bits());
     const_assert_eq!(0x0008_0000_0000, Permissions::CREATE_PUBLIC_THREADS.bits());
     const_assert_eq!(0x0010_0000_0000, Permissions::CREATE_PRIVATE_THREADS.bits());
     const_assert_eq!(
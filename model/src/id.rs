@@ -257,6 +257,23 @@ impl From<u64> for RoleId {
     }
 }
 
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
+)]
+pub struct ScheduledEventId(#[serde(with = "string")] pub u64);
+
+impl Display for ScheduledEventId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl From<u64> for ScheduledEventId {
+    fn from(id: u64) -> Self {
+        ScheduledEventId(id)
+    }
+}
+
 #[derive(
     Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
 )]
@@ -312,7 +329,8 @@ impl From<u64> for WebhookId {
 mod tests {
     use super::{
         ApplicationId, AttachmentId, AuditLogEntryId, ChannelId, CommandId, EmojiId, GenericId,
-        GuildId, IntegrationId, InteractionId, MessageId, RoleId, StageId, UserId, WebhookId,
+        GuildId, IntegrationId, InteractionId, MessageId, RoleId, ScheduledEventId, StageId,
+        UserId, WebhookId,
     };
     use serde_test::Token;
 
@@ -507,6 +525,24 @@ mod tests {
                 Token::U64(114_941_315_417_899_012),
             ],
         );
+        serde_test::assert_tokens(
+            &ScheduledEventId(114_941_315_417_899_012),
+            &[
+                Token::NewtypeStruct {
+                    name: "ScheduledEventId",
+                },
+                Token::Str("114941315417899012"),
+            ],
+        );
+        serde_test::assert_de_tokens(
+            &ScheduledEventId(114_941_315_417_899_012),
+            &[
+                Token::NewtypeStruct {
+                    name: "ScheduledEventId",
+                },
+                Token::U64(114_941_315_417_899_012),
+            ],
+        );
         serde_test::assert_tokens(
             &StageId(114_941_315_417_899_012),
             &[
@@ -0,0 +1,116 @@
+use super::Scope;
+use serde::{Deserialize, Serialize};
+
+/// Access token granted by exchanging an authorization code, refreshing a
+/// token, or a client credentials grant.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct AccessTokenResponse {
+    /// Token to authorize requests with.
+    pub access_token: String,
+    /// Number of seconds from when the token was granted until it expires.
+    pub expires_in: u64,
+    /// Token to request a new access token with once this one expires.
+    ///
+    /// Not present for tokens granted via client credentials.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
+    /// Scopes the token has been granted.
+    #[serde(with = "scopes")]
+    pub scope: Vec<Scope>,
+    /// Type of the token, which is `Bearer`.
+    pub token_type: String,
+}
+
+/// (De)serialize a list of scopes as a space separated string.
+mod scopes {
+    use super::Scope;
+    use serde::{
+        de::{
+            value::{Error as ValueError, StrDeserializer},
+            Error as DeError, IntoDeserializer,
+        },
+        Deserialize, Deserializer, Serializer,
+    };
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Scope>, D::Error> {
+        let value = String::deserialize(deserializer)?;
+
+        value
+            .split_whitespace()
+            .map(|scope| {
+                let deserializer: StrDeserializer<'_, ValueError> = scope.into_deserializer();
+
+                Scope::deserialize(deserializer).map_err(D::Error::custom)
+            })
+            .collect()
+    }
+
+    #[allow(clippy::ptr_arg)]
+    pub fn serialize<S: Serializer>(value: &Vec<Scope>, serializer: S) -> Result<S::Ok, S::Error> {
+        let scopes = value.iter().map(|scope| scope.as_str()).collect::<Vec<_>>();
+
+        serializer.serialize_str(&scopes.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AccessTokenResponse, Scope};
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_fields!(
+        AccessTokenResponse: access_token,
+        expires_in,
+        refresh_token,
+        scope,
+        token_type
+    );
+
+    assert_impl_all!(
+        AccessTokenResponse: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
+
+    #[test]
+    fn test_access_token_response() {
+        let value = AccessTokenResponse {
+            access_token: "6qrZcUqja7812RVdnEKjpzOL4CvHBFG".to_owned(),
+            expires_in: 604_800,
+            refresh_token: Some("D43f5y0ahjqew82jZ4NViEr2YafMKhue".to_owned()),
+            scope: vec![Scope::Identify, Scope::Guilds],
+            token_type: "Bearer".to_owned(),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "AccessTokenResponse",
+                    len: 5,
+                },
+                Token::Str("access_token"),
+                Token::Str("6qrZcUqja7812RVdnEKjpzOL4CvHBFG"),
+                Token::Str("expires_in"),
+                Token::U64(604_800),
+                Token::Str("refresh_token"),
+                Token::Some,
+                Token::Str("D43f5y0ahjqew82jZ4NViEr2YafMKhue"),
+                Token::Str("scope"),
+                Token::Str("identify guilds"),
+                Token::Str("token_type"),
+                Token::Str("Bearer"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
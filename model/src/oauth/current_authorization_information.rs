@@ -0,0 +1,147 @@
+use super::Scope;
+use crate::{id::ApplicationId, user::User};
+use serde::{Deserialize, Serialize};
+
+/// Information about the authorization of the current `OAuth2` access token.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct CurrentAuthorizationInformation {
+    /// Application the token was granted to.
+    pub application: AuthorizationApplication,
+    /// ISO 8601 timestamp of when the token expires.
+    pub expires: String,
+    /// Scopes the token has been granted.
+    pub scopes: Vec<Scope>,
+    /// User who authorized the application.
+    ///
+    /// Only present if the token has been granted the [`Scope::Identify`]
+    /// scope.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+}
+
+/// Application an `OAuth2` access token was granted to.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct AuthorizationApplication {
+    /// Whether users other than the owner can add the application's bot to
+    /// guilds.
+    pub bot_public: bool,
+    /// Whether adding the application's bot requires a full authorization
+    /// code grant.
+    pub bot_require_code_grant: bool,
+    /// Description of the application.
+    pub description: String,
+    /// Hash of the application's icon.
+    pub icon: Option<String>,
+    /// ID of the application.
+    pub id: ApplicationId,
+    /// Name of the application.
+    pub name: String,
+    /// Hex encoded key used to verify interactions sent to the application.
+    pub verify_key: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AuthorizationApplication, CurrentAuthorizationInformation, Scope};
+    use crate::id::ApplicationId;
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_fields!(
+        AuthorizationApplication: bot_public,
+        bot_require_code_grant,
+        description,
+        icon,
+        id,
+        name,
+        verify_key
+    );
+    assert_fields!(CurrentAuthorizationInformation: application, expires, scopes, user);
+
+    assert_impl_all!(
+        AuthorizationApplication: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
+    assert_impl_all!(
+        CurrentAuthorizationInformation: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
+
+    #[test]
+    fn test_current_authorization_information() {
+        let value = CurrentAuthorizationInformation {
+            application: AuthorizationApplication {
+                bot_public: true,
+                bot_require_code_grant: false,
+                description: "a test application".to_owned(),
+                icon: None,
+                id: ApplicationId(1),
+                name: "test".to_owned(),
+                verify_key: "1e0a356058d627ca38a5c8c9648818061d49e49bd9da9e3ab17d98ad4d6bg2u8"
+                    .to_owned(),
+            },
+            expires: "2021-08-01T00:00:00.000000+00:00".to_owned(),
+            scopes: vec![Scope::Guilds],
+            user: None,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "CurrentAuthorizationInformation",
+                    len: 3,
+                },
+                Token::Str("application"),
+                Token::Struct {
+                    name: "AuthorizationApplication",
+                    len: 7,
+                },
+                Token::Str("bot_public"),
+                Token::Bool(true),
+                Token::Str("bot_require_code_grant"),
+                Token::Bool(false),
+                Token::Str("description"),
+                Token::Str("a test application"),
+                Token::Str("icon"),
+                Token::None,
+                Token::Str("id"),
+                Token::NewtypeStruct {
+                    name: "ApplicationId",
+                },
+                Token::Str("1"),
+                Token::Str("name"),
+                Token::Str("test"),
+                Token::Str("verify_key"),
+                Token::Str("1e0a356058d627ca38a5c8c9648818061d49e49bd9da9e3ab17d98ad4d6bg2u8"),
+                Token::StructEnd,
+                Token::Str("expires"),
+                Token::Str("2021-08-01T00:00:00.000000+00:00"),
+                Token::Str("scopes"),
+                Token::Seq { len: Some(1) },
+                Token::UnitVariant {
+                    name: "Scope",
+                    variant: "guilds",
+                },
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,142 @@
+use serde::{Deserialize, Serialize};
+
+/// Permission that an `OAuth2` access token may be granted.
+///
+/// Refer to [the discord docs] for the endpoints and actions each scope
+/// grants access to.
+///
+/// [the discord docs]: https://discord.com/developers/docs/topics/oauth2#shared-resources-oauth2-scopes
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[non_exhaustive]
+pub enum Scope {
+    /// Read the activities of the user.
+    #[serde(rename = "activities.read")]
+    ActivitiesRead,
+    /// Update the activities of the user.
+    #[serde(rename = "activities.write")]
+    ActivitiesWrite,
+    /// Read build data of the user's applications.
+    #[serde(rename = "applications.builds.read")]
+    ApplicationsBuildsRead,
+    /// Upload and update builds of the user's applications.
+    #[serde(rename = "applications.builds.upload")]
+    ApplicationsBuildsUpload,
+    /// Use application commands in a guild.
+    #[serde(rename = "applications.commands")]
+    ApplicationsCommands,
+    /// Update the application's commands with a bearer token.
+    #[serde(rename = "applications.commands.update")]
+    ApplicationsCommandsUpdate,
+    /// Read the entitlements of the user's applications.
+    #[serde(rename = "applications.entitlements")]
+    ApplicationsEntitlements,
+    /// Read and update store data of the user's applications.
+    #[serde(rename = "applications.store.update")]
+    ApplicationsStoreUpdate,
+    /// Add the application's bot to a guild.
+    #[serde(rename = "bot")]
+    Bot,
+    /// Read the third-party connections of the user.
+    #[serde(rename = "connections")]
+    Connections,
+    /// Read the email address of the user.
+    #[serde(rename = "email")]
+    Email,
+    /// Join the user to a group DM.
+    #[serde(rename = "gdm.join")]
+    GroupDmJoin,
+    /// Read the guilds the user is in.
+    #[serde(rename = "guilds")]
+    Guilds,
+    /// Join the user to a guild.
+    #[serde(rename = "guilds.join")]
+    GuildsJoin,
+    /// Read the username, avatar, and discriminator of the user.
+    #[serde(rename = "identify")]
+    Identify,
+    /// Read messages from all client channels via local RPC.
+    #[serde(rename = "messages.read")]
+    MessagesRead,
+    /// Read the friends and blocked users of the user.
+    #[serde(rename = "relationships.read")]
+    RelationshipsRead,
+    /// Control the user's local Discord client.
+    #[serde(rename = "rpc")]
+    Rpc,
+    /// Receive notifications pushed to the user's local Discord client.
+    #[serde(rename = "rpc.notifications.read")]
+    RpcNotificationsRead,
+    /// Create a webhook in a channel chosen by the user.
+    #[serde(rename = "webhook.incoming")]
+    WebhookIncoming,
+}
+
+impl Scope {
+    /// Name of the scope as used by the API.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::ActivitiesRead => "activities.read",
+            Self::ActivitiesWrite => "activities.write",
+            Self::ApplicationsBuildsRead => "applications.builds.read",
+            Self::ApplicationsBuildsUpload => "applications.builds.upload",
+            Self::ApplicationsCommands => "applications.commands",
+            Self::ApplicationsCommandsUpdate => "applications.commands.update",
+            Self::ApplicationsEntitlements => "applications.entitlements",
+            Self::ApplicationsStoreUpdate => "applications.store.update",
+            Self::Bot => "bot",
+            Self::Connections => "connections",
+            Self::Email => "email",
+            Self::GroupDmJoin => "gdm.join",
+            Self::Guilds => "guilds",
+            Self::GuildsJoin => "guilds.join",
+            Self::Identify => "identify",
+            Self::MessagesRead => "messages.read",
+            Self::RelationshipsRead => "relationships.read",
+            Self::Rpc => "rpc",
+            Self::RpcNotificationsRead => "rpc.notifications.read",
+            Self::WebhookIncoming => "webhook.incoming",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Scope;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        let scopes = [
+            Scope::ActivitiesRead,
+            Scope::ActivitiesWrite,
+            Scope::ApplicationsBuildsRead,
+            Scope::ApplicationsBuildsUpload,
+            Scope::ApplicationsCommands,
+            Scope::ApplicationsCommandsUpdate,
+            Scope::ApplicationsEntitlements,
+            Scope::ApplicationsStoreUpdate,
+            Scope::Bot,
+            Scope::Connections,
+            Scope::Email,
+            Scope::GroupDmJoin,
+            Scope::Guilds,
+            Scope::GuildsJoin,
+            Scope::Identify,
+            Scope::MessagesRead,
+            Scope::RelationshipsRead,
+            Scope::Rpc,
+            Scope::RpcNotificationsRead,
+            Scope::WebhookIncoming,
+        ];
+
+        for scope in &scopes {
+            serde_test::assert_tokens(
+                scope,
+                &[Token::UnitVariant {
+                    name: "Scope",
+                    variant: scope.as_str(),
+                }],
+            );
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Type of location a [`GuildScheduledEvent`] takes place in.
+///
+/// [`GuildScheduledEvent`]: super::GuildScheduledEvent
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
+)]
+#[repr(u8)]
+pub enum EntityType {
+    /// Event takes place in a stage channel.
+    StageInstance = 1,
+    /// Event takes place in a voice channel.
+    Voice = 2,
+    /// Event takes place outside of Discord, at a location.
+    External = 3,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EntityType;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&EntityType::StageInstance, &[Token::U8(1)]);
+        serde_test::assert_tokens(&EntityType::Voice, &[Token::U8(2)]);
+        serde_test::assert_tokens(&EntityType::External, &[Token::U8(3)]);
+    }
+}
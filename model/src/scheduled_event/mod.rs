@@ -0,0 +1,305 @@
+//! Scheduled events of guilds.
+//!
+//! See the [Discord documentation] for more information.
+//!
+//! [Discord documentation]: https://discord.com/developers/docs/resources/guild-scheduled-event
+
+mod entity_type;
+mod privacy_level;
+mod recurrence;
+mod status;
+
+pub use self::{
+    entity_type::EntityType,
+    privacy_level::PrivacyLevel,
+    recurrence::{RecurrenceFrequency, RecurrenceNWeekday, RecurrenceRule, RecurrenceWeekday},
+    status::Status,
+};
+
+use crate::{
+    guild::PartialMember,
+    id::{ChannelId, GenericId, GuildId, ScheduledEventId, UserId},
+    user::User,
+};
+use serde::{Deserialize, Serialize};
+
+/// Event scheduled to take place in a guild.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct GuildScheduledEvent {
+    /// ID of the stage or voice channel the event takes place in.
+    ///
+    /// This is `None` for [`EntityType::External`] events.
+    pub channel_id: Option<ChannelId>,
+    /// User who created the event.
+    ///
+    /// This is not present for events created before October 25th, 2021.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creator: Option<User>,
+    /// ID of the user who created the event.
+    ///
+    /// This is `None` for events created before October 25th, 2021.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator_id: Option<UserId>,
+    /// Description of the event.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// ID of the entity associated with the event, such as a stage instance.
+    pub entity_id: Option<GenericId>,
+    /// Additional data about the event's location.
+    ///
+    /// This is only present for [`EntityType::External`] events.
+    pub entity_metadata: Option<EntityMetadata>,
+    /// Type of location the event takes place in.
+    pub entity_type: EntityType,
+    /// ID of the guild the event is in.
+    pub guild_id: GuildId,
+    /// ID of the event.
+    pub id: ScheduledEventId,
+    /// Hash of the event's cover image.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    /// Name of the event.
+    pub name: String,
+    /// Who can see the event.
+    pub privacy_level: PrivacyLevel,
+    /// Rules by which the event repeats, if it does.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recurrence_rule: Option<RecurrenceRule>,
+    /// ISO 8601 timestamp of when the event ends.
+    ///
+    /// This is required for [`EntityType::External`] events.
+    pub scheduled_end_time: Option<String>,
+    /// ISO 8601 timestamp of when the event starts.
+    pub scheduled_start_time: String,
+    /// Status of the event.
+    pub status: Status,
+    /// Number of users subscribed to the event.
+    ///
+    /// This is only present when requested with `with_user_count`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_count: Option<u64>,
+}
+
+/// Additional data about the location of a [`GuildScheduledEvent`].
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct EntityMetadata {
+    /// Location of the event, between 1 and 100 characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+}
+
+/// User subscribed to a [`GuildScheduledEvent`].
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct GuildScheduledEventUser {
+    /// ID of the event the user is subscribed to.
+    pub guild_scheduled_event_id: ScheduledEventId,
+    /// Guild member data of the user.
+    ///
+    /// This is only present when requested with `with_member`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member: Option<PartialMember>,
+    /// User subscribed to the event.
+    pub user: User,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        EntityMetadata, EntityType, GuildScheduledEvent, GuildScheduledEventUser, PrivacyLevel,
+        Status,
+    };
+    use crate::id::{ChannelId, GenericId, GuildId, ScheduledEventId, UserId};
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_fields!(
+        GuildScheduledEvent: channel_id,
+        creator,
+        creator_id,
+        description,
+        entity_id,
+        entity_metadata,
+        entity_type,
+        guild_id,
+        id,
+        image,
+        name,
+        privacy_level,
+        recurrence_rule,
+        scheduled_end_time,
+        scheduled_start_time,
+        status,
+        user_count
+    );
+    assert_fields!(EntityMetadata: location);
+    assert_fields!(GuildScheduledEventUser: guild_scheduled_event_id, member, user);
+    assert_impl_all!(
+        GuildScheduledEvent: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+    assert_impl_all!(
+        GuildScheduledEventUser: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[test]
+    fn test_stage_instance() {
+        let value = GuildScheduledEvent {
+            channel_id: Some(ChannelId(2)),
+            creator: None,
+            creator_id: Some(UserId(3)),
+            description: None,
+            entity_id: Some(GenericId(4)),
+            entity_metadata: None,
+            entity_type: EntityType::StageInstance,
+            guild_id: GuildId(1),
+            id: ScheduledEventId(5),
+            image: None,
+            name: "event".to_owned(),
+            privacy_level: PrivacyLevel::GuildOnly,
+            recurrence_rule: None,
+            scheduled_end_time: None,
+            scheduled_start_time: "2021-01-01T00:00:00+00:00".to_owned(),
+            status: Status::Scheduled,
+            user_count: None,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "GuildScheduledEvent",
+                    len: 12,
+                },
+                Token::Str("channel_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "ChannelId" },
+                Token::Str("2"),
+                Token::Str("creator_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "UserId" },
+                Token::Str("3"),
+                Token::Str("entity_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "GenericId" },
+                Token::Str("4"),
+                Token::Str("entity_metadata"),
+                Token::None,
+                Token::Str("entity_type"),
+                Token::U8(1),
+                Token::Str("guild_id"),
+                Token::NewtypeStruct { name: "GuildId" },
+                Token::Str("1"),
+                Token::Str("id"),
+                Token::NewtypeStruct {
+                    name: "ScheduledEventId",
+                },
+                Token::Str("5"),
+                Token::Str("name"),
+                Token::Str("event"),
+                Token::Str("privacy_level"),
+                Token::U8(2),
+                Token::Str("scheduled_end_time"),
+                Token::None,
+                Token::Str("scheduled_start_time"),
+                Token::Str("2021-01-01T00:00:00+00:00"),
+                Token::Str("status"),
+                Token::U8(1),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_external() {
+        let value = GuildScheduledEvent {
+            channel_id: None,
+            creator: None,
+            creator_id: None,
+            description: Some("description".to_owned()),
+            entity_id: None,
+            entity_metadata: Some(EntityMetadata {
+                location: Some("park".to_owned()),
+            }),
+            entity_type: EntityType::External,
+            guild_id: GuildId(1),
+            id: ScheduledEventId(5),
+            image: None,
+            name: "event".to_owned(),
+            privacy_level: PrivacyLevel::GuildOnly,
+            recurrence_rule: None,
+            scheduled_end_time: Some("2021-01-02T00:00:00+00:00".to_owned()),
+            scheduled_start_time: "2021-01-01T00:00:00+00:00".to_owned(),
+            status: Status::Active,
+            user_count: Some(7),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "GuildScheduledEvent",
+                    len: 13,
+                },
+                Token::Str("channel_id"),
+                Token::None,
+                Token::Str("description"),
+                Token::Some,
+                Token::Str("description"),
+                Token::Str("entity_id"),
+                Token::None,
+                Token::Str("entity_metadata"),
+                Token::Some,
+                Token::Struct {
+                    name: "EntityMetadata",
+                    len: 1,
+                },
+                Token::Str("location"),
+                Token::Some,
+                Token::Str("park"),
+                Token::StructEnd,
+                Token::Str("entity_type"),
+                Token::U8(3),
+                Token::Str("guild_id"),
+                Token::NewtypeStruct { name: "GuildId" },
+                Token::Str("1"),
+                Token::Str("id"),
+                Token::NewtypeStruct {
+                    name: "ScheduledEventId",
+                },
+                Token::Str("5"),
+                Token::Str("name"),
+                Token::Str("event"),
+                Token::Str("privacy_level"),
+                Token::U8(2),
+                Token::Str("scheduled_end_time"),
+                Token::Some,
+                Token::Str("2021-01-02T00:00:00+00:00"),
+                Token::Str("scheduled_start_time"),
+                Token::Str("2021-01-01T00:00:00+00:00"),
+                Token::Str("status"),
+                Token::U8(2),
+                Token::Str("user_count"),
+                Token::Some,
+                Token::U64(7),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
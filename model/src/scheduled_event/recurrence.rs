@@ -0,0 +1,152 @@
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// How often a [`RecurrenceRule`] repeats.
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
+)]
+#[repr(u8)]
+pub enum RecurrenceFrequency {
+    Yearly = 0,
+    Monthly = 1,
+    Weekly = 2,
+    Daily = 3,
+}
+
+/// Day of the week a [`RecurrenceRule`] repeats on.
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
+)]
+#[repr(u8)]
+pub enum RecurrenceWeekday {
+    Monday = 0,
+    Tuesday = 1,
+    Wednesday = 2,
+    Thursday = 3,
+    Friday = 4,
+    Saturday = 5,
+    Sunday = 6,
+}
+
+/// Specific occurrence of a day of the week within a month, such as the
+/// second Tuesday.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct RecurrenceNWeekday {
+    /// Day of the week.
+    pub day: RecurrenceWeekday,
+    /// Week of the month, from 1 to 5.
+    pub n: u8,
+}
+
+/// Rules by which a [`GuildScheduledEvent`] repeats.
+///
+/// [`GuildScheduledEvent`]: super::GuildScheduledEvent
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct RecurrenceRule {
+    /// Months the event repeats in, from 1 to 12.
+    pub by_month: Option<Vec<u8>>,
+    /// Days of the month the event repeats on, from 1 to 31.
+    pub by_month_day: Option<Vec<u8>>,
+    /// Specific days within a month the event repeats on.
+    pub by_n_weekday: Option<Vec<RecurrenceNWeekday>>,
+    /// Days of the week the event repeats on.
+    pub by_weekday: Option<Vec<RecurrenceWeekday>>,
+    /// Days of the year the event repeats on, from 1 to 364.
+    pub by_year_day: Option<Vec<u16>>,
+    /// Total number of times the event repeats.
+    pub count: Option<u32>,
+    /// ISO 8601 timestamp of when the recurrence ends.
+    pub end: Option<String>,
+    /// How often the event repeats.
+    pub frequency: RecurrenceFrequency,
+    /// Spacing between occurrences, in units of the frequency.
+    pub interval: u16,
+    /// ISO 8601 timestamp of when the recurrence starts.
+    pub start: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RecurrenceFrequency, RecurrenceNWeekday, RecurrenceRule, RecurrenceWeekday};
+    use serde_test::Token;
+
+    #[test]
+    fn test_frequency_variants() {
+        serde_test::assert_tokens(&RecurrenceFrequency::Yearly, &[Token::U8(0)]);
+        serde_test::assert_tokens(&RecurrenceFrequency::Monthly, &[Token::U8(1)]);
+        serde_test::assert_tokens(&RecurrenceFrequency::Weekly, &[Token::U8(2)]);
+        serde_test::assert_tokens(&RecurrenceFrequency::Daily, &[Token::U8(3)]);
+    }
+
+    #[test]
+    fn test_weekday_variants() {
+        serde_test::assert_tokens(&RecurrenceWeekday::Monday, &[Token::U8(0)]);
+        serde_test::assert_tokens(&RecurrenceWeekday::Tuesday, &[Token::U8(1)]);
+        serde_test::assert_tokens(&RecurrenceWeekday::Wednesday, &[Token::U8(2)]);
+        serde_test::assert_tokens(&RecurrenceWeekday::Thursday, &[Token::U8(3)]);
+        serde_test::assert_tokens(&RecurrenceWeekday::Friday, &[Token::U8(4)]);
+        serde_test::assert_tokens(&RecurrenceWeekday::Saturday, &[Token::U8(5)]);
+        serde_test::assert_tokens(&RecurrenceWeekday::Sunday, &[Token::U8(6)]);
+    }
+
+    #[test]
+    fn test_rule() {
+        let value = RecurrenceRule {
+            by_month: None,
+            by_month_day: None,
+            by_n_weekday: Some(vec![RecurrenceNWeekday {
+                day: RecurrenceWeekday::Tuesday,
+                n: 2,
+            }]),
+            by_weekday: None,
+            by_year_day: None,
+            count: None,
+            end: None,
+            frequency: RecurrenceFrequency::Monthly,
+            interval: 1,
+            start: "2021-11-09T18:00:00+00:00".to_owned(),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "RecurrenceRule",
+                    len: 10,
+                },
+                Token::Str("by_month"),
+                Token::None,
+                Token::Str("by_month_day"),
+                Token::None,
+                Token::Str("by_n_weekday"),
+                Token::Some,
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "RecurrenceNWeekday",
+                    len: 2,
+                },
+                Token::Str("day"),
+                Token::U8(1),
+                Token::Str("n"),
+                Token::U8(2),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::Str("by_weekday"),
+                Token::None,
+                Token::Str("by_year_day"),
+                Token::None,
+                Token::Str("count"),
+                Token::None,
+                Token::Str("end"),
+                Token::None,
+                Token::Str("frequency"),
+                Token::U8(1),
+                Token::Str("interval"),
+                Token::U16(1),
+                Token::Str("start"),
+                Token::Str("2021-11-09T18:00:00+00:00"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
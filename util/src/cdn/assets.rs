@@ -0,0 +1,222 @@
+use super::CdnUrl;
+use twilight_model::{
+    channel::message::sticker::{MessageSticker, Sticker},
+    guild::{Emoji, Guild, Member},
+    user::{CurrentUser, User},
+};
+
+/// URLs to the images of a user.
+pub trait UserAssets {
+    /// URL to the user's avatar, if they have one.
+    fn avatar_url(&self) -> Option<CdnUrl>;
+
+    /// URL to the user's banner, if they have one.
+    fn banner_url(&self) -> Option<CdnUrl>;
+
+    /// URL to the default avatar of the user, based on their discriminator.
+    fn default_avatar_url(&self) -> CdnUrl;
+
+    /// URL to the avatar displayed by the client, which is the user's avatar
+    /// or the default avatar if they don't have one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::{id::UserId, user::User};
+    /// use twilight_util::cdn::UserAssets;
+    ///
+    /// let user = User {
+    ///     accent_color: None,
+    ///     avatar: None,
+    ///     banner: None,
+    ///     bot: false,
+    ///     discriminator: "0006".to_owned(),
+    ///     email: None,
+    ///     flags: None,
+    ///     id: UserId(1),
+    ///     locale: None,
+    ///     mfa_enabled: None,
+    ///     name: "twilight".to_owned(),
+    ///     premium_type: None,
+    ///     public_flags: None,
+    ///     system: None,
+    ///     verified: None,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     "https://cdn.discordapp.com/embed/avatars/1.png",
+    ///     user.display_avatar_url().to_string(),
+    /// );
+    /// ```
+    fn display_avatar_url(&self) -> CdnUrl {
+        self.avatar_url()
+            .unwrap_or_else(|| self.default_avatar_url())
+    }
+}
+
+impl UserAssets for CurrentUser {
+    fn avatar_url(&self) -> Option<CdnUrl> {
+        self.avatar
+            .as_deref()
+            .map(|hash| CdnUrl::user_avatar(self.id, hash))
+    }
+
+    fn banner_url(&self) -> Option<CdnUrl> {
+        self.banner
+            .as_deref()
+            .map(|hash| CdnUrl::user_banner(self.id, hash))
+    }
+
+    fn default_avatar_url(&self) -> CdnUrl {
+        default_avatar(&self.discriminator)
+    }
+}
+
+impl UserAssets for Member {
+    fn avatar_url(&self) -> Option<CdnUrl> {
+        self.user.avatar_url()
+    }
+
+    fn banner_url(&self) -> Option<CdnUrl> {
+        self.user.banner_url()
+    }
+
+    fn default_avatar_url(&self) -> CdnUrl {
+        self.user.default_avatar_url()
+    }
+}
+
+impl UserAssets for User {
+    fn avatar_url(&self) -> Option<CdnUrl> {
+        self.avatar
+            .as_deref()
+            .map(|hash| CdnUrl::user_avatar(self.id, hash))
+    }
+
+    fn banner_url(&self) -> Option<CdnUrl> {
+        self.banner
+            .as_deref()
+            .map(|hash| CdnUrl::user_banner(self.id, hash))
+    }
+
+    fn default_avatar_url(&self) -> CdnUrl {
+        default_avatar(&self.discriminator)
+    }
+}
+
+/// URLs to the images of a guild.
+pub trait GuildAssets {
+    /// URL to the guild's banner, if it has one.
+    fn banner_url(&self) -> Option<CdnUrl>;
+
+    /// URL to the guild's discovery splash, if it has one.
+    fn discovery_splash_url(&self) -> Option<CdnUrl>;
+
+    /// URL to the guild's icon, if it has one.
+    fn icon_url(&self) -> Option<CdnUrl>;
+
+    /// URL to the guild's invite splash, if it has one.
+    fn splash_url(&self) -> Option<CdnUrl>;
+}
+
+impl GuildAssets for Guild {
+    fn banner_url(&self) -> Option<CdnUrl> {
+        self.banner
+            .as_deref()
+            .map(|hash| CdnUrl::guild_banner(self.id, hash))
+    }
+
+    fn discovery_splash_url(&self) -> Option<CdnUrl> {
+        self.discovery_splash
+            .as_deref()
+            .map(|hash| CdnUrl::guild_discovery_splash(self.id, hash))
+    }
+
+    fn icon_url(&self) -> Option<CdnUrl> {
+        self.icon
+            .as_deref()
+            .map(|hash| CdnUrl::guild_icon(self.id, hash))
+    }
+
+    fn splash_url(&self) -> Option<CdnUrl> {
+        self.splash
+            .as_deref()
+            .map(|hash| CdnUrl::guild_splash(self.id, hash))
+    }
+}
+
+/// URL to the image of a custom emoji.
+pub trait EmojiAssets {
+    /// URL to the emoji's image.
+    fn url(&self) -> CdnUrl;
+}
+
+impl EmojiAssets for Emoji {
+    fn url(&self) -> CdnUrl {
+        CdnUrl::emoji(self.id, self.animated)
+    }
+}
+
+/// URL to the image of a sticker.
+pub trait StickerAssets {
+    /// URL to the sticker's image or animation.
+    fn url(&self) -> CdnUrl;
+}
+
+impl StickerAssets for MessageSticker {
+    fn url(&self) -> CdnUrl {
+        CdnUrl::sticker(&self.id, self.format_type)
+    }
+}
+
+impl StickerAssets for Sticker {
+    fn url(&self) -> CdnUrl {
+        CdnUrl::sticker(&self.id, self.format_type)
+    }
+}
+
+/// URL to the default avatar of a discriminator, which is the first one if
+/// the discriminator is invalid.
+fn default_avatar(discriminator: &str) -> CdnUrl {
+    CdnUrl::default_user_avatar(discriminator.parse().unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UserAssets;
+    use twilight_model::{id::UserId, user::User};
+
+    fn user(avatar: Option<&str>) -> User {
+        User {
+            accent_color: None,
+            avatar: avatar.map(ToOwned::to_owned),
+            banner: None,
+            bot: false,
+            discriminator: "0004".to_owned(),
+            email: None,
+            flags: None,
+            id: UserId(2),
+            locale: None,
+            mfa_enabled: None,
+            name: "twilight".to_owned(),
+            premium_type: None,
+            public_flags: None,
+            system: None,
+            verified: None,
+        }
+    }
+
+    #[test]
+    fn test_user() {
+        let user = user(Some("a_hash"));
+        assert_eq!(
+            "https://cdn.discordapp.com/avatars/2/a_hash.gif",
+            user.display_avatar_url().to_string()
+        );
+        assert_eq!(
+            "https://cdn.discordapp.com/embed/avatars/4.png",
+            user.default_avatar_url().to_string()
+        );
+        assert!(user.banner_url().is_none());
+    }
+}
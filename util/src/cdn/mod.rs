@@ -0,0 +1,556 @@
+//! Utilities for building URLs to images and other assets hosted on Discord's
+//! CDN.
+//!
+//! Models such as [`User`] and [`Guild`] only carry the hashes of their
+//! images. [`CdnUrl`] turns an ID and hash into a URL, picking the GIF format
+//! for animated hashes, while the traits in this module provide shortcuts on
+//! the models themselves.
+//!
+//! # Examples
+//!
+//! Build the URL to a user's avatar with a specific size:
+//!
+//! ```
+//! use twilight_model::id::UserId;
+//! use twilight_util::cdn::{CdnUrl, ImageSize};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let url = CdnUrl::user_avatar(UserId(1), "a_1269e74af4df7417b13759eae50c83dc")
+//!     .size(ImageSize::new(256)?);
+//!
+//! assert_eq!(
+//!     "https://cdn.discordapp.com/avatars/1/a_1269e74af4df7417b13759eae50c83dc.gif?size=256",
+//!     url.to_string(),
+//! );
+//! # Ok(()) }
+//! ```
+//!
+//! [`Guild`]: twilight_model::guild::Guild
+//! [`User`]: twilight_model::user::User
+
+mod assets;
+
+pub use self::assets::{EmojiAssets, GuildAssets, StickerAssets, UserAssets};
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    channel::message::sticker::{StickerBannerAssetId, StickerFormatType, StickerId},
+    id::{ApplicationId, EmojiId, GuildId, RoleId, ScheduledEventId, UserId},
+    oauth::id::TeamId,
+};
+
+/// Base URL of Discord's CDN.
+const BASE: &str = "https://cdn.discordapp.com/";
+
+/// ID of the application owning the banners of sticker packs.
+const STICKER_PACK_APPLICATION_ID: u64 = 710_982_414_301_790_216;
+
+/// Formats of images that may be animated.
+const ANIMATED_FORMATS: &[ImageFormat] = &[
+    ImageFormat::Gif,
+    ImageFormat::Png,
+    ImageFormat::Jpeg,
+    ImageFormat::WebP,
+];
+
+/// Formats of images that are never animated.
+const STATIC_FORMATS: &[ImageFormat] = &[ImageFormat::Png, ImageFormat::Jpeg, ImageFormat::WebP];
+
+/// Error when configuring a [`CdnUrl`].
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug)]
+pub struct CdnError {
+    kind: CdnErrorType,
+}
+
+impl CdnError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &CdnErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (CdnErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, None)
+    }
+}
+
+impl Display for CdnError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.kind {
+            CdnErrorType::FormatUnsupported { format } => {
+                f.write_str("asset is not available in the ")?;
+                f.write_str(format.extension())?;
+
+                f.write_str(" format")
+            }
+            CdnErrorType::SizeInvalid { size } => {
+                f.write_str("size ")?;
+                Display::fmt(&size, f)?;
+
+                f.write_str(" is not a power of two between ")?;
+                Display::fmt(&ImageSize::MIN.get(), f)?;
+                f.write_str(" and ")?;
+
+                Display::fmt(&ImageSize::MAX.get(), f)
+            }
+        }
+    }
+}
+
+impl Error for CdnError {}
+
+/// Type of [`CdnError`] that occurred.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum CdnErrorType {
+    /// Asset is not available in the requested format.
+    ///
+    /// For example, GIFs are only available for animated images.
+    FormatUnsupported {
+        /// Requested format.
+        format: ImageFormat,
+    },
+    /// Size is not a power of two between [`ImageSize::MIN`] and
+    /// [`ImageSize::MAX`].
+    SizeInvalid {
+        /// Provided size.
+        size: u16,
+    },
+}
+
+/// Format of an asset on the CDN.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ImageFormat {
+    /// GIF, only available for animated images.
+    Gif,
+    /// JPEG.
+    Jpeg,
+    /// Lottie animation, only available for stickers of the
+    /// [`StickerFormatType::Lottie`] type.
+    Json,
+    /// PNG.
+    Png,
+    /// WebP.
+    WebP,
+}
+
+impl ImageFormat {
+    /// File extension of the format, such as `png`.
+    #[must_use = "retrieving the extension has no effect if left unused"]
+    pub const fn extension(self) -> &'static str {
+        match self {
+            Self::Gif => "gif",
+            Self::Jpeg => "jpg",
+            Self::Json => "json",
+            Self::Png => "png",
+            Self::WebP => "webp",
+        }
+    }
+}
+
+/// Size of an image on the CDN, which is a power of two between
+/// [`MIN`] and [`MAX`].
+///
+/// [`MAX`]: Self::MAX
+/// [`MIN`]: Self::MIN
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ImageSize(u16);
+
+impl ImageSize {
+    /// Largest size of an image.
+    pub const MAX: Self = Self(4096);
+
+    /// Smallest size of an image.
+    pub const MIN: Self = Self(16);
+
+    /// Create a new size, validating that it is allowed by the CDN.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CdnErrorType::SizeInvalid`] error type if the size is not
+    /// a power of two between [`MIN`] and [`MAX`].
+    ///
+    /// [`MAX`]: Self::MAX
+    /// [`MIN`]: Self::MIN
+    pub const fn new(size: u16) -> Result<Self, CdnError> {
+        if size < Self::MIN.0 || size > Self::MAX.0 || !size.is_power_of_two() {
+            return Err(CdnError {
+                kind: CdnErrorType::SizeInvalid { size },
+            });
+        }
+
+        Ok(Self(size))
+    }
+
+    /// Create the smallest allowed size that is at least as large as the
+    /// provided size, capped at [`MAX`].
+    ///
+    /// This is useful when displaying an image at an arbitrary size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_util::cdn::ImageSize;
+    ///
+    /// assert_eq!(128, ImageSize::nearest(100).get());
+    /// assert_eq!(16, ImageSize::nearest(0).get());
+    /// assert_eq!(4096, ImageSize::nearest(10_000).get());
+    /// ```
+    ///
+    /// [`MAX`]: Self::MAX
+    #[must_use = "creating a size has no effect if left unused"]
+    pub fn nearest(size: u16) -> Self {
+        let size = size.max(Self::MIN.0);
+
+        if size > Self::MAX.0 {
+            return Self::MAX;
+        }
+
+        Self(size.next_power_of_two())
+    }
+
+    /// Value of the size in pixels.
+    #[must_use = "retrieving the value has no effect if left unused"]
+    pub const fn get(self) -> u16 {
+        self.0
+    }
+}
+
+/// URL to an asset hosted on Discord's CDN.
+///
+/// Images default to the PNG format, or the GIF format if their hash marks
+/// them as animated. Use [`format`] and [`size`] to request a different
+/// format or size, and [`Display`] or [`ToString::to_string`] to retrieve the
+/// URL.
+///
+/// [`format`]: Self::format
+/// [`size`]: Self::size
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CdnUrl {
+    formats: &'static [ImageFormat],
+    format: ImageFormat,
+    path: String,
+    size: Option<ImageSize>,
+}
+
+impl CdnUrl {
+    /// Create a URL to an image identified by a hash, detecting whether it is
+    /// animated.
+    fn hashed(path: String, hash: &str) -> Self {
+        if is_animated(hash) {
+            Self::new(path, ANIMATED_FORMATS)
+        } else {
+            Self::new(path, STATIC_FORMATS)
+        }
+    }
+
+    /// Create a URL to an asset, defaulting to the first of the supported
+    /// formats.
+    fn new(path: String, formats: &'static [ImageFormat]) -> Self {
+        Self {
+            formats,
+            format: formats[0],
+            path,
+            size: None,
+        }
+    }
+
+    /// URL to the icon of an application.
+    #[must_use = "URLs must be displayed to be used"]
+    pub fn application_icon(application_id: ApplicationId, hash: &str) -> Self {
+        Self::hashed(format!("app-icons/{}/{}", application_id, hash), hash)
+    }
+
+    /// URL to the cover image of an application's store page.
+    #[must_use = "URLs must be displayed to be used"]
+    pub fn application_cover(application_id: ApplicationId, hash: &str) -> Self {
+        Self::hashed(format!("app-icons/{}/{}", application_id, hash), hash)
+    }
+
+    /// URL to the default avatar of users with a discriminator.
+    ///
+    /// Only the PNG format is available.
+    #[must_use = "URLs must be displayed to be used"]
+    pub fn default_user_avatar(discriminator: u16) -> Self {
+        Self::new(
+            format!("embed/avatars/{}", discriminator % 5),
+            &[ImageFormat::Png],
+        )
+    }
+
+    /// URL to a custom emoji.
+    #[must_use = "URLs must be displayed to be used"]
+    pub fn emoji(emoji_id: EmojiId, animated: bool) -> Self {
+        let formats = if animated {
+            ANIMATED_FORMATS
+        } else {
+            STATIC_FORMATS
+        };
+
+        Self::new(format!("emojis/{}", emoji_id), formats)
+    }
+
+    /// URL to the banner of a guild.
+    #[must_use = "URLs must be displayed to be used"]
+    pub fn guild_banner(guild_id: GuildId, hash: &str) -> Self {
+        Self::hashed(format!("banners/{}/{}", guild_id, hash), hash)
+    }
+
+    /// URL to the discovery splash of a guild.
+    #[must_use = "URLs must be displayed to be used"]
+    pub fn guild_discovery_splash(guild_id: GuildId, hash: &str) -> Self {
+        Self::hashed(format!("discovery-splashes/{}/{}", guild_id, hash), hash)
+    }
+
+    /// URL to the icon of a guild.
+    #[must_use = "URLs must be displayed to be used"]
+    pub fn guild_icon(guild_id: GuildId, hash: &str) -> Self {
+        Self::hashed(format!("icons/{}/{}", guild_id, hash), hash)
+    }
+
+    /// URL to the invite splash of a guild.
+    #[must_use = "URLs must be displayed to be used"]
+    pub fn guild_splash(guild_id: GuildId, hash: &str) -> Self {
+        Self::hashed(format!("splashes/{}/{}", guild_id, hash), hash)
+    }
+
+    /// URL to the avatar a member has set in a guild.
+    #[must_use = "URLs must be displayed to be used"]
+    pub fn member_avatar(guild_id: GuildId, user_id: UserId, hash: &str) -> Self {
+        Self::hashed(
+            format!("guilds/{}/users/{}/avatars/{}", guild_id, user_id, hash),
+            hash,
+        )
+    }
+
+    /// URL to the icon of a role.
+    #[must_use = "URLs must be displayed to be used"]
+    pub fn role_icon(role_id: RoleId, hash: &str) -> Self {
+        Self::hashed(format!("role-icons/{}/{}", role_id, hash), hash)
+    }
+
+    /// URL to the cover image of a scheduled event.
+    #[must_use = "URLs must be displayed to be used"]
+    pub fn scheduled_event_cover(scheduled_event_id: ScheduledEventId, hash: &str) -> Self {
+        Self::hashed(
+            format!("guild-events/{}/{}", scheduled_event_id, hash),
+            hash,
+        )
+    }
+
+    /// URL to a sticker.
+    ///
+    /// PNG and APNG stickers are only available in the PNG format, while
+    /// Lottie stickers are only available in the JSON format.
+    #[must_use = "URLs must be displayed to be used"]
+    pub fn sticker(sticker_id: &StickerId, format_type: StickerFormatType) -> Self {
+        let formats: &'static [ImageFormat] = match format_type {
+            StickerFormatType::Png | StickerFormatType::Apng => &[ImageFormat::Png],
+            StickerFormatType::Lottie => &[ImageFormat::Json],
+        };
+
+        Self::new(format!("stickers/{}", sticker_id), formats)
+    }
+
+    /// URL to the banner of a sticker pack.
+    #[must_use = "URLs must be displayed to be used"]
+    pub fn sticker_pack_banner(banner_asset_id: &StickerBannerAssetId) -> Self {
+        Self::new(
+            format!(
+                "app-assets/{}/store/{}",
+                STICKER_PACK_APPLICATION_ID, banner_asset_id
+            ),
+            STATIC_FORMATS,
+        )
+    }
+
+    /// URL to the icon of a team.
+    #[must_use = "URLs must be displayed to be used"]
+    pub fn team_icon(team_id: &TeamId, hash: &str) -> Self {
+        Self::hashed(format!("team-icons/{}/{}", team_id, hash), hash)
+    }
+
+    /// URL to the avatar of a user.
+    #[must_use = "URLs must be displayed to be used"]
+    pub fn user_avatar(user_id: UserId, hash: &str) -> Self {
+        Self::hashed(format!("avatars/{}/{}", user_id, hash), hash)
+    }
+
+    /// URL to the banner of a user.
+    #[must_use = "URLs must be displayed to be used"]
+    pub fn user_banner(user_id: UserId, hash: &str) -> Self {
+        Self::hashed(format!("banners/{}/{}", user_id, hash), hash)
+    }
+
+    /// Whether the asset is animated.
+    #[must_use = "retrieving whether the asset is animated has no effect if left unused"]
+    pub fn animated(&self) -> bool {
+        self.formats.contains(&ImageFormat::Gif)
+    }
+
+    /// Format of the asset.
+    #[must_use = "retrieving the format has no effect if left unused"]
+    pub const fn image_format(&self) -> ImageFormat {
+        self.format
+    }
+
+    /// Request the asset in a different format.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CdnErrorType::FormatUnsupported`] error type if the asset
+    /// is not available in the format, such as when requesting a GIF of an
+    /// image that isn't animated.
+    pub fn format(mut self, format: ImageFormat) -> Result<Self, CdnError> {
+        if !self.formats.contains(&format) {
+            return Err(CdnError {
+                kind: CdnErrorType::FormatUnsupported { format },
+            });
+        }
+
+        self.format = format;
+
+        Ok(self)
+    }
+
+    /// Request the asset with a specific size.
+    ///
+    /// Refer to [`ImageSize::nearest`] to round an arbitrary size.
+    #[must_use = "URLs must be displayed to be used"]
+    pub const fn size(mut self, size: ImageSize) -> Self {
+        self.size = Some(size);
+
+        self
+    }
+}
+
+impl Display for CdnUrl {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(BASE)?;
+        f.write_str(&self.path)?;
+        f.write_str(".")?;
+        f.write_str(self.format.extension())?;
+
+        if let Some(size) = self.size {
+            f.write_str("?size=")?;
+            Display::fmt(&size.get(), f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Whether an image hash marks the image as animated.
+fn is_animated(hash: &str) -> bool {
+    hash.starts_with("a_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CdnError, CdnErrorType, CdnUrl, ImageFormat, ImageSize};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug, hash::Hash};
+    use twilight_model::{
+        channel::message::sticker::{StickerBannerAssetId, StickerFormatType, StickerId},
+        id::{EmojiId, GuildId, UserId},
+    };
+
+    assert_impl_all!(CdnError: Error, Send, Sync);
+    assert_impl_all!(CdnErrorType: Debug, Send, Sync);
+    assert_impl_all!(CdnUrl: Clone, Debug, Eq, Hash, PartialEq, Send, Sync);
+    assert_impl_all!(ImageFormat: Clone, Copy, Debug, Eq, Hash, PartialEq, Send, Sync);
+    assert_impl_all!(ImageSize: Clone, Copy, Debug, Eq, Hash, Ord, Send, Sync);
+
+    #[test]
+    fn test_size() {
+        assert!(ImageSize::new(16).is_ok());
+        assert!(ImageSize::new(4096).is_ok());
+        assert!(matches!(
+            ImageSize::new(8).unwrap_err().kind(),
+            CdnErrorType::SizeInvalid { size: 8 }
+        ));
+        assert!(ImageSize::new(100).is_err());
+        assert!(ImageSize::new(8192).is_err());
+
+        assert_eq!(16, ImageSize::nearest(1).get());
+        assert_eq!(64, ImageSize::nearest(64).get());
+        assert_eq!(4096, ImageSize::nearest(4097).get());
+        assert_eq!(4096, ImageSize::nearest(u16::MAX).get());
+    }
+
+    #[test]
+    fn test_hashed() -> Result<(), CdnError> {
+        let url = CdnUrl::guild_icon(GuildId(1), "abc");
+        assert!(!url.animated());
+        assert_eq!(ImageFormat::Png, url.image_format());
+        assert_eq!(
+            "https://cdn.discordapp.com/icons/1/abc.png",
+            url.to_string()
+        );
+        assert!(matches!(
+            url.clone().format(ImageFormat::Gif).unwrap_err().kind(),
+            CdnErrorType::FormatUnsupported {
+                format: ImageFormat::Gif
+            }
+        ));
+        assert_eq!(
+            "https://cdn.discordapp.com/icons/1/abc.webp?size=64",
+            url.format(ImageFormat::WebP)?
+                .size(ImageSize::new(64)?)
+                .to_string()
+        );
+
+        let url = CdnUrl::member_avatar(GuildId(1), UserId(2), "a_abc");
+        assert!(url.animated());
+        assert_eq!(
+            "https://cdn.discordapp.com/guilds/1/users/2/avatars/a_abc.gif",
+            url.to_string()
+        );
+        assert_eq!(
+            "https://cdn.discordapp.com/guilds/1/users/2/avatars/a_abc.jpg",
+            url.format(ImageFormat::Jpeg)?.to_string()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fixed_formats() {
+        assert_eq!(
+            "https://cdn.discordapp.com/embed/avatars/2.png",
+            CdnUrl::default_user_avatar(7).to_string()
+        );
+        assert!(CdnUrl::default_user_avatar(7)
+            .format(ImageFormat::WebP)
+            .is_err());
+
+        assert_eq!(
+            "https://cdn.discordapp.com/emojis/1.gif",
+            CdnUrl::emoji(EmojiId(1), true).to_string()
+        );
+        assert_eq!(
+            "https://cdn.discordapp.com/stickers/2.json",
+            CdnUrl::sticker(&StickerId(2), StickerFormatType::Lottie).to_string()
+        );
+        assert_eq!(
+            "https://cdn.discordapp.com/app-assets/710982414301790216/store/3.png",
+            CdnUrl::sticker_pack_banner(&StickerBannerAssetId(3)).to_string()
+        );
+    }
+}
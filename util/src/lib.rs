@@ -7,6 +7,11 @@
 //!
 //! ## Features
 //!
+//! ### `cdn`
+//!
+//! Allows building URLs to images hosted on Discord's CDN, such as avatars,
+//! icons, emojis and stickers.
+//!
 //! ### `link`
 //!
 //! Provides implementations for parsing and formatting entities' URLs, such as
@@ -47,6 +52,10 @@
 #![allow(clippy::semicolon_if_nothing_returned)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+#[cfg(feature = "cdn")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cdn")))]
+pub mod cdn;
+
 #[cfg(feature = "link")]
 #[cfg_attr(docsrs, doc(cfg(feature = "link")))]
 pub mod link;